@@ -0,0 +1,242 @@
+//! Conditional downloads of static assets
+//!
+//! An [AssetCache](AssetCache) keeps downloaded assets (e.g. set icons and symbology SVGs)
+//! in a directory, along with their validators (`ETag` and `Last-Modified` headers).
+//!
+//! Cached assets are revalidated on every [fetch](AssetCache::fetch) with a conditional GET
+//! (`If-None-Match` and `If-Modified-Since`): unchanged assets are answered by the server
+//! with `304 Not Modified` and an empty body, and served from the cache,
+//! so that asset-heavy applications only download what changed.
+//!
+//! Assets are stored under deterministic file names derived from their URLs:
+//!
+//! ```text
+//! {host}/{path}
+//! ```
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::assets::AssetCache;
+//! use scryfall_sdk_rust::resources::card_sets::CardSet;
+//! use scryfall_sdk_rust::Scryfall;
+//!
+//! # async fn example(set: CardSet) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Scryfall::default();
+//! let cache = AssetCache::new("assets");
+//!
+//! let icon = cache.fetch(&client, &set.icon_svg_uri).await?;
+//! println!("{} ({:?})", icon.path.display(), icon.status);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use reqwest::header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use url::Url;
+use crate::resources::errors::ErrorBody;
+use crate::Scryfall;
+
+/// Directory of downloaded assets
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetCache {
+    dir: PathBuf,
+}
+
+/// Validators of a cached asset, sent back to the server to check if the asset changed
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Validators {
+    /// The `ETag` header of the asset
+    pub etag: Option<String>,
+
+    /// The `Last-Modified` header of the asset
+    pub last_modified: Option<String>,
+}
+
+impl Validators {
+    /// Gets the validators of a response
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name| headers.get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+
+        Validators { etag: header(ETAG), last_modified: header(LAST_MODIFIED) }
+    }
+
+    /// Checks if there are no validators (so requests cannot be conditional)
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// A fetched asset
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Asset {
+    /// The path of the asset in the cache
+    pub path: PathBuf,
+
+    /// Whether the asset was downloaded or served from the cache
+    pub status: AssetStatus,
+}
+
+/// Outcome of fetching an asset
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetStatus {
+    /// The asset was not cached or changed, and was downloaded
+    Downloaded,
+
+    /// The cached asset is still up to date (`304 Not Modified`)
+    NotModified,
+}
+
+impl AssetCache {
+    /// Creates a cache in a directory (created on the first download)
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        AssetCache { dir: dir.as_ref().into() }
+    }
+
+    /// Gets the path of an asset in the cache (whether it is cached or not)
+    pub fn path(&self, url: &Url) -> PathBuf {
+        let host = url.host_str().map(sanitize).unwrap_or_else(|| "_".into());
+
+        url.path_segments()
+            .into_iter()
+            .flatten()
+            .filter(|segment| !segment.is_empty())
+            .fold(self.dir.join(host), |path, segment| path.join(sanitize(segment)))
+    }
+
+    /// Gets the path of an asset, if it is cached (without checking if it changed)
+    pub fn get(&self, url: &Url) -> Option<PathBuf> {
+        Some(self.path(url)).filter(|path| path.is_file())
+    }
+
+    /// Gets the stored validators of a cached asset
+    pub fn validators(&self, url: &Url) -> Option<Validators> {
+        self.get(url)?;
+
+        let json = fs::read_to_string(validators_path(&self.path(url))).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    /// Stores an asset and its validators
+    pub fn insert(&self, url: &Url, asset: &[u8], validators: &Validators) -> io::Result<PathBuf> {
+        let path = self.path(url);
+        let partial = path.with_extension("partial");
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&partial, asset)?;
+        fs::rename(&partial, &path)?;
+
+        let validators_path = validators_path(&path);
+        match validators.is_empty() {
+            true => match fs::remove_file(&validators_path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => {},
+            },
+            false => fs::write(&validators_path, serde_json::to_string(validators)?)?,
+        }
+
+        Ok(path)
+    }
+
+    /// Gets an asset, downloading it (using the async client) unless the cached asset is up to date
+    ///
+    /// Cached assets with validators are revalidated with a conditional GET.
+    /// Cached assets without validators are downloaded again.
+    pub async fn fetch(&self, client: &Scryfall<'_>, url: &Url) -> Result<Asset, ErrorBody> {
+        let validators = self.validators(url).unwrap_or_default();
+        let mut request = client.http_client().get(url.clone());
+
+        if let Some(etag) = &validators.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &validators.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+
+        client.throttle().await;
+
+        let response = request
+            .send().await
+            .and_then(|res| res.error_for_status())
+            .map_err(ErrorBody::from_reqwest_error)?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return self.get(url)
+                .map(|path| Asset { path, status: AssetStatus::NotModified })
+                .ok_or_else(|| ErrorBody::client_error(format!("{url} is not modified, but not cached")));
+        }
+
+        let validators = Validators::from_headers(response.headers());
+        let asset = response.bytes().await.map_err(ErrorBody::from_reqwest_error)?;
+
+        self.insert(url, &asset, &validators)
+            .map(|path| Asset { path, status: AssetStatus::Downloaded })
+            .map_err(|e| ErrorBody::client_error(e.to_string()))
+    }
+
+    /// Gets an asset, downloading it (using the blocking client) unless the cached asset is up to date
+    ///
+    /// See [fetch](AssetCache::fetch).
+    #[cfg(feature = "blocking")]
+    pub fn fetch_blocking(&self, client: &crate::ScryfallBlocking<'_>, url: &Url) -> Result<Asset, ErrorBody> {
+        client.block_on(self.fetch(client.as_async(), url))
+    }
+}
+
+/// Gets the path of the validators of an asset
+fn validators_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".validators");
+
+    path.with_file_name(name)
+}
+
+/// Replaces characters that are not safe in file names
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '_' })
+        .collect::<String>()
+        .replace("..", "__")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("scryfall-sdk-{}-assets-{name}", std::process::id()))
+    }
+
+    #[rstest]
+    #[case::set_icon("https://svgs.scryfall.io/sets/dom.svg?1700000000", "assets/svgs.scryfall.io/sets/dom.svg")]
+    #[case::symbol("https://svgs.scryfall.io/card-symbols/W%2FU.svg", "assets/svgs.scryfall.io/card-symbols/W_2FU.svg")]
+    #[case::traversal("https://some.url/../../etc/passwd", "assets/some.url/etc/passwd")]
+    fn asset_paths_should_be_deterministic(#[case] url: &str, #[case] expected: &str) {
+        assert_eq!(Path::new(expected), AssetCache::new("assets").path(&Url::parse(url).unwrap()));
+    }
+
+    #[rstest]
+    fn validators_should_be_stored_with_assets() {
+        let cache = AssetCache::new(dir("validators"));
+        let url = Url::parse("https://svgs.scryfall.io/sets/dom.svg").unwrap();
+        let validators = Validators { etag: Some("\"abc\"".into()), last_modified: None };
+
+        assert_eq!(None, cache.validators(&url));
+
+        cache.insert(&url, b"<svg/>", &validators).unwrap();
+        assert_eq!(Some(validators), cache.validators(&url));
+
+        cache.insert(&url, b"<svg/>", &Validators::default()).unwrap();
+        assert_eq!(None, cache.validators(&url));
+
+        fs::remove_dir_all(dir("validators")).unwrap();
+    }
+}
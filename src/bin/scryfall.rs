@@ -0,0 +1,156 @@
+//! Command line interface for the Scryfall API
+//!
+//! ```text
+//! scryfall card <name>
+//! scryfall complete <prefix>
+//! scryfall search <query>
+//! scryfall sets
+//! scryfall bulk download <kind> [file]
+//! scryfall deck price <file>
+//! ```
+//!
+//! The CLI has to be enabled by the **cli** optional feature.
+//!
+//! Requests are sent to the API at `SCRYFALL_API_URL` (e.g. a mirror), if set.
+
+use std::error::Error;
+use std::fs::File;
+use std::io::BufWriter;
+use std::process::ExitCode;
+
+use scryfall_sdk_rust::deck::list::DeckList;
+use scryfall_sdk_rust::resources::cards::{CardFinish, SearchQueryParams};
+use scryfall_sdk_rust::{BulkDataResource, CardCatalogResource, CardResource, CardSetListResource, ScryfallBlocking, ScryfallBuilder};
+
+const USAGE: &str = "\
+Usage:
+    scryfall card <name>                  Show a card (fuzzy name match)
+    scryfall complete <prefix>            List card names starting with a prefix (one per line)
+    scryfall search <query>               List all cards matching a search query
+    scryfall sets                         List all sets
+    scryfall bulk download <kind> [file]  Download a bulk data file (e.g. oracle_cards)
+    scryfall deck price <file>            Price a deck list (USD)";
+
+type CliResult = Result<(), Box<dyn Error>>;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let api_url = std::env::var("SCRYFALL_API_URL").ok();
+
+    let client = match api_url.as_deref().map(|url| ScryfallBuilder::new().try_base_url(url)) {
+        Some(Ok(builder)) => builder.build_blocking(),
+        Some(Err(e)) => {
+            eprintln!("error: SCRYFALL_API_URL: {e}");
+            return ExitCode::from(2);
+        },
+        None => ScryfallBlocking::default(),
+    };
+
+    let result = match args.as_slice() {
+        ["card", name @ ..] if !name.is_empty() => card(&client, &name.join(" ")),
+        ["complete", prefix @ ..] if !prefix.is_empty() => complete(&client, &prefix.join(" ")),
+        ["search", query @ ..] if !query.is_empty() => search(&client, &query.join(" ")),
+        ["sets"] => sets(&client),
+        ["bulk", "download", kind] => bulk_download(&client, kind, None),
+        ["bulk", "download", kind, file] => bulk_download(&client, kind, Some(file)),
+        ["deck", "price", file] => deck_price(&client, file),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        },
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn card(client: &ScryfallBlocking, name: &str) -> CliResult {
+    let card = client.request(&CardResource::NamedFuzzy(name.into()))?;
+
+    println!("{} {}", card.name, card.mana_cost.unwrap_or_default());
+    println!("{}", card.type_line);
+    if let Some(text) = card.oracle_text {
+        println!("{text}");
+    }
+    println!("{} #{} ({:?})", card.set.to_uppercase(), card.collector_number, card.rarity);
+
+    Ok(())
+}
+
+fn complete(client: &ScryfallBlocking, prefix: &str) -> CliResult {
+    for name in client.request(&CardCatalogResource::Autocomplete(prefix))?.data {
+        println!("{name}");
+    }
+
+    Ok(())
+}
+
+fn search(client: &ScryfallBlocking, query: &str) -> CliResult {
+    for card in client.search_all(SearchQueryParams::with_q(query))? {
+        println!("{} ({} #{})", card.name, card.set.to_uppercase(), card.collector_number);
+    }
+
+    Ok(())
+}
+
+fn sets(client: &ScryfallBlocking) -> CliResult {
+    for set in client.request(&CardSetListResource::All)?.data {
+        println!("{:<6} {} {} ({} cards)", set.code, set.released_at, set.name, set.card_count);
+    }
+
+    Ok(())
+}
+
+fn bulk_download(client: &ScryfallBlocking, kind: &str, file: Option<&str>) -> CliResult {
+    let bulk = client.request(&BulkDataResource::Filter(kind.into()))?;
+
+    let file = file.map(String::from).unwrap_or_else(|| bulk.file_name());
+    let partial = format!("{file}.part");
+
+    let mut writer = BufWriter::new(File::create(&partial)?);
+    let download = client.download_bulk(&bulk, &mut writer)?;
+    drop(writer);
+
+    // Without a gzip decoder, files served gzip-encoded are saved compressed
+    let file = download.file_name(&file);
+    std::fs::rename(&partial, &file)?;
+
+    let encoding = if download.is_gzip() { ", gzip-compressed" } else { "" };
+    println!("{} ({} bytes{encoding}) saved to {file}", bulk.name, download.bytes);
+    Ok(())
+}
+
+fn deck_price(client: &ScryfallBlocking, file: &str) -> CliResult {
+    let list: DeckList = std::fs::read_to_string(file)?.parse()?;
+    let resolved = list.resolve_blocking(client)?;
+    let deck = &resolved.deck;
+
+    let commanders = deck.commanders.iter().map(|card| (1, card));
+    let entries = deck.mainboard.iter()
+        .chain(deck.sideboard.iter())
+        .map(|entry| (entry.quantity, &entry.card));
+
+    let mut total = 0.0;
+    for (quantity, card) in commanders.chain(entries) {
+        match card.prices.usd_of(CardFinish::NonFoil) {
+            Some(price) => {
+                total += price * f64::from(quantity);
+                println!("{quantity} {} ${:.2}", card.name, price * f64::from(quantity));
+            },
+            None => println!("{quantity} {} (no price)", card.name),
+        }
+    }
+
+    for entry in &resolved.not_found {
+        println!("{} {} (not found)", entry.quantity, entry.name);
+    }
+
+    println!("Total: ${total:.2}");
+    Ok(())
+}
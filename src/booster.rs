@@ -0,0 +1,175 @@
+//! Booster pack value estimation
+//!
+//! Estimates the expected value of a booster pack of a set from the (non-foil USD) prices
+//! of the set's booster cards and the number of cards of each rarity in a pack.
+//!
+//! The estimate is the sum, over all rarities, of the number of cards of the rarity in a pack
+//! times the average price of the cards of the rarity. The underlying per-rarity aggregation
+//! ([rarity_prices](rarity_prices)) can be used directly for custom models.
+//!
+//! The cards of a set can be fetched with [booster_search](booster_search), or the whole
+//! estimation done with `pack_value` of both clients.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::booster::{PackModel, estimate};
+//! use scryfall_sdk_rust::resources::cards::Card;
+//!
+//! # fn example(cards: Vec<Card>) {
+//! let value = estimate(&cards, &PackModel::draft_booster());
+//! println!("Expected value: ${:.2}", value.total);
+//! # }
+//! ```
+
+use crate::resources::cards::{Card, CardFinish, Rarity, SearchQueryParams};
+
+/// Number of cards of each rarity in a pack (on average)
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackModel {
+    pub slots: Vec<(Rarity, f64)>,
+}
+
+impl PackModel {
+    /// Creates a model from the (average) number of cards of each rarity in a pack
+    pub fn new(slots: Vec<(Rarity, f64)>) -> Self {
+        PackModel { slots }
+    }
+
+    /// Classic draft booster: 10 commons, 3 uncommons and a rare (a mythic 1 in 8 packs)
+    pub fn draft_booster() -> Self {
+        PackModel::new(vec![
+            (Rarity::Common, 10.0),
+            (Rarity::Uncommon, 3.0),
+            (Rarity::Rare, 0.875),
+            (Rarity::Mythic, 0.125),
+        ])
+    }
+}
+
+/// Price statistics of the cards of a rarity
+#[derive(Clone, Debug, PartialEq)]
+pub struct RarityPrices {
+    pub rarity: Rarity,
+
+    /// Number of cards of the rarity
+    pub cards: usize,
+
+    /// Number of cards of the rarity with a price
+    pub priced: usize,
+
+    /// Sum of the prices of the priced cards
+    pub total: f64,
+}
+
+impl RarityPrices {
+    /// Gets the average price of the priced cards (if any)
+    pub fn average(&self) -> Option<f64> {
+        match self.priced {
+            0 => None,
+            priced => Some(self.total / priced as f64),
+        }
+    }
+}
+
+/// Estimated value of a pack
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackValue {
+    /// Expected value of a pack
+    pub total: f64,
+
+    /// Expected value contributed by each rarity of the model
+    pub by_rarity: Vec<(Rarity, f64)>,
+
+    /// Rarities of the model without any priced cards (not contributing to the total)
+    pub unpriced: Vec<Rarity>,
+}
+
+/// Aggregates the non-foil USD prices of cards by rarity (in order of first appearance)
+pub fn rarity_prices(cards: &[Card]) -> Vec<RarityPrices> {
+    let mut prices: Vec<RarityPrices> = vec![];
+
+    for card in cards {
+        let index = match prices.iter().position(|p| p.rarity == card.rarity) {
+            Some(index) => index,
+            None => {
+                prices.push(RarityPrices { rarity: card.rarity.clone(), cards: 0, priced: 0, total: 0.0 });
+                prices.len() - 1
+            },
+        };
+
+        let stats = &mut prices[index];
+        stats.cards += 1;
+
+        if let Some(price) = card.prices.usd_of(CardFinish::NonFoil) {
+            stats.priced += 1;
+            stats.total += price;
+        }
+    }
+
+    prices
+}
+
+/// Estimates the expected value of a pack containing the given cards
+pub fn estimate(cards: &[Card], model: &PackModel) -> PackValue {
+    let prices = rarity_prices(cards);
+    let mut value = PackValue { total: 0.0, by_rarity: vec![], unpriced: vec![] };
+
+    for (rarity, count) in &model.slots {
+        let average = prices.iter()
+            .find(|p| &p.rarity == rarity)
+            .and_then(RarityPrices::average);
+
+        match average {
+            Some(average) => {
+                value.total += count * average;
+                value.by_rarity.push((rarity.clone(), count * average));
+            },
+            None => value.unpriced.push(rarity.clone()),
+        }
+    }
+
+    value
+}
+
+/// Search for the cards of a set that can be found in its boosters
+pub fn booster_search(set_code: &str) -> SearchQueryParams {
+    SearchQueryParams::with_q(&format!("e:{set_code} is:booster"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use rstest::rstest;
+    use serde_json::json;
+
+    fn cards() -> Vec<Card> {
+        vec![
+            card(json!({ "rarity": "common", "prices": { "usd": "0.10" } })),
+            card(json!({ "rarity": "common", "prices": { "usd": "0.30" } })),
+            card(json!({ "rarity": "common", "prices": {} })),
+            card(json!({ "rarity": "rare", "prices": { "usd": "4.00" } })),
+            card(json!({ "rarity": "mythic", "prices": {} })),
+        ]
+    }
+
+    #[rstest]
+    fn prices_should_be_aggregated_by_rarity() {
+        let prices = rarity_prices(&cards());
+
+        assert_eq!(3, prices.len());
+        assert_eq!(Rarity::Common, prices[0].rarity);
+        assert_eq!((3, 2), (prices[0].cards, prices[0].priced));
+        assert_eq!(Some(0.2), prices[0].average().map(|a| (a * 100.0).round() / 100.0));
+        assert_eq!(None, prices[2].average());
+    }
+
+    #[rstest]
+    fn pack_value_should_be_estimated() {
+        let value = estimate(&cards(), &PackModel::draft_booster());
+
+        assert_eq!(5.5, (value.total * 100.0).round() / 100.0);
+        assert_eq!(2, value.by_rarity.len());
+        assert_eq!(vec![Rarity::Uncommon, Rarity::Mythic], value.unpriced);
+    }
+}
@@ -3,41 +3,333 @@
 //! Async client is the default implementation.
 //!
 //! Blocking client can be used also by enabling the **blocking** optional feature.
+//!
+//! Both clients can cache GET responses in a shared [ResponseCache](cache::ResponseCache).
 
-use reqwest::Error;
+use reqwest::{Method, StatusCode};
 use reqwest::RequestBuilder;
 use reqwest::Client;
+use reqwest::header::{HeaderValue, USER_AGENT};
 use serde::Deserialize;
 use crate::HttpResource;
-use crate::resources::Response;
+#[cfg(feature = "cards")]
+use crate::CardPageResource;
+#[cfg(feature = "cards")]
+use crate::resources::cards::{Card, SearchQueryParams, TagKind};
+#[cfg(feature = "cards")]
+use crate::resources::card_symbols::ColorSymbol;
+#[cfg(feature = "cards")]
+use crate::ids::{self, CardId, IdKind};
+#[cfg(feature = "cards")]
+use crate::prints::{self, Printing};
+#[cfg(feature = "cards")]
+use crate::booster::{self, PackModel, PackValue};
+#[cfg(feature = "cards")]
+use crate::export::{Export, ExportManifest, PageResource};
+#[cfg(feature = "cards")]
+use crate::hydrate::{self, Found, HydrateOptions, Hydrated, Lookup};
+#[cfg(feature = "cards")]
+use crate::RulingListResource;
+#[cfg(feature = "cards")]
+use futures_util::stream::{self, StreamExt};
+#[cfg(all(feature = "cards", feature = "bulk"))]
+use crate::resources::bulk_data::{BulkDataKind, BulkDataResource};
+#[cfg(feature = "bulk")]
+use crate::resources::bulk_data::{BulkData, BulkDownload};
+#[cfg(feature = "cards")]
+use uuid::Uuid;
+#[cfg(feature = "cards")]
+use time::Date;
+#[cfg(feature = "cards")]
+use std::collections::{hash_map::Entry, HashMap};
+#[cfg(feature = "cards")]
+use crate::random::Rng;
+#[cfg(feature = "cards")]
+use crate::MigrationListResource;
+#[cfg(feature = "cards")]
+use crate::resources::migrations::Migration;
+#[cfg(feature = "sets")]
+use crate::CardSetListResource;
+#[cfg(all(feature = "cards", feature = "sets"))]
+use crate::CardSetResource;
+#[cfg(all(feature = "cards", feature = "sets"))]
+use crate::sets::PreviewProgress;
+#[cfg(feature = "sets")]
+use crate::resources::card_sets::CardSet;
+use crate::CatalogResource;
+use crate::resources::catalog::TypeCatalog;
+use crate::resources::{encode_path, ResourceKind, Response};
 use crate::resources::errors::ErrorBody;
+use crate::resources::validation::ValidationMode;
+use builder::ScryfallBuilder;
+use cache::ResponseCache;
+use circuit_breaker::CircuitBreaker;
+use dump::ResponseDump;
+use events::{ClientEvent, EventHandler};
+use meta::{Freshness, ResponseMeta};
+use prepared::PreparedRequest;
+use rate_limit::{Priority, RateLimiter};
+use retry::RetryPolicy;
+use std::time::Duration;
 
 #[cfg(feature = "blocking")]
 pub mod blocking;
+pub mod builder;
+pub mod cache;
+pub mod circuit_breaker;
+pub mod dump;
+pub mod events;
+pub mod meta;
+pub mod prepared;
+pub mod rate_limit;
+pub mod refresher;
+pub mod retry;
 
-type ResponseResult<M> = Result<Response<M>, Error>;
 type BodyResult<M> = Result<M, ErrorBody>;
 
+/// Request options shared by both clients
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ClientOptions {
+    pub(crate) cache: Option<ResponseCache>,
+    pub(crate) search_cache: Option<ResponseCache>,
+    pub(crate) not_found_cache: Option<ResponseCache>,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) circuit_breaker: Option<CircuitBreaker>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) on_event: Option<EventHandler>,
+    pub(crate) priority: Priority,
+    pub(crate) validation: ValidationMode,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) max_response_size: Option<usize>,
+    pub(crate) dump: Option<ResponseDump>,
+}
+
+type RawResponse = Result<(StatusCode, Freshness, String), reqwest::Error>;
+
+impl ClientOptions {
+    fn emit(&self, event: ClientEvent) {
+        if let Some(handler) = &self.on_event {
+            handler.emit(&event);
+        }
+    }
+
+    /// Records the outcome of a request in the circuit breaker (if any),
+    /// by the status of its response (`None` for transport errors)
+    ///
+    /// Server errors and transport errors (e.g. timeouts) count as failures.
+    fn record_outcome(&self, status: Option<StatusCode>) {
+        if let Some(breaker) = &self.circuit_breaker {
+            match status {
+                Some(status) if !status.is_server_error() => breaker.record_success(),
+                _ => if breaker.record_failure() {
+                    self.emit(ClientEvent::CircuitOpened);
+                },
+            }
+        }
+    }
+
+    /// Gets the delay before retrying a request (if it should be retried),
+    /// by the status of its response (`None` for transport errors)
+    fn retry_delay(&self, url: &str, retry: u32, status: Option<StatusCode>) -> Option<Duration> {
+        let retryable = match status {
+            Some(status) => RetryPolicy::is_retryable(status),
+            None => true,
+        };
+
+        let delay = self.retry_policy
+            .filter(|_| retryable)
+            .and_then(|policy| policy.delay(retry))?;
+
+        self.emit(ClientEvent::RetryScheduled { url: url.into(), attempt: retry, delay });
+        Some(delay)
+    }
+
+    /// Validates a parsed model according to the validation mode
+    fn validate<R, M>(&self, url: &str, resource: &R, model: M) -> BodyResult<M>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        if self.validation == ValidationMode::Off {
+            return Ok(model);
+        }
+
+        let violations = resource.validate(&model);
+        if violations.is_empty() {
+            return Ok(model);
+        }
+
+        match self.validation {
+            ValidationMode::Strict => Err(ErrorBody::invalid_response(violations)),
+            _ => {
+                self.emit(ClientEvent::InvalidResponse { url: url.into(), violations });
+                Ok(model)
+            }
+        }
+    }
+
+    fn into_body(response: RawResponse) -> BodyResult<(String, Freshness)> {
+        let (status, freshness, text) = response.map_err(ErrorBody::from_reqwest_error)?;
+        check_status(status, &text)?;
+
+        Ok((text, freshness))
+    }
+}
+
 /// Scryfall async client
 #[derive(Clone)]
 pub struct Scryfall<'a> {
     base_url: &'a str,
-    http_client: Client
+    http_client: Client,
+    options: ClientOptions,
 }
 
 impl<'a> Scryfall<'a> {
     /// Creates a client from a URL
+    ///
+    /// The URL is not checked: use [try_from_url](Self::try_from_url)
+    /// for URLs coming from users or configuration.
     #[must_use]
     pub fn from_url(url: &'a str) -> Self {
-        Scryfall {
-            base_url: url,
-            http_client: Client::builder()
-                .user_agent("Rust-SDK: async-clients")
-                .build()
-                .unwrap_or_default()
+        ScryfallBuilder::new().base_url(url).build()
+    }
+
+    /// Creates a client from a URL, checking that it is an absolute `http` or `https` URL
+    ///
+    /// See [try_base_url](ScryfallBuilder::try_base_url).
+    pub fn try_from_url(url: &'a str) -> BodyResult<Self> {
+        Ok(ScryfallBuilder::new().try_base_url(url)?.build())
+    }
+
+    /// Creates a builder for tuning the client
+    #[must_use]
+    pub fn builder() -> ScryfallBuilder<'a> {
+        ScryfallBuilder::new()
+    }
+
+    pub(crate) fn from_parts(
+        base_url: &'a str,
+        http_client: Client,
+        options: ClientOptions,
+    ) -> Self {
+        Scryfall { base_url, http_client, options }
+    }
+
+    /// Enables caching of GET responses in the given cache
+    ///
+    /// The cache can be shared with other clients by cloning it.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.options.cache = Some(cache);
+        self
+    }
+
+    /// Gets the response cache (if enabled)
+    #[must_use]
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.options.cache.as_ref()
+    }
+
+    /// Enables caching of search pages in the given cache
+    ///
+    /// Searches are cached under a normalized key (see [SearchQueryParams::normalized](crate::resources::cards::SearchQueryParams::normalized)),
+    /// so equivalent searches share cached pages. Searches are then no longer stored
+    /// in the response cache, so they can have their own time-to-live.
+    #[must_use]
+    pub fn with_search_cache(mut self, cache: ResponseCache) -> Self {
+        self.options.search_cache = Some(cache);
+        self
+    }
+
+    /// Gets the search cache (if enabled)
+    #[must_use]
+    pub fn search_cache(&self) -> Option<&ResponseCache> {
+        self.options.search_cache.as_ref()
+    }
+
+    /// Enables caching of not found (404) responses in the given cache
+    ///
+    /// Repeated lookups of unknown cards (e.g. user-typed names) are then answered
+    /// with the cached error. A short time-to-live is recommended, since cards
+    /// may be added at any time (e.g. during previews).
+    #[must_use]
+    pub fn with_not_found_cache(mut self, cache: ResponseCache) -> Self {
+        self.options.not_found_cache = Some(cache);
+        self
+    }
+
+    /// Gets the not found cache (if enabled)
+    #[must_use]
+    pub fn not_found_cache(&self) -> Option<&ResponseCache> {
+        self.options.not_found_cache.as_ref()
+    }
+
+    /// Removes the cached not found response of an endpoint (if any)
+    pub fn invalidate_not_found<R, M>(&self, resource: &R)
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        if let Some((cache, key)) = self.not_found_entry(resource) {
+            cache.invalidate(&key);
         }
     }
 
+    /// Enables throttling of requests with the given rate limiter
+    ///
+    /// The rate limiter can be shared with other clients by cloning it.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.options.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Gets the rate limiter (if enabled)
+    #[must_use]
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.options.rate_limiter.as_ref()
+    }
+
+    /// Enables failing fast with the given circuit breaker
+    ///
+    /// The circuit breaker can be shared with other clients by cloning it.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.options.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Enables retrying failed requests with the given policy
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.options.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Registers a hook invoked with every [client event](ClientEvent)
+    #[must_use]
+    pub fn on_event<F>(mut self, handler: F) -> Self
+        where F: Fn(&ClientEvent) + Send + Sync + 'static
+    {
+        self.options.on_event = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// Sets the priority lane of the requests made by this client
+    ///
+    /// A clone with background priority can be used for batch jobs,
+    /// while sharing the rate limiter with interactive clones.
+    #[must_use]
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.options.priority = priority;
+        self
+    }
+
+    /// Sets how parsed responses are [validated](crate::resources::validation)
+    #[must_use]
+    pub fn with_validation(mut self, mode: ValidationMode) -> Self {
+        self.options.validation = mode;
+        self
+    }
+
     /// Gets a clone of the wrapped Client object
     ///
     /// Cloning the Client happens in order to re-use its connection pool.
@@ -46,30 +338,620 @@ impl<'a> Scryfall<'a> {
         self.http_client.clone()
     }
 
+    /// Prepares the HTTP request of an endpoint, without sending it
+    ///
+    /// See [prepared](crate::client::prepared) requests.
+    pub fn prepare<R, M>(&self, resource: &R) -> BodyResult<PreparedRequest>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        let request = self.build_request(resource)
+            .build()
+            .map_err(ErrorBody::from_reqwest_error)?;
+
+        let mut headers = request.headers().clone();
+        if let Some(user_agent) = self.options.user_agent.as_deref() {
+            if let Ok(value) = HeaderValue::from_str(user_agent) {
+                headers.entry(USER_AGENT).or_insert(value);
+            }
+        }
+
+        Ok(PreparedRequest {
+            method: request.method().clone(),
+            url: request.url().to_string(),
+            headers,
+            body: resource.json(),
+        })
+    }
+
+    /// Decodes the response of an endpoint sent by a custom transport
+    /// (see [prepared](crate::client::prepared) requests)
+    ///
+    /// The body goes through the same pipeline as the responses of the client:
+    /// size limit, [dump](crate::client::dump), parsing of models and error bodies, and validation.
+    /// Unsuccessful responses (and empty ones, e.g. `204 No Content`) without an error object
+    /// fail with an [HTTP_ERROR](ErrorBody::http_error) of their status.
+    #[cfg(feature = "http")]
+    pub fn decode<R, M>(&self, resource: &R, response: http::Response<Vec<u8>>) -> BodyResult<M>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        let (parts, body) = response.into_parts();
+
+        if let Some(limit) = self.options.max_response_size.filter(|limit| body.len() > *limit) {
+            return Err(ErrorBody::response_too_large(limit));
+        }
+
+        let text = String::from_utf8_lossy(&body);
+        if let Some(dump) = &self.options.dump {
+            dump.write(resource.method().as_str(), &self.url(resource), parts.status.as_u16(), &text);
+        }
+
+        check_status(parts.status, &text)?;
+        self.validated(resource, &text)
+    }
+
     /// Makes an HTTP request to an endpoint
     pub async fn request<R, M>(&self, resource: &R) -> BodyResult<M>
         where R: HttpResource<M>,
               M: for<'de> Deserialize<'de>
     {
-        let req = self.build_request(resource);
+        self.fetch(resource, true, |text| self.validated(resource, text)).await
+            .map(|(model, _)| model)
+    }
+
+    /// Makes an HTTP request to an endpoint, returning the [metadata](ResponseMeta) of the response along with the model
+    ///
+    /// The metadata tells whether the response was served from the cache, how long it stays fresh,
+    /// and the warnings of the response (e.g. about ignored search terms).
+    pub async fn request_with_meta<R, M>(&self, resource: &R) -> BodyResult<(M, ResponseMeta)>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        self.fetch(resource, true, |text| Ok((self.validated(resource, text)?, meta::warnings(text)))).await
+            .map(|((model, warnings), meta)| (model, ResponseMeta { warnings, ..meta }))
+    }
+
+    /// Makes an HTTP request to an endpoint, deserializing the response into a custom model
+    ///
+    /// The custom model can be any struct deriving `Deserialize` with a subset of the fields
+    /// of the endpoint's model (see [parse_partial](crate::client::parse_partial)).
+    pub async fn request_partial<T, R, M>(&self, resource: &R) -> BodyResult<T>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>,
+              T: for<'de> Deserialize<'de>
+    {
+        self.fetch(resource, true, |text| parse_partial::<T>(text)
+            .map_err(|e| unexpected_object(resource.kind(), text).unwrap_or(e))
+        ).await
+            .map(|(model, _)| model)
+    }
+
+    /// Makes an HTTP request to an endpoint, bypassing the cache
+    ///
+    /// Successful responses of GET endpoints are stored in the cache (if enabled).
+    pub async fn refresh<R, M>(&self, resource: &R) -> BodyResult<M>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        self.fetch(resource, false, |text| self.validated(resource, text)).await
+            .map(|(model, _)| model)
+    }
+
+    /// Searches for cards, following all result pages
+    ///
+    /// The `page` parameter is used as the starting page (defaults to the first one).
+    #[cfg(feature = "cards")]
+    pub async fn search_all(&self, mut params: SearchQueryParams) -> BodyResult<Vec<Card>> {
+        let mut cards = vec![];
+        let mut page = params.page.unwrap_or(1);
+
+        loop {
+            params.page = Some(page);
+            let resource = CardPageResource::Search(params);
+            let result = self.request(&resource).await?;
+            cards.extend(result.data);
+
+            if !result.has_more {
+                return Ok(cards);
+            }
+
+            let CardPageResource::Search(next) = resource;
+            params = next;
+            page += 1;
+        }
+    }
+
+    /// Searches for cards, following the `next_page` links of all result pages
+    /// and recording them in an [export manifest](crate::export)
+    ///
+    /// With the **bulk** feature, the `updated_at` timestamp of the `default_cards` bulk data
+    /// is recorded as well (at the cost of an extra request).
+    #[cfg(feature = "cards")]
+    pub async fn search_export(&self, params: SearchQueryParams) -> BodyResult<Export> {
+        let export = self.start_export(params).await?;
+
+        self.complete_export(export).await
+    }
+
+    /// Starts a search export, without fetching any page
+    ///
+    /// The pages are fetched one by one with [export_next_page](Scryfall::export_next_page),
+    /// so that the export can be saved (and [resumed](Scryfall::resume_export)) after each page.
+    #[cfg(feature = "cards")]
+    pub async fn start_export(&self, params: SearchQueryParams) -> BodyResult<Export> {
+        #[cfg(feature = "bulk")]
+        let bulk_updated_at = Some(self.request(&BulkDataResource::by_kind(&BulkDataKind::DefaultCards)).await?.updated_at);
+        #[cfg(not(feature = "bulk"))]
+        let bulk_updated_at = None;
+
+        let resource = CardPageResource::Search(params);
+        let first = url::Url::parse(&self.url(&resource))
+            .map_err(|e| ErrorBody::client_error(format!("invalid search url: {e}")))?;
+
+        let CardPageResource::Search(params) = &resource;
+        Ok(Export::new(ExportManifest::new(params.normalized(), first, bulk_updated_at)))
+    }
+
+    /// Fetches the next page of an export (if any), adding its new cards to the export
+    ///
+    /// Returns whether there are more pages to fetch.
+    #[cfg(feature = "cards")]
+    pub async fn export_next_page(&self, export: &mut Export) -> BodyResult<bool> {
+        if let Some(next) = export.manifest.next_page.clone() {
+            let page = self.request(&PageResource(self.relative_path(&next))).await?;
+            export.record(next, page);
+        }
+
+        Ok(export.manifest.next_page.is_some())
+    }
+
+    /// Continues an interrupted export from its saved manifest, following the remaining pages
+    ///
+    /// Only the cards of the remaining pages are returned (without the cards already exported).
+    #[cfg(feature = "cards")]
+    pub async fn resume_export(&self, manifest: ExportManifest) -> BodyResult<Export> {
+        self.complete_export(Export::resume(manifest)).await
+    }
+
+    #[cfg(feature = "cards")]
+    async fn complete_export(&self, mut export: Export) -> BodyResult<Export> {
+        while self.export_next_page(&mut export).await? {}
+
+        Ok(export)
+    }
+
+    /// Fetches again the pages recorded in an export manifest (in order)
+    ///
+    /// The pages are fetched exactly as they were followed, so the results only differ
+    /// from the original export if the data changed in the meantime
+    /// (which can be told by the `updated_at` timestamp of the bulk data).
+    #[cfg(feature = "cards")]
+    pub async fn replay_export(&self, manifest: &ExportManifest) -> BodyResult<Vec<Card>> {
+        let mut cards = vec![];
+        for url in &manifest.pages {
+            cards.extend(self.request(&PageResource(self.relative_path(url))).await?.data);
+        }
+
+        Ok(cards)
+    }
+
+    /// Fetches the sets, rulings and related cards of cards, as [hydrated](crate::hydrate) data
+    ///
+    /// Each set, Oracle card and related card is looked up once, with a few lookups
+    /// running at a time (sharing the cache and rate limiter of the client).
+    /// Sets not found are skipped; other errors fail the whole hydration.
+    #[cfg(feature = "cards")]
+    pub async fn hydrate(&self, cards: &[Card], options: HydrateOptions) -> BodyResult<Hydrated> {
+        let mut hydrated = Hydrated::default();
+        let lookups = hydrate::plan(cards, options, &mut hydrated);
+
+        let mut found = stream::iter(lookups)
+            .map(|lookup| self.lookup(lookup))
+            .buffer_unordered(hydrate::CONCURRENCY);
+
+        while let Some(result) = found.next().await {
+            hydrated.add(result?);
+        }
+
+        Ok(hydrated)
+    }
+
+    #[cfg(feature = "cards")]
+    async fn lookup(&self, lookup: Lookup) -> BodyResult<Found> {
+        match lookup {
+            #[cfg(feature = "sets")]
+            Lookup::Set(code) => match self.request(&CardSetResource::Filter(code.clone().into())).await {
+                Ok(set) => Ok(Found::Set(code, Some(Box::new(set)))),
+                Err(e) if e.status == 404 => Ok(Found::Set(code, None)),
+                Err(e) => Err(e),
+            },
+            Lookup::Rulings { oracle_id, card_id } => {
+                let rulings = self.request(&RulingListResource::by_card_id(&card_id)).await?;
+                Ok(Found::Rulings(oracle_id, rulings.data))
+            },
+            Lookup::Parts(resource) => Ok(Found::Parts(self.request(&resource).await?.cards)),
+        }
+    }
+
+    /// Gets all creatures legal in standard
+    #[cfg(feature = "cards")]
+    pub async fn standard_legal_creatures(&self) -> BodyResult<Vec<Card>> {
+        self.search_all(SearchQueryParams::standard_legal_creatures()).await
+    }
+
+    /// Gets all cards that can be commanders of a deck with the given color identity
+    #[cfg(feature = "cards")]
+    pub async fn commanders_in_identity(&self, colors: &[ColorSymbol]) -> BodyResult<Vec<Card>> {
+        self.search_all(SearchQueryParams::commanders_in_identity(colors)).await
+    }
+
+    /// Gets all cards with a keyword ability (e.g. `Cascade`)
+    #[cfg(feature = "cards")]
+    pub async fn cards_with_keyword(&self, keyword: &str) -> BodyResult<Vec<Card>> {
+        self.search_all(SearchQueryParams::with_keyword(keyword)).await
+    }
+
+    /// Gets all cards with a Tagger tag (e.g. art tag `squirrel`)
+    #[cfg(feature = "cards")]
+    pub async fn cards_with_tag(&self, kind: TagKind, tag: &str) -> BodyResult<Vec<Card>> {
+        self.search_all(SearchQueryParams::with_tag(kind, tag)).await
+    }
+
+    /// Gets a catalog of types (e.g. [creature types](crate::CatalogResource::CreatureTypes)),
+    /// for validating types in queries (see [checked_creature_type](crate::query::SearchQuery::checked_creature_type))
+    ///
+    /// Catalogs rarely change: with a [response cache](Scryfall::with_cache), repeated calls
+    /// are served from the cache.
+    pub async fn type_catalog(&self, resource: CatalogResource) -> BodyResult<TypeCatalog> {
+        self.request(&resource).await.map(TypeCatalog::from)
+    }
+
+    /// Gets the sets released after today, ordered by release date
+    ///
+    /// Sets without cards (announced, but not previewed yet) can be skipped with `skip_empty`.
+    #[cfg(feature = "sets")]
+    pub async fn upcoming_sets(&self, skip_empty: bool) -> BodyResult<Vec<CardSet>> {
+        let sets = self.request(&CardSetListResource::All).await?;
+
+        Ok(crate::sets::upcoming(sets.data, time::OffsetDateTime::now_utc().date(), skip_empty))
+    }
+
+    /// Gets the preview progress of a set (N of M cards revealed)
+    #[cfg(all(feature = "cards", feature = "sets"))]
+    pub async fn preview_progress(&self, set_code: &str) -> BodyResult<PreviewProgress> {
+        let set = self.request(&CardSetResource::Filter(set_code.into())).await?;
+        let search = CardPageResource::Search(crate::sets::preview_search(set_code));
+
+        let revealed = match self.request(&search).await {
+            Ok(page) => page.total_cards,
+            Err(e) if e.status == 404 => 0,
+            Err(e) => return Err(e),
+        };
+
+        Ok(PreviewProgress::of(&set, revealed))
+    }
+
+    /// Converts a card identifier to another kind, by fetching the card it refers to
+    ///
+    /// Returns `None` if the card has no identifier of the requested kind.
+    #[cfg(feature = "cards")]
+    pub async fn convert_id(&self, from: &CardId, to: IdKind) -> BodyResult<Option<CardId>> {
+        let value = from.to_string();
+
+        let card = match ids::resource(from, &value) {
+            Some(resource) => self.request(&resource).await?,
+            None => {
+                let search = SearchQueryParams::with_q(&format!("oracleid:{value}"));
+
+                match self.request(&CardPageResource::Search(search)).await?.data.into_iter().next() {
+                    Some(card) => card,
+                    None => return Ok(None),
+                }
+            }
+        };
+
+        Ok(CardId::of(&card, to))
+    }
+
+    /// Converts many card identifiers to another kind (in the same order)
+    ///
+    /// Identifiers supported by the collection endpoint are fetched in batches,
+    /// the rest one by one. Identifiers of unknown cards are converted to `None`.
+    #[cfg(feature = "cards")]
+    pub async fn convert_ids(&self, from: &[CardId], to: IdKind) -> BodyResult<Vec<Option<CardId>>> {
+        let mut cards = vec![];
+        for resource in ids::collection_requests(from) {
+            cards.extend(self.request(&resource).await?.cards);
+        }
+
+        let mut converted = ids::convert_all(from, &cards, to);
+
+        for (id, result) in from.iter().zip(converted.iter_mut()) {
+            if id.identifier().is_some() {
+                continue;
+            }
+
+            *result = match self.convert_id(id, to).await {
+                Ok(converted) => converted,
+                Err(e) if e.status == 404 => None,
+                Err(e) => return Err(e),
+            };
+        }
+
+        Ok(converted)
+    }
+
+    /// Gets all printings of an oracle card, ordered by release date
+    #[cfg(feature = "cards")]
+    pub async fn print_history(&self, oracle_id: &Uuid) -> BodyResult<Vec<Printing>> {
+        let cards = self.search_all(prints::prints_search(oracle_id)).await?;
+
+        Ok(prints::timeline(&cards))
+    }
+
+    /// Gets the original (earliest) printing of an oracle card (if any)
+    #[cfg(feature = "cards")]
+    pub async fn original_printing(&self, oracle_id: &Uuid) -> BodyResult<Option<Card>> {
+        let search = CardPageResource::Search(prints::prints_search(oracle_id));
+
+        match self.request(&search).await {
+            Ok(page) => Ok(page.data.into_iter().next()),
+            Err(e) if e.status == 404 => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Gets `n` distinct random cards matching a search
+    ///
+    /// Only the result pages of the picked cards are fetched (using the total number
+    /// of results of the first page), which is much cheaper than fetching random cards
+    /// one by one and discarding duplicates. All matching cards are returned (shuffled)
+    /// when there are at most `n`, and none when the search matches nothing.
+    #[cfg(feature = "cards")]
+    pub async fn random_sample(&self, mut params: SearchQueryParams, n: usize) -> BodyResult<Vec<Card>> {
+        params.page = Some(1);
+        let resource = CardPageResource::Search(params);
+
+        let first = match self.request(&resource).await {
+            Ok(page) => page,
+            Err(e) if e.status == 404 => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+
+        let page_size = first.data.len().max(1);
+        let total = if first.has_more { first.total_cards.max(0) as usize } else { first.data.len() };
+
+        let mut rng = Rng::new();
+        let picked = rng.sample(total, n);
+
+        let CardPageResource::Search(mut params) = resource;
+        let mut pages = HashMap::from([(1, first.data)]);
+        let mut cards = vec![];
+
+        for index in picked {
+            let page = (index / page_size + 1) as u32;
+
+            if let Entry::Vacant(entry) = pages.entry(page) {
+                params.page = Some(page);
+                let resource = CardPageResource::Search(params);
+                entry.insert(self.request(&resource).await?.data);
+
+                let CardPageResource::Search(next) = resource;
+                params = next;
+            }
+
+            // Results may have changed since the first page, so indexes past the end are skipped
+            cards.extend(pages[&page].get(index % page_size).cloned());
+        }
+
+        Ok(cards)
+    }
+
+    /// Estimates the expected value of a booster pack of a set
+    ///
+    /// See [booster](crate::booster) for how the value is estimated.
+    #[cfg(feature = "cards")]
+    pub async fn pack_value(&self, set_code: &str, model: &PackModel) -> BodyResult<PackValue> {
+        let cards = self.search_all(booster::booster_search(set_code)).await?;
+
+        Ok(booster::estimate(&cards, model))
+    }
+
+    /// Gets all migrations performed on or after the given date (newest first)
+    ///
+    /// All migrations are fetched when no date is given.
+    #[cfg(feature = "cards")]
+    pub async fn migrations_since(&self, since: Option<Date>) -> BodyResult<Vec<Migration>> {
+        let mut migrations = vec![];
+        let mut page = 1;
+
+        loop {
+            let list = self.request(&MigrationListResource::Page(page)).await?;
+            let mut reached_since = !list.has_more;
+
+            for migration in list.data {
+                match since {
+                    Some(date) if migration.performed_at < date => reached_since = true,
+                    _ => migrations.push(migration),
+                }
+            }
+
+            if reached_since {
+                return Ok(migrations);
+            }
+
+            page += 1;
+        }
+    }
+
+    /// Parses a response body and validates the model
+    fn validated<R, M>(&self, resource: &R, text: &str) -> BodyResult<M>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        let model = parse_body::<M>(text)
+            .map_err(|e| unexpected_object(resource.kind(), text).unwrap_or(e))?;
+
+        self.options.validate(&self.url(resource), resource, model)
+    }
+
+    async fn fetch<R, M, T, P>(
+        &self,
+        resource: &R,
+        use_cache: bool,
+        parse: P,
+    ) -> BodyResult<(T, ResponseMeta)>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>,
+              P: Fn(&str) -> BodyResult<T>
+    {
+        let request = format!("{} {}", resource.method(), encode_path(&resource.path()));
+        let parse = |text: &str| parse(text).map_err(|e| e.with_request(&request));
+
+        let cached = [self.cache_entry(resource), self.not_found_entry(resource)]
+            .into_iter()
+            .flatten()
+            .filter(|_| use_cache)
+            .find_map(|(cache, key)| cache.lookup(&key));
+
+        if let Some((body, remaining)) = cached {
+            self.options.emit(ClientEvent::CacheHit { url: self.url(resource) });
+            let freshness = Freshness { max_age: Some(remaining), no_store: false };
 
-        match req.send().await {
-            Ok(req_ok) => {
-                let res = req_ok.json::<Response<M>>().await;
-                self.extract_body(res)
+            return parse(&body).map(|model| (model, ResponseMeta::new(true, freshness)));
+        }
+
+        let (text, freshness) = self.send(resource).await.map_err(|e| e.with_request(&request))?;
+        let body = match parse(&text) {
+            Err(e) if e.status == 404 => {
+                if let Some((cache, key)) = self.not_found_entry(resource) {
+                    cache.insert_fresh(&key, text, &freshness);
+                }
+                return Err(e);
             },
-            Err(e) => Result::Err(ErrorBody::from_reqwest_error(e)),
+            result => result?,
+        };
+
+        let meta = ResponseMeta::new(false, freshness);
+        if let Some((cache, key)) = self.cache_entry(resource) {
+            cache.insert_fresh(&key, text, &freshness);
         }
+
+        Ok((body, meta))
     }
 
-    fn build_request<R, M>(&self, resource: &R) -> RequestBuilder
+    async fn send<R, M>(&self, resource: &R) -> BodyResult<(String, Freshness)>
         where R: HttpResource<M>,
               M: for<'de> Deserialize<'de>
     {
-        let url = format!("{}/{}", self.base_url, resource.path());
+        let url = self.url(resource);
+        let mut retry = 0;
+
+        loop {
+            if let Some(breaker) = &self.options.circuit_breaker {
+                breaker.check()?;
+            }
+
+            self.throttle().await;
+
+            self.options.emit(ClientEvent::RequestStarted {
+                method: resource.method().to_string(),
+                url: url.clone(),
+            });
+
+            let response = match self.build_request(resource).send().await {
+                Ok(res) => {
+                    let status = res.status();
+                    let freshness = Freshness::from_headers(res.headers());
+                    read_text(res, self.options.max_response_size).await?
+                        .map(|text| {
+                            if let Some(dump) = &self.options.dump {
+                                dump.write(resource.method().as_str(), &url, status.as_u16(), &text);
+                            }
+                            (status, freshness, text)
+                        })
+                },
+                Err(e) => Err(e),
+            };
+
+            let status = response.as_ref().ok().map(|(status, _, _)| *status);
+            self.options.record_outcome(status);
+
+            retry += 1;
+            match self.options.retry_delay(&url, retry, status) {
+                Some(delay) => futures_timer::Delay::new(delay).await,
+                None => return ClientOptions::into_body(response),
+            }
+        }
+    }
+
+    /// Downloads the file of a bulk data item to a writer, returning the number of bytes written
+    /// and the encoding of the response
+    ///
+    /// The download goes through the rate limiter, circuit breaker and retry policy of the client
+    /// (failed downloads are only retried before the first byte is written).
+    /// The size limit of responses does not apply to bulk files.
+    ///
+    /// Files are written as served: files served with a `Content-Encoding: gzip` header
+    /// are written compressed (the SDK has no gzip decoder), see [BulkDownload::is_gzip].
+    #[cfg(feature = "bulk")]
+    pub async fn download_bulk<W: std::io::Write>(&self, bulk: &BulkData, writer: &mut W) -> BodyResult<BulkDownload> {
+        let url = bulk.download_uri.to_string();
+        let mut retry = 0;
+
+        let mut response = loop {
+            if let Some(breaker) = &self.options.circuit_breaker {
+                breaker.check()?;
+            }
+
+            self.throttle().await;
+
+            self.options.emit(ClientEvent::RequestStarted { method: Method::GET.to_string(), url: url.clone() });
+
+            let response = self.http_client().get(bulk.download_uri.clone()).send().await;
+            let status = response.as_ref().ok().map(reqwest::Response::status);
+            self.options.record_outcome(status);
+
+            retry += 1;
+            match self.options.retry_delay(&url, retry, status) {
+                Some(delay) => futures_timer::Delay::new(delay).await,
+                None => break response
+                    .and_then(reqwest::Response::error_for_status)
+                    .map_err(ErrorBody::from_reqwest_error)?,
+            }
+        };
 
+        let content_encoding = response.headers().get(reqwest::header::CONTENT_ENCODING)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+
+        let mut bytes = 0;
+        while let Some(chunk) = response.chunk().await.map_err(ErrorBody::from_reqwest_error)? {
+            writer.write_all(&chunk).map_err(|e| ErrorBody::client_error(e.to_string()))?;
+            bytes += chunk.len() as u64;
+        }
+
+        writer.flush().map_err(|e| ErrorBody::client_error(e.to_string()))?;
+        Ok(BulkDownload { bytes, content_encoding })
+    }
+
+    /// Waits for the rate limiter (if any) to allow a request
+    pub(crate) async fn throttle(&self) {
+        if let Some(limiter) = &self.options.rate_limiter {
+            while let Err(wait) = limiter.try_acquire(self.options.priority) {
+                self.options.emit(ClientEvent::RateLimited { wait });
+                futures_timer::Delay::new(wait).await;
+            }
+        }
+    }
+
+    fn build_request<R, M>(&self, resource: &R) -> RequestBuilder
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
         let mut req = self.http_client()
-            .request(resource.method(), url)
+            .request(resource.method(), self.url(resource))
             .header("Content-Type", "application/json");
        
         if let Some(b) = resource.json() {
@@ -79,15 +961,56 @@ impl<'a> Scryfall<'a> {
         req
     }
 
-    fn extract_body<M>(&self, result: ResponseResult<M>) -> BodyResult<M> 
-        where M: for<'de> Deserialize<'de>
+    fn url<R, M>(&self, resource: &R) -> String
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        format!("{}/{}", self.base_url, encode_path(&resource.path()))
+    }
+
+    /// Gets the path of a URL relative to the base url (e.g. of a `next_page` link)
+    ///
+    /// URLs of other hosts (e.g. `api.scryfall.com` links served through a proxy)
+    /// are requested by their path.
+    #[cfg(feature = "cards")]
+    fn relative_path(&self, url: &url::Url) -> String {
+        match url.as_str().strip_prefix(self.base_url).and_then(|path| path.strip_prefix('/')) {
+            Some(path) => path.to_string(),
+            None => match url.query() {
+                Some(query) => format!("{}?{query}", &url.path()[1..]),
+                None => url.path()[1..].to_string(),
+            },
+        }
+    }
+
+    fn cache_for<R, M>(&self, resource: &R) -> Option<&ResponseCache>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
     {
-        match result {
-            Ok(response) => match response {
-                Response::Ok(body_ok) => Result::Ok(body_ok),
-                Response::Err(body_err) => Result::Err(body_err),
-            }    
-            Err(e) => Result::Err(ErrorBody::from_reqwest_error(e)),
+        self.options.cache.as_ref().filter(|_| resource.method() == Method::GET)
+    }
+
+    /// Gets the not found cache of an endpoint and the key of its responses
+    fn not_found_entry<R, M>(&self, resource: &R) -> Option<(&ResponseCache, String)>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        self.options.not_found_cache.as_ref()
+            .filter(|_| resource.method() == Method::GET)
+            .map(|cache| (cache, self.url(resource)))
+    }
+
+    /// Gets the cache of an endpoint and the key of its responses
+    ///
+    /// Searches are stored in the search cache (if enabled) under their normalized key,
+    /// other GET endpoints in the response cache under their URL.
+    fn cache_entry<R, M>(&self, resource: &R) -> Option<(&ResponseCache, String)>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        match (&self.options.search_cache, resource.search_key()) {
+            (Some(cache), Some(key)) => Some((cache, key)),
+            _ => self.cache_for(resource).map(|cache| (cache, self.url(resource))),
         }
     }
 }
@@ -96,4 +1019,121 @@ impl<'a> Default for Scryfall<'a> {
     fn default() -> Self {
         Scryfall::from_url("https://api.scryfall.com")
     }
-}
\ No newline at end of file
+}
+
+/// Parses a raw response body into a custom (partial) model (or an error body)
+///
+/// Custom models only need to derive `Deserialize` for the fields they need.
+/// Unknown fields are always ignored, as none of the SDK models (and the error body)
+/// deny unknown fields, so responses can be deserialized directly into lightweight structs,
+/// saving memory on large results.
+///
+/// The response is checked for an error body (`"object": "error"`) first,
+/// so that partial models with optional fields never swallow errors.
+///
+/// # Example
+/// ```
+/// use serde::Deserialize;
+/// use scryfall_sdk_rust::client::parse_partial;
+///
+/// #[derive(Debug, Deserialize)]
+/// struct CardName {
+///     name: String,
+/// }
+///
+/// let card: CardName = parse_partial(r#"{ "object": "card", "name": "Opt", "cmc": 1.0 }"#).unwrap();
+/// assert_eq!("Opt", card.name);
+///
+/// let error = parse_partial::<CardName>(
+///     r#"{ "object": "error", "code": "not_found", "status": 404, "details": "No card" }"#
+/// ).unwrap_err();
+/// assert_eq!(404, error.status);
+/// ```
+pub fn parse_partial<T>(text: &str) -> BodyResult<T>
+    where T: for<'de> Deserialize<'de>
+{
+    let decode_error = |e: serde_json::Error| ErrorBody::client_error(
+        format!("error decoding response body: {e}")
+    );
+
+    match serde_json::from_str::<Object>(text) {
+        Ok(Object { object: Some(ResourceKind::Error) }) => Err(
+            serde_json::from_str::<ErrorBody>(text).map_err(decode_error)?
+        ),
+        _ => serde_json::from_str::<T>(text).map_err(decode_error),
+    }
+}
+
+/// The `object` field of a response
+#[derive(Deserialize)]
+struct Object {
+    object: Option<ResourceKind>,
+}
+
+/// Checks the `object` of a response that failed to decode against the expected kind
+///
+/// Returns an [unexpected object](ErrorBody::unexpected_object) error on mismatch.
+fn unexpected_object(expected: Option<ResourceKind>, text: &str) -> Option<ErrorBody> {
+    let expected = expected?;
+    let got = serde_json::from_str::<Object>(text).ok()?.object?;
+
+    (got != expected && got != ResourceKind::Error)
+        .then(|| ErrorBody::unexpected_object(expected, got))
+}
+
+/// Reads the body of a response as text, up to an (optional) maximum size
+///
+/// Bodies are read chunk by chunk, so that oversized responses fail
+/// without being buffered entirely. Transport errors are returned in the inner result,
+/// so that they can still be retried.
+async fn read_text(mut res: reqwest::Response, limit: Option<usize>) -> BodyResult<reqwest::Result<String>> {
+    let limit = match limit {
+        Some(limit) => limit,
+        None => return Ok(res.text().await),
+    };
+
+    if matches!(res.content_length(), Some(length) if length > limit as u64) {
+        return Err(ErrorBody::response_too_large(limit));
+    }
+
+    let mut body = Vec::new();
+    loop {
+        match res.chunk().await {
+            Ok(Some(chunk)) => {
+                if body.len() + chunk.len() > limit {
+                    return Err(ErrorBody::response_too_large(limit));
+                }
+                body.extend_from_slice(&chunk);
+            },
+            Ok(None) => return Ok(Ok(String::from_utf8_lossy(&body).into_owned())),
+            Err(e) => return Ok(Err(e)),
+        }
+    }
+}
+
+/// Checks the status of a response whose body is not an error object
+///
+/// Unsuccessful responses (and empty ones, e.g. `204 No Content`) fail with an
+/// [HTTP_ERROR](ErrorBody::http_error) of their status, unless their body is an error object
+/// (which is returned when the body is parsed).
+fn check_status(status: StatusCode, text: &str) -> BodyResult<()> {
+    if status.is_success() && !text.trim().is_empty() {
+        return Ok(());
+    }
+
+    match serde_json::from_str::<Object>(text).ok().and_then(|object| object.object) {
+        Some(ResourceKind::Error) => Ok(()),
+        _ => Err(ErrorBody::http_error(status)),
+    }
+}
+
+/// Parses a raw response body into a model (or an error body)
+pub(crate) fn parse_body<M>(text: &str) -> BodyResult<M>
+    where M: for<'de> Deserialize<'de>
+{
+    match serde_json::from_str::<Response<M>>(text) {
+        Ok(Response::Ok(body_ok)) => Ok(body_ok),
+        Ok(Response::Err(body_err)) => Err(body_err),
+        Err(e) => Err(ErrorBody::client_error(format!("error decoding response body: {e}"))),
+    }
+}
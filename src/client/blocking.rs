@@ -1,89 +1,455 @@
 //! Blocking client implementation
 //!
+//! The blocking client is a thin wrapper driving the [async client](crate::Scryfall)
+//! on an internal (current thread) runtime, so both clients always share the same features
+//! (caching, rate limiting, retries, circuit breaking, validation and helpers).
+//!
+//! As with any blocking client, it must not be used from within an async runtime.
+//!
 //! Blocking has to be enabled by the **blocking** optional feature.
 
-use reqwest::blocking::{RequestBuilder, Client};
-use reqwest::Error;
+use std::future::Future;
+use std::sync::Arc;
+use reqwest::Client;
 use serde::Deserialize;
+use tokio::runtime::{Builder, Runtime};
 
 use crate::HttpResource;
-use crate::resources::Response;
+#[cfg(feature = "cards")]
+use crate::resources::cards::{Card, SearchQueryParams, TagKind};
+#[cfg(feature = "cards")]
+use crate::resources::card_symbols::ColorSymbol;
+#[cfg(feature = "cards")]
+use crate::ids::{CardId, IdKind};
+#[cfg(feature = "cards")]
+use crate::prints::Printing;
+#[cfg(feature = "cards")]
+use crate::booster::{PackModel, PackValue};
+#[cfg(feature = "cards")]
+use crate::export::{Export, ExportManifest};
+#[cfg(feature = "cards")]
+use crate::hydrate::{HydrateOptions, Hydrated};
+#[cfg(feature = "cards")]
+use uuid::Uuid;
+#[cfg(feature = "cards")]
+use time::Date;
+#[cfg(feature = "cards")]
+use crate::resources::migrations::Migration;
+#[cfg(all(feature = "cards", feature = "sets"))]
+use crate::sets::PreviewProgress;
+#[cfg(feature = "sets")]
+use crate::resources::card_sets::CardSet;
+use crate::CatalogResource;
+#[cfg(feature = "bulk")]
+use crate::resources::bulk_data::{BulkData, BulkDownload};
+use crate::resources::catalog::TypeCatalog;
 use crate::resources::errors::ErrorBody;
+use super::builder::ScryfallBuilder;
+use super::cache::ResponseCache;
+use super::meta::ResponseMeta;
+use super::circuit_breaker::CircuitBreaker;
+use super::events::ClientEvent;
+use super::prepared::PreparedRequest;
+use super::rate_limit::{Priority, RateLimiter};
+use super::retry::RetryPolicy;
+use crate::resources::validation::ValidationMode;
 
-type ResponseResult<M> = Result<Response<M>, Error>;
 type BodyResult<M> = Result<M, ErrorBody>;
 
 /// Scryfall blocking client
 #[derive(Clone)]
 pub struct Scryfall<'a> {
-    base_url: &'a str,
-    http_client: Client
+    client: crate::Scryfall<'a>,
+    runtime: Arc<Runtime>,
 }
 
 impl<'a> Scryfall<'a> {
     /// Creates a client from a URL
+    ///
+    /// The URL is not checked: use [try_from_url](Self::try_from_url)
+    /// for URLs coming from users or configuration.
     #[must_use]
     pub fn from_url(url: &'a str) -> Self {
-        Scryfall {
-            base_url: url,
-            http_client: Client::builder()
-                .user_agent("Rust-SDK: sync-client")
-                .build()
-                .unwrap_or_default()
-        }
+        ScryfallBuilder::new().base_url(url).build_blocking()
+    }
+
+    /// Creates a client from a URL, checking that it is an absolute `http` or `https` URL
+    ///
+    /// See [try_base_url](ScryfallBuilder::try_base_url).
+    pub fn try_from_url(url: &'a str) -> BodyResult<Self> {
+        Ok(ScryfallBuilder::new().try_base_url(url)?.build_blocking())
+    }
+
+    /// Creates a builder for tuning the client
+    #[must_use]
+    pub fn builder() -> ScryfallBuilder<'a> {
+        ScryfallBuilder::new()
+    }
+
+    /// Creates a blocking client driving the given async client
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal runtime cannot be created.
+    #[must_use]
+    pub fn from_async(client: crate::Scryfall<'a>) -> Self {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Expected a runtime for the blocking client");
+
+        Scryfall { client, runtime: Arc::new(runtime) }
+    }
+
+    /// Gets the async client driven by this client
+    #[must_use]
+    pub fn as_async(&self) -> &crate::Scryfall<'a> {
+        &self.client
+    }
+
+    /// Runs a future of the async client to completion
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Enables caching of GET responses in the given cache
+    ///
+    /// The cache can be shared with other clients by cloning it.
+    #[must_use]
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.client = self.client.with_cache(cache);
+        self
     }
 
-    /// Gets a clone of the wrapped Client object
+    /// Gets the response cache (if enabled)
+    #[must_use]
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.client.cache()
+    }
+
+    /// Enables caching of search pages in the given cache
+    ///
+    /// Searches are cached under a normalized key (see [SearchQueryParams::normalized](crate::resources::cards::SearchQueryParams::normalized)),
+    /// so equivalent searches share cached pages. Searches are then no longer stored
+    /// in the response cache, so they can have their own time-to-live.
+    #[must_use]
+    pub fn with_search_cache(mut self, cache: ResponseCache) -> Self {
+        self.client = self.client.with_search_cache(cache);
+        self
+    }
+
+    /// Gets the search cache (if enabled)
+    #[must_use]
+    pub fn search_cache(&self) -> Option<&ResponseCache> {
+        self.client.search_cache()
+    }
+
+    /// Enables caching of not found (404) responses in the given cache
+    ///
+    /// Repeated lookups of unknown cards (e.g. user-typed names) are then answered
+    /// with the cached error. A short time-to-live is recommended, since cards
+    /// may be added at any time (e.g. during previews).
+    #[must_use]
+    pub fn with_not_found_cache(mut self, cache: ResponseCache) -> Self {
+        self.client = self.client.with_not_found_cache(cache);
+        self
+    }
+
+    /// Gets the not found cache (if enabled)
+    #[must_use]
+    pub fn not_found_cache(&self) -> Option<&ResponseCache> {
+        self.client.not_found_cache()
+    }
+
+    /// Removes the cached not found response of an endpoint (if any)
+    pub fn invalidate_not_found<R, M>(&self, resource: &R)
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        self.client.invalidate_not_found(resource)
+    }
+
+    /// Enables throttling of requests with the given rate limiter
+    ///
+    /// The rate limiter can be shared with other clients by cloning it.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(rate_limiter);
+        self
+    }
+
+    /// Gets the rate limiter (if enabled)
+    #[must_use]
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.client.rate_limiter()
+    }
+
+    /// Enables failing fast with the given circuit breaker
+    ///
+    /// The circuit breaker can be shared with other clients by cloning it.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.client = self.client.with_circuit_breaker(circuit_breaker);
+        self
+    }
+
+    /// Enables retrying failed requests with the given policy
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.client = self.client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Registers a hook invoked with every [client event](ClientEvent)
+    #[must_use]
+    pub fn on_event<F>(mut self, handler: F) -> Self
+        where F: Fn(&ClientEvent) + Send + Sync + 'static
+    {
+        self.client = self.client.on_event(handler);
+        self
+    }
+
+    /// Sets the priority lane of the requests made by this client
+    ///
+    /// A clone with background priority can be used for batch jobs,
+    /// while sharing the rate limiter with interactive clones.
+    #[must_use]
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.client = self.client.with_priority(priority);
+        self
+    }
+
+    /// Sets how parsed responses are [validated](crate::resources::validation)
+    #[must_use]
+    pub fn with_validation(mut self, mode: ValidationMode) -> Self {
+        self.client = self.client.with_validation(mode);
+        self
+    }
+
+    /// Gets a clone of the wrapped (async) Client object
     ///
     /// Cloning the Client happens in order to re-use its connection pool.
     #[must_use]
     pub fn http_client(&self) -> Client {
-        self.http_client.clone()
+        self.client.http_client()
     }
 
-    /// Makes an HTTP request to an endpoint
-    pub fn request<R, M>(&self, resource: &R) -> BodyResult<M> //Result<R, Error>
+    /// Prepares the HTTP request of an endpoint, without sending it
+    ///
+    /// See [prepared](crate::client::prepared) requests.
+    pub fn prepare<R, M>(&self, resource: &R) -> BodyResult<PreparedRequest>
         where R: HttpResource<M>,
               M: for<'de> Deserialize<'de>
     {
-        let req = self.build_request(resource);
-
-        match req.send() {
-            Ok(req_ok) => {
-                let res = req_ok.json::<Response<M>>();
-                self.extract_body(res)
-            },
-            Err(e) => Result::Err(ErrorBody::from_reqwest_error(e)),
-        }
-    }
-    
-    fn build_request<R, M>(&self, resource: &R) -> RequestBuilder
+        self.client.prepare(resource)
+    }
+
+    /// Decodes the response of an endpoint sent by a custom transport
+    ///
+    /// See [decode](crate::Scryfall::decode).
+    #[cfg(feature = "http")]
+    pub fn decode<R, M>(&self, resource: &R, response: http::Response<Vec<u8>>) -> BodyResult<M>
         where R: HttpResource<M>,
               M: for<'de> Deserialize<'de>
     {
-        let url = format!("{}/{}", self.base_url, resource.path());
+        self.client.decode(resource, response)
+    }
 
-        let mut req = self.http_client()
-            .request(resource.method(), url)
-            .header("Content-Type", "application/json");
-       
-        if let Some(b) = resource.json() {
-            req = req.body(b);
-        }
+    /// Makes an HTTP request to an endpoint
+    pub fn request<R, M>(&self, resource: &R) -> BodyResult<M>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        self.block_on(self.client.request(resource))
+    }
 
-        req
+    /// Makes an HTTP request to an endpoint, returning the [metadata](ResponseMeta) of the response along with the model
+    ///
+    /// The metadata tells whether the response was served from the cache, how long it stays fresh,
+    /// and the warnings of the response (e.g. about ignored search terms).
+    pub fn request_with_meta<R, M>(&self, resource: &R) -> BodyResult<(M, ResponseMeta)>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
+    {
+        self.block_on(self.client.request_with_meta(resource))
     }
 
-    fn extract_body<M>(&self, result: ResponseResult<M>) -> BodyResult<M> 
-        where M: for<'de> Deserialize<'de>
+    /// Makes an HTTP request to an endpoint, deserializing the response into a custom model
+    ///
+    /// The custom model can be any struct deriving `Deserialize` with a subset of the fields
+    /// of the endpoint's model (see [parse_partial](crate::client::parse_partial)).
+    pub fn request_partial<T, R, M>(&self, resource: &R) -> BodyResult<T>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>,
+              T: for<'de> Deserialize<'de>
+    {
+        self.block_on(self.client.request_partial(resource))
+    }
+
+    /// Makes an HTTP request to an endpoint, bypassing the cache
+    ///
+    /// Successful responses of GET endpoints are stored in the cache (if enabled).
+    pub fn refresh<R, M>(&self, resource: &R) -> BodyResult<M>
+        where R: HttpResource<M>,
+              M: for<'de> Deserialize<'de>
     {
-        match result {
-            Ok(response) => match response {
-                Response::Ok(body_ok) => Result::Ok(body_ok),
-                Response::Err(body_err) => Result::Err(body_err),
-            }    
-            Err(e) => Result::Err(ErrorBody::from_reqwest_error(e)),
-        }
+        self.block_on(self.client.refresh(resource))
+    }
+
+    /// Searches for cards, following all result pages
+    ///
+    /// The `page` parameter is used as the starting page (defaults to the first one).
+    #[cfg(feature = "cards")]
+    pub fn search_all(&self, params: SearchQueryParams) -> BodyResult<Vec<Card>> {
+        self.block_on(self.client.search_all(params))
+    }
+
+    /// Searches for cards, following the `next_page` links of all result pages
+    /// and recording them in an [export manifest](crate::export)
+    ///
+    /// See [search_export](crate::Scryfall::search_export).
+    #[cfg(feature = "cards")]
+    pub fn search_export(&self, params: SearchQueryParams) -> BodyResult<Export> {
+        self.block_on(self.client.search_export(params))
+    }
+
+    /// Starts a search export, without fetching any page
+    ///
+    /// See [start_export](crate::Scryfall::start_export).
+    #[cfg(feature = "cards")]
+    pub fn start_export(&self, params: SearchQueryParams) -> BodyResult<Export> {
+        self.block_on(self.client.start_export(params))
+    }
+
+    /// Fetches the next page of an export (if any), adding its new cards to the export
+    ///
+    /// Returns whether there are more pages to fetch.
+    #[cfg(feature = "cards")]
+    pub fn export_next_page(&self, export: &mut Export) -> BodyResult<bool> {
+        self.block_on(self.client.export_next_page(export))
+    }
+
+    /// Continues an interrupted export from its saved manifest, following the remaining pages
+    #[cfg(feature = "cards")]
+    pub fn resume_export(&self, manifest: ExportManifest) -> BodyResult<Export> {
+        self.block_on(self.client.resume_export(manifest))
+    }
+
+    /// Fetches again the pages recorded in an export manifest (in order)
+    #[cfg(feature = "cards")]
+    pub fn replay_export(&self, manifest: &ExportManifest) -> BodyResult<Vec<Card>> {
+        self.block_on(self.client.replay_export(manifest))
+    }
+
+    /// Fetches the sets, rulings and related cards of cards, as [hydrated](crate::hydrate) data
+    ///
+    /// See [hydrate](crate::Scryfall::hydrate).
+    #[cfg(feature = "cards")]
+    pub fn hydrate(&self, cards: &[Card], options: HydrateOptions) -> BodyResult<Hydrated> {
+        self.block_on(self.client.hydrate(cards, options))
+    }
+
+    /// Gets all creatures legal in standard
+    #[cfg(feature = "cards")]
+    pub fn standard_legal_creatures(&self) -> BodyResult<Vec<Card>> {
+        self.block_on(self.client.standard_legal_creatures())
+    }
+
+    /// Gets all cards that can be commanders of a deck with the given color identity
+    #[cfg(feature = "cards")]
+    pub fn commanders_in_identity(&self, colors: &[ColorSymbol]) -> BodyResult<Vec<Card>> {
+        self.block_on(self.client.commanders_in_identity(colors))
+    }
+
+    /// Gets all cards with a keyword ability (e.g. `Cascade`)
+    #[cfg(feature = "cards")]
+    pub fn cards_with_keyword(&self, keyword: &str) -> BodyResult<Vec<Card>> {
+        self.block_on(self.client.cards_with_keyword(keyword))
+    }
+
+    /// Gets all cards with a Tagger tag (e.g. art tag `squirrel`)
+    #[cfg(feature = "cards")]
+    pub fn cards_with_tag(&self, kind: TagKind, tag: &str) -> BodyResult<Vec<Card>> {
+        self.block_on(self.client.cards_with_tag(kind, tag))
+    }
+
+    /// Downloads the file of a bulk data item to a writer, returning the number of bytes written
+    /// and the encoding of the response
+    ///
+    /// See [download_bulk](crate::Scryfall::download_bulk).
+    #[cfg(feature = "bulk")]
+    pub fn download_bulk<W: std::io::Write>(&self, bulk: &BulkData, writer: &mut W) -> BodyResult<BulkDownload> {
+        self.block_on(self.client.download_bulk(bulk, writer))
+    }
+
+    /// Gets a catalog of types (e.g. [creature types](crate::CatalogResource::CreatureTypes))
+    ///
+    /// See [type_catalog](crate::Scryfall::type_catalog).
+    pub fn type_catalog(&self, resource: CatalogResource) -> BodyResult<TypeCatalog> {
+        self.block_on(self.client.type_catalog(resource))
+    }
+
+    /// Gets the sets released after today, ordered by release date
+    ///
+    /// Sets without cards (announced, but not previewed yet) can be skipped with `skip_empty`.
+    #[cfg(feature = "sets")]
+    pub fn upcoming_sets(&self, skip_empty: bool) -> BodyResult<Vec<CardSet>> {
+        self.block_on(self.client.upcoming_sets(skip_empty))
+    }
+
+    /// Gets the preview progress of a set (N of M cards revealed)
+    #[cfg(all(feature = "cards", feature = "sets"))]
+    pub fn preview_progress(&self, set_code: &str) -> BodyResult<PreviewProgress> {
+        self.block_on(self.client.preview_progress(set_code))
+    }
+
+    /// Converts a card identifier to another kind, by fetching the card it refers to
+    ///
+    /// Returns `None` if the card has no identifier of the requested kind.
+    #[cfg(feature = "cards")]
+    pub fn convert_id(&self, from: &CardId, to: IdKind) -> BodyResult<Option<CardId>> {
+        self.block_on(self.client.convert_id(from, to))
+    }
+
+    /// Converts many card identifiers to another kind (in the same order)
+    ///
+    /// Identifiers supported by the collection endpoint are fetched in batches,
+    /// the rest one by one. Identifiers of unknown cards are converted to `None`.
+    #[cfg(feature = "cards")]
+    pub fn convert_ids(&self, from: &[CardId], to: IdKind) -> BodyResult<Vec<Option<CardId>>> {
+        self.block_on(self.client.convert_ids(from, to))
+    }
+
+    /// Gets all printings of an oracle card, ordered by release date
+    #[cfg(feature = "cards")]
+    pub fn print_history(&self, oracle_id: &Uuid) -> BodyResult<Vec<Printing>> {
+        self.block_on(self.client.print_history(oracle_id))
+    }
+
+    /// Gets the original (earliest) printing of an oracle card (if any)
+    #[cfg(feature = "cards")]
+    pub fn original_printing(&self, oracle_id: &Uuid) -> BodyResult<Option<Card>> {
+        self.block_on(self.client.original_printing(oracle_id))
+    }
+
+    /// Gets `n` distinct random cards matching a search
+    #[cfg(feature = "cards")]
+    pub fn random_sample(&self, params: SearchQueryParams, n: usize) -> BodyResult<Vec<Card>> {
+        self.block_on(self.client.random_sample(params, n))
+    }
+
+    /// Estimates the expected value of a booster pack of a set
+    #[cfg(feature = "cards")]
+    pub fn pack_value(&self, set_code: &str, model: &PackModel) -> BodyResult<PackValue> {
+        self.block_on(self.client.pack_value(set_code, model))
+    }
+
+    /// Gets all migrations performed on or after the given date (newest first)
+    ///
+    /// All migrations are fetched when no date is given.
+    #[cfg(feature = "cards")]
+    pub fn migrations_since(&self, since: Option<Date>) -> BodyResult<Vec<Migration>> {
+        self.block_on(self.client.migrations_since(since))
     }
 }
 
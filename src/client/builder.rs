@@ -0,0 +1,369 @@
+//! Client builder
+//!
+//! Allows tuning the underlying HTTP client (e.g. its connection pool)
+//! before creating an async or blocking client.
+//!
+//! # Example
+//! ```
+//! use std::time::Duration;
+//! use scryfall_sdk_rust::ScryfallBuilder;
+//!
+//! let client = ScryfallBuilder::new()
+//!     .pool_idle_timeout(Duration::from_secs(30))
+//!     .pool_max_idle_per_host(4)
+//!     .build();
+//! ```
+//!
+//! The base URL can have a path prefix (e.g. `https://proxy.internal/scryfall`), and trailing
+//! slashes are ignored. [try_base_url](ScryfallBuilder::try_base_url) also rejects URLs
+//! that are not absolute `http` or `https` URLs, which [base_url](ScryfallBuilder::base_url)
+//! does not check.
+
+use std::time::Duration;
+use url::Url;
+use crate::client::ClientOptions;
+use crate::client::cache::ResponseCache;
+use crate::client::circuit_breaker::CircuitBreaker;
+use crate::client::dump::ResponseDump;
+use crate::client::events::{ClientEvent, EventHandler};
+use crate::client::rate_limit::RateLimiter;
+use crate::client::retry::RetryPolicy;
+use crate::resources::errors::ErrorBody;
+use crate::resources::validation::ValidationMode;
+use crate::Scryfall;
+
+/// Default request timeout of blocking clients
+#[cfg(feature = "blocking")]
+const BLOCKING_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Builder for [Scryfall](crate::Scryfall) clients
+#[derive(Clone, Debug)]
+pub struct ScryfallBuilder<'a> {
+    base_url: &'a str,
+    user_agent: Option<String>,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    http2_prior_knowledge: bool,
+    http2_adaptive_window: bool,
+    http2_keep_alive_interval: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    timeout: Option<Duration>,
+    options: ClientOptions,
+}
+
+impl<'a> ScryfallBuilder<'a> {
+    /// Creates a builder for the default Scryfall API URL
+    #[must_use]
+    pub fn new() -> Self {
+        ScryfallBuilder {
+            base_url: "https://api.scryfall.com",
+            user_agent: None,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            http2_prior_knowledge: false,
+            http2_adaptive_window: false,
+            http2_keep_alive_interval: None,
+            tcp_keepalive: None,
+            timeout: None,
+            options: ClientOptions::default(),
+        }
+    }
+
+    /// Sets the base URL of the API
+    ///
+    /// Trailing slashes are ignored, so that `https://proxy.internal/scryfall/`
+    /// requests `https://proxy.internal/scryfall/cards/...`.
+    ///
+    /// The URL is not checked: use [try_base_url](ScryfallBuilder::try_base_url)
+    /// for URLs coming from users or configuration.
+    #[must_use]
+    pub fn base_url(mut self, url: &'a str) -> Self {
+        self.base_url = url.trim_end_matches('/');
+        self
+    }
+
+    /// Sets the base URL of the API, checking that it is an absolute `http` or `https` URL
+    /// without query or fragment
+    ///
+    /// Fails with an `INVALID_INPUT` error for other URLs.
+    pub fn try_base_url(self, url: &'a str) -> Result<Self, ErrorBody> {
+        validate_base_url(url)?;
+
+        Ok(self.base_url(url))
+    }
+
+    /// Overrides the `User-Agent` header sent with every request
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets how long idle connections are kept in the pool
+    #[must_use]
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept per host
+    #[must_use]
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Uses HTTP/2 without negotiating it first
+    #[must_use]
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    }
+
+    /// Enables HTTP/2 adaptive flow control
+    #[must_use]
+    pub fn http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.http2_adaptive_window = enabled;
+        self
+    }
+
+    /// Sets the interval of HTTP/2 keep-alive pings
+    #[must_use]
+    pub fn http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Sets the TCP keep-alive duration of connections
+    #[must_use]
+    pub fn tcp_keepalive(mut self, keepalive: Duration) -> Self {
+        self.tcp_keepalive = Some(keepalive);
+        self
+    }
+
+    /// Sets the timeout of each request (from connecting until the response body is read)
+    ///
+    /// Timed out requests fail with a client error (and are retried by the retry policy, if any).
+    /// Async clients have no timeout by default, blocking clients time out after 30 seconds.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum size (in bytes) of response bodies
+    ///
+    /// Larger responses (e.g. from misbehaving proxies) fail with a
+    /// [response too large](crate::resources::errors::ErrorBody::response_too_large) error,
+    /// without buffering more than the limit. Responses are not limited by default.
+    #[must_use]
+    pub fn max_response_size(mut self, bytes: usize) -> Self {
+        self.options.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Enables caching of GET responses in the given cache
+    #[must_use]
+    pub fn cache(mut self, cache: ResponseCache) -> Self {
+        self.options.cache = Some(cache);
+        self
+    }
+
+    /// Enables caching of search pages (by normalized search) in the given cache
+    #[must_use]
+    pub fn search_cache(mut self, cache: ResponseCache) -> Self {
+        self.options.search_cache = Some(cache);
+        self
+    }
+
+    /// Enables caching of not found (404) responses in the given cache
+    #[must_use]
+    pub fn not_found_cache(mut self, cache: ResponseCache) -> Self {
+        self.options.not_found_cache = Some(cache);
+        self
+    }
+
+    /// Enables throttling of requests with the given rate limiter
+    ///
+    /// Clones of the limiter share the same budget, so it can be shared between clients.
+    #[must_use]
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.options.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Enables failing fast with the given circuit breaker
+    #[must_use]
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.options.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Enables retrying failed requests with the given policy
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.options.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Registers a hook invoked with every [client event](ClientEvent)
+    #[must_use]
+    pub fn on_event<F>(mut self, handler: F) -> Self
+        where F: Fn(&ClientEvent) + Send + Sync + 'static
+    {
+        self.options.on_event = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// Writes the raw body of every response to a [dump](crate::client::dump) (for debugging)
+    #[must_use]
+    pub fn dump_responses(mut self, dump: ResponseDump) -> Self {
+        self.options.dump = Some(dump);
+        self
+    }
+
+    /// Sets how parsed responses are [validated](crate::resources::validation)
+    #[must_use]
+    pub fn validation(mut self, mode: ValidationMode) -> Self {
+        self.options.validation = mode;
+        self
+    }
+
+    /// Builds an async client
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be built (e.g. with an invalid user agent):
+    /// use [try_build](ScryfallBuilder::try_build) to handle this error.
+    #[must_use]
+    pub fn build(self) -> Scryfall<'a> {
+        self.try_build().expect("Failed to build the HTTP client, use try_build to handle this error")
+    }
+
+    /// Builds an async client, failing with a client error if the HTTP client cannot be built
+    pub fn try_build(mut self) -> Result<Scryfall<'a>, ErrorBody> {
+        let user_agent = self.user_agent.unwrap_or_else(|| "Rust-SDK: async-clients".into());
+
+        let mut builder = reqwest::Client::builder()
+            .user_agent(&user_agent)
+            .http2_adaptive_window(self.http2_adaptive_window)
+            .tcp_keepalive(self.tcp_keepalive);
+
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(interval) = self.http2_keep_alive_interval {
+            builder = builder.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        self.options.user_agent = Some(user_agent);
+
+        Ok(Scryfall::from_parts(
+            self.base_url,
+            builder.build().map_err(ErrorBody::from_reqwest_error)?,
+            self.options,
+        ))
+    }
+
+    /// Builds a blocking client
+    ///
+    /// The blocking client drives an async client (built with the same settings)
+    /// on an internal runtime. Unless a [timeout](ScryfallBuilder::timeout) is set,
+    /// requests time out after 30 seconds (like the blocking client of reqwest).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be built (e.g. with an invalid user agent):
+    /// use [try_build_blocking](ScryfallBuilder::try_build_blocking) to handle this error.
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn build_blocking(self) -> crate::ScryfallBlocking<'a> {
+        self.try_build_blocking().expect("Failed to build the HTTP client, use try_build_blocking to handle this error")
+    }
+
+    /// Builds a blocking client, failing with a client error if the HTTP client cannot be built
+    #[cfg(feature = "blocking")]
+    pub fn try_build_blocking(mut self) -> Result<crate::ScryfallBlocking<'a>, ErrorBody> {
+        self.user_agent = self.user_agent.or_else(|| Some("Rust-SDK: sync-client".into()));
+        self.timeout = self.timeout.or(Some(BLOCKING_TIMEOUT));
+
+        Ok(crate::ScryfallBlocking::from_async(self.try_build()?))
+    }
+}
+
+impl<'a> Default for ScryfallBuilder<'a> {
+    fn default() -> Self {
+        ScryfallBuilder::new()
+    }
+}
+
+/// Checks that a base URL is an absolute `http` or `https` URL, without query or fragment
+pub(crate) fn validate_base_url(url: &str) -> Result<(), ErrorBody> {
+    let invalid = |reason: &str| ErrorBody::invalid_input(format!("Invalid base URL `{url}`: {reason}"));
+    let parsed = Url::parse(url).map_err(|e| invalid(&e.to_string()))?;
+
+    match parsed.scheme() {
+        "http" | "https" => {},
+        scheme => return Err(invalid(&format!("unsupported scheme `{scheme}`"))),
+    }
+    if parsed.query().is_some() || parsed.fragment().is_some() {
+        return Err(invalid("query and fragment are not supported"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::https("https://api.scryfall.com")]
+    #[case::prefix("https://proxy.internal/scryfall")]
+    #[case::trailing_slash("http://127.0.0.1:8080/scryfall/")]
+    fn http_base_urls_should_be_accepted(#[case] url: &str) {
+        assert!(ScryfallBuilder::new().try_base_url(url).is_ok());
+    }
+
+    #[rstest]
+    #[case::relative("api.scryfall.com")]
+    #[case::scheme("ftp://api.scryfall.com")]
+    #[case::file("file:///tmp/scryfall")]
+    #[case::query("https://api.scryfall.com?format=json")]
+    #[case::fragment("https://api.scryfall.com#cards")]
+    fn other_base_urls_should_be_rejected(#[case] url: &str) {
+        let error = ScryfallBuilder::new().try_base_url(url).unwrap_err();
+
+        assert_eq!("INVALID_INPUT", error.code);
+    }
+
+    #[rstest]
+    fn trailing_slashes_should_be_ignored() {
+        let builder = ScryfallBuilder::new().base_url("https://proxy.internal/scryfall//");
+
+        assert_eq!("https://proxy.internal/scryfall", builder.base_url);
+    }
+
+    #[rstest]
+    fn invalid_settings_should_fail_to_build() {
+        let error = ScryfallBuilder::new().user_agent("Rust-SDK\n").try_build().err().unwrap();
+
+        assert_eq!("CLIENT_ERR", error.code);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Failed to build the HTTP client")]
+    fn invalid_settings_should_panic_on_build() {
+        let _ = ScryfallBuilder::new().user_agent("Rust-SDK\n").build();
+    }
+}
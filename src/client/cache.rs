@@ -0,0 +1,227 @@
+//! In-memory response cache
+//!
+//! Caches the raw body of successful GET responses, keyed by the requested URL.
+//! The cache is shared between clones (of the cache and of the clients using it).
+//!
+//! The time-to-live of the entries stored by the clients follows the caching headers of
+//! the responses (see [Freshness](super::meta::Freshness)): responses are kept as long as
+//! their `Cache-Control: max-age` (or `Expires`) allows, and not stored at all with
+//! `no-store` or `no-cache`. The time-to-live of the cache only applies to responses
+//! without caching headers, unless the headers are [ignored](ResponseCache::ignore_cache_headers).
+//!
+//! Expired entries are removed when new entries are stored, and the cache holds at most
+//! [max_entries](ResponseCache::with_max_entries) entries (10 000 by default), evicting the
+//! oldest entries first.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use super::meta::Freshness;
+
+const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+/// Shared in-memory cache of response bodies
+#[derive(Clone, Debug)]
+pub struct ResponseCache {
+    ttl: Duration,
+    respect_headers: bool,
+    max_entries: usize,
+    entries: Arc<RwLock<HashMap<String, CacheEntry>>>,
+}
+
+#[derive(Clone, Debug)]
+struct CacheEntry {
+    body: String,
+    stored_at: Instant,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    /// Creates a cache whose entries expire after the given time-to-live
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        ResponseCache { ttl, respect_headers: true, max_entries: DEFAULT_MAX_ENTRIES, entries: Arc::default() }
+    }
+
+    /// Sets the maximum number of entries, evicting the oldest entries when the cache is full
+    #[must_use]
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Ignores the caching headers of responses, so that all entries expire after the time-to-live of the cache
+    #[must_use]
+    pub fn ignore_cache_headers(mut self) -> Self {
+        self.respect_headers = false;
+        self
+    }
+
+    /// Gets the cached body of a URL, if present and not expired
+    pub fn get(&self, url: &str) -> Option<String> {
+        self.lookup(url).map(|(body, _)| body)
+    }
+
+    /// Gets the cached body of a URL and its remaining time-to-live, if present and not expired
+    pub fn lookup(&self, url: &str) -> Option<(String, Duration)> {
+        let entries = self.entries.read().ok()?;
+        let entry = entries.get(url)?;
+
+        entry.ttl.checked_sub(entry.stored_at.elapsed())
+            .filter(|remaining| !remaining.is_zero())
+            .map(|remaining| (entry.body.clone(), remaining))
+    }
+
+    /// Stores the body of a URL, replacing any previous entry
+    pub fn insert(&self, url: &str, body: String) {
+        self.insert_with_ttl(url, body, self.ttl);
+    }
+
+    /// Stores the body of a URL with its own time-to-live, replacing any previous entry
+    ///
+    /// Expired entries are removed first, then the oldest entries if the cache is still full.
+    pub fn insert_with_ttl(&self, url: &str, body: String, ttl: Duration) {
+        if self.max_entries == 0 {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.write() {
+            entries.retain(|key, entry| key != url && !entry.is_expired());
+
+            while entries.len() >= self.max_entries {
+                let oldest = entries.iter()
+                    .min_by_key(|(_, entry)| entry.stored_at)
+                    .map(|(key, _)| key.clone());
+
+                match oldest {
+                    Some(key) => entries.remove(&key),
+                    None => break,
+                };
+            }
+
+            entries.insert(url.into(), CacheEntry { body, stored_at: Instant::now(), ttl });
+        }
+    }
+
+    /// Stores the body of a response according to its freshness (unless the headers are ignored)
+    ///
+    /// Responses that must not be stored remove any previous entry of the URL.
+    pub fn insert_fresh(&self, url: &str, body: String, freshness: &Freshness) {
+        match self.respect_headers {
+            false => self.insert(url, body),
+            true if freshness.is_cacheable() => self.insert_with_ttl(url, body, freshness.max_age.unwrap_or(self.ttl)),
+            true => self.invalidate(url),
+        }
+    }
+
+    /// Removes the entry of a URL
+    pub fn invalidate(&self, url: &str) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.remove(url);
+        }
+    }
+
+    /// Removes all entries
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.clear();
+        }
+    }
+
+    /// Gets the number of entries (including expired ones not yet removed)
+    pub fn len(&self) -> usize {
+        self.entries.read().map(|e| e.len()).unwrap_or_default()
+    }
+
+    /// Checks whether the cache has no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CacheEntry {
+    fn is_expired(&self) -> bool {
+        self.stored_at.elapsed() >= self.ttl
+    }
+}
+
+impl Default for ResponseCache {
+    /// Creates a cache with a time-to-live of one day
+    fn default() -> Self {
+        ResponseCache::new(Duration::from_secs(24 * 60 * 60))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn cache_should_be_shared_between_clones() {
+        let cache = ResponseCache::default();
+        let clone = cache.clone();
+
+        clone.insert("http://some.url", "{}".into());
+
+        assert_eq!(Some("{}".into()), cache.get("http://some.url"));
+        assert_eq!(None, cache.get("http://other.url"));
+
+        cache.invalidate("http://some.url");
+        assert!(clone.is_empty());
+    }
+
+    #[rstest]
+    fn expired_entries_should_be_ignored() {
+        let cache = ResponseCache::new(Duration::ZERO);
+
+        cache.insert("http://some.url", "{}".into());
+
+        assert_eq!(None, cache.get("http://some.url"));
+        assert_eq!(1, cache.len());
+    }
+
+    #[rstest]
+    fn expired_entries_should_be_removed_on_insert() {
+        let cache = ResponseCache::new(Duration::from_secs(3600));
+        cache.insert_with_ttl("http://expired.url", "{}".into(), Duration::ZERO);
+        cache.insert_with_ttl("http://fresh.url", "{}".into(), Duration::from_secs(60));
+
+        cache.insert("http://some.url", "{}".into());
+
+        assert_eq!(2, cache.len());
+        assert_eq!(Some("{}".into()), cache.get("http://fresh.url"));
+    }
+
+    #[rstest]
+    fn oldest_entries_should_be_evicted_when_full() {
+        let cache = ResponseCache::default().with_max_entries(2);
+        cache.insert("http://first.url", "1".into());
+        cache.insert("http://second.url", "2".into());
+        cache.insert("http://first.url", "1".into());
+
+        cache.insert("http://third.url", "3".into());
+
+        assert_eq!(2, cache.len());
+        assert_eq!(None, cache.get("http://second.url"));
+        assert_eq!(Some("1".into()), cache.get("http://first.url"));
+        assert_eq!(Some("3".into()), cache.get("http://third.url"));
+    }
+
+    #[rstest]
+    #[case::max_age(Freshness { max_age: Some(Duration::from_secs(60)), no_store: false }, false, Some(60))]
+    #[case::no_headers(Freshness::default(), false, Some(3600))]
+    #[case::no_store(Freshness { max_age: None, no_store: true }, false, None)]
+    #[case::ignored(Freshness { max_age: None, no_store: true }, true, Some(3600))]
+    fn entries_should_expire_according_to_freshness(#[case] freshness: Freshness, #[case] ignore: bool, #[case] expected: Option<u64>) {
+        let cache = match ignore {
+            true => ResponseCache::new(Duration::from_secs(3600)).ignore_cache_headers(),
+            false => ResponseCache::new(Duration::from_secs(3600)),
+        };
+        cache.insert("http://some.url", "{}".into());
+
+        cache.insert_fresh("http://some.url", "{}".into(), &freshness);
+
+        let remaining = cache.lookup("http://some.url").map(|(_, ttl)| ttl);
+        assert_eq!(expected, remaining.map(|ttl| ttl.as_secs_f64().ceil() as u64));
+    }
+}
@@ -0,0 +1,133 @@
+//! Circuit breaker for persistent failures
+//!
+//! A [CircuitBreaker](CircuitBreaker) counts consecutive failed requests
+//! (server errors, timeouts, connection errors). After a threshold is reached
+//! the circuit opens and requests fail fast (with a `CIRCUIT_OPEN` error)
+//! until a cooldown period has passed, protecting both the application and the API
+//! during outages.
+//!
+//! After the cooldown, requests are let through again; the circuit
+//! closes on the first success or opens again on the next failure.
+//! Its state lives behind an `Arc` and is shared between clones.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use crate::resources::errors::ErrorBody;
+
+/// Shared circuit breaker
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Creates a breaker opening after `threshold` consecutive failures for `cooldown`
+    #[must_use]
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        CircuitBreaker { threshold: threshold.max(1), cooldown, state: Arc::default() }
+    }
+
+    /// Checks whether requests are allowed
+    ///
+    /// Returns a `CIRCUIT_OPEN` error while the circuit is open.
+    pub fn check(&self) -> Result<(), ErrorBody> {
+        let state = self.state();
+
+        match state.opened_at {
+            Some(opened_at) if opened_at.elapsed() < self.cooldown => Err(
+                ErrorBody::circuit_open(self.cooldown - opened_at.elapsed())
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks whether the circuit is open
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.check().is_err()
+    }
+
+    /// Records a successful request, closing the circuit
+    pub fn record_success(&self) {
+        let mut state = self.state();
+        state.failures = 0;
+        state.opened_at = None;
+    }
+
+    /// Records a failed request, opening the circuit when the threshold is reached
+    ///
+    /// Returns whether the circuit was opened.
+    pub fn record_failure(&self) -> bool {
+        let mut state = self.state();
+        state.failures = state.failures.saturating_add(1);
+
+        let open = state.failures >= self.threshold;
+        if open {
+            state.opened_at = Some(Instant::now());
+        }
+
+        open
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+impl Default for CircuitBreaker {
+    /// Creates a breaker opening after 5 consecutive failures for 30 seconds
+    fn default() -> Self {
+        CircuitBreaker::new(5, Duration::from_secs(30))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn breaker_should_open_after_threshold() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+
+        assert!(!breaker.record_failure());
+        assert!(!breaker.is_open());
+
+        assert!(breaker.clone().record_failure());
+        assert_eq!("CIRCUIT_OPEN", breaker.check().unwrap_err().code);
+
+        breaker.record_success();
+        assert!(!breaker.is_open());
+    }
+
+    #[rstest]
+    fn breaker_should_let_requests_through_after_cooldown() {
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+
+        breaker.record_failure();
+
+        assert!(!breaker.is_open());
+    }
+
+    #[rstest]
+    fn success_should_reset_failures() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+
+        assert!(!breaker.is_open());
+    }
+}
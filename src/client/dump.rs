@@ -0,0 +1,159 @@
+//! Persistence of raw responses (for debugging)
+//!
+//! A [ResponseDump](ResponseDump) writes the raw body of every response received by the
+//! clients to a directory, so that the payload behind a deserialization bug can be captured
+//! in production. Each response is written to its own file, named after the time it was
+//! received, its status and the requested path, e.g.
+//! `1718701330928-000042-200-get-cards-search-q-t-goblin.json`.
+//!
+//! The dump stops writing once its size budget is spent (100 MiB by default),
+//! so that it cannot fill the disk. Clones share the same budget.
+//! Responses served from the cache are not written, and failures to write are ignored.
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::ScryfallBuilder;
+//! use scryfall_sdk_rust::client::dump::ResponseDump;
+//!
+//! let client = ScryfallBuilder::new()
+//!     .dump_responses(ResponseDump::new("/tmp/scryfall-responses").max_bytes(10 * 1024 * 1024))
+//!     .build();
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default size budget of a dump (100 MiB)
+const DEFAULT_MAX_BYTES: usize = 100 * 1024 * 1024;
+
+/// Maximum length of the path-derived part of file names
+const MAX_SLUG_LEN: usize = 80;
+
+/// Directory receiving the raw bodies of responses
+#[derive(Clone, Debug)]
+pub struct ResponseDump {
+    dir: PathBuf,
+    max_bytes: usize,
+    written: Arc<AtomicUsize>,
+    sequence: Arc<AtomicU64>,
+}
+
+impl ResponseDump {
+    /// Creates a dump writing to a directory (created if needed)
+    #[must_use]
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        ResponseDump {
+            dir: dir.as_ref().to_path_buf(),
+            max_bytes: DEFAULT_MAX_BYTES,
+            written: Arc::default(),
+            sequence: Arc::default(),
+        }
+    }
+
+    /// Sets the maximum number of bytes written, after which responses are no longer written
+    #[must_use]
+    pub fn max_bytes(mut self, bytes: usize) -> Self {
+        self.max_bytes = bytes;
+        self
+    }
+
+    /// Gets the directory of the dump
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Gets the number of bytes written so far
+    pub fn written(&self) -> usize {
+        self.written.load(Ordering::Relaxed)
+    }
+
+    /// Writes the body of a response, if it fits in the remaining budget
+    ///
+    /// Returns the path of the written file.
+    pub fn write(&self, method: &str, url: &str, status: u16, body: &str) -> Option<PathBuf> {
+        let reserved = self.written.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |written| {
+            Some(written + body.len()).filter(|total| *total <= self.max_bytes)
+        });
+        if reserved.is_err() {
+            return None;
+        }
+
+        let millis = SystemTime::now().duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis())
+            .unwrap_or_default();
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let name = format!("{millis}-{sequence:06}-{status}-{}.json", slug(&format!("{method} {}", path_of(url))));
+        let path = self.dir.join(name);
+
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&path, body))
+            .ok()
+            .map(|_| path)
+    }
+}
+
+/// Gets the path and query of a URL (without scheme and host)
+fn path_of(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+
+    without_scheme.find('/')
+        .map(|start| &without_scheme[start..])
+        .unwrap_or("")
+}
+
+/// Converts a text to a lowercase file name part, with dashes between words
+fn slug(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    slug.truncate(MAX_SLUG_LEN);
+    slug.trim_end_matches('-').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("scryfall-sdk-{}-dump-{name}", std::process::id()))
+    }
+
+    #[rstest]
+    #[case::search("GET", "https://api.scryfall.com/cards/search?q=t%3Agoblin", "get-cards-search-q-t-3agoblin")]
+    #[case::prefix("GET", "http://127.0.0.1:8080/scryfall/sets/dom", "get-scryfall-sets-dom")]
+    #[case::no_path("POST", "https://api.scryfall.com", "post")]
+    fn file_names_should_be_derived_from_paths(#[case] method: &str, #[case] url: &str, #[case] expected: &str) {
+        assert_eq!(expected, slug(&format!("{method} {}", path_of(url))));
+    }
+
+    #[rstest]
+    fn long_paths_should_be_truncated() {
+        assert_eq!(MAX_SLUG_LEN, slug(&"a".repeat(200)).len());
+    }
+
+    #[rstest]
+    fn responses_should_be_written_within_budget() {
+        let dir = dir("budget");
+        let dump = ResponseDump::new(&dir).max_bytes(10);
+
+        let first = dump.write("GET", "https://api.scryfall.com/sets/dom", 200, "{\"a\":1}").unwrap();
+        let skipped = dump.clone().write("GET", "https://api.scryfall.com/sets/dom", 200, "{\"a\":1}");
+
+        assert_eq!("{\"a\":1}", fs::read_to_string(&first).unwrap());
+        assert!(first.file_name().unwrap().to_str().unwrap().ends_with("-000000-200-get-sets-dom.json"));
+        assert_eq!((None, 7), (skipped, dump.written()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+//! Structured client events
+//!
+//! Host applications can observe the behavior of the clients
+//! (requests, retries, throttling, caching, failures) by registering an `on_event` hook,
+//! without enabling full tracing.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::ScryfallBuilder;
+//! use scryfall_sdk_rust::client::events::ClientEvent;
+//!
+//! let client = ScryfallBuilder::new()
+//!     .on_event(|event: &ClientEvent| println!("{event:?}"))
+//!     .build();
+//! ```
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// An event emitted by a client
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClientEvent {
+    /// A request is about to be sent
+    RequestStarted {
+        method: String,
+        url: String,
+    },
+
+    /// A failed request will be retried after a delay
+    RetryScheduled {
+        url: String,
+        attempt: u32,
+        delay: Duration,
+    },
+
+    /// A request has to wait for the rate limiter
+    RateLimited {
+        wait: Duration,
+    },
+
+    /// A response was served from the cache
+    CacheHit {
+        url: String,
+    },
+
+    /// The circuit breaker opened after consecutive failures
+    CircuitOpened,
+
+    /// A response failed validation (see [ValidationMode](crate::resources::validation::ValidationMode))
+    InvalidResponse {
+        url: String,
+        violations: Vec<String>,
+    },
+}
+
+/// Shared hook invoked with every client event
+#[derive(Clone)]
+pub struct EventHandler(Arc<dyn Fn(&ClientEvent) + Send + Sync>);
+
+impl EventHandler {
+    /// Creates a handler from a function
+    pub fn new<F>(handler: F) -> Self
+        where F: Fn(&ClientEvent) + Send + Sync + 'static
+    {
+        EventHandler(Arc::new(handler))
+    }
+
+    /// Invokes the handler
+    pub fn emit(&self, event: &ClientEvent) {
+        (self.0)(event)
+    }
+}
+
+impl Debug for EventHandler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("EventHandler")
+    }
+}
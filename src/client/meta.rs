@@ -0,0 +1,178 @@
+//! Response metadata
+//!
+//! [request_with_meta](crate::Scryfall::request_with_meta) returns along with the model
+//! a [ResponseMeta](ResponseMeta), telling whether the response was served from the cache
+//! and how long it stays fresh, for consumers doing their own caching, along with the
+//! warnings of the response (e.g. about ignored parts of a search query).
+//!
+//! The [freshness](Freshness) of a response is computed from its `Cache-Control`
+//! (`max-age`, `no-store` and `no-cache` directives), `Expires`, `Date` and `Age` headers.
+//! The clients use it to set the time-to-live of cached responses
+//! (see [ResponseCache](super::cache::ResponseCache)).
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::{CardResource, Scryfall};
+//! use scryfall_sdk_rust::resources::errors::ErrorBody;
+//!
+//! async fn print_freshness(client: &Scryfall<'_>) -> Result<(), ErrorBody> {
+//!     let (card, meta) = client.request_with_meta(&CardResource::NamedExact("Opt".into())).await?;
+//!
+//!     println!("{} is fresh for {:?} (cached: {})", card.name, meta.freshness.max_age, meta.cached);
+//!     for warning in meta.warnings {
+//!         println!("Warning: {warning}");
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use std::time::Duration;
+use reqwest::header::{HeaderMap, AGE, CACHE_CONTROL, DATE, EXPIRES};
+use serde::Deserialize;
+use time::format_description::well_known::Rfc2822;
+use time::OffsetDateTime;
+
+/// Metadata of a response
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// Whether the response was served from a cache of the client
+    pub cached: bool,
+
+    /// How long the response stays fresh
+    ///
+    /// For cached responses, this is the remaining time-to-live of the cache entry.
+    pub freshness: Freshness,
+
+    /// The warnings of a successful response (e.g. about ignored search terms)
+    pub warnings: Vec<String>,
+}
+
+impl ResponseMeta {
+    /// Creates the metadata of a response, without warnings
+    ///
+    /// Warnings are only [extracted](warnings) from the body by
+    /// [request_with_meta](crate::Scryfall::request_with_meta).
+    pub(crate) fn new(cached: bool, freshness: Freshness) -> Self {
+        ResponseMeta { cached, freshness, warnings: vec![] }
+    }
+}
+
+/// Freshness of a response, from its caching headers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Freshness {
+    /// How long the response can be reused (`None` when the headers don't tell)
+    pub max_age: Option<Duration>,
+
+    /// Whether the response must not be reused without revalidation (`no-store` or `no-cache`)
+    pub no_store: bool,
+}
+
+impl Freshness {
+    /// Computes the freshness of a response from its headers
+    ///
+    /// `Cache-Control: max-age` takes precedence over `Expires`, which is relative
+    /// to the `Date` of the response (or now, without a date). The `Age` of the response
+    /// is deducted. Invalid `Expires` dates mean that the response has already expired.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+        let mut freshness = Freshness::default();
+
+        for directive in header(CACHE_CONTROL).unwrap_or_default().split(',') {
+            let directive = directive.trim().to_ascii_lowercase();
+
+            match directive.split_once('=') {
+                Some(("max-age", seconds)) => freshness.max_age = seconds.trim_matches('"').parse()
+                    .ok()
+                    .map(Duration::from_secs),
+                None if directive == "no-store" || directive == "no-cache" => freshness.no_store = true,
+                _ => {},
+            }
+        }
+
+        if freshness.max_age.is_none() {
+            freshness.max_age = header(EXPIRES).map(|expires| {
+                let now = header(DATE)
+                    .and_then(parse_date)
+                    .unwrap_or_else(OffsetDateTime::now_utc);
+
+                parse_date(expires)
+                    .map(|expires| Duration::try_from(expires - now).unwrap_or_default())
+                    .unwrap_or_default()
+            });
+        }
+
+        let age = header(AGE).and_then(|age| age.trim().parse().ok()).map(Duration::from_secs);
+        if let (Some(max_age), Some(age)) = (freshness.max_age, age) {
+            freshness.max_age = Some(max_age.saturating_sub(age));
+        }
+
+        freshness
+    }
+
+    /// Checks whether the response can be stored in a cache
+    pub fn is_cacheable(&self) -> bool {
+        !self.no_store && self.max_age != Some(Duration::ZERO)
+    }
+}
+
+/// The `warnings` field of a response
+#[derive(Deserialize)]
+struct Warnings {
+    warnings: Option<Vec<String>>,
+}
+
+/// Gets the warnings of a response body (if any)
+pub(crate) fn warnings(body: &str) -> Vec<String> {
+    serde_json::from_str::<Warnings>(body).ok()
+        .and_then(|w| w.warnings)
+        .unwrap_or_default()
+}
+
+/// Parses an HTTP date (e.g. `Sun, 06 Nov 1994 08:49:37 GMT`)
+fn parse_date(date: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(date.trim(), &Rfc2822).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use rstest::rstest;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs.iter()
+            .map(|(name, value)| (name.parse().unwrap(), HeaderValue::from_static(value)))
+            .collect()
+    }
+
+    #[rstest]
+    #[case::max_age(&[("cache-control", "public, max-age=3600")], Some(3600), false)]
+    #[case::no_store(&[("cache-control", "no-store")], None, true)]
+    #[case::age(&[("cache-control", "max-age=60"), ("age", "20")], Some(40), false)]
+    #[case::max_age_over_expires(&[("cache-control", "max-age=60"), ("expires", "Thu, 01 Jan 2037 00:00:00 GMT")], Some(60), false)]
+    #[case::expires(&[("date", "Sun, 06 Nov 1994 08:49:37 GMT"), ("expires", "Sun, 06 Nov 1994 09:49:37 GMT")], Some(3600), false)]
+    #[case::expired(&[("date", "Sun, 06 Nov 1994 08:49:37 GMT"), ("expires", "Sun, 06 Nov 1994 07:49:37 GMT")], Some(0), false)]
+    #[case::invalid_expires(&[("expires", "0")], Some(0), false)]
+    #[case::none(&[], None, false)]
+    fn freshness_should_be_parsed(#[case] pairs: &[(&'static str, &'static str)], #[case] max_age: Option<u64>, #[case] no_store: bool) {
+        let freshness = Freshness::from_headers(&headers(pairs));
+
+        assert_eq!(Freshness { max_age: max_age.map(Duration::from_secs), no_store }, freshness);
+    }
+
+    #[rstest]
+    #[case::warnings(r#"{ "object": "list", "warnings": ["Invalid expression \"x:y\" was ignored."] }"#, 1)]
+    #[case::null(r#"{ "object": "list", "warnings": null }"#, 0)]
+    #[case::none(r#"{ "object": "card" }"#, 0)]
+    fn warnings_should_be_extracted(#[case] body: &str, #[case] expected: usize) {
+        assert_eq!(expected, warnings(body).len());
+    }
+
+    #[rstest]
+    #[case(Freshness { max_age: None, no_store: false }, true)]
+    #[case(Freshness { max_age: Some(Duration::ZERO), no_store: false }, false)]
+    #[case(Freshness { max_age: Some(Duration::from_secs(1)), no_store: true }, false)]
+    fn cacheability_should_follow_freshness(#[case] freshness: Freshness, #[case] expected: bool) {
+        assert_eq!(expected, freshness.is_cacheable());
+    }
+}
@@ -0,0 +1,117 @@
+//! Prepared (dry-run) requests
+//!
+//! Both clients can [prepare](crate::Scryfall::prepare) the request of an endpoint without sending it,
+//! e.g. for logging requests, reproducing them with cURL, or asserting on request construction in tests.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::{CardResource, Scryfall};
+//!
+//! let request = Scryfall::default().prepare(&CardResource::NamedExact("Opt".into())).unwrap();
+//!
+//! assert_eq!("https://api.scryfall.com/cards/named?exact=Opt", request.url);
+//! println!("{}", request.to_curl());
+//! ```
+//!
+//! With the **http** feature, prepared requests can be converted to `http::Request`s
+//! (see [to_http](PreparedRequest::to_http)), to be sent by custom transports
+//! (e.g. lambda runtimes or service meshes). Their responses are then fed back to the client
+//! with [decode](crate::Scryfall::decode), which parses and validates them like the responses
+//! of the client's own requests.
+
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+
+/// An HTTP request prepared by a client, without sending it
+#[derive(Clone, Debug)]
+pub struct PreparedRequest {
+    pub method: Method,
+
+    /// The full (encoded) URL of the request
+    pub url: String,
+
+    /// The headers of the request (including the `User-Agent` of the client)
+    pub headers: HeaderMap,
+
+    /// The json body of the request (if any)
+    pub body: Option<String>,
+}
+
+impl PreparedRequest {
+    /// Renders the request as a cURL command
+    pub fn to_curl(&self) -> String {
+        let mut command = format!("curl -X {} {}", self.method, quote(&self.url));
+
+        for (name, value) in &self.headers {
+            let value = String::from_utf8_lossy(value.as_bytes());
+            command.push_str(&format!(" -H {}", quote(&format!("{name}: {value}"))));
+        }
+
+        if let Some(body) = &self.body {
+            command.push_str(&format!(" -d {}", quote(body)));
+        }
+
+        command
+    }
+
+    /// Converts the request to an `http::Request` (with the json body, if any, as bytes)
+    #[cfg(feature = "http")]
+    pub fn to_http(&self) -> Result<http::Request<Vec<u8>>, http::Error> {
+        let mut request = http::Request::builder()
+            .method(self.method.clone())
+            .uri(self.url.as_str())
+            .body(self.body.clone().unwrap_or_default().into_bytes())?;
+
+        *request.headers_mut() = self.headers.clone();
+        Ok(request)
+    }
+}
+
+/// Quotes a shell argument
+fn quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{CONTENT_TYPE, HeaderValue};
+    use rstest::rstest;
+
+    #[rstest]
+    fn prepared_request_should_render_curl_command() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let request = PreparedRequest {
+            method: Method::POST,
+            url: "https://api.scryfall.com/cards/collection".into(),
+            headers,
+            body: Some(r#"{"name":"Jace's Erasure"}"#.into()),
+        };
+
+        assert_eq!(
+            r#"curl -X POST 'https://api.scryfall.com/cards/collection' -H 'content-type: application/json' -d '{"name":"Jace'\''s Erasure"}'"#,
+            request.to_curl()
+        );
+    }
+
+    #[cfg(feature = "http")]
+    #[rstest]
+    fn prepared_request_should_convert_to_http_request() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let request = PreparedRequest {
+            method: Method::POST,
+            url: "https://api.scryfall.com/cards/collection".into(),
+            headers,
+            body: Some(r#"{"identifiers":[]}"#.into()),
+        };
+        let converted = request.to_http().unwrap();
+
+        assert_eq!((&Method::POST, "https://api.scryfall.com/cards/collection"), (converted.method(), converted.uri().to_string().as_str()));
+        assert_eq!(Some("application/json"), converted.headers().get(CONTENT_TYPE).and_then(|v| v.to_str().ok()));
+        assert_eq!(br#"{"identifiers":[]}"#.to_vec(), converted.into_body());
+    }
+}
@@ -0,0 +1,182 @@
+//! Client-side rate limiting
+//!
+//! Scryfall asks for 50 - 100 milliseconds between requests
+//! (see [rate limits](https://scryfall.com/docs/api#rate-limits-and-good-citizenship)).
+//!
+//! A [RateLimiter](RateLimiter) is a token bucket refilled by one token per interval,
+//! holding up to a burst budget of tokens.
+//! Its state lives behind an `Arc`, so all clones of a limiter (and of the clients using it)
+//! share the same budget, keeping the aggregate rate of many tasks under the limits.
+//!
+//! Requests are sent in one of two [priority lanes](Priority).
+//! Background requests cannot use the last tokens of the bucket, which are kept
+//! for interactive requests, so e.g. a card lookup for a UI is not queued behind
+//! a long-running background export.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Priority lane of a request
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// User-facing requests, served first
+    #[default]
+    Interactive,
+
+    /// Bulk or batch requests, served when there is spare budget
+    Background,
+}
+
+/// Shared token bucket limiter
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    burst: u32,
+    reserved: u32,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing one request per interval (without bursts)
+    #[must_use]
+    pub fn new(interval: Duration) -> Self {
+        RateLimiter {
+            interval,
+            burst: 1,
+            reserved: 1,
+            bucket: Arc::new(Mutex::new(Bucket { tokens: 1.0, refilled_at: Instant::now() })),
+        }
+    }
+
+    /// Creates a limiter allowing the given number of requests per second
+    #[must_use]
+    pub fn per_second(requests: u32) -> Self {
+        RateLimiter::new(Duration::from_secs(1) / requests.max(1))
+    }
+
+    /// Sets the burst budget, i.e. how many requests can be sent back to back
+    ///
+    /// The bucket starts full.
+    #[must_use]
+    pub fn with_burst(self, burst: u32) -> Self {
+        let burst = burst.max(1);
+        RateLimiter {
+            burst,
+            bucket: Arc::new(Mutex::new(Bucket { tokens: burst as f64, refilled_at: Instant::now() })),
+            ..self
+        }
+    }
+
+    /// Sets how many tokens are kept for [interactive](Priority::Interactive) requests
+    ///
+    /// Defaults to 1. At least one token of the burst budget is always
+    /// available to background requests.
+    #[must_use]
+    pub fn with_reserved(mut self, reserved: u32) -> Self {
+        self.reserved = reserved;
+        self
+    }
+
+    /// Gets the minimum (average) interval between requests
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Gets the burst budget
+    #[must_use]
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Tries to take a token for a request of the given priority
+    ///
+    /// Returns how long to wait before trying again, if there is no token available.
+    /// Waiting callers have to try again, since tokens may be taken by requests
+    /// of higher priority in the meantime.
+    pub fn try_acquire(&self, priority: Priority) -> Result<(), Duration> {
+        let mut bucket = match self.bucket.lock() {
+            Ok(bucket) => bucket,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let now = Instant::now();
+        let refilled = now.duration_since(bucket.refilled_at).as_secs_f64()
+            / self.interval.as_secs_f64().max(f64::EPSILON);
+        bucket.tokens = (bucket.tokens + refilled).min(self.burst as f64);
+        bucket.refilled_at = now;
+
+        let needed = match priority {
+            Priority::Interactive => 1.0,
+            Priority::Background => 1.0 + self.reserved.min(self.burst - 1) as f64,
+        };
+
+        if bucket.tokens >= needed {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(self.interval.mul_f64(needed - bucket.tokens))
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    /// Creates a limiter allowing 10 requests per second (without bursts)
+    fn default() -> Self {
+        RateLimiter::new(Duration::from_millis(100))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn clones_should_share_tokens() {
+        let limiter = RateLimiter::new(Duration::from_secs(10));
+        let clone = limiter.clone();
+
+        assert_eq!(Ok(()), limiter.try_acquire(Priority::Interactive));
+
+        let wait = clone.try_acquire(Priority::Interactive).unwrap_err();
+        assert!(wait > Duration::from_secs(9) && wait <= Duration::from_secs(10));
+    }
+
+    #[rstest]
+    fn burst_should_allow_back_to_back_requests() {
+        let limiter = RateLimiter::new(Duration::from_secs(10)).with_burst(3);
+
+        for _ in 0..3 {
+            assert_eq!(Ok(()), limiter.try_acquire(Priority::Interactive));
+        }
+        assert!(limiter.try_acquire(Priority::Interactive).is_err());
+    }
+
+    #[rstest]
+    fn background_should_not_take_reserved_tokens() {
+        let limiter = RateLimiter::new(Duration::from_secs(10))
+            .with_burst(3)
+            .with_reserved(1);
+
+        assert_eq!(Ok(()), limiter.try_acquire(Priority::Background));
+        assert_eq!(Ok(()), limiter.try_acquire(Priority::Background));
+
+        let wait = limiter.try_acquire(Priority::Background).unwrap_err();
+        assert!(wait > Duration::from_secs(9));
+        assert_eq!(Ok(()), limiter.try_acquire(Priority::Interactive));
+    }
+
+    #[rstest]
+    #[case::ten(10, Duration::from_millis(100))]
+    #[case::zero(0, Duration::from_secs(1))]
+    fn per_second_should_compute_interval(#[case] requests: u32, #[case] expected: Duration) {
+        assert_eq!(expected, RateLimiter::per_second(requests).interval());
+    }
+}
@@ -0,0 +1,86 @@
+//! Background refresher for rarely changing resources
+//!
+//! Keeps catalogs, symbology and the set list (with the respective features) warm in the
+//! [cache](super::cache::ResponseCache) of a client, so that requests
+//! for these resources never have to wait for the API.
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::Scryfall;
+//! use scryfall_sdk_rust::client::cache::ResponseCache;
+//! use scryfall_sdk_rust::client::refresher::Refresher;
+//!
+//! # async fn example() {
+//! let client = Scryfall::default().with_cache(ResponseCache::default());
+//!
+//! // clones share the same cache
+//! tokio::spawn(Refresher::new(client.clone()).run());
+//! # }
+//! ```
+
+use std::time::Duration;
+use crate::{CatalogResource, Scryfall};
+use crate::client::rate_limit::Priority;
+use crate::resources::errors::ErrorBody;
+
+/// Periodically refreshes catalogs, symbology and sets in a client's cache
+pub struct Refresher<'a> {
+    client: Scryfall<'a>,
+    interval: Duration,
+}
+
+impl<'a> Refresher<'a> {
+    /// Creates a refresher for the cache of the given client
+    ///
+    /// Refreshing has no effect if the client has no cache.
+    /// Requests are made in the [background](Priority::Background) lane.
+    #[must_use]
+    pub fn new(client: Scryfall<'a>) -> Self {
+        Refresher { client: client.with_priority(Priority::Background), interval: Duration::from_secs(20 * 60 * 60) }
+    }
+
+    /// Sets the refresh interval
+    ///
+    /// Defaults to 20 hours, so that entries are refreshed
+    /// before they expire from a [default](super::cache::ResponseCache::default) cache.
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Refreshes all resources once
+    pub async fn refresh(&self) -> Result<(), ErrorBody> {
+        #[cfg(feature = "symbols")]
+        self.client.refresh(&crate::CardSymbolsResource).await?;
+        #[cfg(feature = "sets")]
+        self.client.refresh(&crate::CardSetListResource::All).await?;
+
+        for catalog in &CatalogResource::ALL {
+            self.client.refresh(catalog).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Refreshes all resources forever, on every interval
+    ///
+    /// Failed refreshes are retried on the next interval.
+    pub async fn run(self) {
+        loop {
+            let _ = self.refresh().await;
+            futures_timer::Delay::new(self.interval).await;
+        }
+    }
+
+    /// Spawns [run](Refresher::run) as a background tokio task
+    ///
+    /// Available with the **tokio** optional feature. With other runtimes,
+    /// spawn the future returned by [run](Refresher::run) instead.
+    #[cfg(feature = "tokio")]
+    pub fn spawn(self) -> tokio::task::JoinHandle<()>
+        where 'a: 'static
+    {
+        tokio::spawn(self.run())
+    }
+}
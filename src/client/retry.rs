@@ -0,0 +1,87 @@
+//! Retries of failed requests
+//!
+//! A [RetryPolicy](RetryPolicy) retries requests failing with transport errors
+//! (e.g. timeouts), `429 Too Many Requests` or server errors,
+//! waiting with an exponential backoff between attempts.
+
+use std::time::Duration;
+use reqwest::StatusCode;
+
+/// Exponential backoff retry policy
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries (after the first attempt)
+    pub max_retries: u32,
+
+    /// Delay before the first retry, doubled on each subsequent one
+    pub base_delay: Duration,
+
+    /// Upper bound of the delay between retries
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a policy with the given number of retries and default delays
+    #[must_use]
+    pub fn new(max_retries: u32) -> Self {
+        RetryPolicy { max_retries, ..RetryPolicy::default() }
+    }
+
+    /// Gets the delay before a retry (starting from 1), if allowed
+    #[must_use]
+    pub fn delay(&self, retry: u32) -> Option<Duration> {
+        if retry == 0 || retry > self.max_retries {
+            return None;
+        }
+
+        let factor = 2u32.saturating_pow(retry - 1);
+        Some(self.base_delay.saturating_mul(factor).min(self.max_delay))
+    }
+
+    /// Checks whether a response status is worth retrying
+    #[must_use]
+    pub fn is_retryable(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Creates a policy of 3 retries, starting from 250 milliseconds (up to 10 seconds)
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::first(1, Some(Duration::from_millis(250)))]
+    #[case::second(2, Some(Duration::from_millis(500)))]
+    #[case::third(3, Some(Duration::from_secs(1)))]
+    #[case::exhausted(4, None)]
+    fn delay_should_back_off_exponentially(#[case] retry: u32, #[case] expected: Option<Duration>) {
+        assert_eq!(expected, RetryPolicy::default().delay(retry));
+    }
+
+    #[rstest]
+    fn delay_should_be_capped() {
+        let policy = RetryPolicy { max_retries: 40, ..RetryPolicy::default() };
+
+        assert_eq!(Some(Duration::from_secs(10)), policy.delay(40));
+    }
+
+    #[rstest]
+    #[case::too_many_requests(StatusCode::TOO_MANY_REQUESTS, true)]
+    #[case::bad_gateway(StatusCode::BAD_GATEWAY, true)]
+    #[case::not_found(StatusCode::NOT_FOUND, false)]
+    fn statuses_should_be_retryable(#[case] status: StatusCode, #[case] expected: bool) {
+        assert_eq!(expected, RetryPolicy::is_retryable(status));
+    }
+}
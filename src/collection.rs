@@ -0,0 +1,272 @@
+//! Card collection (inventory) model
+//!
+//! An [Inventory](Inventory) holds owned printings of cards, along with
+//! their quantities and finishes, and is the basis for trading utilities
+//! (see [trade](trade)) and set completion tracking (see [set_completion](set_completion)).
+
+use uuid::Uuid;
+use crate::resources::cards::{Card, CardFinish, CardIdentifier, SearchQueryParams, UniqueMode};
+use crate::resources::errors::ErrorBody;
+use crate::Scryfall;
+
+pub mod trade;
+
+/// A collection of owned cards
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Inventory {
+    pub items: Vec<InventoryItem>,
+}
+
+/// An owned printing of a card
+#[derive(Clone, Debug, PartialEq)]
+pub struct InventoryItem {
+    pub card: Card,
+    pub quantity: u32,
+    pub finish: CardFinish,
+}
+
+impl InventoryItem {
+    /// Gets the USD price of a single copy of the item (if any)
+    pub fn unit_price(&self) -> Option<f64> {
+        self.card.prices.usd_of(self.finish)
+    }
+}
+
+impl Inventory {
+    /// Adds copies of a printing to the inventory
+    ///
+    /// If the same printing (with the same finish) already exists
+    /// its quantity is increased instead.
+    pub fn add(&mut self, card: Card, quantity: u32, finish: CardFinish) {
+        match self.items.iter_mut().find(|i| i.card.id == card.id && i.finish == finish) {
+            Some(item) => item.quantity += quantity,
+            None => self.items.push(InventoryItem { card, quantity, finish }),
+        }
+    }
+
+    /// Gets the total number of owned copies
+    pub fn count(&self) -> u32 {
+        self.items.iter().map(|i| i.quantity).sum()
+    }
+
+    /// Gets the total USD value of the inventory
+    ///
+    /// Items without a price are ignored.
+    pub fn value(&self) -> f64 {
+        self.items.iter()
+            .filter_map(|i| i.unit_price().map(|p| p * i.quantity as f64))
+            .sum()
+    }
+
+    /// Iterates over the owned printings of an oracle card (any printing)
+    pub fn printings_of(&self, oracle_id: Uuid) -> impl Iterator<Item = &InventoryItem> {
+        self.items.iter().filter(move |i| i.card.oracle_id == oracle_id)
+    }
+}
+
+/// Options for computing the completion of a set
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompletionOptions {
+    /// Skip variant printings (cards flagged as `variation`)
+    pub ignore_variants: bool,
+
+    /// Skip foil-only printings and do not count owned foil copies
+    pub ignore_foils: bool,
+}
+
+/// Owned and missing cards of a set
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetCompletion {
+    pub set_code: String,
+    pub owned: Vec<Card>,
+    pub missing: Vec<Card>,
+}
+
+impl SetCompletion {
+    /// Computes the completion of a set from its cards
+    ///
+    /// Cards are matched to the inventory by set code and collector number.
+    pub fn of(
+        inventory: &Inventory,
+        set_code: &str,
+        set_cards: Vec<Card>,
+        options: CompletionOptions
+    ) -> Self {
+        let is_owned = |card: &Card| inventory.items.iter().any(|i|
+            i.quantity > 0
+                && i.card.set.eq_ignore_ascii_case(&card.set)
+                && i.card.collector_number == card.collector_number
+                && !(options.ignore_foils && i.finish != CardFinish::NonFoil)
+        );
+
+        let (owned, missing) = set_cards.into_iter()
+            .filter(|c| !(options.ignore_variants && c.variation))
+            .filter(|c| !options.ignore_foils || c.nonfoil)
+            .partition(is_owned);
+
+        SetCompletion { set_code: set_code.to_lowercase(), owned, missing }
+    }
+
+    /// Gets the completion percentage of the set (0 - 100)
+    pub fn percentage(&self) -> f64 {
+        match self.owned.len() + self.missing.len() {
+            0 => 100.0,
+            total => self.owned.len() as f64 * 100.0 / total as f64,
+        }
+    }
+
+    /// Gets identifiers of the missing cards
+    ///
+    /// These can be used with the [collection endpoint](crate::CardCollectionResource)
+    /// (in chunks of 75 identifiers).
+    pub fn missing_identifiers(&self) -> Vec<CardIdentifier> {
+        self.missing.iter()
+            .map(|c| CardIdentifier::SetAndNumber {
+                set: c.set.clone(),
+                number: c.collector_number.clone(),
+            })
+            .collect()
+    }
+
+    /// Exports the missing cards as CSV (`set,collector_number,name`)
+    pub fn missing_csv(&self) -> String {
+        let mut csv = String::from("set,collector_number,name\n");
+
+        for card in &self.missing {
+            let name = if card.name.contains([',', '"']) {
+                format!("\"{}\"", card.name.replace('"', "\"\""))
+            } else {
+                card.name.clone()
+            };
+
+            csv.push_str(&format!("{},{},{}\n", card.set, card.collector_number, name));
+        }
+
+        csv
+    }
+}
+
+fn set_search(set_code: &str, options: CompletionOptions) -> SearchQueryParams {
+    let mut params = SearchQueryParams::with_q(&format!("e:{set_code}"));
+    params.unique = Some(UniqueMode::Prints);
+    params.include_variations = Some(!options.ignore_variants);
+    params
+}
+
+/// Computes owned/missing cards of a set using the async client
+///
+/// # Example
+/// ```no_run
+/// use scryfall_sdk_rust::Scryfall;
+/// use scryfall_sdk_rust::collection::{set_completion, CompletionOptions, Inventory};
+///
+/// # async fn example(inventory: Inventory) -> Result<(), Box<dyn std::error::Error>> {
+/// let completion = set_completion(
+///     &inventory, "dmu", &Scryfall::default(), CompletionOptions::default()
+/// ).await?;
+///
+/// println!("{:.1}% complete", completion.percentage());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_completion(
+    inventory: &Inventory,
+    set_code: &str,
+    client: &Scryfall<'_>,
+    options: CompletionOptions,
+) -> Result<SetCompletion, ErrorBody> {
+    let cards = client.search_all(set_search(set_code, options)).await?;
+
+    Ok(SetCompletion::of(inventory, set_code, cards, options))
+}
+
+/// Computes owned/missing cards of a set using the blocking client
+#[cfg(feature = "blocking")]
+pub fn set_completion_blocking(
+    inventory: &Inventory,
+    set_code: &str,
+    client: &crate::ScryfallBlocking<'_>,
+    options: CompletionOptions,
+) -> Result<SetCompletion, ErrorBody> {
+    let cards = client.search_all(set_search(set_code, options))?;
+
+    Ok(SetCompletion::of(inventory, set_code, cards, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    #[rstest]
+    fn inventory_should_merge_same_printing_and_finish() {
+        let mut inventory = Inventory::default();
+        let card = card(json!({ "prices": { "usd": "1.50", "usd_foil": "4.00" } }));
+
+        inventory.add(card.clone(), 2, CardFinish::NonFoil);
+        inventory.add(card.clone(), 1, CardFinish::NonFoil);
+        inventory.add(card, 1, CardFinish::Foil);
+
+        assert_eq!(2, inventory.items.len());
+        assert_eq!(4, inventory.count());
+        assert_eq!(8.5, inventory.value());
+    }
+
+    fn set_cards() -> Vec<Card> {
+        vec![
+            card(json!({ "collector_number": "1", "name": "One" })),
+            card(json!({ "collector_number": "2", "name": "Two, Again" })),
+            card(json!({ "collector_number": "3", "name": "Three", "variation": true })),
+            card(json!({ "collector_number": "4", "name": "Four", "nonfoil": false })),
+        ]
+    }
+
+    #[rstest]
+    #[case::default(CompletionOptions::default(), vec!["1"], vec!["2", "3", "4"])]
+    #[case::ignore_variants(
+        CompletionOptions { ignore_variants: true, ignore_foils: false },
+        vec!["1"], vec!["2", "4"]
+    )]
+    #[case::ignore_foils(
+        CompletionOptions { ignore_variants: false, ignore_foils: true },
+        vec![], vec!["1", "2", "3"]
+    )]
+    fn set_completion_should_split_owned_and_missing(
+        #[case] options: CompletionOptions,
+        #[case] owned: Vec<&str>,
+        #[case] missing: Vec<&str>,
+    ) {
+        let mut inventory = Inventory::default();
+        inventory.add(card(json!({ "collector_number": "1" })), 1, CardFinish::Foil);
+
+        let completion = SetCompletion::of(&inventory, "TST", set_cards(), options);
+        let numbers = |cards: &[Card]| cards.iter()
+            .map(|c| c.collector_number.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(owned, numbers(&completion.owned));
+        assert_eq!(missing, numbers(&completion.missing));
+    }
+
+    #[rstest]
+    fn set_completion_should_export_missing_cards() {
+        let mut inventory = Inventory::default();
+        inventory.add(card(json!({ "collector_number": "1" })), 1, CardFinish::NonFoil);
+
+        let completion = SetCompletion::of(
+            &inventory, "tst", set_cards(), CompletionOptions { ignore_variants: true, ignore_foils: true }
+        );
+
+        assert_eq!(50.0, completion.percentage());
+        assert_eq!(
+            vec![CardIdentifier::SetAndNumber { set: "tst".into(), number: "2".into() }],
+            completion.missing_identifiers()
+        );
+        assert_eq!(
+            "set,collector_number,name\ntst,2,\"Two, Again\"\n",
+            completion.missing_csv()
+        );
+    }
+}
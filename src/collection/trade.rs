@@ -0,0 +1,258 @@
+//! Trade matching between inventories and want lists
+//!
+//! Wants refer to oracle cards (by oracle id), so any owned printing
+//! of a wanted card satisfies the want.
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::collection::Inventory;
+//! use scryfall_sdk_rust::collection::trade::{suggest_trade, Want};
+//!
+//! fn trade(mine: &Inventory, my_wants: &[Want], theirs: &Inventory, their_wants: &[Want]) {
+//!     let suggestion = suggest_trade(mine, my_wants, theirs, their_wants);
+//!
+//!     println!("give {:.2}, receive {:.2}", suggestion.give.value(), suggestion.receive.value());
+//! }
+//! ```
+
+use std::cmp::Ordering;
+use uuid::Uuid;
+use crate::collection::Inventory;
+use crate::resources::cards::{Card, CardFinish};
+
+/// A wanted oracle card (any printing)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Want {
+    pub oracle_id: Uuid,
+    pub name: String,
+    pub quantity: u32,
+}
+
+impl Want {
+    /// Creates a want for any printing of the given card
+    pub fn new(card: &Card, quantity: u32) -> Self {
+        Want { oracle_id: card.oracle_id, name: card.name.clone(), quantity }
+    }
+}
+
+/// A printing offered in a trade
+#[derive(Clone, Debug, PartialEq)]
+pub struct TradeLine {
+    pub card: Card,
+    pub finish: CardFinish,
+    pub quantity: u32,
+    pub unit_price: Option<f64>,
+}
+
+/// One side of a trade
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trade {
+    pub lines: Vec<TradeLine>,
+}
+
+impl Trade {
+    /// Gets the total number of traded copies
+    pub fn count(&self) -> u32 {
+        self.lines.iter().map(|l| l.quantity).sum()
+    }
+
+    /// Gets the total USD value of the trade (unpriced copies are ignored)
+    pub fn value(&self) -> f64 {
+        self.lines.iter()
+            .filter_map(|l| l.unit_price.map(|p| p * l.quantity as f64))
+            .sum()
+    }
+
+    /// Trims the trade so that its value does not exceed the given one
+    ///
+    /// Copies are kept greedily, most expensive first, as long as they fit.
+    /// Unpriced copies are always kept.
+    pub fn balanced_to(self, value: f64) -> Trade {
+        let mut copies: Vec<TradeLine> = self.lines.into_iter()
+            .flat_map(|l| (0..l.quantity).map(move |_| TradeLine { quantity: 1, ..l.clone() }))
+            .collect();
+        copies.sort_by(|a, b| b.unit_price.partial_cmp(&a.unit_price).unwrap_or(Ordering::Equal));
+
+        let mut total = 0.0;
+        let mut balanced = Trade::default();
+
+        for copy in copies {
+            let price = copy.unit_price.unwrap_or(0.0);
+            if total + price > value {
+                continue;
+            }
+            total += price;
+
+            match balanced.lines.iter_mut()
+                .find(|l| l.card.id == copy.card.id && l.finish == copy.finish)
+            {
+                Some(line) => line.quantity += 1,
+                None => balanced.lines.push(copy),
+            }
+        }
+
+        balanced
+    }
+}
+
+/// A two-sided trade suggestion
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TradeSuggestion {
+    /// Cards given from my inventory
+    pub give: Trade,
+
+    /// Cards received from the other inventory
+    pub receive: Trade,
+}
+
+impl TradeSuggestion {
+    /// Gets the value difference of the trade (received minus given)
+    pub fn difference(&self) -> f64 {
+        self.receive.value() - self.give.value()
+    }
+}
+
+/// Matches the wants of another party against my inventory
+///
+/// For each want, owned printings of the same oracle card are offered,
+/// cheapest first, up to the wanted quantity. Copies offered for a want are not offered
+/// again for another want of the same card (e.g. duplicates in the want list).
+pub fn match_wants(my_inventory: &Inventory, their_wants: &[Want]) -> Trade {
+    let mut trade = Trade::default();
+
+    for want in their_wants {
+        let mut printings: Vec<_> = my_inventory.printings_of(want.oracle_id).collect();
+        printings.sort_by(|a, b| a.unit_price()
+            .unwrap_or(f64::MAX)
+            .partial_cmp(&b.unit_price().unwrap_or(f64::MAX))
+            .unwrap_or(Ordering::Equal)
+        );
+
+        let mut remaining = want.quantity;
+        for item in printings {
+            if remaining == 0 {
+                break;
+            }
+
+            let line = trade.lines.iter_mut()
+                .find(|l| l.card.id == item.card.id && l.finish == item.finish);
+            let offered = line.as_ref().map_or(0, |l| l.quantity);
+
+            let quantity = item.quantity.saturating_sub(offered).min(remaining);
+            if quantity == 0 {
+                continue;
+            }
+            remaining -= quantity;
+
+            match line {
+                Some(line) => line.quantity += quantity,
+                None => trade.lines.push(TradeLine {
+                    card: item.card.clone(),
+                    finish: item.finish,
+                    quantity,
+                    unit_price: item.unit_price(),
+                }),
+            }
+        }
+    }
+
+    trade
+}
+
+/// Suggests a trade between two parties, balanced by price
+///
+/// Both sides are matched with [match_wants](match_wants) and then
+/// the more valuable side is trimmed to the value of the other.
+pub fn suggest_trade(
+    my_inventory: &Inventory,
+    my_wants: &[Want],
+    their_inventory: &Inventory,
+    their_wants: &[Want],
+) -> TradeSuggestion {
+    let give = match_wants(my_inventory, their_wants);
+    let receive = match_wants(their_inventory, my_wants);
+
+    if give.value() > receive.value() {
+        let value = receive.value();
+        TradeSuggestion { give: give.balanced_to(value), receive }
+    } else {
+        let value = give.value();
+        TradeSuggestion { give, receive: receive.balanced_to(value) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    fn printing(id: &str, oracle_id: &str, usd: &str) -> Card {
+        card(json!({ "id": id, "oracle_id": oracle_id, "prices": { "usd": usd } }))
+    }
+
+    const BOLT: &str = "4457ed35-7c10-48c8-9776-456485fdf070";
+    const OPT: &str = "8f3e3e1c-2a4d-4a2b-9c2a-0e8f5b6a1c3d";
+
+    fn inventory() -> Inventory {
+        let mut inventory = Inventory::default();
+        inventory.add(printing("00000000-0000-0000-0000-000000000001", BOLT, "3.00"), 2, CardFinish::NonFoil);
+        inventory.add(printing("00000000-0000-0000-0000-000000000002", BOLT, "1.00"), 1, CardFinish::NonFoil);
+        inventory.add(printing("00000000-0000-0000-0000-000000000003", OPT, "0.10"), 4, CardFinish::NonFoil);
+        inventory
+    }
+
+    fn want(oracle_id: &str, quantity: u32) -> Want {
+        Want { oracle_id: oracle_id.parse().unwrap(), name: "".into(), quantity }
+    }
+
+    #[rstest]
+    fn wants_should_match_any_printing_cheapest_first() {
+        let trade = match_wants(&inventory(), &[want(BOLT, 2)]);
+
+        assert_eq!(2, trade.lines.len());
+        assert_eq!(Some(1.0), trade.lines[0].unit_price);
+        assert_eq!((1, 1), (trade.lines[0].quantity, trade.lines[1].quantity));
+        assert_eq!(4.0, trade.value());
+    }
+
+    #[rstest]
+    fn wants_should_be_capped_by_owned_quantity() {
+        let trade = match_wants(&inventory(), &[want(BOLT, 10), want(OPT, 1)]);
+
+        assert_eq!(4, trade.count());
+        assert_eq!(7.1, trade.value());
+    }
+
+    #[rstest]
+    fn repeated_wants_should_not_exceed_owned_quantity() {
+        let trade = match_wants(&inventory(), &[want(BOLT, 2), want(BOLT, 2), want(OPT, 3), want(OPT, 3)]);
+
+        assert_eq!(7, trade.count());
+        assert_eq!(3, trade.lines.len());
+        assert_eq!((1, 2, 4), (trade.lines[0].quantity, trade.lines[1].quantity, trade.lines[2].quantity));
+    }
+
+    #[rstest]
+    fn trade_should_be_balanced_to_value() {
+        let trade = match_wants(&inventory(), &[want(BOLT, 3), want(OPT, 4)]);
+
+        let balanced = trade.balanced_to(4.2);
+
+        assert_eq!(4, balanced.count());
+        assert!((balanced.value() - 4.2).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn suggestion_should_trim_more_valuable_side() {
+        let mut theirs = Inventory::default();
+        theirs.add(printing("00000000-0000-0000-0000-000000000004", OPT, "1.00"), 4, CardFinish::NonFoil);
+
+        let suggestion = suggest_trade(&inventory(), &[want(OPT, 4)], &theirs, &[want(BOLT, 3)]);
+
+        assert_eq!(4.0, suggestion.receive.value());
+        assert_eq!(4.0, suggestion.give.value());
+        assert_eq!(0.0, suggestion.difference());
+    }
+}
@@ -0,0 +1,547 @@
+//! Deck model and format validation
+//!
+//! A [Deck](Deck) holds already resolved [cards](Card) along with their quantities,
+//! split in sections (commanders, companion, mainboard, sideboard and maybeboard)
+//! and can be validated against the rules of a game [Format](Format).
+//!
+//! Legality in past Standard formats (e.g. for recreating old decks) is checked with [rotation](rotation).
+//!
+//! Text deck lists (e.g. MTG Arena exports) can be parsed with [DeckList](list::DeckList),
+//! while decks hosted in deck builder sites can be imported by enabling
+//! the **importers** optional feature.
+//!
+//! Decks can be exported to MTG Arena with [arena](arena), to Tabletop Simulator with [tts](tts),
+//! to Cockatrice with [cockatrice](cockatrice), and to Forge and XMage with [forge](forge) and [xmage](xmage).
+//! Exporters share the [DeckList](list::DeckList) representation (see [DeckList::from_deck](list::DeckList::from_deck)).
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::deck::{Deck, DeckEntry};
+//! use scryfall_sdk_rust::resources::cards::{Card, Format};
+//!
+//! fn check(cards: Vec<Card>) {
+//!     let deck = Deck {
+//!         mainboard: cards.into_iter()
+//!             .map(|card| DeckEntry { quantity: 4, card })
+//!             .collect(),
+//!         ..Deck::default()
+//!     };
+//!
+//!     if let Err(violations) = deck.validate(Format::Modern) {
+//!         violations.iter().for_each(|v| println!("{v}"));
+//!     }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use crate::deck::companion::Companion;
+use crate::resources::cards::{Card, Format, Legality};
+
+pub mod arena;
+pub mod cockatrice;
+pub mod companion;
+pub mod forge;
+#[cfg(feature = "importers")]
+pub mod importers;
+pub mod list;
+pub mod rotation;
+pub mod tts;
+pub mod xmage;
+
+/// A deck of resolved cards
+#[derive(Debug, Default, PartialEq)]
+pub struct Deck {
+    /// The commander(s) of the deck (for commander-like formats)
+    ///
+    /// Two commanders are allowed only when both have partner (or a similar ability).
+    pub commanders: Vec<Card>,
+
+    /// The companion of the deck (if any)
+    ///
+    /// For constructed formats the companion is expected to also be in the sideboard.
+    pub companion: Option<Card>,
+
+    /// The main cards of the deck, excluding the commander(s)
+    pub mainboard: Vec<DeckEntry>,
+
+    /// The sideboard of the deck
+    pub sideboard: Vec<DeckEntry>,
+
+    /// Cards under consideration, ignored when validating
+    pub maybeboard: Vec<DeckEntry>,
+}
+
+/// A single deck entry (a card and the number of its copies)
+#[derive(Debug, PartialEq)]
+pub struct DeckEntry {
+    pub quantity: u32,
+    pub card: Card,
+}
+
+/// A format rule violated by a deck
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// A card is banned in the format
+    Banned { name: String },
+
+    /// A card is not legal in the format
+    NotLegal { name: String },
+
+    /// A restricted card has more than one copy
+    Restricted { name: String, copies: u32 },
+
+    /// A card exceeds the copy limit of the format
+    TooManyCopies { name: String, copies: u32, limit: u32 },
+
+    /// The deck has less cards than the format minimum
+    TooFewCards { min: u32, actual: u32 },
+
+    /// The deck has more cards than the format maximum
+    TooManyCards { max: u32, actual: u32 },
+
+    /// The sideboard has more cards than the format maximum
+    TooManySideboardCards { max: u32, actual: u32 },
+
+    /// The format requires a commander but the deck has none
+    MissingCommander,
+
+    /// The commanders cannot be paired as partners
+    InvalidPartners { names: Vec<String> },
+
+    /// A card of the deck does not meet the companion's restriction
+    ///
+    /// `name` is `None` when the restriction concerns the deck as a whole.
+    CompanionRestriction { companion: String, name: Option<String> },
+
+    /// A card falls outside of the commander's color identity
+    OutsideColorIdentity { name: String },
+
+    /// A non-deck object (e.g. token, emblem or Art Series card) is part of the deck
+    NotPlayable { name: String },
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::Banned { name } =>
+                write!(f, "{name} is banned"),
+            Violation::NotLegal { name } =>
+                write!(f, "{name} is not legal"),
+            Violation::Restricted { name, copies } =>
+                write!(f, "{name} is restricted but has {copies} copies"),
+            Violation::TooManyCopies { name, copies, limit } =>
+                write!(f, "{name} has {copies} copies (limit is {limit})"),
+            Violation::TooFewCards { min, actual } =>
+                write!(f, "deck has {actual} cards (minimum is {min})"),
+            Violation::TooManyCards { max, actual } =>
+                write!(f, "deck has {actual} cards (maximum is {max})"),
+            Violation::TooManySideboardCards { max, actual } =>
+                write!(f, "sideboard has {actual} cards (maximum is {max})"),
+            Violation::MissingCommander =>
+                write!(f, "deck has no commander"),
+            Violation::InvalidPartners { names } =>
+                write!(f, "{} cannot be partners", names.join(" and ")),
+            Violation::CompanionRestriction { companion, name: Some(name) } =>
+                write!(f, "{name} does not meet the restriction of {companion}"),
+            Violation::CompanionRestriction { companion, name: None } =>
+                write!(f, "deck does not meet the restriction of {companion}"),
+            Violation::OutsideColorIdentity { name } =>
+                write!(f, "{name} is outside of the commander's color identity"),
+            Violation::NotPlayable { name } =>
+                write!(f, "{name} cannot be played in a deck"),
+        }
+    }
+}
+
+/// Deck construction rules of a format
+struct Rules {
+    min_size: u32,
+    max_size: Option<u32>,
+    sideboard_size: Option<u32>,
+    copy_limit: u32,
+    commander: bool,
+}
+
+impl From<Format> for Rules {
+    fn from(format: Format) -> Self {
+        use Format::*;
+
+        match format {
+            Commander | Duel | PauperCommander | HistoricBrawl => Rules {
+                min_size: 100, max_size: Some(100), sideboard_size: None,
+                copy_limit: 1, commander: true,
+            },
+            Brawl => Rules {
+                min_size: 60, max_size: Some(60), sideboard_size: None,
+                copy_limit: 1, commander: true,
+            },
+            Gladiator => Rules {
+                min_size: 100, max_size: Some(100), sideboard_size: None,
+                copy_limit: 1, commander: false,
+            },
+            _ => Rules {
+                min_size: 60, max_size: None, sideboard_size: Some(15),
+                copy_limit: 4, commander: false,
+            },
+        }
+    }
+}
+
+impl Deck {
+    /// Gets the total number of cards in the deck
+    ///
+    /// This includes the mainboard and the commander(s).
+    pub fn size(&self) -> u32 {
+        self.mainboard.iter().map(|e| e.quantity).sum::<u32>()
+            + self.commanders.len() as u32
+    }
+
+    /// Gets the total number of cards in the sideboard
+    pub fn sideboard_size(&self) -> u32 {
+        self.sideboard.iter().map(|e| e.quantity).sum()
+    }
+
+    /// Gets the companion restriction of the deck (if any)
+    pub fn companion_kind(&self) -> Option<Companion> {
+        self.companion.as_ref().and_then(Companion::from_card)
+    }
+
+    /// Validates the deck against the rules of a format
+    ///
+    /// The following rules are checked:
+    ///
+    /// - legality of each card (banned, restricted, not legal)
+    /// - copy limits (4-of or singleton) across mainboard, sideboard and commanders,
+    ///   except for basic lands and cards allowing any number of copies
+    /// - deck and sideboard size
+    /// - presence of commander(s), partner pairing and color identity
+    ///   (for commander-like formats)
+    /// - companion deck building restriction
+    ///
+    /// All violations found are returned, ordered by card name where applicable.
+    pub fn validate(&self, format: Format) -> Result<(), Vec<Violation>> {
+        let rules = Rules::from(format);
+        let mut violations = vec![];
+
+        let mut copies: BTreeMap<&str, (u32, &Card)> = BTreeMap::new();
+        self.commanders.iter()
+            .map(|card| (1, card))
+            .chain(self.mainboard.iter().map(|e| (e.quantity, &e.card)))
+            .chain(self.sideboard.iter().map(|e| (e.quantity, &e.card)))
+            .chain(self.companion.iter()
+                .filter(|_| rules.commander)
+                .map(|card| (1, card))
+            )
+            .for_each(|(quantity, card)| {
+                copies.entry(card.name.as_str()).or_insert((0, card)).0 += quantity;
+            });
+
+        for (name, (count, card)) in copies {
+            let name = name.to_string();
+
+            if !card.is_playable_in_deck() {
+                violations.push(Violation::NotPlayable { name });
+                continue;
+            }
+
+            match card.legalities.of(format) {
+                Legality::Banned => violations.push(Violation::Banned { name }),
+                Legality::NotLegal => violations.push(Violation::NotLegal { name }),
+                Legality::Restricted if count > 1 =>
+                    violations.push(Violation::Restricted { name, copies: count }),
+                _ if count > rules.copy_limit && !has_unlimited_copies(card) =>
+                    violations.push(Violation::TooManyCopies {
+                        name, copies: count, limit: rules.copy_limit
+                    }),
+                _ => {},
+            }
+        }
+
+        let size = self.size();
+        if size < rules.min_size {
+            violations.push(Violation::TooFewCards { min: rules.min_size, actual: size });
+        }
+        if let Some(max) = rules.max_size.filter(|max| size > *max) {
+            violations.push(Violation::TooManyCards { max, actual: size });
+        }
+
+        let sideboard_size = self.sideboard_size();
+        if let Some(max) = rules.sideboard_size.filter(|max| sideboard_size > *max) {
+            violations.push(Violation::TooManySideboardCards { max, actual: sideboard_size });
+        }
+
+        if rules.commander {
+            violations.extend(self.commander_violations());
+        }
+
+        if let Some(companion) = self.companion_kind() {
+            violations.extend(companion.violations(self, rules.min_size));
+        }
+
+        if violations.is_empty() { Ok(()) } else { Err(violations) }
+    }
+
+    fn commander_violations(&self) -> Vec<Violation> {
+        let mut violations = vec![];
+
+        match self.commanders.as_slice() {
+            [] => violations.push(Violation::MissingCommander),
+            [_] => {},
+            [first, second] if can_partner(first, second) => {},
+            commanders => violations.push(Violation::InvalidPartners {
+                names: commanders.iter().map(|c| c.name.clone()).collect()
+            }),
+        }
+
+        let identity: Vec<_> = self.commanders.iter()
+            .flat_map(|c| c.color_identity.iter())
+            .collect();
+
+        if !self.commanders.is_empty() {
+            self.mainboard.iter()
+                .map(|e| &e.card)
+                .chain(self.companion.iter())
+                .filter(|card| card.color_identity.iter().any(|c| !identity.contains(&c)))
+                .for_each(|card| violations.push(Violation::OutsideColorIdentity {
+                    name: card.name.clone()
+                }));
+        }
+
+        violations
+    }
+}
+
+/// Checks if two commanders can be paired
+///
+/// This covers partner, partner with, friends forever and backgrounds.
+/// Commanders with "partner with" can only be paired with the commander they name.
+fn can_partner(first: &Card, second: &Card) -> bool {
+    let has_keyword = |card: &Card, keyword: &str| card.keywords.iter()
+        .any(|k| k.eq_ignore_ascii_case(keyword));
+    let has_text = |card: &Card, text: &str| matches!(
+        &card.oracle_text, Some(t) if t.contains(text)
+    );
+    let is_background = |card: &Card| card.type_line.contains("Background");
+
+    match (partner_with(first), partner_with(second)) {
+        (None, None) => {},
+        (Some(first_partner), Some(second_partner)) =>
+            return first_partner == second.name && second_partner == first.name,
+        _ => return false,
+    }
+
+    (has_keyword(first, "Partner") && has_keyword(second, "Partner"))
+        || (has_keyword(first, "Friends forever") && has_keyword(second, "Friends forever"))
+        || (has_text(first, "Choose a Background") && is_background(second))
+        || (has_text(second, "Choose a Background") && is_background(first))
+}
+
+/// Gets the name of the commander a card has "partner with" (e.g. `Partner with Toothy, Imaginary Friend`)
+fn partner_with(card: &Card) -> Option<&str> {
+    card.oracle_text.as_deref()?
+        .lines()
+        .find_map(|line| line.strip_prefix("Partner with "))
+        .map(|rest| rest.split(" (").next().unwrap_or(rest).trim())
+}
+
+/// Checks if a card is exempt from copy limits
+///
+/// This applies to basic lands and cards like Relentless Rats.
+fn has_unlimited_copies(card: &Card) -> bool {
+    card.type_line.starts_with("Basic")
+        || matches!(
+            &card.oracle_text,
+            Some(text) if text.contains("A deck can have any number of cards named")
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    fn entry(quantity: u32, name: &str) -> DeckEntry {
+        DeckEntry { quantity, card: card(json!({ "name": name })) }
+    }
+
+    fn modern_deck() -> Deck {
+        Deck {
+            mainboard: (0..15).map(|i| entry(4, &format!("Card {i}"))).collect(),
+            ..Deck::default()
+        }
+    }
+
+    #[rstest]
+    fn valid_deck_should_pass_validation() {
+        assert_eq!(Ok(()), modern_deck().validate(Format::Modern));
+    }
+
+    #[rstest]
+    fn deck_should_report_copies_and_size_violations() {
+        let mut deck = modern_deck();
+        deck.mainboard[0].quantity = 5;
+        deck.mainboard.pop();
+
+        assert_eq!(Err(vec![
+            Violation::TooManyCopies { name: "Card 0".into(), copies: 5, limit: 4 },
+            Violation::TooFewCards { min: 60, actual: 57 },
+        ]), deck.validate(Format::Modern));
+    }
+
+    #[rstest]
+    fn deck_should_aggregate_copies_across_printings() {
+        let mut deck = modern_deck();
+        deck.mainboard.pop();
+        deck.mainboard.push(entry(1, "Card 0"));
+        deck.mainboard.push(entry(3, "Card 15"));
+
+        assert_eq!(Err(vec![
+            Violation::TooManyCopies { name: "Card 0".into(), copies: 5, limit: 4 },
+        ]), deck.validate(Format::Modern));
+    }
+
+    #[rstest]
+    #[case::basic_land(json!({ "name": "Plains", "type_line": "Basic Land — Plains" }))]
+    #[case::any_number(json!({
+        "name": "Relentless Rats",
+        "type_line": "Creature — Rat",
+        "oracle_text": "A deck can have any number of cards named Relentless Rats."
+    }))]
+    fn deck_should_allow_unlimited_copies(#[case] overrides: serde_json::Value) {
+        let mut deck = modern_deck();
+        deck.mainboard.truncate(10);
+        deck.mainboard.push(DeckEntry { quantity: 20, card: card(overrides) });
+
+        assert_eq!(Ok(()), deck.validate(Format::Modern));
+    }
+
+    #[rstest]
+    fn deck_should_report_non_deck_cards() {
+        let mut deck = modern_deck();
+        deck.mainboard.push(DeckEntry { quantity: 1, card: card(json!({ "name": "Goblin", "layout": "token" })) });
+
+        assert_eq!(Err(vec![
+            Violation::NotPlayable { name: "Goblin".into() },
+        ]), deck.validate(Format::Modern));
+    }
+
+    #[rstest]
+    #[case::banned("banned", Violation::Banned { name: "Card 0".into() })]
+    #[case::not_legal("not_legal", Violation::NotLegal { name: "Card 0".into() })]
+    #[case::restricted("restricted", Violation::Restricted { name: "Card 0".into(), copies: 4 })]
+    fn deck_should_report_legality_violations(
+        #[case] legality: &str,
+        #[case] expected: Violation,
+    ) {
+        let mut deck = modern_deck();
+        deck.mainboard[0].card.legalities.vintage = serde_json::from_value(json!(legality)).unwrap();
+
+        assert_eq!(Err(vec![expected]), deck.validate(Format::Vintage));
+    }
+
+    #[rstest]
+    fn commander_deck_should_be_singleton_with_commander() {
+        let deck = Deck {
+            mainboard: (0..49).map(|i| entry(2, &format!("Card {i}"))).collect(),
+            ..Deck::default()
+        };
+
+        let violations = deck.validate(Format::Commander).unwrap_err();
+
+        assert_eq!(51, violations.len());
+        assert_eq!(Some(&Violation::TooFewCards { min: 100, actual: 98 }), violations.get(49));
+        assert_eq!(Some(&Violation::MissingCommander), violations.last());
+    }
+
+    #[rstest]
+    fn commander_deck_should_respect_color_identity() {
+        let deck = Deck {
+            commanders: vec![card(json!({ "name": "Commander", "color_identity": ["W", "U"] }))],
+            mainboard: (0..98).map(|i| entry(1, &format!("Card {i}")))
+                .chain([DeckEntry {
+                    quantity: 1,
+                    card: card(json!({ "name": "Red Card", "color_identity": ["R"] })),
+                }])
+                .collect(),
+            ..Deck::default()
+        };
+
+        assert_eq!(Err(vec![
+            Violation::OutsideColorIdentity { name: "Red Card".into() },
+        ]), deck.validate(Format::Commander));
+    }
+
+    #[rstest]
+    fn deck_should_count_sideboard_copies_and_size() {
+        let mut deck = modern_deck();
+        deck.sideboard = vec![entry(1, "Card 0"), entry(15, "Card 20")];
+
+        assert_eq!(Err(vec![
+            Violation::TooManyCopies { name: "Card 0".into(), copies: 5, limit: 4 },
+            Violation::TooManyCopies { name: "Card 20".into(), copies: 15, limit: 4 },
+            Violation::TooManySideboardCards { max: 15, actual: 16 },
+        ]), deck.validate(Format::Modern));
+    }
+
+    #[rstest]
+    #[case::partners(json!({ "keywords": ["Partner"] }), json!({ "keywords": ["Partner"] }), true)]
+    #[case::background(
+        json!({ "oracle_text": "Choose a Background" }),
+        json!({ "type_line": "Legendary Enchantment — Background" }),
+        true
+    )]
+    #[case::no_partners(json!({ "keywords": ["Partner"] }), json!({}), false)]
+    #[case::partner_with(
+        json!({ "keywords": ["Partner with"], "oracle_text": "Flying\nPartner with Second (When this creature enters, ...)" }),
+        json!({ "keywords": ["Partner with"], "oracle_text": "Partner with First" }),
+        true
+    )]
+    #[case::partner_with_other(
+        json!({ "keywords": ["Partner with"], "oracle_text": "Partner with Third" }),
+        json!({ "keywords": ["Partner with"], "oracle_text": "Partner with First" }),
+        false
+    )]
+    #[case::partner_with_generic_partner(
+        json!({ "keywords": ["Partner with", "Partner"], "oracle_text": "Partner with Third" }),
+        json!({ "keywords": ["Partner"], "oracle_text": "Partner" }),
+        false
+    )]
+    fn commander_deck_should_check_partners(
+        #[case] mut first: serde_json::Value,
+        #[case] mut second: serde_json::Value,
+        #[case] valid: bool,
+    ) {
+        first["name"] = json!("First");
+        second["name"] = json!("Second");
+
+        let deck = Deck {
+            commanders: vec![card(first), card(second)],
+            mainboard: (0..98).map(|i| entry(1, &format!("Card {i}"))).collect(),
+            ..Deck::default()
+        };
+
+        let expected = if valid { Ok(()) } else {
+            Err(vec![Violation::InvalidPartners { names: vec!["First".into(), "Second".into()] }])
+        };
+
+        assert_eq!(expected, deck.validate(Format::Commander));
+    }
+
+    #[rstest]
+    fn deck_should_check_companion_restriction() {
+        let mut deck = modern_deck();
+        deck.companion = Some(card(json!({ "name": "Lurrus of the Dream-Den" })));
+        deck.mainboard[0].card = card(json!({
+            "name": "Card 0", "type_line": "Creature — Elf", "cmc": 3
+        }));
+
+        assert_eq!(Some(Companion::Lurrus), deck.companion_kind());
+        assert_eq!(Err(vec![Violation::CompanionRestriction {
+            companion: "Lurrus of the Dream-Den".into(),
+            name: Some("Card 0".into()),
+        }]), deck.validate(Format::Modern));
+    }
+}
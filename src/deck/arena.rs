@@ -0,0 +1,93 @@
+//! MTG Arena export
+//!
+//! Builds deck lists that import cleanly into MTG Arena:
+//!
+//! - printings available on Arena (with an `arena_id`) are listed with their Arena set code
+//!   (which differs from the Scryfall code for a few sets, e.g. `DAR` for Dominaria)
+//! - other printings are listed by name only, so Arena picks any available printing
+//! - multi-faced cards (except split cards) are listed by their front face name
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::deck::{arena, Deck};
+//!
+//! fn print(deck: &Deck) {
+//!     println!("{}", arena::export(deck));
+//! }
+//! ```
+
+use crate::deck::list::{front_face_name, DeckList, DeckListEntry};
+use crate::deck::Deck;
+use crate::resources::cards::Card;
+
+/// Sets with a different code on Arena (Scryfall code, Arena code)
+const SET_CODES: &[(&str, &str)] = &[
+    ("con", "CONF"),
+    ("dom", "DAR"),
+];
+
+/// Gets the Arena code of a set
+pub fn arena_set(code: &str) -> String {
+    SET_CODES.iter()
+        .find(|(scryfall, _)| scryfall.eq_ignore_ascii_case(code))
+        .map(|(_, arena)| arena.to_string())
+        .unwrap_or_else(|| code.to_uppercase())
+}
+
+/// Gets the Arena deck list entry for copies of a card
+pub fn entry(quantity: u32, card: &Card) -> DeckListEntry {
+    let name = front_face_name(card).to_string();
+
+    match card.arena_id {
+        Some(_) => DeckListEntry {
+            quantity,
+            name,
+            set: Some(arena_set(&card.set)),
+            collector_number: Some(card.collector_number.clone()),
+        },
+        None => DeckListEntry { quantity, name, set: None, collector_number: None },
+    }
+}
+
+/// Gets the Arena deck list of a deck
+pub fn deck_list(deck: &Deck) -> DeckList {
+    DeckList::from_deck(deck, entry)
+}
+
+/// Exports a deck in the Arena import format
+pub fn export(deck: &Deck) -> String {
+    deck_list(deck).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::deck::DeckEntry;
+    use crate::fixtures::card;
+
+    #[rstest]
+    #[case::remapped("dom", "DAR")]
+    #[case::same("m10", "M10")]
+    fn sets_should_be_mapped_to_arena_codes(#[case] code: &str, #[case] expected: &str) {
+        assert_eq!(expected, arena_set(code));
+    }
+
+    #[rstest]
+    fn deck_should_be_exported_for_arena() {
+        let deck = Deck {
+            commanders: vec![card(json!({ "name": "Teferi, Hero of Dominaria", "set": "dom", "collector_number": "207", "arena_id": 67106 }))],
+            mainboard: vec![
+                DeckEntry { quantity: 4, card: card(json!({ "name": "Delver of Secrets // Insectile Aberration", "layout": "transform", "arena_id": 1 })) },
+                DeckEntry { quantity: 2, card: card(json!({ "name": "Fire // Ice", "layout": "split", "arena_id": null })) },
+            ],
+            ..Deck::default()
+        };
+
+        assert_eq!(
+            "Commander\n1 Teferi, Hero of Dominaria (DAR) 207\n\nDeck\n4 Delver of Secrets (TST) 1\n2 Fire // Ice\n",
+            export(&deck)
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Cockatrice export
+//!
+//! Exports decks as [Cockatrice](https://cockatrice.github.io) `.cod` files with [deck](deck),
+//! and cards as entries of a Cockatrice card database (`cards.xml`, version 4) with [card](card),
+//! e.g. for adding custom or newly previewed cards.
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::deck::Deck;
+//! use scryfall_sdk_rust::deck::cockatrice;
+//!
+//! fn save(deck: &Deck) -> std::io::Result<()> {
+//!     std::fs::write("deck.cod", cockatrice::deck(deck, "Mono Red"))
+//! }
+//! ```
+
+use std::fmt::Write;
+use crate::deck::{Deck, DeckEntry};
+use crate::resources::card_symbols::ColorSymbol;
+use crate::resources::cards::Card;
+
+/// Builds the `.cod` file of a deck
+///
+/// Cockatrice has no commander zone, so commanders are exported in the main zone.
+pub fn deck(deck: &Deck, name: &str) -> String {
+    let mut cod = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<cockatrice_deck version=\"1\">\n");
+    let _ = writeln!(cod, "    <deckname>{}</deckname>", escape(name));
+    cod.push_str("    <comments></comments>\n");
+
+    let commanders = deck.commanders.iter().map(|card| (1, card));
+    let mainboard = commanders.chain(deck.mainboard.iter().map(entry)).collect::<Vec<_>>();
+    let sideboard = deck.sideboard.iter().map(entry).collect::<Vec<_>>();
+
+    for (zone, cards) in [("main", mainboard), ("side", sideboard)] {
+        if cards.is_empty() {
+            continue;
+        }
+
+        let _ = writeln!(cod, "    <zone name=\"{zone}\">");
+        for (quantity, card) in cards {
+            let _ = writeln!(cod, "        <card number=\"{quantity}\" name=\"{}\"/>", escape(&card.name));
+        }
+        cod.push_str("    </zone>\n");
+    }
+
+    cod.push_str("</cockatrice_deck>\n");
+    cod
+}
+
+/// Builds the card database entry (`<card>` element) of a card
+pub fn card(card: &Card) -> String {
+    let mut xml = String::from("<card>\n");
+    let _ = writeln!(xml, "    <name>{}</name>", escape(&card.name));
+    let _ = writeln!(xml, "    <text>{}</text>", escape(card.oracle_text.as_deref().unwrap_or_default()));
+
+    xml.push_str("    <prop>\n");
+    let _ = writeln!(xml, "        <layout>{}</layout>", serde_json::to_value(&card.layout).ok()
+        .and_then(|layout| layout.as_str().map(String::from))
+        .unwrap_or_default());
+    let _ = writeln!(xml, "        <side>front</side>");
+    let _ = writeln!(xml, "        <type>{}</type>", escape(&card.type_line));
+    let _ = writeln!(xml, "        <maintype>{}</maintype>", escape(main_type(&card.type_line)));
+    let _ = writeln!(xml, "        <manacost>{}</manacost>", escape(&mana_cost(card.mana_cost.as_deref().unwrap_or_default())));
+    let _ = writeln!(xml, "        <cmc>{}</cmc>", card.cmc);
+    let _ = writeln!(xml, "        <colors>{}</colors>", colors(card.colors.as_deref().unwrap_or_default()));
+    let _ = writeln!(xml, "        <coloridentity>{}</coloridentity>", colors(&card.color_identity));
+    if let (Some(power), Some(toughness)) = (&card.power, &card.toughness) {
+        let _ = writeln!(xml, "        <pt>{}/{}</pt>", escape(power), escape(toughness));
+    }
+    if let Some(loyalty) = &card.loyalty {
+        let _ = writeln!(xml, "        <loyalty>{}</loyalty>", escape(loyalty));
+    }
+    xml.push_str("    </prop>\n");
+
+    let rarity = serde_json::to_value(&card.rarity).ok()
+        .and_then(|rarity| rarity.as_str().map(String::from))
+        .unwrap_or_default();
+    let picture = card.face_image_uris(0)
+        .map(|uris| format!(" picurl=\"{}\"", escape(uris.large.as_str())))
+        .unwrap_or_default();
+    let _ = writeln!(
+        xml,
+        "    <set rarity=\"{rarity}\" uuid=\"{}\" num=\"{}\"{picture}>{}</set>",
+        card.id,
+        escape(&card.collector_number),
+        escape(&card.set.to_uppercase()),
+    );
+    let _ = writeln!(xml, "    <tablerow>{}</tablerow>", table_row(&card.type_line));
+
+    xml.push_str("</card>\n");
+    xml
+}
+
+fn entry(entry: &DeckEntry) -> (u32, &Card) {
+    (entry.quantity, &entry.card)
+}
+
+/// Gets the main card type (the last type before the subtypes, e.g. `Creature` for `Artifact Creature — Golem`)
+fn main_type(type_line: &str) -> &str {
+    type_line
+        .split(" // ").next().unwrap_or_default()
+        .split(" — ").next().unwrap_or_default()
+        .split_whitespace().last().unwrap_or_default()
+}
+
+/// Gets the table row of a card (0: lands, 1: other permanents, 2: creatures, 3: spells)
+fn table_row(type_line: &str) -> u8 {
+    match main_type(type_line) {
+        "Land" => 0,
+        "Creature" => 2,
+        "Instant" | "Sorcery" => 3,
+        _ => 1,
+    }
+}
+
+/// Strips the braces of a mana cost (e.g. `{2}{U}{U}` to `2UU`)
+fn mana_cost(cost: &str) -> String {
+    cost.replace(['{', '}'], "")
+}
+
+/// Formats colors in WUBRG order
+fn colors(colors: &[ColorSymbol]) -> String {
+    [ColorSymbol::W, ColorSymbol::U, ColorSymbol::B, ColorSymbol::R, ColorSymbol::G].iter()
+        .filter(|color| colors.contains(color))
+        .map(|color| format!("{color:?}"))
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card as card_fixture;
+
+    #[rstest]
+    fn deck_should_be_exported_in_zones() {
+        let deck = Deck {
+            commanders: vec![card_fixture(json!({ "name": "Krenko, Mob Boss" }))],
+            mainboard: vec![DeckEntry { quantity: 4, card: card_fixture(json!({ "name": "Lightning Bolt" })) }],
+            ..Deck::default()
+        };
+
+        let cod = super::deck(&deck, "Goblins & Co");
+
+        assert!(cod.contains("<deckname>Goblins &amp; Co</deckname>"));
+        assert!(cod.contains("<zone name=\"main\">\n        <card number=\"1\" name=\"Krenko, Mob Boss\"/>\n        <card number=\"4\" name=\"Lightning Bolt\"/>\n    </zone>"));
+        assert!(!cod.contains("<zone name=\"side\">"));
+    }
+
+    #[rstest]
+    fn card_should_be_exported_as_database_entry() {
+        let card = card_fixture(json!({
+            "name": "Goblin Guide",
+            "mana_cost": "{R}",
+            "type_line": "Creature — Goblin Scout",
+            "colors": ["R"],
+            "color_identity": ["R"],
+            "power": "2",
+            "toughness": "2",
+            "set": "zen",
+            "collector_number": "126",
+        }));
+
+        let xml = super::card(&card);
+
+        assert!(xml.contains("<maintype>Creature</maintype>"));
+        assert!(xml.contains("<manacost>R</manacost>"));
+        assert!(xml.contains("<colors>R</colors>"));
+        assert!(xml.contains("<pt>2/2</pt>"));
+        assert!(xml.contains("num=\"126\""));
+        assert!(xml.contains(">ZEN</set>"));
+        assert!(xml.contains("<tablerow>2</tablerow>"));
+    }
+
+    #[rstest]
+    #[case::colors(vec![ColorSymbol::G, ColorSymbol::W, ColorSymbol::U], "WUG")]
+    #[case::colorless(vec![], "")]
+    fn colors_should_be_in_wubrg_order(#[case] symbols: Vec<ColorSymbol>, #[case] expected: &str) {
+        assert_eq!(expected, colors(&symbols));
+    }
+}
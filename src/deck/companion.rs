@@ -0,0 +1,265 @@
+//! Companion detection and deck building restrictions
+//!
+//! See [companion rules](https://mtg.fandom.com/wiki/Companion) for more info.
+
+use strum_macros::Display;
+use crate::deck::{Deck, Violation};
+use crate::resources::cards::Card;
+
+/// A companion card with its deck building restriction
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum Companion {
+    /// Each nonland card has an even mana value
+    #[strum(serialize = "Gyruda, Doom of Depths")]
+    Gyruda,
+
+    /// No card has more than one of the same mana symbol in its mana cost
+    #[strum(serialize = "Jegantha, the Wellspring")]
+    Jegantha,
+
+    /// Each creature card is a Cat, Elemental, Nightmare, Dinosaur, or Beast
+    #[strum(serialize = "Kaheera, the Orphanguard")]
+    Kaheera,
+
+    /// Each nonland card has mana value 3 or greater
+    #[strum(serialize = "Keruga, the Macrosage")]
+    Keruga,
+
+    /// Each permanent card has mana value 2 or less
+    #[strum(serialize = "Lurrus of the Dream-Den")]
+    Lurrus,
+
+    /// Each nonland card has a different name
+    #[strum(serialize = "Lutri, the Spellchaser")]
+    Lutri,
+
+    /// Each nonland card has an odd mana value
+    #[strum(serialize = "Obosh, the Preypiercer")]
+    Obosh,
+
+    /// Each nonland card shares a card type
+    #[strum(serialize = "Umori, the Collector")]
+    Umori,
+
+    /// The deck contains at least twenty cards more than the minimum deck size
+    #[strum(serialize = "Yorion, Sky Nomad")]
+    Yorion,
+
+    /// Each permanent card has an activated ability
+    ///
+    /// Activated abilities are detected by the presence of `:` in oracle text.
+    #[strum(serialize = "Zirda, the Dawnwaker")]
+    Zirda,
+}
+
+const COMPANIONS: [Companion; 10] = [
+    Companion::Gyruda, Companion::Jegantha, Companion::Kaheera, Companion::Keruga,
+    Companion::Lurrus, Companion::Lutri, Companion::Obosh, Companion::Umori,
+    Companion::Yorion, Companion::Zirda,
+];
+
+const PERMANENT_TYPES: [&str; 6] = [
+    "Artifact", "Battle", "Creature", "Enchantment", "Land", "Planeswalker",
+];
+
+const NONLAND_TYPES: [&str; 7] = [
+    "Artifact", "Battle", "Creature", "Enchantment", "Instant", "Planeswalker", "Sorcery",
+];
+
+impl Companion {
+    /// Detects the companion represented by a card (if any)
+    pub fn from_card(card: &Card) -> Option<Self> {
+        COMPANIONS.into_iter().find(|c| c.to_string() == card.name)
+    }
+
+    /// Checks the companion's restriction against the starting deck
+    ///
+    /// `min_size` is the minimum deck size of the format, used by [Yorion](Companion::Yorion).
+    pub(crate) fn violations(&self, deck: &Deck, min_size: u32) -> Vec<Violation> {
+        let starting_deck = || deck.commanders.iter()
+            .chain(deck.mainboard.iter().map(|e| &e.card));
+        let nonland = || starting_deck().filter(|c| !has_type(c, "Land"));
+        let permanent = || starting_deck()
+            .filter(|c| PERMANENT_TYPES.iter().any(|t| has_type(c, t)));
+
+        let offending: Vec<&Card> = match self {
+            Companion::Gyruda => nonland()
+                .filter(|c| c.cmc as i64 % 2 != 0)
+                .collect(),
+            Companion::Jegantha => starting_deck()
+                .filter(|c| has_repeated_symbol(c))
+                .collect(),
+            Companion::Kaheera => starting_deck()
+                .filter(|c| has_type(c, "Creature"))
+                .filter(|c| !["Cat", "Elemental", "Nightmare", "Dinosaur", "Beast"]
+                    .iter()
+                    .any(|t| has_subtype(c, t))
+                )
+                .collect(),
+            Companion::Keruga => nonland()
+                .filter(|c| c.cmc < 3.0)
+                .collect(),
+            Companion::Lurrus => permanent()
+                .filter(|c| c.cmc > 2.0)
+                .collect(),
+            Companion::Lutri => nonland()
+                .filter(|c| deck.mainboard.iter()
+                    .filter(|e| e.card.name == c.name)
+                    .map(|e| e.quantity)
+                    .sum::<u32>() > 1
+                )
+                .collect(),
+            Companion::Obosh => nonland()
+                .filter(|c| c.cmc as i64 % 2 == 0)
+                .collect(),
+            Companion::Umori => {
+                let shared = NONLAND_TYPES.iter()
+                    .max_by_key(|t| nonland().filter(|c| has_type(c, t)).count())
+                    .unwrap_or(&"");
+
+                nonland()
+                    .filter(|c| !has_type(c, shared))
+                    .collect()
+            },
+            Companion::Yorion => {
+                return if deck.size() < min_size + 20 {
+                    vec![Violation::CompanionRestriction {
+                        companion: self.to_string(),
+                        name: None,
+                    }]
+                } else {
+                    vec![]
+                };
+            },
+            Companion::Zirda => permanent()
+                .filter(|c| !matches!(&c.oracle_text, Some(t) if t.contains(':')))
+                .collect(),
+        };
+
+        let mut names: Vec<&str> = offending.iter().map(|c| c.name.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        names.into_iter()
+            .map(|name| Violation::CompanionRestriction {
+                companion: self.to_string(),
+                name: Some(name.into()),
+            })
+            .collect()
+    }
+}
+
+fn has_type(card: &Card, kind: &str) -> bool {
+    card.type_line
+        .split(" // ")
+        .any(|face| face.split(" — ").next().unwrap_or("").contains(kind))
+}
+
+fn has_subtype(card: &Card, kind: &str) -> bool {
+    card.type_line
+        .split(" // ")
+        .filter_map(|face| face.split(" — ").nth(1))
+        .any(|subtypes| subtypes.split(' ').any(|t| t == kind))
+}
+
+fn has_repeated_symbol(card: &Card) -> bool {
+    card.mana_cost.as_deref()
+        .unwrap_or("")
+        .split(" // ")
+        .any(|cost| {
+            let mut symbols: Vec<&str> = cost.split_inclusive('}').collect();
+            let count = symbols.len();
+            symbols.sort_unstable();
+            symbols.dedup();
+            symbols.len() != count
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::deck::DeckEntry;
+    use crate::fixtures::card;
+
+    fn deck_with(cards: Vec<Card>) -> Deck {
+        Deck {
+            mainboard: cards.into_iter()
+                .map(|card| DeckEntry { quantity: 1, card })
+                .collect(),
+            ..Deck::default()
+        }
+    }
+
+    #[rstest]
+    fn companion_should_be_detected_by_name() {
+        assert_eq!(
+            Some(Companion::Lurrus),
+            Companion::from_card(&card(json!({ "name": "Lurrus of the Dream-Den" })))
+        );
+        assert_eq!(None, Companion::from_card(&card(json!({ "name": "Lurrus" }))));
+    }
+
+    #[rstest]
+    #[case::lurrus(Companion::Lurrus, json!({ "type_line": "Creature — Elf", "cmc": 3 }))]
+    #[case::gyruda(Companion::Gyruda, json!({ "type_line": "Instant", "cmc": 1 }))]
+    #[case::obosh(Companion::Obosh, json!({ "type_line": "Instant", "cmc": 2 }))]
+    #[case::keruga(Companion::Keruga, json!({ "type_line": "Sorcery", "cmc": 2 }))]
+    #[case::jegantha(Companion::Jegantha, json!({ "mana_cost": "{1}{R}{R}" }))]
+    #[case::kaheera(Companion::Kaheera, json!({ "type_line": "Creature — Elf Druid" }))]
+    #[case::zirda(Companion::Zirda, json!({ "type_line": "Artifact", "oracle_text": "Flash" }))]
+    fn companion_should_report_offending_cards(
+        #[case] companion: Companion,
+        #[case] mut overrides: serde_json::Value,
+    ) {
+        overrides["name"] = json!("Offender");
+        let deck = deck_with(vec![card(overrides)]);
+
+        assert_eq!(vec![Violation::CompanionRestriction {
+            companion: companion.to_string(),
+            name: Some("Offender".into()),
+        }], companion.violations(&deck, 60));
+    }
+
+    #[rstest]
+    #[case::lurrus(Companion::Lurrus, json!({ "type_line": "Land", "cmc": 0 }))]
+    #[case::gyruda(Companion::Gyruda, json!({ "type_line": "Instant", "cmc": 2 }))]
+    #[case::obosh(Companion::Obosh, json!({ "type_line": "Land", "cmc": 0 }))]
+    #[case::jegantha(Companion::Jegantha, json!({ "mana_cost": "{1}{R}{G} // {R}" }))]
+    #[case::kaheera(Companion::Kaheera, json!({ "type_line": "Legendary Creature — Cat Warrior" }))]
+    #[case::zirda(Companion::Zirda, json!({ "type_line": "Land", "oracle_text": "{T}: Add {G}." }))]
+    fn companion_should_allow_matching_cards(
+        #[case] companion: Companion,
+        #[case] overrides: serde_json::Value,
+    ) {
+        let deck = deck_with(vec![card(overrides)]);
+
+        assert!(companion.violations(&deck, 60).is_empty());
+    }
+
+    #[rstest]
+    fn umori_should_report_cards_not_sharing_the_most_common_type() {
+        let deck = deck_with(vec![
+            card(json!({ "name": "A", "type_line": "Creature — Elf" })),
+            card(json!({ "name": "B", "type_line": "Artifact Creature — Golem" })),
+            card(json!({ "name": "C", "type_line": "Instant" })),
+        ]);
+
+        assert_eq!(vec![Violation::CompanionRestriction {
+            companion: Companion::Umori.to_string(),
+            name: Some("C".into()),
+        }], Companion::Umori.violations(&deck, 60));
+    }
+
+    #[rstest]
+    fn yorion_should_require_twenty_extra_cards() {
+        let mut deck = deck_with(vec![card(json!({}))]);
+        deck.mainboard[0].quantity = 79;
+
+        assert_eq!(1, Companion::Yorion.violations(&deck, 60).len());
+
+        deck.mainboard[0].quantity = 80;
+        assert!(Companion::Yorion.violations(&deck, 60).is_empty());
+    }
+}
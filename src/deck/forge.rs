@@ -0,0 +1,72 @@
+//! Forge export
+//!
+//! Exports decks as [Forge](https://github.com/Card-Forge/forge) `.dck` files, e.g.:
+//!
+//! ```text
+//! [metadata]
+//! Name=Mono Red
+//! [Main]
+//! 4 Lightning Bolt|M10
+//! 20 Mountain|M10
+//! [Sideboard]
+//! 2 Smash to Smithereens|ALA
+//! ```
+
+use std::fmt::Write;
+use crate::deck::list::{front_face_name, DeckList, DeckListEntry};
+use crate::deck::Deck;
+use crate::resources::cards::Card;
+
+/// Gets the Forge entry for copies of a card
+pub fn entry(quantity: u32, card: &Card) -> DeckListEntry {
+    DeckListEntry {
+        name: front_face_name(card).into(),
+        ..DeckListEntry::of(quantity, card)
+    }
+}
+
+/// Exports a deck as a Forge `.dck` file
+///
+/// The companion is exported in the sideboard.
+pub fn export(deck: &Deck, name: &str) -> String {
+    let list = DeckList::from_deck(deck, entry);
+    let sideboard: Vec<&DeckListEntry> = list.companion.iter().chain(&list.sideboard).collect();
+
+    let mut dck = format!("[metadata]\nName={name}\n");
+    for (section, entries) in [("Commander", list.commanders.iter().collect()), ("Main", list.mainboard.iter().collect()), ("Sideboard", sideboard)] {
+        if entries.is_empty() {
+            continue;
+        }
+
+        let _ = writeln!(dck, "[{section}]");
+        for entry in entries {
+            let _ = writeln!(dck, "{} {}|{}", entry.quantity, entry.name, entry.set.as_deref().unwrap_or_default().to_uppercase());
+        }
+    }
+
+    dck
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::deck::DeckEntry;
+    use crate::fixtures::card;
+
+    #[rstest]
+    fn deck_should_be_exported_for_forge() {
+        let deck = Deck {
+            commanders: vec![card(json!({ "name": "Krenko, Mob Boss", "set": "m19" }))],
+            mainboard: vec![DeckEntry { quantity: 4, card: card(json!({ "name": "Brazen Borrower // Petty Theft", "layout": "adventure", "set": "eld" })) }],
+            companion: Some(card(json!({ "name": "Lurrus of the Dream-Den", "set": "iko" }))),
+            ..Deck::default()
+        };
+
+        assert_eq!(
+            "[metadata]\nName=Test\n[Commander]\n1 Krenko, Mob Boss|M19\n[Main]\n4 Brazen Borrower|ELD\n[Sideboard]\n1 Lurrus of the Dream-Den|IKO\n",
+            export(&deck, "Test")
+        );
+    }
+}
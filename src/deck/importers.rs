@@ -0,0 +1,376 @@
+//! Deck importers for popular deck builder sites
+//!
+//! Importing has to be enabled by the **importers** optional feature.
+//!
+//! Public deck lists are fetched from the following sites:
+//!
+//! - <https://www.moxfield.com>
+//! - <https://archidekt.com>
+//! - <https://tappedout.net>
+//!
+//! and normalized into a [DeckList](DeckList), which is then resolved
+//! through the [collection endpoint](crate::CardCollectionResource).
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::Scryfall;
+//! use scryfall_sdk_rust::deck::importers;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let resolved = importers::import(
+//!     &Scryfall::default(),
+//!     "https://www.moxfield.com/decks/abc123"
+//! ).await?;
+//!
+//! println!("{} cards", resolved.deck.size());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use serde::Deserialize;
+use url::Url;
+use crate::deck::list::{DeckList, DeckListEntry, ResolvedDeck};
+use crate::resources::errors::ErrorBody;
+use crate::Scryfall;
+
+/// A deck hosted in a supported deck builder site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckSource {
+    /// Moxfield deck with its public id
+    Moxfield(String),
+
+    /// Archidekt deck with its numeric id
+    Archidekt(String),
+
+    /// TappedOut deck with its slug
+    TappedOut(String),
+}
+
+impl DeckSource {
+    /// Detects the deck source from a public deck URL
+    ///
+    /// # Example
+    /// ```
+    /// use scryfall_sdk_rust::deck::importers::DeckSource;
+    ///
+    /// assert_eq!(
+    ///     Some(DeckSource::Archidekt("1234".into())),
+    ///     DeckSource::from_url("https://archidekt.com/decks/1234/my_deck")
+    /// );
+    /// ```
+    pub fn from_url(url: &str) -> Option<Self> {
+        let url = Url::parse(url).ok()?;
+        let host = url.host_str()?.trim_start_matches("www.");
+        let segments: Vec<&str> = url.path_segments()?
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        match (host, segments.as_slice()) {
+            ("moxfield.com", ["decks", id, ..]) => Some(DeckSource::Moxfield(id.to_string())),
+            ("archidekt.com", ["decks", id, ..]) => Some(DeckSource::Archidekt(id.to_string())),
+            ("tappedout.net", ["mtg-decks", slug, ..]) => Some(DeckSource::TappedOut(slug.to_string())),
+            _ => None,
+        }
+    }
+
+    /// Gets the URL serving the deck list in a machine readable format
+    pub fn api_url(&self) -> String {
+        match self {
+            DeckSource::Moxfield(id) => format!("https://api2.moxfield.com/v2/decks/all/{id}"),
+            DeckSource::Archidekt(id) => format!("https://archidekt.com/api/decks/{id}/"),
+            DeckSource::TappedOut(slug) => format!("https://tappedout.net/mtg-decks/{slug}/?fmt=txt"),
+        }
+    }
+
+    /// Parses the body served by [api_url](DeckSource::api_url) into a deck list
+    pub fn parse(&self, body: &str) -> Result<DeckList, ErrorBody> {
+        let parsed = match self {
+            DeckSource::Moxfield(_) => serde_json::from_str::<MoxfieldDeck>(body)
+                .map(DeckList::from)
+                .map_err(|e| e.to_string()),
+            DeckSource::Archidekt(_) => serde_json::from_str::<ArchidektDeck>(body)
+                .map(DeckList::from)
+                .map_err(|e| e.to_string()),
+            DeckSource::TappedOut(_) => body.parse::<DeckList>()
+                .map_err(|e| e.to_string()),
+        };
+
+        parsed.map_err(|e| ErrorBody::client_error(format!("invalid deck list: {e}")))
+    }
+}
+
+/// Imports and resolves a public deck using the async client
+pub async fn import(client: &Scryfall<'_>, url: &str) -> Result<ResolvedDeck, ErrorBody> {
+    let source = source_of(url)?;
+
+    let body = client.http_client()
+        .get(source.api_url())
+        .send().await
+        .and_then(|r| r.error_for_status())
+        .map_err(ErrorBody::from_reqwest_error)?
+        .text().await
+        .map_err(ErrorBody::from_reqwest_error)?;
+
+    source.parse(&body)?.resolve(client).await
+}
+
+/// Imports and resolves a public deck using the blocking client
+#[cfg(feature = "blocking")]
+pub fn import_blocking(
+    client: &crate::ScryfallBlocking<'_>,
+    url: &str
+) -> Result<ResolvedDeck, ErrorBody> {
+    client.block_on(import(client.as_async(), url))
+}
+
+fn source_of(url: &str) -> Result<DeckSource, ErrorBody> {
+    DeckSource::from_url(url)
+        .ok_or_else(|| ErrorBody::client_error(format!("unsupported deck url: {url}")))
+}
+
+// -- Moxfield
+
+#[derive(Deserialize)]
+struct MoxfieldDeck {
+    #[serde(default)]
+    commanders: BTreeMap<String, MoxfieldEntry>,
+    #[serde(default)]
+    companions: BTreeMap<String, MoxfieldEntry>,
+    #[serde(default)]
+    mainboard: BTreeMap<String, MoxfieldEntry>,
+    #[serde(default)]
+    sideboard: BTreeMap<String, MoxfieldEntry>,
+    #[serde(default)]
+    maybeboard: BTreeMap<String, MoxfieldEntry>,
+}
+
+#[derive(Deserialize)]
+struct MoxfieldEntry {
+    quantity: u32,
+    card: MoxfieldCard,
+}
+
+#[derive(Deserialize)]
+struct MoxfieldCard {
+    name: String,
+    set: Option<String>,
+    cn: Option<String>,
+}
+
+impl From<MoxfieldDeck> for DeckList {
+    fn from(deck: MoxfieldDeck) -> Self {
+        let entries = |board: BTreeMap<String, MoxfieldEntry>| board.into_values()
+            .map(|e| DeckListEntry {
+                quantity: e.quantity,
+                name: e.card.name,
+                set: e.card.set,
+                collector_number: e.card.cn,
+            })
+            .collect::<Vec<_>>();
+
+        DeckList {
+            commanders: entries(deck.commanders),
+            companion: entries(deck.companions).into_iter().next(),
+            mainboard: entries(deck.mainboard),
+            sideboard: entries(deck.sideboard),
+            maybeboard: entries(deck.maybeboard),
+        }
+    }
+}
+
+// -- Archidekt
+
+#[derive(Deserialize)]
+struct ArchidektDeck {
+    cards: Vec<ArchidektEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchidektEntry {
+    quantity: u32,
+    #[serde(default)]
+    categories: Vec<String>,
+    card: ArchidektCard,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchidektCard {
+    collector_number: Option<String>,
+    edition: Option<ArchidektEdition>,
+    oracle_card: ArchidektOracleCard,
+}
+
+#[derive(Deserialize)]
+struct ArchidektEdition {
+    editioncode: String,
+}
+
+#[derive(Deserialize)]
+struct ArchidektOracleCard {
+    name: String,
+}
+
+impl From<ArchidektDeck> for DeckList {
+    fn from(deck: ArchidektDeck) -> Self {
+        let mut list = DeckList::default();
+
+        for e in deck.cards {
+            let in_category = |name: &str| e.categories.iter()
+                .any(|c| c.eq_ignore_ascii_case(name));
+
+            let is_commander = in_category("Commander");
+            let is_companion = in_category("Companion");
+            let is_sideboard = in_category("Sideboard");
+            let is_maybeboard = in_category("Maybeboard");
+
+            let entry = DeckListEntry {
+                quantity: e.quantity,
+                name: e.card.oracle_card.name,
+                set: e.card.edition.map(|ed| ed.editioncode),
+                collector_number: e.card.collector_number,
+            };
+
+            if is_commander {
+                list.commanders.push(entry)
+            } else if is_companion {
+                list.companion = Some(entry)
+            } else if is_sideboard {
+                list.sideboard.push(entry)
+            } else if is_maybeboard {
+                list.maybeboard.push(entry)
+            } else {
+                list.mainboard.push(entry)
+            }
+        }
+
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use rstest::rstest;
+
+    fn entry(quantity: u32, name: &str, set: Option<&str>, number: Option<&str>) -> DeckListEntry {
+        DeckListEntry {
+            quantity,
+            name: name.into(),
+            set: set.map(String::from),
+            collector_number: number.map(String::from),
+        }
+    }
+
+    #[rstest]
+    #[case::moxfield("https://www.moxfield.com/decks/abc-123", Some(DeckSource::Moxfield("abc-123".into())))]
+    #[case::archidekt("https://archidekt.com/decks/1234/name", Some(DeckSource::Archidekt("1234".into())))]
+    #[case::tappedout("https://tappedout.net/mtg-decks/my-deck/", Some(DeckSource::TappedOut("my-deck".into())))]
+    #[case::unknown_host("https://example.com/decks/1234", None)]
+    #[case::unknown_path("https://www.moxfield.com/users/someone", None)]
+    #[case::invalid("not a url", None)]
+    fn source_should_be_detected_from_url(
+        #[case] url: &str,
+        #[case] expected: Option<DeckSource>,
+    ) {
+        assert_eq!(expected, DeckSource::from_url(url));
+    }
+
+    #[rstest]
+    fn moxfield_response_should_parse_to_deck_list() {
+        let body = indoc!(r#"
+        {
+            "commanders": {
+                "Lurrus of the Dream-Den": {
+                    "quantity": 1,
+                    "card": { "name": "Lurrus of the Dream-Den", "set": "iko", "cn": "226" }
+                }
+            },
+            "mainboard": {
+                "Mountain": { "quantity": 20, "card": { "name": "Mountain" } },
+                "Lightning Bolt": {
+                    "quantity": 4,
+                    "card": { "name": "Lightning Bolt", "set": "m10", "cn": "146" }
+                }
+            },
+            "sideboard": {}
+        }
+        "#);
+
+        let list = DeckSource::Moxfield("id".into()).parse(body).unwrap();
+
+        assert_eq!(DeckList {
+            commanders: vec![entry(1, "Lurrus of the Dream-Den", Some("iko"), Some("226"))],
+            mainboard: vec![
+                entry(4, "Lightning Bolt", Some("m10"), Some("146")),
+                entry(20, "Mountain", None, None),
+            ],
+            ..DeckList::default()
+        }, list);
+    }
+
+    #[rstest]
+    fn archidekt_response_should_parse_to_deck_list() {
+        let body = indoc!(r#"
+        {
+            "cards": [
+                {
+                    "quantity": 1,
+                    "categories": ["Commander"],
+                    "card": {
+                        "collectorNumber": "226",
+                        "edition": { "editioncode": "iko" },
+                        "oracleCard": { "name": "Lurrus of the Dream-Den" }
+                    }
+                },
+                {
+                    "quantity": 2,
+                    "categories": ["Sideboard"],
+                    "card": { "oracleCard": { "name": "Duress" } }
+                },
+                {
+                    "quantity": 4,
+                    "categories": ["Removal"],
+                    "card": { "oracleCard": { "name": "Lightning Bolt" } }
+                }
+            ]
+        }
+        "#);
+
+        let list = DeckSource::Archidekt("id".into()).parse(body).unwrap();
+
+        assert_eq!(DeckList {
+            commanders: vec![entry(1, "Lurrus of the Dream-Den", Some("iko"), Some("226"))],
+            mainboard: vec![entry(4, "Lightning Bolt", None, None)],
+            sideboard: vec![entry(2, "Duress", None, None)],
+            ..DeckList::default()
+        }, list);
+    }
+
+    #[rstest]
+    fn tappedout_response_should_parse_to_deck_list() {
+        let body = "4x Lightning Bolt\n20 Mountain\n\nSideboard:\n2 Duress\n";
+
+        let list = DeckSource::TappedOut("slug".into()).parse(body).unwrap();
+
+        assert_eq!(DeckList {
+            mainboard: vec![
+                entry(4, "Lightning Bolt", None, None),
+                entry(20, "Mountain", None, None),
+            ],
+            sideboard: vec![entry(2, "Duress", None, None)],
+            ..DeckList::default()
+        }, list);
+    }
+
+    #[rstest]
+    fn invalid_response_should_be_client_error() {
+        let error = DeckSource::Moxfield("id".into()).parse("<html>").unwrap_err();
+
+        assert_eq!("CLIENT_ERR", error.code);
+        assert!(error.details.starts_with("invalid deck list"));
+    }
+}
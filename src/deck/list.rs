@@ -0,0 +1,590 @@
+//! Unresolved (text) deck lists
+//!
+//! Parses deck lists in the format exported by MTG Arena
+//! (and most deck builders), e.g.:
+//!
+//! ```text
+//! Commander
+//! 1 Lurrus of the Dream-Den (IKO) 226
+//!
+//! Deck
+//! 4 Lightning Bolt (M10) 146
+//! 20 Mountain
+//!
+//! Sideboard
+//! 2 Duress
+//! ```
+//!
+//! Sections are introduced by a header line (`Commander`, `Companion`, `Deck`,
+//! `Sideboard`, `Maybeboard`). Lists without headers are also supported,
+//! in which case a blank line separates the mainboard from the sideboard.
+//!
+//! A parsed list can be resolved to a [Deck](Deck) through
+//! the [collection endpoint](crate::CardCollectionResource).
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::Scryfall;
+//! use scryfall_sdk_rust::deck::list::DeckList;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let list: DeckList = "4 Lightning Bolt\n20 Mountain".parse()?;
+//! let resolved = list.resolve(&Scryfall::default()).await?;
+//!
+//! assert!(resolved.not_found.is_empty());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use crate::deck::{Deck, DeckEntry};
+use crate::resources::cards::{Card, CardCollectionResource, CardIdentifier, CardIdentifiers, Layout};
+use crate::resources::errors::ErrorBody;
+use crate::Scryfall;
+
+/// Maximum number of identifiers accepted by the collection endpoint
+const COLLECTION_LIMIT: usize = 75;
+
+/// A deck list of card names (not yet resolved to cards)
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DeckList {
+    pub commanders: Vec<DeckListEntry>,
+    pub companion: Option<DeckListEntry>,
+    pub mainboard: Vec<DeckListEntry>,
+    pub sideboard: Vec<DeckListEntry>,
+    pub maybeboard: Vec<DeckListEntry>,
+}
+
+/// A single deck list line, e.g. `4 Lightning Bolt (M10) 146`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckListEntry {
+    pub quantity: u32,
+    pub name: String,
+    pub set: Option<String>,
+    pub collector_number: Option<String>,
+}
+
+impl DeckListEntry {
+    /// Gets the most specific identifier for the entry
+    ///
+    /// This can be used with the [collection endpoint](crate::CardCollectionResource).
+    pub fn identifier(&self) -> CardIdentifier {
+        match (&self.set, &self.collector_number) {
+            (Some(set), Some(number)) => CardIdentifier::SetAndNumber {
+                set: set.to_lowercase(),
+                number: number.clone(),
+            },
+            (Some(set), None) => CardIdentifier::SetAndName {
+                set: set.to_lowercase(),
+                name: self.name.clone(),
+            },
+            _ => CardIdentifier::Name { val: self.name.clone() },
+        }
+    }
+
+    /// Checks if a card satisfies the entry
+    ///
+    /// Names are compared case-insensitively, also matching the front face
+    /// of multi-faced cards (e.g. `Delver of Secrets`).
+    pub fn matches(&self, card: &Card) -> bool {
+        let name_matches = card.name.eq_ignore_ascii_case(&self.name)
+            || matches!(
+                card.name.split_once(" // "),
+                Some((face, _)) if face.eq_ignore_ascii_case(&self.name)
+            );
+
+        match (&self.set, &self.collector_number) {
+            (Some(set), Some(number)) =>
+                card.set.eq_ignore_ascii_case(set) && &card.collector_number == number,
+            (Some(set), None) =>
+                card.set.eq_ignore_ascii_case(set) && name_matches,
+            _ => name_matches,
+        }
+    }
+}
+
+impl Display for DeckListEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.quantity, self.name)?;
+
+        if let Some(set) = &self.set {
+            write!(f, " ({})", set.to_uppercase())?;
+        }
+        if let Some(number) = &self.collector_number {
+            write!(f, " {number}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DeckListEntry {
+    /// Creates an entry for copies of a printing (with its set and collector number)
+    pub fn of(quantity: u32, card: &Card) -> Self {
+        DeckListEntry {
+            quantity,
+            name: card.name.clone(),
+            set: Some(card.set.clone()),
+            collector_number: Some(card.collector_number.clone()),
+        }
+    }
+}
+
+impl FromStr for DeckListEntry {
+    type Err = ParseDeckError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = s.trim();
+        let err = || ParseDeckError { line: 0, content: line.into() };
+
+        let (quantity, rest) = line.split_once(' ').ok_or_else(err)?;
+        let quantity = quantity.trim_end_matches('x').parse::<u32>().map_err(|_| err())?;
+        let rest = rest.trim();
+
+        let (name, set, collector_number) = match rest.rfind(" (") {
+            Some(open) => {
+                let (name, printing) = rest.split_at(open);
+                let (set, number) = printing.trim()
+                    .trim_start_matches('(')
+                    .split_once(')')
+                    .ok_or_else(err)?;
+                let number = Some(number.trim())
+                    .filter(|n| !n.is_empty())
+                    .map(String::from);
+
+                (name.trim(), Some(set.to_string()), number)
+            },
+            None => (rest, None, None),
+        };
+
+        if name.is_empty() {
+            return Err(err());
+        }
+
+        Ok(DeckListEntry { quantity, name: name.into(), set, collector_number })
+    }
+}
+
+/// Section of a deck list
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    Commander,
+    Companion,
+    Mainboard,
+    Sideboard,
+    Maybeboard,
+}
+
+impl Section {
+    fn from_header(line: &str) -> Option<Self> {
+        match line.trim().trim_end_matches(':').to_lowercase().as_str() {
+            "commander" | "commanders" => Some(Section::Commander),
+            "companion" => Some(Section::Companion),
+            "deck" | "main" | "mainboard" => Some(Section::Mainboard),
+            "sideboard" => Some(Section::Sideboard),
+            "maybeboard" | "considering" => Some(Section::Maybeboard),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for DeckList {
+    type Err = ParseDeckError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut list = DeckList::default();
+        let mut section = Section::Mainboard;
+        let has_headers = s.lines().any(|l| Section::from_header(l).is_some());
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() {
+                if !has_headers && section == Section::Mainboard && !list.mainboard.is_empty() {
+                    section = Section::Sideboard;
+                }
+                continue;
+            }
+
+            if line.starts_with("//") || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = Section::from_header(line) {
+                section = header;
+                continue;
+            }
+
+            let entry = line.parse::<DeckListEntry>()
+                .map_err(|e| ParseDeckError { line: index + 1, ..e })?;
+
+            match section {
+                Section::Commander => list.commanders.push(entry),
+                Section::Companion => list.companion = Some(entry),
+                Section::Mainboard => list.mainboard.push(entry),
+                Section::Sideboard => list.sideboard.push(entry),
+                Section::Maybeboard => list.maybeboard.push(entry),
+            }
+        }
+
+        Ok(list)
+    }
+}
+
+/// Gets the name of a card as listed by most game clients
+///
+/// Multi-faced cards (except split cards) are listed by their front face name.
+pub(crate) fn front_face_name(card: &Card) -> &str {
+    match (&card.layout, card.name.split_once(" // ")) {
+        (Layout::Split, _) | (_, None) => &card.name,
+        (_, Some((front, _))) => front,
+    }
+}
+
+/// Formats the list with section headers (the format parsed by [from_str](DeckList::from_str))
+impl Display for DeckList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sections: [(&str, Vec<&DeckListEntry>); 5] = [
+            ("Commander", self.commanders.iter().collect()),
+            ("Companion", self.companion.iter().collect()),
+            ("Deck", self.mainboard.iter().collect()),
+            ("Sideboard", self.sideboard.iter().collect()),
+            ("Maybeboard", self.maybeboard.iter().collect()),
+        ];
+
+        let mut first = true;
+        for (header, entries) in sections.iter().filter(|(_, entries)| !entries.is_empty()) {
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+
+            writeln!(f, "{header}")?;
+            for entry in entries.iter() {
+                writeln!(f, "{entry}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Lists the printings of a resolved deck
+impl From<&Deck> for DeckList {
+    fn from(deck: &Deck) -> Self {
+        DeckList::from_deck(deck, DeckListEntry::of)
+    }
+}
+
+impl DeckList {
+    /// Iterates over all entries of the list (in all sections)
+    pub fn entries(&self) -> impl Iterator<Item = &DeckListEntry> {
+        self.commanders.iter()
+            .chain(self.companion.iter())
+            .chain(self.mainboard.iter())
+            .chain(self.sideboard.iter())
+            .chain(self.maybeboard.iter())
+    }
+
+    /// Lists the cards of a resolved deck, creating the entries with the given function
+    ///
+    /// This is the common representation of the deck exporters (e.g. for adapting names
+    /// or set codes to another application).
+    pub fn from_deck<F>(deck: &Deck, entry: F) -> Self
+        where F: Fn(u32, &Card) -> DeckListEntry
+    {
+        let section = |entries: &[DeckEntry]| entries.iter()
+            .map(|e| entry(e.quantity, &e.card))
+            .collect();
+
+        DeckList {
+            commanders: deck.commanders.iter().map(|card| entry(1, card)).collect(),
+            companion: deck.companion.as_ref().map(|card| entry(1, card)),
+            mainboard: section(&deck.mainboard),
+            sideboard: section(&deck.sideboard),
+            maybeboard: section(&deck.maybeboard),
+        }
+    }
+
+    /// Builds the collection requests needed to resolve the list
+    ///
+    /// Identifiers are de-duplicated and split in chunks of 75 (the endpoint's limit).
+    pub fn collection_requests(&self) -> Vec<CardCollectionResource> {
+        let mut identifiers: Vec<CardIdentifier> = vec![];
+
+        self.entries()
+            .map(DeckListEntry::identifier)
+            .for_each(|id| if !identifiers.contains(&id) {
+                identifiers.push(id)
+            });
+
+        identifiers
+            .chunks(COLLECTION_LIMIT)
+            .map(|chunk| CardCollectionResource::WithIdentifiers(
+                CardIdentifiers { identifiers: chunk.to_vec() }
+            ))
+            .collect()
+    }
+
+    /// Assembles a deck from the cards returned by the collection endpoint
+    ///
+    /// Entries without a matching card are reported as not found.
+    pub fn assemble(&self, cards: &[Card]) -> ResolvedDeck {
+        let mut not_found = vec![];
+        let mut find = |entry: &DeckListEntry| {
+            let card = cards.iter().find(|c| entry.matches(c)).cloned();
+            if card.is_none() {
+                not_found.push(entry.clone());
+            }
+            card
+        };
+
+        let mut section = |entries: &[DeckListEntry]| -> Vec<DeckEntry> {
+            entries.iter()
+                .filter_map(|e| find(e).map(|card| DeckEntry { quantity: e.quantity, card }))
+                .collect()
+        };
+
+        let commanders = section(&self.commanders).into_iter().map(|e| e.card).collect();
+        let mainboard = section(&self.mainboard);
+        let sideboard = section(&self.sideboard);
+        let maybeboard = section(&self.maybeboard);
+        let companion = self.companion.as_ref().and_then(&mut find);
+
+        ResolvedDeck {
+            deck: Deck { commanders, companion, mainboard, sideboard, maybeboard },
+            not_found,
+        }
+    }
+
+    /// Resolves the list to a deck using the async client
+    pub async fn resolve(&self, client: &Scryfall<'_>) -> Result<ResolvedDeck, ErrorBody> {
+        let mut cards = vec![];
+
+        for request in self.collection_requests() {
+            cards.extend(client.request(&request).await?.cards);
+        }
+
+        Ok(self.assemble(&cards))
+    }
+
+    /// Resolves the list to a deck using the blocking client
+    #[cfg(feature = "blocking")]
+    pub fn resolve_blocking(
+        &self,
+        client: &crate::ScryfallBlocking<'_>
+    ) -> Result<ResolvedDeck, ErrorBody> {
+        let mut cards = vec![];
+
+        for request in self.collection_requests() {
+            cards.extend(client.request(&request)?.cards);
+        }
+
+        Ok(self.assemble(&cards))
+    }
+}
+
+/// A deck resolved from a [DeckList](DeckList)
+#[derive(Debug, PartialEq)]
+pub struct ResolvedDeck {
+    /// The deck containing all the resolved entries
+    pub deck: Deck,
+
+    /// The entries that could not be resolved to a card
+    pub not_found: Vec<DeckListEntry>,
+}
+
+/// Error when a deck list line cannot be parsed
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDeckError {
+    /// The (1-based) line number
+    pub line: usize,
+
+    /// The content of the line
+    pub content: String,
+}
+
+impl Display for ParseDeckError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid deck list entry at line {}: {}", self.line, self.content)
+    }
+}
+
+impl Error for ParseDeckError { }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    fn entry(quantity: u32, name: &str, set: Option<&str>, number: Option<&str>) -> DeckListEntry {
+        DeckListEntry {
+            quantity,
+            name: name.into(),
+            set: set.map(String::from),
+            collector_number: number.map(String::from),
+        }
+    }
+
+    #[rstest]
+    #[case::name_only("4 Lightning Bolt", entry(4, "Lightning Bolt", None, None))]
+    #[case::with_x("4x Lightning Bolt", entry(4, "Lightning Bolt", None, None))]
+    #[case::with_set("1 Opt (XLN)", entry(1, "Opt", Some("XLN"), None))]
+    #[case::with_number("1 Fire // Ice (MH2) 290", entry(1, "Fire // Ice", Some("MH2"), Some("290")))]
+    fn entry_should_parse_from_line(#[case] line: &str, #[case] expected: DeckListEntry) {
+        assert_eq!(Ok(expected), line.parse());
+    }
+
+    #[rstest]
+    fn list_should_format_with_section_headers() {
+        let text = "Commander\n1 Lurrus of the Dream-Den (IKO) 226\n\nDeck\n4 Lightning Bolt (M10) 146\n20 Mountain\n";
+        let list: DeckList = text.parse().unwrap();
+
+        assert_eq!(text, list.to_string());
+    }
+
+    #[rstest]
+    #[case::no_quantity("Lightning Bolt")]
+    #[case::no_name("4 ")]
+    fn entry_should_fail_to_parse_invalid_line(#[case] line: &str) {
+        assert!(line.parse::<DeckListEntry>().is_err());
+    }
+
+    #[rstest]
+    fn entry_should_display_as_line() {
+        let line = "1 Fire // Ice (MH2) 290";
+        assert_eq!(line, line.parse::<DeckListEntry>().unwrap().to_string());
+    }
+
+    #[rstest]
+    #[case::set_and_number(
+        entry(1, "Opt", Some("XLN"), Some("65")),
+        CardIdentifier::SetAndNumber { set: "xln".into(), number: "65".into() }
+    )]
+    #[case::set_and_name(
+        entry(1, "Opt", Some("XLN"), None),
+        CardIdentifier::SetAndName { set: "xln".into(), name: "Opt".into() }
+    )]
+    #[case::name(entry(1, "Opt", None, None), CardIdentifier::Name { val: "Opt".into() })]
+    fn entry_should_map_to_identifier(
+        #[case] entry: DeckListEntry,
+        #[case] expected: CardIdentifier,
+    ) {
+        assert_eq!(expected, entry.identifier());
+    }
+
+    #[rstest]
+    fn list_should_parse_arena_export() {
+        let list: DeckList = indoc!("
+            Commander
+            1 Lurrus of the Dream-Den (IKO) 226
+
+            Companion
+            1 Jegantha, the Wellspring (IKO) 222
+
+            Deck
+            4 Lightning Bolt (M10) 146
+            20 Mountain
+
+            Sideboard
+            2 Duress (M21) 96
+        ").parse().unwrap();
+
+        assert_eq!(DeckList {
+            commanders: vec![entry(1, "Lurrus of the Dream-Den", Some("IKO"), Some("226"))],
+            companion: Some(entry(1, "Jegantha, the Wellspring", Some("IKO"), Some("222"))),
+            mainboard: vec![
+                entry(4, "Lightning Bolt", Some("M10"), Some("146")),
+                entry(20, "Mountain", None, None),
+            ],
+            sideboard: vec![entry(2, "Duress", Some("M21"), Some("96"))],
+            maybeboard: vec![],
+        }, list);
+    }
+
+    #[rstest]
+    fn list_without_headers_should_use_blank_line_as_sideboard_separator() {
+        let list: DeckList = indoc!("
+            4 Lightning Bolt
+            20 Mountain
+
+            2 Duress
+        ").parse().unwrap();
+
+        assert_eq!(2, list.mainboard.len());
+        assert_eq!(vec![entry(2, "Duress", None, None)], list.sideboard);
+    }
+
+    #[rstest]
+    fn list_should_report_line_of_invalid_entry() {
+        let error = "Deck\n4 Lightning Bolt\nnonsense".parse::<DeckList>().unwrap_err();
+
+        assert_eq!(ParseDeckError { line: 3, content: "nonsense".into() }, error);
+    }
+
+    #[rstest]
+    #[case::name("Delver of Secrets", None, None, true)]
+    #[case::name_case_insensitive("delver of secrets", None, None, true)]
+    #[case::set_and_name("Delver of Secrets", Some("ISD"), None, true)]
+    #[case::set_and_number("Other", Some("isd"), Some("51"), true)]
+    #[case::other_name("Opt", None, None, false)]
+    #[case::other_set("Delver of Secrets", Some("mid"), None, false)]
+    fn entry_should_match_card(
+        #[case] name: &str,
+        #[case] set: Option<&str>,
+        #[case] number: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let card = card(json!({
+            "name": "Delver of Secrets // Insectile Aberration",
+            "set": "isd",
+            "collector_number": "51",
+        }));
+
+        assert_eq!(expected, entry(1, name, set, number).matches(&card));
+    }
+
+    #[rstest]
+    fn list_should_chunk_unique_identifiers_in_collection_requests() {
+        let list = DeckList {
+            mainboard: (0..80).map(|i| entry(1, &format!("Card {i}"), None, None)).collect(),
+            sideboard: vec![entry(1, "Card 0", None, None)],
+            ..DeckList::default()
+        };
+
+        let sizes: Vec<usize> = list.collection_requests().iter()
+            .map(|CardCollectionResource::WithIdentifiers(ids)| ids.identifiers.len())
+            .collect();
+
+        assert_eq!(vec![75, 5], sizes);
+    }
+
+    #[rstest]
+    fn list_should_assemble_deck_from_cards() {
+        let list = DeckList {
+            commanders: vec![entry(1, "Commander", None, None)],
+            mainboard: vec![entry(4, "Opt", None, None), entry(1, "Missing", None, None)],
+            sideboard: vec![entry(2, "Opt", None, None)],
+            ..DeckList::default()
+        };
+        let commander = card(json!({ "name": "Commander" }));
+        let opt = card(json!({ "name": "Opt" }));
+
+        let resolved = list.assemble(&[commander.clone(), opt.clone()]);
+
+        assert_eq!(ResolvedDeck {
+            deck: Deck {
+                commanders: vec![commander],
+                mainboard: vec![DeckEntry { quantity: 4, card: opt.clone() }],
+                sideboard: vec![DeckEntry { quantity: 2, card: opt }],
+                ..Deck::default()
+            },
+            not_found: vec![entry(1, "Missing", None, None)],
+        }, resolved);
+    }
+}
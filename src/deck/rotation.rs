@@ -0,0 +1,162 @@
+//! Historical Standard legality
+//!
+//! Current [legalities](crate::resources::cards::Legalities) only tell whether a card is legal today.
+//! To validate decks of past Standard formats, legality on a given date is derived from
+//! the release dates of the printings of a card and a bundled table of Standard rotations.
+//!
+//! A printing was Standard legal on a date when it belongs to an expansion or core set
+//! released on or before the date, and not before the oldest set that remained legal after
+//! the last rotation. Banned cards are not taken into account.
+//!
+//! The table covers the rotations since Theros (2013); earlier dates are not supported.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::deck::rotation::was_standard_legal_on;
+//! use scryfall_sdk_rust::resources::cards::Card;
+//! use scryfall_sdk_rust::time::{Date, Month};
+//!
+//! # fn example(printings: Vec<Card>) {
+//! let date = Date::from_calendar_date(2019, Month::June, 1).unwrap();
+//!
+//! if was_standard_legal_on(&printings, date) == Some(true) {
+//!     println!("{} was legal in Standard", printings[0].name);
+//! }
+//! # }
+//! ```
+
+use time::{Date, Month};
+use crate::resources::cards::Card;
+
+/// A Standard rotation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rotation {
+    /// The code of the set whose release triggered the rotation
+    pub set: &'static str,
+
+    /// The date of the rotation
+    pub date: Date,
+
+    /// The release date of the oldest set still legal after the rotation
+    pub oldest_legal: Date,
+}
+
+/// A date as (year, month, day)
+type CalendarDate = (i32, u8, u8);
+
+/// Rotations as (set code, rotation date, release date of the oldest legal set)
+const ROTATIONS: &[(&str, CalendarDate, CalendarDate)] = &[
+    ("ths", (2013, 9, 27), (2012, 10, 5)),
+    ("ktk", (2014, 9, 26), (2013, 9, 27)),
+    ("bfz", (2015, 10, 2), (2014, 9, 26)),
+    ("soi", (2016, 4, 8), (2015, 3, 27)),
+    ("kld", (2016, 9, 30), (2015, 10, 2)),
+    ("xln", (2017, 9, 29), (2016, 9, 30)),
+    ("grn", (2018, 10, 5), (2017, 9, 29)),
+    ("eld", (2019, 10, 4), (2018, 10, 5)),
+    ("znr", (2020, 9, 25), (2019, 10, 4)),
+    ("mid", (2021, 9, 24), (2020, 9, 25)),
+    ("dmu", (2022, 9, 9), (2021, 9, 24)),
+    ("woe", (2023, 9, 8), (2022, 9, 9)),
+    ("tdm", (2025, 4, 11), (2023, 9, 8)),
+];
+
+/// Set types whose cards enter Standard
+const STANDARD_SET_TYPES: &[&str] = &["core", "expansion"];
+
+/// Gets the bundled Standard rotations (oldest first)
+pub fn standard_rotations() -> Vec<Rotation> {
+    ROTATIONS.iter()
+        .filter_map(|(set, date, oldest)| Some(Rotation { set, date: date_of(*date)?, oldest_legal: date_of(*oldest)? }))
+        .collect()
+}
+
+/// Gets the rotation in effect on a date (if covered by the table)
+pub fn rotation_on(date: Date) -> Option<Rotation> {
+    standard_rotations().into_iter()
+        .take_while(|rotation| rotation.date <= date)
+        .last()
+}
+
+/// Checks whether a printing was Standard legal on a date (`None` if the date is not covered)
+pub fn printing_standard_legal_on(card: &Card, date: Date) -> Option<bool> {
+    let rotation = rotation_on(date)?;
+
+    Some(STANDARD_SET_TYPES.contains(&card.set_type.as_str())
+        && card.released_at <= date
+        && card.released_at >= rotation.oldest_legal)
+}
+
+/// Checks whether a card was Standard legal on a date, given all its printings
+///
+/// A card is legal when any of its printings is (e.g. a reprint in a core set).
+/// Returns `None` if the date is not covered by the rotation table.
+pub fn was_standard_legal_on(printings: &[Card], date: Date) -> Option<bool> {
+    rotation_on(date)?;
+
+    Some(printings.iter().any(|card| printing_standard_legal_on(card, date) == Some(true)))
+}
+
+fn date_of((year, month, day): CalendarDate) -> Option<Date> {
+    Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use rstest::rstest;
+    use serde_json::json;
+    use time::macros::date;
+
+    #[rstest]
+    fn rotations_should_be_ordered() {
+        let rotations = standard_rotations();
+
+        assert_eq!(ROTATIONS.len(), rotations.len());
+        assert!(rotations.windows(2).all(|w| w[0].date < w[1].date && w[0].oldest_legal < w[1].oldest_legal));
+    }
+
+    #[rstest]
+    #[case::legal(date!(2019 - 06 - 01), Some(true))]
+    #[case::not_released(date!(2018 - 01 - 01), Some(false))]
+    #[case::rotated(date!(2019 - 10 - 04), Some(false))]
+    #[case::not_covered(date!(2010 - 01 - 01), None)]
+    fn printing_legality_should_follow_rotations(#[case] date: Date, #[case] expected: Option<bool>) {
+        let card = card(json!({ "set": "dom", "set_type": "expansion", "released_at": "2018-04-27" }));
+
+        assert_eq!(expected, printing_standard_legal_on(&card, date));
+    }
+
+    #[rstest]
+    #[case::znr_before_dmu("znr", "2020-09-25", date!(2022 - 09 - 08), true)]
+    #[case::znr_after_dmu("znr", "2020-09-25", date!(2022 - 10 - 01), false)]
+    #[case::mid_before_woe("mid", "2021-09-24", date!(2023 - 09 - 07), true)]
+    #[case::mid_after_woe("mid", "2021-09-24", date!(2023 - 09 - 08), false)]
+    #[case::dmu_after_woe("dmu", "2022-09-09", date!(2023 - 10 - 01), true)]
+    #[case::mat_before_tdm("mat", "2023-05-12", date!(2025 - 04 - 10), true)]
+    #[case::mat_after_tdm("mat", "2023-05-12", date!(2025 - 04 - 11), false)]
+    #[case::woe_after_tdm("woe", "2023-09-08", date!(2025 - 09 - 01), true)]
+    fn recent_rotations_should_rotate_out_the_oldest_sets(
+        #[case] set: &str,
+        #[case] released_at: &str,
+        #[case] date: Date,
+        #[case] expected: bool,
+    ) {
+        let card = card(json!({ "set": set, "set_type": "expansion", "released_at": released_at }));
+
+        assert_eq!(Some(expected), printing_standard_legal_on(&card, date));
+    }
+
+    #[rstest]
+    fn reprint_should_make_card_legal() {
+        let printings = vec![
+            card(json!({ "set": "m10", "set_type": "core", "released_at": "2009-07-17" })),
+            card(json!({ "set": "m19", "set_type": "core", "released_at": "2018-07-13" })),
+            card(json!({ "set": "sld", "set_type": "box", "released_at": "2020-01-01" })),
+        ];
+
+        assert_eq!(Some(true), was_standard_legal_on(&printings, date!(2019 - 01 - 01)));
+        assert_eq!(Some(false), was_standard_legal_on(&printings, date!(2020 - 01 - 01)));
+    }
+}
@@ -0,0 +1,172 @@
+//! Tabletop Simulator export
+//!
+//! Builds the saved object JSON of a deck for [Tabletop Simulator](https://www.tabletopsimulator.com),
+//! using the card images as faces. The saved object can be placed in the
+//! `Saved Objects` folder of the game, and spawned from the in-game menu.
+//!
+//! The mainboard (with the commanders on top) and the sideboard are exported as separate decks.
+//! Double-faced cards use the image of their back face as the card back.
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::deck::Deck;
+//! use scryfall_sdk_rust::deck::tts::{saved_object, CARD_BACK_URL};
+//!
+//! fn save(deck: &Deck) -> std::io::Result<()> {
+//!     let json = saved_object(deck, CARD_BACK_URL);
+//!     std::fs::write("deck.json", json.to_string())
+//! }
+//! ```
+
+use serde_json::{json, Map, Value};
+use crate::deck::Deck;
+use crate::images::ImageSize;
+use crate::resources::cards::Card;
+
+/// Image of the standard Magic card back
+pub const CARD_BACK_URL: &str = "https://backs.scryfall.io/large/59/2/597b79b3-7d77-4261-871a-60dd17403388.jpg";
+
+/// Builds the Tabletop Simulator saved object of a deck
+///
+/// Cards without images are skipped.
+pub fn saved_object(deck: &Deck, back_url: &str) -> Value {
+    let mainboard = deck.commanders.iter().map(|card| (1, card))
+        .chain(deck.mainboard.iter().map(|entry| (entry.quantity, &entry.card)));
+    let sideboard = deck.sideboard.iter().map(|entry| (entry.quantity, &entry.card));
+
+    let states: Vec<Value> = [pile(mainboard, back_url, 0.0), pile(sideboard, back_url, 3.0)]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    json!({ "ObjectStates": states })
+}
+
+/// Builds a deck object (or a single card object) from cards and their quantities
+fn pile<'a, I>(cards: I, back_url: &str, pos_x: f64) -> Option<Value>
+    where I: Iterator<Item = (u32, &'a Card)>
+{
+    let mut custom_deck = Map::new();
+    let mut contained = vec![];
+    let mut deck_ids = vec![];
+
+    for (quantity, card) in cards {
+        let (face, back) = match faces(card, back_url) {
+            Some(faces) => faces,
+            None => continue,
+        };
+
+        let deck_id = custom_deck.len() + 1;
+        let card_id = deck_id * 100;
+
+        custom_deck.insert(deck_id.to_string(), json!({
+            "FaceURL": face,
+            "BackURL": back,
+            "NumWidth": 1,
+            "NumHeight": 1,
+            "BackIsHidden": true,
+            "UniqueBack": back != back_url,
+        }));
+
+        for _ in 0..quantity {
+            deck_ids.push(card_id);
+            contained.push(json!({
+                "Name": "Card",
+                "Nickname": card.name,
+                "CardID": card_id,
+                "Transform": transform(pos_x),
+            }));
+        }
+    }
+
+    match contained.len() {
+        0 => None,
+        1 => {
+            let mut card = contained.remove(0);
+            card["CustomDeck"] = Value::Object(custom_deck);
+            Some(card)
+        },
+        _ => Some(json!({
+            "Name": "DeckCustom",
+            "ContainedObjects": contained,
+            "DeckIDs": deck_ids,
+            "CustomDeck": custom_deck,
+            "Transform": transform(pos_x),
+        })),
+    }
+}
+
+/// Gets the face and back images of a card
+fn faces<'a>(card: &'a Card, back_url: &'a str) -> Option<(&'a str, &'a str)> {
+    let face = ImageSize::Large.uri(card.face_image_uris(0)?).as_str();
+    let back = card.face_image_uris(1)
+        .map(|uris| ImageSize::Large.uri(uris).as_str())
+        .unwrap_or(back_url);
+
+    Some((face, back))
+}
+
+fn transform(pos_x: f64) -> Value {
+    json!({
+        "posX": pos_x, "posY": 1.0, "posZ": 0.0,
+        "rotX": 0.0, "rotY": 180.0, "rotZ": 180.0,
+        "scaleX": 1.0, "scaleY": 1.0, "scaleZ": 1.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use crate::deck::DeckEntry;
+    use crate::fixtures::card;
+
+    fn image_uris(name: &str) -> Value {
+        json!({
+            "small": format!("http://img/{name}"),
+            "normal": format!("http://img/{name}"),
+            "large": format!("http://img/{name}"),
+            "png": format!("http://img/{name}"),
+            "art_crop": format!("http://img/{name}"),
+            "border_crop": format!("http://img/{name}"),
+        })
+    }
+
+    #[rstest]
+    fn deck_should_be_exported_with_faces_and_backs() {
+        let bolt = card(json!({ "name": "Lightning Bolt", "image_uris": image_uris("bolt") }));
+        let delver = card(json!({
+            "name": "Delver of Secrets",
+            "card_faces": [
+                card_face(image_uris("delver")),
+                card_face(image_uris("aberration")),
+            ]
+        }));
+        let deck = Deck {
+            mainboard: vec![
+                DeckEntry { quantity: 2, card: bolt },
+                DeckEntry { quantity: 1, card: delver },
+            ],
+            sideboard: vec![DeckEntry { quantity: 1, card: card(json!({ "name": "No Image" })) }],
+            ..Deck::default()
+        };
+
+        let object = saved_object(&deck, "http://back");
+        let states = object["ObjectStates"].as_array().unwrap();
+
+        assert_eq!(1, states.len());
+        assert_eq!(json!([100, 100, 200]), states[0]["DeckIDs"]);
+        assert_eq!(json!("http://back"), states[0]["CustomDeck"]["1"]["BackURL"]);
+        assert_eq!(json!("http://img/aberration"), states[0]["CustomDeck"]["2"]["BackURL"]);
+        assert_eq!(json!(true), states[0]["CustomDeck"]["2"]["UniqueBack"]);
+    }
+
+    fn card_face(image_uris: Value) -> Value {
+        json!({
+            "object": "card_face",
+            "name": "Face",
+            "mana_cost": "",
+            "image_uris": image_uris,
+        })
+    }
+}
@@ -0,0 +1,68 @@
+//! XMage export
+//!
+//! Exports decks as [XMage](https://xmage.today) `.dck` files, e.g.:
+//!
+//! ```text
+//! NAME:Mono Red
+//! 4 [M10:146] Lightning Bolt
+//! 20 [M10:230] Mountain
+//! SB: 2 [ALA:115] Smash to Smithereens
+//! ```
+
+use std::fmt::Write;
+use crate::deck::list::{DeckList, DeckListEntry};
+use crate::deck::Deck;
+
+/// Exports a deck as an XMage `.dck` file
+///
+/// XMage keeps commanders (and the companion) in the sideboard.
+/// Multi-faced cards are exported by their front face name (see [forge::entry](super::forge::entry)).
+pub fn export(deck: &Deck, name: &str) -> String {
+    let list = DeckList::from_deck(deck, super::forge::entry);
+    let sideboard = list.commanders.iter()
+        .chain(&list.companion)
+        .chain(&list.sideboard);
+
+    let mut dck = format!("NAME:{name}\n");
+    for entry in &list.mainboard {
+        let _ = writeln!(dck, "{}", line(entry));
+    }
+    for entry in sideboard {
+        let _ = writeln!(dck, "SB: {}", line(entry));
+    }
+
+    dck
+}
+
+fn line(entry: &DeckListEntry) -> String {
+    format!(
+        "{} [{}:{}] {}",
+        entry.quantity,
+        entry.set.as_deref().unwrap_or_default().to_uppercase(),
+        entry.collector_number.as_deref().unwrap_or_default(),
+        entry.name,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::deck::DeckEntry;
+    use crate::fixtures::card;
+
+    #[rstest]
+    fn deck_should_be_exported_for_xmage() {
+        let deck = Deck {
+            commanders: vec![card(json!({ "name": "Krenko, Mob Boss", "set": "m19", "collector_number": "143" }))],
+            mainboard: vec![DeckEntry { quantity: 4, card: card(json!({ "name": "Lightning Bolt", "set": "m10", "collector_number": "146" })) }],
+            ..Deck::default()
+        };
+
+        assert_eq!(
+            "NAME:Test\n4 [M10:146] Lightning Bolt\nSB: 1 [M19:143] Krenko, Mob Boss\n",
+            export(&deck, "Test")
+        );
+    }
+}
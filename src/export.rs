@@ -0,0 +1,231 @@
+//! Reproducible multi-page search exports
+//!
+//! Results can shift between the pages of a long search (e.g. while Scryfall updates
+//! its data), so an export is only reproducible if it is known which pages were fetched.
+//! [search_export](crate::Scryfall::search_export) follows the `next_page` links of a search
+//! and returns the cards along with an [ExportManifest](ExportManifest) recording:
+//! - the normalized search and the total number of results of the first page
+//! - the URLs of the pages actually followed (in order)
+//! - the `updated_at` timestamp of the `default_cards` bulk data (with the **bulk** feature),
+//!   identifying the version of the data the export was taken from
+//!
+//! Manifests can be serialized next to the exported data. The recorded pages can then be
+//! fetched again (exactly as followed) with [replay_export](crate::Scryfall::replay_export).
+//!
+//! Long exports can be run page by page, saving the exported cards and the manifest after
+//! each page: an export is started with [start_export](crate::Scryfall::start_export), and
+//! its pages are fetched with [export_next_page](crate::Scryfall::export_next_page).
+//! After a crash, the export continues from the saved manifest with
+//! [resume_export](crate::Scryfall::resume_export) (or [Export::resume](Export::resume)
+//! for page by page runs), from the stored `next_page` URL. The ids of the exported cards
+//! are kept in the manifest, so that cards shifting onto a later page are not exported twice.
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::Scryfall;
+//! use scryfall_sdk_rust::resources::cards::SearchQueryParams;
+//!
+//! # async fn example() -> Result<(), scryfall_sdk_rust::resources::errors::ErrorBody> {
+//! let client = Scryfall::default();
+//! let export = client.search_export(SearchQueryParams::with_q("t:goblin")).await?;
+//!
+//! println!("{} cards from {} pages", export.cards.len(), export.manifest.pages.len());
+//! println!("{}", serde_json::to_string(&export.manifest).unwrap());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeSet;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use time::serde::iso8601;
+use url::Url;
+use uuid::Uuid;
+use crate::resources::{HttpResource, ResourceKind};
+use crate::resources::cards::{Card, CardPage};
+use crate::resources::validation::Validate;
+
+/// Record of the pages followed by an [export](self)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExportManifest {
+    /// The normalized search (see [SearchQueryParams](crate::resources::cards::SearchQueryParams))
+    pub query: String,
+
+    /// The `updated_at` timestamp of the `default_cards` bulk data when the export started
+    #[serde(default, with = "iso8601::option")]
+    pub bulk_updated_at: Option<OffsetDateTime>,
+
+    /// The total number of results reported by the first page
+    pub total_cards: i64,
+
+    /// The URLs of the pages followed, in order
+    pub pages: Vec<Url>,
+
+    /// The URL of the next page to follow (`None` once the last page is fetched)
+    pub next_page: Option<Url>,
+
+    /// The number of exported cards
+    pub cards: usize,
+
+    /// The ids of the exported cards
+    #[serde(default)]
+    pub seen: BTreeSet<Uuid>,
+}
+
+impl ExportManifest {
+    /// Creates the manifest of an export that has not fetched any page yet
+    ///
+    /// The export starts from the `first_page` URL.
+    pub fn new(query: impl Into<String>, first_page: Url, bulk_updated_at: Option<OffsetDateTime>) -> Self {
+        ExportManifest {
+            query: query.into(),
+            bulk_updated_at,
+            total_cards: 0,
+            pages: vec![],
+            next_page: Some(first_page),
+            cards: 0,
+            seen: BTreeSet::new(),
+        }
+    }
+
+    /// Checks if all pages of the search were fetched
+    pub fn is_complete(&self) -> bool {
+        !self.pages.is_empty() && self.next_page.is_none()
+    }
+}
+
+/// Cards of a search export, along with the [manifest](ExportManifest) of the export
+#[derive(Clone, Debug, PartialEq)]
+pub struct Export {
+    /// The exported cards (in the order of the pages)
+    pub cards: Vec<Card>,
+
+    /// The record of the pages followed
+    pub manifest: ExportManifest,
+}
+
+impl Export {
+    /// Creates an export without cards
+    pub fn new(manifest: ExportManifest) -> Self {
+        Export { cards: vec![], manifest }
+    }
+
+    /// Continues an interrupted export from its saved manifest
+    ///
+    /// The cards exported before the interruption are not kept: only the cards
+    /// of the pages fetched from now on are added to the export.
+    pub fn resume(manifest: ExportManifest) -> Self {
+        Export::new(manifest)
+    }
+
+    /// Adds a fetched page to the export, skipping the cards already exported
+    pub(crate) fn record(&mut self, url: Url, page: CardPage) {
+        if self.manifest.pages.is_empty() {
+            self.manifest.total_cards = page.total_cards;
+        }
+
+        self.manifest.pages.push(url);
+        self.manifest.next_page = page.next_page.filter(|_| page.has_more);
+
+        for card in page.data {
+            if self.manifest.seen.insert(card.id) {
+                self.manifest.cards += 1;
+                self.cards.push(card);
+            }
+        }
+    }
+}
+
+/// A page of search results, by the path of its URL (relative to the base url of the client)
+pub(crate) struct PageResource(pub(crate) String);
+
+impl HttpResource<CardPage> for PageResource {
+    fn path(&self) -> String {
+        self.0.clone()
+    }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::List)
+    }
+
+    fn validate(&self, model: &CardPage) -> Vec<String> {
+        model.violations()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use rstest::rstest;
+    use serde_json::json;
+    use time::macros::datetime;
+
+    fn page(ids: &[u128], next_page: Option<&str>) -> CardPage {
+        CardPage {
+            data: ids.iter().map(|id| card(json!({ "id": Uuid::from_u128(*id) }))).collect(),
+            has_more: next_page.is_some(),
+            kind: ResourceKind::List,
+            next_page: next_page.map(|url| url.parse().unwrap()),
+            total_cards: 3,
+        }
+    }
+
+    fn url(page: u32) -> Url {
+        format!("https://api.scryfall.com/cards/search?page={page}&q=t%3Agoblin").parse().unwrap()
+    }
+
+    fn manifest() -> ExportManifest {
+        ExportManifest::new("q=t:goblin", url(1), None)
+    }
+
+    #[rstest]
+    fn pages_should_be_recorded() {
+        let mut export = Export::new(manifest());
+        assert!(!export.manifest.is_complete());
+
+        export.record(url(1), page(&[1, 2], Some(url(2).as_str())));
+        assert_eq!((Some(url(2)), false), (export.manifest.next_page.clone(), export.manifest.is_complete()));
+
+        export.record(url(2), page(&[3], None));
+        assert_eq!(vec![url(1), url(2)], export.manifest.pages);
+        assert_eq!((3, 3, 3), (export.cards.len(), export.manifest.cards, export.manifest.total_cards));
+        assert!(export.manifest.is_complete());
+    }
+
+    #[rstest]
+    fn resumed_exports_should_skip_exported_cards() {
+        let mut export = Export::new(manifest());
+        export.record(url(1), page(&[1, 2], Some(url(2).as_str())));
+
+        let json = serde_json::to_string(&export.manifest).unwrap();
+        let mut resumed = Export::resume(serde_json::from_str(&json).unwrap());
+        resumed.record(url(2), page(&[2, 3], None));
+
+        assert_eq!(vec![Uuid::from_u128(3)], resumed.cards.iter().map(|card| card.id).collect::<Vec<_>>());
+        assert_eq!((3, 3), (resumed.manifest.cards, resumed.manifest.seen.len()));
+        assert_eq!(vec![url(1), url(2)], resumed.manifest.pages);
+    }
+
+    #[rstest]
+    fn next_pages_should_not_be_followed_without_more_results() {
+        let mut export = Export::new(manifest());
+        let mut last = page(&[1], Some("http://some.url"));
+        last.has_more = false;
+
+        export.record(url(1), last);
+
+        assert!(export.manifest.is_complete());
+    }
+
+    #[rstest]
+    fn manifests_should_round_trip() {
+        let mut manifest = ExportManifest::new("q=t:goblin", url(2), Some(datetime!(2024-05-01 09:02:10 UTC)));
+        manifest.pages.push(url(1));
+        manifest.seen.insert(Uuid::from_u128(1));
+
+        let json = serde_json::to_string(&manifest).unwrap();
+
+        assert_eq!(manifest, serde_json::from_str(&json).unwrap());
+    }
+}
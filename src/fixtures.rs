@@ -0,0 +1,127 @@
+//! Shared model fixtures for unit tests
+//!
+//! The card fixtures are also used by integration tests and downstream applications,
+//! through the [testing](crate::testing) module (**test-util** feature).
+
+use serde_json::Value;
+#[cfg(feature = "cards")]
+use crate::resources::cards::Card;
+#[cfg(all(test, feature = "sets"))]
+use crate::resources::card_sets::CardSet;
+
+#[cfg(test)]
+pub mod compact;
+
+/// Merges the top-level fields of the overrides into a base json
+fn merge(mut base: Value, overrides: Value) -> Value {
+    if let (Some(base), Value::Object(overrides)) = (base.as_object_mut(), overrides) {
+        base.extend(overrides);
+    }
+
+    base
+}
+
+/// Builds a [Card](Card) from a minimal valid card json,
+/// overriding its top-level fields with the provided ones.
+#[cfg(feature = "cards")]
+pub fn card(overrides: Value) -> Card {
+    serde_json::from_value(card_json(overrides)).expect("Expected a valid card fixture")
+}
+
+/// Builds a minimal valid card json, overriding its top-level fields with the provided ones
+#[cfg(feature = "cards")]
+pub fn card_json(overrides: Value) -> Value {
+    let base: Value = serde_json::from_str(r#"{
+        "object": "card",
+        "id": "f295b713-1d6a-43fd-910d-fb35414bf58a",
+        "oracle_id": "7bc3f92f-68a2-4934-afc4-89f6d0e8cf98",
+        "name": "Test Card",
+        "lang": "en",
+        "released_at": "2022-06-10",
+        "uri": "http://some.url",
+        "scryfall_uri": "http://some.url",
+        "layout": "normal",
+        "highres_image": false,
+        "image_status": "highres_scan",
+        "cmc": 1,
+        "type_line": "Instant",
+        "color_identity": [],
+        "keywords": [],
+        "legalities": {
+            "standard": "legal",
+            "future": "legal",
+            "historic": "legal",
+            "gladiator": "legal",
+            "pioneer": "legal",
+            "explorer": "legal",
+            "modern": "legal",
+            "legacy": "legal",
+            "pauper": "legal",
+            "vintage": "legal",
+            "penny": "legal",
+            "commander": "legal",
+            "brawl": "legal",
+            "historicbrawl": "legal",
+            "alchemy": "legal",
+            "paupercommander": "legal",
+            "duel": "legal",
+            "oldschool": "legal",
+            "premodern": "legal"
+        },
+        "games": ["paper"],
+        "reserved": false,
+        "foil": false,
+        "nonfoil": true,
+        "finishes": ["nonfoil"],
+        "oversized": false,
+        "promo": false,
+        "reprint": false,
+        "variation": false,
+        "set_id": "5e4c3fe8-fd57-4b20-ad56-c03790a16cea",
+        "set": "tst",
+        "set_name": "Test Set",
+        "set_type": "expansion",
+        "set_uri": "http://some.url",
+        "set_search_uri": "http://some.url",
+        "scryfall_set_uri": "http://some.url",
+        "rulings_uri": "http://some.url",
+        "prints_search_uri": "http://some.url",
+        "collector_number": "1",
+        "digital": false,
+        "rarity": "common",
+        "artist_ids": [],
+        "border_color": "black",
+        "frame": "2015",
+        "full_art": false,
+        "textless": false,
+        "booster": true,
+        "story_spotlight": false,
+        "prices": {}
+    }"#).expect("Expected a valid json");
+
+    merge(base, overrides)
+}
+
+/// Builds a [CardSet](CardSet) from a minimal valid set json,
+/// overriding its top-level fields with the provided ones.
+#[cfg(all(test, feature = "sets"))]
+pub fn card_set(overrides: Value) -> CardSet {
+    let base = serde_json::json!({
+        "object": "set",
+        "id": "5e4c3fe8-fd57-4b20-ad56-c03790a16cea",
+        "code": "tst",
+        "name": "Test Set",
+        "uri": "http://some.url",
+        "scryfall_uri": "http://some.url",
+        "search_uri": "http://some.url",
+        "released_at": "2022-09-09",
+        "set_type": "expansion",
+        "card_count": 1,
+        "digital": false,
+        "nonfoil_only": false,
+        "foil_only": false,
+        "icon_svg_uri": "http://some.url"
+    });
+
+    serde_json::from_value(merge(base, overrides)).expect("Expected a valid set fixture")
+}
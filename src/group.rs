@@ -0,0 +1,268 @@
+//! Grouping of cards into sections
+//!
+//! Helpers for rendering search results in sections, like the Scryfall website does.
+//! Groups are returned as ordered lists of `(key, cards)` pairs; the cards of each group
+//! keep their original order (e.g. the order of the search results).
+//!
+//! - [group_by_set](group_by_set): by set code, in order of first appearance
+//! - [group_by_color_identity](group_by_color_identity): by color identity, ordered white, blue,
+//!   black, red, green, multicolored and colorless
+//! - [group_by_type](group_by_type): by main card type, in [TYPES](TYPES) order
+//! - [group_by_language](group_by_language): by oracle id, with one entry per language
+//!   (for searches with `include_multilingual`), as [MultilingualGroup](MultilingualGroup)s
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::group::group_by_type;
+//! use scryfall_sdk_rust::resources::cards::Card;
+//!
+//! # fn example(cards: Vec<Card>) {
+//! for (card_type, cards) in group_by_type(&cards) {
+//!     println!("{card_type} ({})", cards.len());
+//! }
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use uuid::Uuid;
+use crate::resources::card_symbols::ColorSymbol;
+use crate::resources::cards::Card;
+use crate::sort::colors_rank;
+
+/// Sections of [group_by_type](group_by_type) (in order)
+pub const TYPES: &[&str] = &[
+    "Creature", "Planeswalker", "Battle", "Instant", "Sorcery",
+    "Artifact", "Enchantment", "Land", "Other",
+];
+
+/// Groups cards by set code (in order of first appearance)
+pub fn group_by_set(cards: &[Card]) -> Vec<(String, Vec<&Card>)> {
+    group_by(cards, |card| card.set.clone())
+}
+
+/// Groups cards by color identity (colors in WUBRG order)
+pub fn group_by_color_identity(cards: &[Card]) -> Vec<(Vec<ColorSymbol>, Vec<&Card>)> {
+    let mut groups = group_by(cards, |card| {
+        let mut colors = card.color_identity.clone();
+        colors.sort_by_key(|color| colors_rank(&[*color]));
+        colors.dedup();
+        colors
+    });
+
+    groups.sort_by_key(|(colors, _)| colors_rank(colors));
+    groups
+}
+
+/// Groups cards by main card type (of their front face)
+///
+/// Cards with several types are grouped by the first of creature, planeswalker, battle
+/// and land, then by their last type (e.g. `Artifact Land` is a land, `Enchantment Creature`
+/// a creature). Cards without any of [TYPES](TYPES) (e.g. tokens of other types) are grouped as `Other`.
+pub fn group_by_type(cards: &[Card]) -> Vec<(&'static str, Vec<&Card>)> {
+    let mut groups = group_by(cards, |card| main_type(&card.type_line));
+
+    groups.sort_by_key(|(card_type, _)| TYPES.iter().position(|t| t == card_type));
+    groups
+}
+
+/// A gameplay card in several languages
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultilingualGroup<'a> {
+    /// The oracle id of the card
+    pub oracle_id: Uuid,
+
+    /// The first card of each language, in order of first appearance
+    pub entries: Vec<&'a Card>,
+}
+
+impl<'a> MultilingualGroup<'a> {
+    /// Gets the oracle (English) name of the card
+    pub fn name(&self) -> &'a str {
+        self.entries.first().map(|card| card.name.as_str()).unwrap_or_default()
+    }
+
+    /// Gets the languages of the card (in order of first appearance)
+    pub fn languages(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.entries.iter().map(|card| card.lang.as_str())
+    }
+
+    /// Gets the card in a language (e.g. `ja`)
+    pub fn get(&self, lang: &str) -> Option<&'a Card> {
+        self.entries.iter().copied().find(|card| card.lang == lang)
+    }
+
+    /// Gets the card in the first available of the preferred languages,
+    /// falling back to English, then to the first entry
+    pub fn localized(&self, preferred: &[&str]) -> Option<&'a Card> {
+        preferred.iter().chain(&["en"])
+            .find_map(|lang| self.get(lang))
+            .or_else(|| self.entries.first().copied())
+    }
+}
+
+/// Groups cards by oracle id, keeping the first card of each language
+///
+/// Searches with `include_multilingual` return the same card once per language:
+/// groups (in order of first appearance) have one entry per language instead.
+/// Reversible cards (without a top-level oracle id) are grouped by the oracle id of their first face.
+pub fn group_by_language(cards: &[Card]) -> Vec<MultilingualGroup<'_>> {
+    group_by(cards, Card::effective_oracle_id).into_iter()
+        .map(|(oracle_id, cards)| {
+            let mut entries: Vec<&Card> = vec![];
+            for card in cards {
+                if !entries.iter().any(|entry| entry.lang == card.lang) {
+                    entries.push(card);
+                }
+            }
+
+            MultilingualGroup { oracle_id, entries }
+        })
+        .collect()
+}
+
+/// Groups cards by a key, in order of first appearance
+fn group_by<K, F>(cards: &[Card], key: F) -> Vec<(K, Vec<&Card>)>
+    where K: Clone + Eq + Hash,
+          F: Fn(&Card) -> K
+{
+    let mut order = vec![];
+    let mut groups: HashMap<K, Vec<&Card>> = HashMap::new();
+
+    for card in cards {
+        let key = key(card);
+        groups.entry(key.clone()).or_insert_with(|| {
+            order.push(key);
+            vec![]
+        }).push(card);
+    }
+
+    order.into_iter()
+        .filter_map(|key| groups.remove(&key).map(|cards| (key, cards)))
+        .collect()
+}
+
+/// Gets the section of a type line
+fn main_type(type_line: &str) -> &'static str {
+    let types: Vec<&str> = type_line
+        .split(" // ").next().unwrap_or_default()
+        .split(" — ").next().unwrap_or_default()
+        .split_whitespace()
+        .collect();
+
+    ["Creature", "Planeswalker", "Battle", "Land"].iter()
+        .find(|t| types.contains(t))
+        .or_else(|| types.iter().rev().find_map(|t| TYPES.iter().find(|known| *known == t)))
+        .copied()
+        .unwrap_or("Other")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use rstest::rstest;
+    use serde_json::json;
+
+    fn names<K>(groups: Vec<(K, Vec<&Card>)>) -> Vec<(K, Vec<&str>)> {
+        groups.into_iter()
+            .map(|(key, cards)| (key, cards.iter().map(|card| card.name.as_str()).collect()))
+            .collect()
+    }
+
+    #[rstest]
+    fn cards_should_be_grouped_by_set() {
+        let cards = vec![
+            card(json!({ "name": "A", "set": "m21" })),
+            card(json!({ "name": "B", "set": "dom" })),
+            card(json!({ "name": "C", "set": "m21" })),
+        ];
+
+        assert_eq!(
+            vec![("m21".to_string(), vec!["A", "C"]), ("dom".to_string(), vec!["B"])],
+            names(group_by_set(&cards))
+        );
+    }
+
+    #[rstest]
+    fn cards_should_be_grouped_by_color_identity() {
+        let cards = vec![
+            card(json!({ "name": "Colorless", "color_identity": [] })),
+            card(json!({ "name": "Boros", "color_identity": ["R", "W"] })),
+            card(json!({ "name": "Red", "color_identity": ["R"] })),
+            card(json!({ "name": "Blue", "color_identity": ["U"] })),
+            card(json!({ "name": "Also Boros", "color_identity": ["W", "R"] })),
+        ];
+
+        assert_eq!(
+            vec![
+                (vec![ColorSymbol::U], vec!["Blue"]),
+                (vec![ColorSymbol::R], vec!["Red"]),
+                (vec![ColorSymbol::W, ColorSymbol::R], vec!["Boros", "Also Boros"]),
+                (vec![], vec!["Colorless"]),
+            ],
+            names(group_by_color_identity(&cards))
+        );
+    }
+
+    #[rstest]
+    fn cards_should_be_grouped_by_language() {
+        let other = "00000000-0000-0000-0000-000000000009";
+        let cards = vec![
+            card(json!({ "id": "00000000-0000-0000-0000-000000000001", "name": "Opt", "lang": "en" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000002", "name": "Opt", "lang": "ja", "printed_name": "選択" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000003", "name": "Shock", "lang": "de", "oracle_id": other })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000004", "name": "Opt", "lang": "ja" })),
+        ];
+
+        let groups = group_by_language(&cards);
+        fn languages<'a>(group: &MultilingualGroup<'a>) -> Vec<&'a str> {
+            group.languages().collect()
+        }
+
+        assert_eq!(2, groups.len());
+        assert_eq!(("Opt", vec!["en", "ja"]), (groups[0].name(), languages(&groups[0])));
+        assert_eq!(Some("選択"), groups[0].get("ja").map(Card::localized_name));
+        assert_eq!((other.to_string(), vec!["de"]), (groups[1].oracle_id.to_string(), languages(&groups[1])));
+    }
+
+    #[rstest]
+    #[case::preferred(&["fr", "ja"], "ja")]
+    #[case::english(&["fr"], "en")]
+    #[case::none(&[], "en")]
+    fn localized_cards_should_fall_back_to_english(#[case] preferred: &[&str], #[case] expected: &str) {
+        let cards = vec![
+            card(json!({ "id": "00000000-0000-0000-0000-000000000001", "lang": "de" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000002", "lang": "en" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000003", "lang": "ja" })),
+        ];
+
+        let groups = group_by_language(&cards);
+
+        assert_eq!(Some(expected), groups[0].localized(preferred).map(|card| card.lang.as_str()));
+    }
+
+    #[rstest]
+    #[case::creature("Enchantment Creature — God", "Creature")]
+    #[case::land("Artifact Land", "Land")]
+    #[case::artifact("Legendary Artifact — Equipment", "Artifact")]
+    #[case::split("Instant // Sorcery", "Instant")]
+    #[case::other("Scheme", "Other")]
+    fn main_type_should_be_found(#[case] type_line: &str, #[case] expected: &str) {
+        assert_eq!(expected, main_type(type_line));
+    }
+
+    #[rstest]
+    fn cards_should_be_grouped_by_type() {
+        let cards = vec![
+            card(json!({ "name": "Island", "type_line": "Basic Land — Island" })),
+            card(json!({ "name": "Opt", "type_line": "Instant" })),
+            card(json!({ "name": "Bear", "type_line": "Creature — Bear" })),
+        ];
+
+        assert_eq!(
+            vec![("Creature", vec!["Bear"]), ("Instant", vec!["Opt"]), ("Land", vec!["Island"])],
+            names(group_by_type(&cards))
+        );
+    }
+}
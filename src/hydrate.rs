@@ -0,0 +1,240 @@
+//! Batched follow-up lookups for cards
+//!
+//! Applications displaying cards often need more than the card objects: their sets,
+//! their rulings and their related cards (tokens, meld parts, combo pieces).
+//! [hydrate](crate::Scryfall::hydrate) gathers these lookups for a slice of cards,
+//! without duplicates, and runs them concurrently through the client (so that they share
+//! its cache and rate limiter):
+//! - sets are fetched once per set code (with the **sets** feature)
+//! - rulings are fetched once per Oracle id (rulings are shared by all printings)
+//! - related cards are fetched in batches with the collection endpoint
+//!   (related cards of the slice itself are not fetched again)
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::Scryfall;
+//! use scryfall_sdk_rust::hydrate::HydrateOptions;
+//! use scryfall_sdk_rust::resources::cards::Card;
+//!
+//! # async fn example(client: Scryfall<'_>, cards: Vec<Card>) -> Result<(), scryfall_sdk_rust::resources::errors::ErrorBody> {
+//! let hydrated = client.hydrate(&cards, HydrateOptions { sets: true, rulings: true, parts: true }).await?;
+//!
+//! for card in &cards {
+//!     println!("{}: {} rulings, {} related cards", card.name, hydrated.rulings(card).len(), hydrated.parts(card).len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+use crate::ids::{collection_requests, CardId};
+use crate::resources::cards::{Card, CardCollectionResource};
+#[cfg(feature = "sets")]
+use crate::resources::card_sets::CardSet;
+use crate::resources::rulings::Ruling;
+
+/// Maximum number of lookups running at a time
+pub(crate) const CONCURRENCY: usize = 4;
+
+/// Lookups to run for the cards passed to [hydrate](crate::Scryfall::hydrate)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HydrateOptions {
+    /// Fetches the sets of the cards (ignored without the **sets** feature)
+    pub sets: bool,
+
+    /// Fetches the rulings of the cards
+    pub rulings: bool,
+
+    /// Fetches the related cards (`all_parts`) of the cards
+    pub parts: bool,
+}
+
+impl HydrateOptions {
+    /// Runs all lookups
+    pub fn all() -> Self {
+        HydrateOptions { sets: true, rulings: true, parts: true }
+    }
+}
+
+/// Results of the lookups of [hydrate](crate::Scryfall::hydrate)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hydrated {
+    /// Sets by (lowercase) set code
+    #[cfg(feature = "sets")]
+    pub sets: HashMap<String, CardSet>,
+
+    /// Rulings by Oracle id
+    pub rulings: HashMap<Uuid, Vec<Ruling>>,
+
+    /// Related cards by Scryfall id
+    pub parts: HashMap<Uuid, Card>,
+}
+
+impl Hydrated {
+    /// Gets the set of a card (if fetched)
+    #[cfg(feature = "sets")]
+    pub fn set(&self, card: &Card) -> Option<&CardSet> {
+        self.sets.get(&card.set.to_lowercase())
+    }
+
+    /// Gets the rulings of a card (empty if not fetched)
+    pub fn rulings(&self, card: &Card) -> &[Ruling] {
+        self.rulings.get(&card.effective_oracle_id())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Gets the related cards of a card (other than the card itself), in `all_parts` order
+    pub fn parts(&self, card: &Card) -> Vec<&Card> {
+        card.all_parts.iter()
+            .flatten()
+            .filter(|part| part.id != card.id)
+            .filter_map(|part| self.parts.get(&part.id))
+            .collect()
+    }
+
+    /// Adds the result of a lookup
+    pub(crate) fn add(&mut self, found: Found) {
+        match found {
+            #[cfg(feature = "sets")]
+            Found::Set(code, set) => if let Some(set) = set {
+                self.sets.insert(code, *set);
+            },
+            Found::Rulings(oracle_id, rulings) => { self.rulings.insert(oracle_id, rulings); },
+            Found::Parts(cards) => self.parts.extend(cards.into_iter().map(|card| (card.id, card))),
+        }
+    }
+}
+
+/// A lookup to run
+pub(crate) enum Lookup {
+    /// Fetches a set by code
+    #[cfg(feature = "sets")]
+    Set(String),
+
+    /// Fetches the rulings of an Oracle id, by the id of one of its printings
+    Rulings { oracle_id: Uuid, card_id: Uuid },
+
+    /// Fetches a batch of related cards
+    Parts(CardCollectionResource),
+}
+
+/// Result of a [Lookup](Lookup)
+pub(crate) enum Found {
+    #[cfg(feature = "sets")]
+    Set(String, Option<Box<CardSet>>),
+    Rulings(Uuid, Vec<Ruling>),
+    Parts(Vec<Card>),
+}
+
+/// Plans the lookups for the cards, without duplicates
+///
+/// Related cards found in the slice are added to the result right away.
+pub(crate) fn plan(cards: &[Card], options: HydrateOptions, hydrated: &mut Hydrated) -> Vec<Lookup> {
+    let mut lookups = vec![];
+
+    #[cfg(feature = "sets")]
+    if options.sets {
+        let codes: BTreeSet<String> = cards.iter().map(|card| card.set.to_lowercase()).collect();
+        lookups.extend(codes.into_iter().map(Lookup::Set));
+    }
+
+    if options.rulings {
+        let mut oracle_ids = BTreeSet::new();
+        for card in cards {
+            let oracle_id = card.effective_oracle_id();
+            if !oracle_id.is_nil() && oracle_ids.insert(oracle_id) {
+                lookups.push(Lookup::Rulings { oracle_id, card_id: card.id });
+            }
+        }
+    }
+
+    if options.parts {
+        let known: HashMap<Uuid, &Card> = cards.iter().map(|card| (card.id, card)).collect();
+        let related: BTreeSet<Uuid> = cards.iter()
+            .flat_map(|card| card.all_parts.iter().flatten().filter(move |part| part.id != card.id))
+            .map(|part| part.id)
+            .collect();
+
+        let mut missing = vec![];
+        for id in related {
+            match known.get(&id) {
+                Some(card) => { hydrated.parts.insert(id, (*card).clone()); },
+                None => missing.push(CardId::Scryfall(id)),
+            }
+        }
+
+        lookups.extend(collection_requests(&missing).into_iter().map(Lookup::Parts));
+    }
+
+    lookups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    fn part(id: u128) -> Value {
+        json!({
+            "object": "related_card",
+            "id": Uuid::from_u128(id),
+            "component": "token",
+            "name": "Part",
+            "type_line": "Token",
+            "uri": "http://some.url",
+        })
+    }
+
+    fn cards() -> Vec<Card> {
+        vec![
+            card(json!({ "id": Uuid::from_u128(1), "oracle_id": Uuid::from_u128(10), "set": "DOM", "all_parts": [part(1), part(2), part(3)] })),
+            card(json!({ "id": Uuid::from_u128(2), "oracle_id": Uuid::from_u128(10), "set": "dom", "all_parts": [part(3)] })),
+        ]
+    }
+
+    #[rstest]
+    fn lookups_should_be_planned_without_duplicates() {
+        let mut hydrated = Hydrated::default();
+        let lookups = plan(&cards(), HydrateOptions::all(), &mut hydrated);
+
+        let rulings: Vec<_> = lookups.iter().filter(|l| matches!(l, Lookup::Rulings { .. })).collect();
+        let parts: Vec<_> = lookups.iter().filter_map(|l| match l {
+            Lookup::Parts(CardCollectionResource::WithIdentifiers(ids)) => Some(ids.identifiers.len()),
+            _ => None,
+        }).collect();
+
+        assert_eq!(1, rulings.len());
+        assert_eq!(vec![1], parts);
+        assert_eq!(vec![Uuid::from_u128(2)], hydrated.parts.keys().copied().collect::<Vec<_>>());
+        #[cfg(feature = "sets")]
+        assert_eq!(1, lookups.iter().filter(|l| matches!(l, Lookup::Set(code) if code == "dom")).count());
+    }
+
+    #[rstest]
+    fn disabled_lookups_should_not_be_planned() {
+        let mut hydrated = Hydrated::default();
+
+        assert!(plan(&cards(), HydrateOptions::default(), &mut hydrated).is_empty());
+        assert_eq!(Hydrated::default(), hydrated);
+    }
+
+    #[rstest]
+    fn results_should_be_looked_up_by_card() {
+        let cards = cards();
+        let mut hydrated = Hydrated::default();
+        plan(&cards, HydrateOptions::all(), &mut hydrated);
+        hydrated.add(Found::Parts(vec![card(json!({ "id": Uuid::from_u128(3), "name": "Token" }))]));
+        hydrated.add(Found::Rulings(Uuid::from_u128(10), vec![]));
+
+        let names: Vec<&str> = hydrated.parts(&cards[0]).iter().map(|card| card.name.as_str()).collect();
+
+        assert_eq!(2, names.len());
+        assert_eq!("Token", names[1]);
+        assert!(hydrated.rulings.contains_key(&cards[1].effective_oracle_id()));
+        assert!(hydrated.rulings(&cards[1]).is_empty());
+    }
+}
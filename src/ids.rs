@@ -0,0 +1,188 @@
+//! Card identifier conversions
+//!
+//! Cards are identified differently by each platform (Scryfall, Arena, MTGO, Gatherer,
+//! TCGplayer, Cardmarket). A [CardId](CardId) can be converted to another [IdKind](IdKind)
+//! by fetching the card it refers to, using `convert_id` (or `convert_ids` for batches)
+//! of both clients.
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::Scryfall;
+//! use scryfall_sdk_rust::ids::{CardId, IdKind};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let id = Scryfall::default().convert_id(&CardId::Arena(79563), IdKind::Scryfall).await?;
+//!
+//! println!("{id:?}");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+use crate::CardResource;
+use crate::resources::cards::{Card, CardIdentifier, CardIdentifiers};
+use crate::CardCollectionResource;
+
+/// Maximum number of identifiers per collection request
+const COLLECTION_LIMIT: usize = 75;
+
+/// Kind of card identifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IdKind {
+    Scryfall,
+    Oracle,
+    Arena,
+    Mtgo,
+    Multiverse,
+    Tcgplayer,
+    Cardmarket,
+}
+
+/// A card identifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CardId {
+    Scryfall(Uuid),
+    Oracle(Uuid),
+    Arena(i32),
+    Mtgo(i32),
+    Multiverse(i32),
+    Tcgplayer(i32),
+    Cardmarket(i32),
+}
+
+impl Display for CardId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CardId::Scryfall(id) | CardId::Oracle(id) => write!(f, "{id}"),
+            CardId::Arena(id) | CardId::Mtgo(id) | CardId::Multiverse(id)
+            | CardId::Tcgplayer(id) | CardId::Cardmarket(id) => write!(f, "{id}"),
+        }
+    }
+}
+
+impl CardId {
+    /// Gets the identifier of a card (if any)
+    ///
+    /// For cards with many multiverse ids, the first one is returned.
+    pub fn of(card: &Card, kind: IdKind) -> Option<CardId> {
+        match kind {
+            IdKind::Scryfall => Some(CardId::Scryfall(card.id)),
+            IdKind::Oracle => Some(CardId::Oracle(card.oracle_id)),
+            IdKind::Arena => card.arena_id.map(CardId::Arena),
+            IdKind::Mtgo => card.mtgo_id.map(CardId::Mtgo),
+            IdKind::Multiverse => card.multiverse_ids.as_ref()
+                .and_then(|ids| ids.first().copied())
+                .map(CardId::Multiverse),
+            IdKind::Tcgplayer => card.tcgplayer_id.map(CardId::Tcgplayer),
+            IdKind::Cardmarket => card.cardmarket_id.map(CardId::Cardmarket),
+        }
+    }
+
+    /// Gets the kind of the identifier
+    pub fn kind(&self) -> IdKind {
+        match self {
+            CardId::Scryfall(_) => IdKind::Scryfall,
+            CardId::Oracle(_) => IdKind::Oracle,
+            CardId::Arena(_) => IdKind::Arena,
+            CardId::Mtgo(_) => IdKind::Mtgo,
+            CardId::Multiverse(_) => IdKind::Multiverse,
+            CardId::Tcgplayer(_) => IdKind::Tcgplayer,
+            CardId::Cardmarket(_) => IdKind::Cardmarket,
+        }
+    }
+
+    /// Checks if the identifier refers to the given card
+    pub fn matches(&self, card: &Card) -> bool {
+        match self {
+            CardId::Multiverse(id) => matches!(&card.multiverse_ids, Some(ids) if ids.contains(id)),
+            _ => CardId::of(card, self.kind()).as_ref() == Some(self),
+        }
+    }
+
+    /// Gets the identifier in the form accepted by the collection endpoint (if supported)
+    pub fn identifier(&self) -> Option<CardIdentifier> {
+        match *self {
+            CardId::Scryfall(id) => Some(CardIdentifier::ScryfallId { val: id.to_string() }),
+            CardId::Oracle(id) => Some(CardIdentifier::OracleId { val: id.to_string() }),
+            CardId::Mtgo(id) => Some(CardIdentifier::MtgoId { val: id.to_string() }),
+            CardId::Multiverse(id) => u32::try_from(id).ok()
+                .map(|val| CardIdentifier::MutliverseId { val }),
+            _ => None,
+        }
+    }
+}
+
+/// Gets the single card endpoint of an identifier (if any)
+///
+/// The value is the [formatted](CardId::to_string) identifier.
+pub(crate) fn resource<'a>(id: &CardId, value: &'a str) -> Option<CardResource<'a>> {
+    let resource = match id {
+        CardId::Scryfall(_) => CardResource::ById(value.into()),
+        CardId::Arena(_) => CardResource::ByArenaId(value.into()),
+        CardId::Mtgo(_) => CardResource::ByMtgoId(value.into()),
+        CardId::Multiverse(_) => CardResource::ByMultiverseId(value.into()),
+        CardId::Tcgplayer(_) => CardResource::ByTcgplayerId(value.into()),
+        CardId::Cardmarket(_) => CardResource::ByCardmarketId(value.into()),
+        CardId::Oracle(_) => return None,
+    };
+
+    Some(resource)
+}
+
+/// Gets the collection requests for the identifiers supported by the collection endpoint
+pub(crate) fn collection_requests(ids: &[CardId]) -> Vec<CardCollectionResource> {
+    let identifiers: Vec<CardIdentifier> = ids.iter().filter_map(CardId::identifier).collect();
+
+    identifiers.chunks(COLLECTION_LIMIT)
+        .map(|chunk| CardCollectionResource::WithIdentifiers(
+            CardIdentifiers { identifiers: chunk.to_vec() }
+        ))
+        .collect()
+}
+
+/// Converts identifiers using the fetched cards (in the order of the identifiers)
+pub(crate) fn convert_all(ids: &[CardId], cards: &[Card], to: IdKind) -> Vec<Option<CardId>> {
+    ids.iter()
+        .map(|id| cards.iter().find(|c| id.matches(c)).and_then(|c| CardId::of(c, to)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    fn printing() -> Card {
+        card(json!({ "arena_id": 79563, "multiverse_ids": [1, 2], "mtgo_id": 100 }))
+    }
+
+    #[rstest]
+    #[case::arena(IdKind::Arena, Some(CardId::Arena(79563)))]
+    #[case::multiverse(IdKind::Multiverse, Some(CardId::Multiverse(1)))]
+    #[case::tcgplayer(IdKind::Tcgplayer, None)]
+    fn card_ids_should_be_read(#[case] kind: IdKind, #[case] expected: Option<CardId>) {
+        assert_eq!(expected, CardId::of(&printing(), kind));
+    }
+
+    #[rstest]
+    fn ids_should_be_converted_by_matching_cards() {
+        let ids = [CardId::Multiverse(2), CardId::Arena(1), CardId::Mtgo(100)];
+
+        let converted = convert_all(&ids, &[printing()], IdKind::Arena);
+
+        assert_eq!(vec![Some(CardId::Arena(79563)), None, Some(CardId::Arena(79563))], converted);
+    }
+
+    #[rstest]
+    fn collection_requests_should_skip_unsupported_ids() {
+        let ids: Vec<CardId> = (0..80).map(CardId::Mtgo).chain([CardId::Arena(1)]).collect();
+
+        let requests = collection_requests(&ids);
+
+        assert_eq!(2, requests.len());
+        assert!(matches!(&requests[1], CardCollectionResource::WithIdentifiers(r) if r.identifiers.len() == 5));
+    }
+}
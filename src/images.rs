@@ -0,0 +1,485 @@
+//! Card image cache
+//!
+//! An [ImageCache](ImageCache) keeps downloaded card images in a directory,
+//! with deterministic file names:
+//!
+//! ```text
+//! {set}/{collector_number}-{face}-{size}.jpg
+//! {set}/{lang}/{collector_number}-{face}-{size}.jpg   (printings in other languages than English)
+//! ```
+//!
+//! Images are looked up in the directory before downloading, and the oldest images
+//! are evicted once the directory exceeds its (optional) maximum size.
+//! The size of the directory is computed once, and then tracked as images are stored.
+//!
+//! All images of a deck or collection can be downloaded ahead of time
+//! (e.g. for tabletop simulators) with [prefetch](prefetch), which evicts images
+//! once all downloads are done.
+//!
+//! File system operations are blocking, including in the async [fetch](ImageCache::fetch)
+//! and [prefetch](prefetch): with a multi-threaded runtime, this only blocks the current
+//! worker thread while an image is written (or while the directory is scanned).
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::{CardResource, Scryfall};
+//! use scryfall_sdk_rust::images::{ImageCache, ImageSize};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Scryfall::default();
+//! let cache = ImageCache::new("images").with_max_size(512 * 1024 * 1024);
+//!
+//! let card = client.request(&CardResource::NamedExact("Delver of Secrets".into())).await?;
+//! let back = cache.fetch(&client, &card, 1, ImageSize::Normal).await?;
+//!
+//! println!("{}", back.display());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use futures_util::stream::{self, StreamExt};
+use strum_macros::Display;
+use url::Url;
+use uuid::Uuid;
+use crate::resources::cards::{Card, ImageUris};
+use crate::resources::errors::ErrorBody;
+use crate::Scryfall;
+
+/// Size (version) of a card image
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "snake_case")]
+pub enum ImageSize {
+    Small,
+    Normal,
+    Large,
+    Png,
+    ArtCrop,
+    BorderCrop,
+}
+
+impl ImageSize {
+    /// Gets the URL of the image size
+    pub fn uri(self, uris: &ImageUris) -> &Url {
+        match self {
+            ImageSize::Small => &uris.small,
+            ImageSize::Normal => &uris.normal,
+            ImageSize::Large => &uris.large,
+            ImageSize::Png => &uris.png,
+            ImageSize::ArtCrop => &uris.art_crop,
+            ImageSize::BorderCrop => &uris.border_crop,
+        }
+    }
+
+    /// Gets the file extension of the image size
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageSize::Png => "png",
+            _ => "jpg",
+        }
+    }
+}
+
+impl Card {
+    /// Gets the image URLs of a card face
+    ///
+    /// Single-faced cards (and cards with a single image for all faces, e.g. split cards)
+    /// have their images on the card itself, returned for face `0`.
+    pub fn face_image_uris(&self, face: usize) -> Option<&ImageUris> {
+        match (&self.image_uris, face) {
+            (Some(uris), 0) => Some(uris),
+            (Some(_), _) => None,
+            (None, _) => self.card_faces.as_ref()?.get(face)?.image_uris.as_ref(),
+        }
+    }
+}
+
+/// Directory of downloaded card images
+///
+/// Clones share the tracked size of the directory.
+#[derive(Clone, Debug)]
+pub struct ImageCache {
+    dir: PathBuf,
+    max_size: Option<u64>,
+
+    /// Total size of the images (`None` until the directory is scanned)
+    usage: Arc<Mutex<Option<u64>>>,
+}
+
+impl PartialEq for ImageCache {
+    fn eq(&self, other: &Self) -> bool {
+        self.dir == other.dir && self.max_size == other.max_size
+    }
+}
+
+impl Eq for ImageCache { }
+
+impl ImageCache {
+    /// Creates a cache in a directory (created on the first download)
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        ImageCache { dir: dir.as_ref().into(), max_size: None, usage: Arc::default() }
+    }
+
+    /// Sets the maximum total size (in bytes) of the cached images
+    #[must_use]
+    pub fn with_max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// Gets the path of an image in the cache (whether it is cached or not)
+    ///
+    /// Printings in other languages than English are stored in a subdirectory of their set,
+    /// named after their language (e.g. `isd/ja`).
+    pub fn path(&self, card: &Card, face: usize, size: ImageSize) -> PathBuf {
+        let mut dir = self.dir.join(sanitize(&card.set));
+        if !card.lang.is_empty() && card.lang != "en" {
+            dir.push(sanitize(&card.lang));
+        }
+
+        dir.join(format!("{}-{face}-{size}.{}", sanitize(&card.collector_number), size.extension()))
+    }
+
+    /// Gets the path of an image, if it is cached
+    pub fn get(&self, card: &Card, face: usize, size: ImageSize) -> Option<PathBuf> {
+        Some(self.path(card, face, size)).filter(|path| path.is_file())
+    }
+
+    /// Stores an image, evicting the oldest images if the cache exceeds its maximum size
+    ///
+    /// The stored image itself is never evicted (even if it alone exceeds the maximum size).
+    pub fn insert(&self, card: &Card, face: usize, size: ImageSize, image: &[u8]) -> io::Result<PathBuf> {
+        let path = self.store(card, face, size, image)?;
+
+        if self.is_full()? {
+            self.evict_except(Some(&path))?;
+        }
+        Ok(path)
+    }
+
+    /// Removes the oldest images until the cache fits its maximum size
+    ///
+    /// Returns the number of removed images.
+    pub fn evict(&self) -> io::Result<usize> {
+        self.evict_except(None)
+    }
+
+    /// Writes an image, updating the tracked size of the directory
+    fn store(&self, card: &Card, face: usize, size: ImageSize, image: &[u8]) -> io::Result<PathBuf> {
+        let path = self.path(card, face, size);
+        let partial = path.with_extension("partial");
+        let replaced = fs::metadata(&path).map(|metadata| metadata.len()).unwrap_or_default();
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&partial, image)?;
+        fs::rename(&partial, &path)?;
+
+        if let Some(usage) = self.usage().as_mut() {
+            *usage = (*usage + image.len() as u64).saturating_sub(replaced);
+        }
+        Ok(path)
+    }
+
+    /// Checks if the images exceed the maximum size (scanning the directory the first time)
+    fn is_full(&self) -> io::Result<bool> {
+        let max_size = match self.max_size {
+            Some(max_size) => max_size,
+            None => return Ok(false),
+        };
+
+        let mut usage = self.usage();
+        let total = match *usage {
+            Some(total) => total,
+            None => {
+                let mut images = vec![];
+                collect_images(&self.dir, &mut images)?;
+                *usage.insert(images.iter().map(|(_, len, _)| len).sum())
+            },
+        };
+
+        Ok(total > max_size)
+    }
+
+    /// Removes the oldest images (except the given one) until the cache fits its maximum size
+    fn evict_except(&self, kept: Option<&Path>) -> io::Result<usize> {
+        let max_size = match self.max_size {
+            Some(max_size) => max_size,
+            None => return Ok(0),
+        };
+
+        let mut images = vec![];
+        collect_images(&self.dir, &mut images)?;
+        images.sort_by_key(|(modified, _, _)| *modified);
+
+        let mut total: u64 = images.iter().map(|(_, len, _)| len).sum();
+        let mut removed = 0;
+
+        for (_, len, path) in images {
+            if total <= max_size {
+                break;
+            }
+            if Some(path.as_path()) == kept {
+                continue;
+            }
+            fs::remove_file(path)?;
+            total -= len;
+            removed += 1;
+        }
+
+        *self.usage() = Some(total);
+        Ok(removed)
+    }
+
+    fn usage(&self) -> std::sync::MutexGuard<'_, Option<u64>> {
+        self.usage.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Gets the path of an image, downloading it (using the async client) if it is not cached
+    ///
+    /// Downloaded images are written with blocking file system calls (see the [module](self) docs).
+    pub async fn fetch(&self, client: &Scryfall<'_>, card: &Card, face: usize, size: ImageSize) -> Result<PathBuf, ErrorBody> {
+        self.download(client, card, face, size, true).await
+    }
+
+    /// Gets the path of an image, downloading it if it is not cached,
+    /// and evicting the oldest images only if `evict` is set
+    async fn download(&self, client: &Scryfall<'_>, card: &Card, face: usize, size: ImageSize, evict: bool) -> Result<PathBuf, ErrorBody> {
+        if let Some(path) = self.get(card, face, size) {
+            return Ok(path);
+        }
+
+        let url = image_url(card, face, size)?;
+        client.throttle().await;
+
+        let image = client.http_client()
+            .get(url.clone())
+            .send().await
+            .and_then(|res| res.error_for_status())
+            .map_err(ErrorBody::from_reqwest_error)?
+            .bytes().await
+            .map_err(ErrorBody::from_reqwest_error)?;
+
+        let stored = if evict {
+            self.insert(card, face, size, &image)
+        } else {
+            self.store(card, face, size, &image)
+        };
+        stored.map_err(|e| ErrorBody::client_error(e.to_string()))
+    }
+
+    /// Gets the path of an image, downloading it (using the blocking client) if it is not cached
+    #[cfg(feature = "blocking")]
+    pub fn fetch_blocking(&self, client: &crate::ScryfallBlocking<'_>, card: &Card, face: usize, size: ImageSize) -> Result<PathBuf, ErrorBody> {
+        client.block_on(self.fetch(client.as_async(), card, face, size))
+    }
+}
+
+/// Outcome of a [prefetch](prefetch)
+#[derive(Debug, Default, PartialEq)]
+pub struct PrefetchReport {
+    /// Number of images that were already cached
+    pub cached: usize,
+
+    /// Number of downloaded images
+    pub downloaded: usize,
+
+    /// Images that failed to download (card id, face and error)
+    pub failed: Vec<(Uuid, usize, ErrorBody)>,
+}
+
+/// Downloads the images of all faces of the given cards into a cache, using the async client
+///
+/// Cached images are skipped, and up to `concurrency` images are downloaded at a time
+/// (still respecting the rate limiter of the client, if any).
+/// The progress callback is invoked with the number of processed and total images.
+///
+/// The oldest images are evicted once all images are downloaded (if the cache exceeds
+/// its maximum size), so that the directory is scanned only once. Failures to evict
+/// are ignored.
+pub async fn prefetch<F>(
+    client: &Scryfall<'_>,
+    cards: &[Card],
+    size: ImageSize,
+    cache: &ImageCache,
+    concurrency: usize,
+    progress: F,
+) -> PrefetchReport
+    where F: Fn(usize, usize)
+{
+    let images: Vec<(&Card, usize)> = cards.iter()
+        .flat_map(|card| (0..face_count(card)).map(move |face| (card, face)))
+        .collect();
+    let total = images.len();
+    let mut report = PrefetchReport::default();
+
+    let mut downloads = stream::iter(images)
+        .map(|(card, face)| async move {
+            let cached = cache.get(card, face, size).is_some();
+            (card.id, face, cached, cache.download(client, card, face, size, false).await)
+        })
+        .buffer_unordered(concurrency.max(1));
+
+    let mut done = 0;
+    while let Some((id, face, cached, result)) = downloads.next().await {
+        match result {
+            Ok(_) if cached => report.cached += 1,
+            Ok(_) => report.downloaded += 1,
+            Err(e) => report.failed.push((id, face, e)),
+        }
+
+        done += 1;
+        progress(done, total);
+    }
+
+    if report.downloaded > 0 && cache.is_full().unwrap_or(false) {
+        let _ = cache.evict();
+    }
+
+    report
+}
+
+/// Downloads the images of all faces of the given cards into a cache, using the blocking client
+///
+/// See [prefetch](prefetch).
+#[cfg(feature = "blocking")]
+pub fn prefetch_blocking<F>(
+    client: &crate::ScryfallBlocking<'_>,
+    cards: &[Card],
+    size: ImageSize,
+    cache: &ImageCache,
+    concurrency: usize,
+    progress: F,
+) -> PrefetchReport
+    where F: Fn(usize, usize)
+{
+    client.block_on(prefetch(client.as_async(), cards, size, cache, concurrency, progress))
+}
+
+/// Gets the number of faces with their own images
+fn face_count(card: &Card) -> usize {
+    match (&card.image_uris, &card.card_faces) {
+        (None, Some(faces)) => faces.len(),
+        _ => 1,
+    }
+}
+
+fn image_url(card: &Card, face: usize, size: ImageSize) -> Result<&Url, ErrorBody> {
+    card.face_image_uris(face)
+        .map(|uris| size.uri(uris))
+        .ok_or_else(|| ErrorBody::client_error(format!("{} has no image for face {face}", card.name)))
+}
+
+/// Collects the modification time, size and path of all files under a directory
+fn collect_images(dir: &Path, images: &mut Vec<(SystemTime, u64, PathBuf)>) -> io::Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+
+        if metadata.is_dir() {
+            collect_images(&entry.path(), images)?;
+        } else {
+            images.push((metadata.modified()?, metadata.len(), entry.path()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Replaces characters that are not safe in file names (e.g. `/` in collector numbers)
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '★' { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    fn dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("scryfall-sdk-{}-images-{name}", std::process::id()))
+    }
+
+    #[rstest]
+    fn image_paths_should_be_deterministic() {
+        let cache = ImageCache::new("images");
+        let card = card(json!({ "set": "isd", "collector_number": "51" }));
+
+        assert_eq!(Path::new("images/isd/51-1-normal.jpg"), cache.path(&card, 1, ImageSize::Normal));
+        assert_eq!(Path::new("images/isd/51-0-art_crop.jpg"), cache.path(&card, 0, ImageSize::ArtCrop));
+        assert_eq!(Path::new("images/isd/51-0-png.png"), cache.path(&card, 0, ImageSize::Png));
+    }
+
+    #[rstest]
+    fn oldest_images_should_be_evicted() {
+        let dir = dir("evict");
+        let cache = ImageCache::new(&dir).with_max_size(10);
+        let first = card(json!({ "set": "isd", "collector_number": "1" }));
+        let second = card(json!({ "set": "isd", "collector_number": "2" }));
+
+        cache.insert(&first, 0, ImageSize::Small, &[0; 8]).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        cache.insert(&second, 0, ImageSize::Small, &[0; 8]).unwrap();
+
+        assert_eq!(None, cache.get(&first, 0, ImageSize::Small));
+        assert!(cache.get(&second, 0, ImageSize::Small).is_some());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[rstest]
+    fn image_paths_should_include_non_english_languages() {
+        let cache = ImageCache::new("images");
+        let english = card(json!({ "set": "war", "collector_number": "97", "lang": "en" }));
+        let japanese = card(json!({ "set": "war", "collector_number": "97", "lang": "ja" }));
+
+        assert_eq!(Path::new("images/war/97-0-normal.jpg"), cache.path(&english, 0, ImageSize::Normal));
+        assert_eq!(Path::new("images/war/ja/97-0-normal.jpg"), cache.path(&japanese, 0, ImageSize::Normal));
+    }
+
+    #[rstest]
+    fn inserted_images_should_not_be_evicted() {
+        let dir = dir("oversized");
+        let cache = ImageCache::new(&dir).with_max_size(10);
+        let small = card(json!({ "set": "isd", "collector_number": "1" }));
+        let large = card(json!({ "set": "isd", "collector_number": "2" }));
+
+        cache.insert(&small, 0, ImageSize::Small, &[0; 4]).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        cache.insert(&large, 0, ImageSize::Small, &[0; 16]).unwrap();
+
+        assert_eq!(None, cache.get(&small, 0, ImageSize::Small));
+        assert!(cache.get(&large, 0, ImageSize::Small).is_some());
+        assert_eq!(Some(16), *cache.usage());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[rstest]
+    fn size_should_be_tracked_without_rescanning() {
+        let dir = dir("usage");
+        let cache = ImageCache::new(&dir).with_max_size(100);
+        let first = card(json!({ "set": "isd", "collector_number": "1" }));
+
+        cache.insert(&first, 0, ImageSize::Small, &[0; 8]).unwrap();
+        cache.insert(&first, 0, ImageSize::Small, &[0; 6]).unwrap();
+        cache.clone().insert(&first, 1, ImageSize::Small, &[0; 5]).unwrap();
+
+        assert_eq!(Some(11), *cache.usage());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,279 @@
+//! In-memory oracle text search
+//!
+//! A [CardIndex](CardIndex) keeps cards (e.g. from bulk data) along with their oracle text,
+//! pre-lowercased and with the card name replaced by `~` (like Scryfall does), so that
+//! power-user searches run locally without API round-trips:
+//! - [search_oracle](CardIndex::search_oracle) finds cards whose text contains a text
+//! - [search_oracle_regex](CardIndex::search_oracle_regex) finds cards whose text matches
+//!   a regular expression (like the `o:/.../` syntax of the website), with the **regex** feature
+//!
+//! Both can exclude reminder text (text in parentheses) from the search.
+//!
+//! The texts of all faces of multi-faced cards are searched (separated by new lines).
+//!
+//! The memory used by an index (e.g. to size a service) is reported by [stats](CardIndex::stats).
+//!
+//! For ranked searches over names, type lines and oracle texts, see [fulltext](fulltext)
+//! (with the **fulltext** feature).
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::index::CardIndex;
+//! use scryfall_sdk_rust::resources::cards::Card;
+//!
+//! # fn example(cards: Vec<Card>) {
+//! let index = CardIndex::new(cards);
+//!
+//! for card in index.search_oracle("when ~ enters", true) {
+//!     println!("{}", card.name);
+//! }
+//! # }
+//! ```
+
+use std::io::{self, Write};
+use std::mem::size_of;
+use crate::quiz::redact;
+use crate::resources::cards::Card;
+
+#[cfg(feature = "fulltext")]
+pub mod fulltext;
+
+/// Cards indexed by oracle text
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CardIndex {
+    cards: Vec<Card>,
+    texts: Vec<IndexedText>,
+}
+
+/// Normalized oracle text of a card
+#[derive(Clone, Debug, Default, PartialEq)]
+struct IndexedText {
+    full: String,
+    without_reminder: String,
+}
+
+impl CardIndex {
+    /// Indexes cards
+    pub fn new(cards: Vec<Card>) -> Self {
+        let texts = cards.iter().map(IndexedText::of).collect();
+
+        CardIndex { cards, texts }
+    }
+
+    /// Gets the indexed cards
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Gets the number of indexed cards
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Checks if the index has no cards
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Reports the number of indexed cards and the approximate heap usage of the index
+    ///
+    /// This walks all cards, so it is meant for occasional reporting (e.g. at startup).
+    pub fn stats(&self) -> IndexStats {
+        IndexStats {
+            cards: self.cards.len(),
+            card_bytes: self.cards.capacity() * size_of::<Card>()
+                + self.cards.iter().map(card_heap_bytes).sum::<usize>(),
+            text_bytes: self.texts.capacity() * size_of::<IndexedText>()
+                + self.texts.iter().map(|text| text.full.capacity() + text.without_reminder.capacity()).sum::<usize>(),
+        }
+    }
+
+    /// Finds the cards whose oracle text contains a text (case insensitive, `~` for the card name)
+    pub fn search_oracle(&self, text: &str, exclude_reminder: bool) -> Vec<&Card> {
+        let text = text.to_lowercase();
+
+        self.matching(exclude_reminder, |oracle| oracle.contains(&text))
+    }
+
+    /// Finds the cards whose oracle text matches a regular expression (case insensitive)
+    ///
+    /// The pattern can be written with or without the surrounding slashes of the search syntax
+    /// (e.g. `/^{t}: add/`), and can use `~` for the card name.
+    #[cfg(feature = "regex")]
+    pub fn search_oracle_regex(&self, pattern: &str, exclude_reminder: bool) -> Result<Vec<&Card>, regex::Error> {
+        let pattern = pattern.strip_prefix('/')
+            .and_then(|p| p.strip_suffix('/'))
+            .unwrap_or(pattern);
+
+        let regex = regex::RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .multi_line(true)
+            .build()?;
+
+        Ok(self.matching(exclude_reminder, |oracle| regex.is_match(oracle)))
+    }
+
+    fn matching<P: Fn(&str) -> bool>(&self, exclude_reminder: bool, predicate: P) -> Vec<&Card> {
+        self.cards.iter()
+            .zip(&self.texts)
+            .filter(|(_, text)| match exclude_reminder {
+                true => predicate(&text.without_reminder),
+                false => predicate(&text.full),
+            })
+            .map(|(card, _)| card)
+            .collect()
+    }
+}
+
+/// Memory usage of a [CardIndex](CardIndex)
+///
+/// Sizes are approximate: the heap data of cards (strings, lists and maps) is estimated
+/// by their serialized size, and allocator overhead is not counted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IndexStats {
+    /// Number of indexed cards
+    pub cards: usize,
+
+    /// Approximate heap usage of the cards (in bytes)
+    pub card_bytes: usize,
+
+    /// Heap usage of the normalized oracle texts (in bytes)
+    pub text_bytes: usize,
+}
+
+impl IndexStats {
+    /// Gets the approximate total heap usage (in bytes)
+    pub fn heap_bytes(&self) -> usize {
+        self.card_bytes + self.text_bytes
+    }
+}
+
+/// Estimates the heap data of a card by its serialized size
+pub(crate) fn card_heap_bytes(card: &Card) -> usize {
+    let mut counter = ByteCounter(0);
+    serde_json::to_writer(&mut counter, card).map(|_| counter.0).unwrap_or_default()
+}
+
+/// Writer counting the written bytes
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl From<Vec<Card>> for CardIndex {
+    fn from(cards: Vec<Card>) -> Self {
+        CardIndex::new(cards)
+    }
+}
+
+impl IndexedText {
+    fn of(card: &Card) -> Self {
+        let legendary = card.type_line.contains("Legendary");
+
+        let mut texts: Vec<String> = card.oracle_text.iter()
+            .map(|text| redact(text, &card.name, legendary))
+            .collect();
+
+        if let Some(faces) = card.card_faces.as_ref().filter(|_| texts.is_empty()) {
+            texts = faces.iter()
+                .filter_map(|face| face.oracle_text.as_deref().map(|text| redact(text, &face.name, legendary)))
+                .collect();
+        }
+
+        let full = texts.join("\n").to_lowercase();
+        let without_reminder = strip_reminder(&full);
+
+        IndexedText { full, without_reminder }
+    }
+}
+
+/// Removes reminder text (text in parentheses, and the whitespace before it)
+fn strip_reminder(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut depth = 0usize;
+
+    for c in text.chars() {
+        match c {
+            '(' => {
+                if depth == 0 {
+                    let trimmed = result.trim_end_matches(' ').len();
+                    result.truncate(trimmed);
+                }
+                depth += 1;
+            },
+            ')' if depth > 0 => depth -= 1,
+            c if depth == 0 => result.push(c),
+            _ => {},
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use rstest::{fixture, rstest};
+    use serde_json::json;
+
+    #[fixture]
+    fn index() -> CardIndex {
+        CardIndex::new(vec![
+            card(json!({ "name": "Llanowar Elves", "oracle_text": "{T}: Add {G}." })),
+            card(json!({ "name": "Elvish Visionary", "oracle_text": "When Elvish Visionary enters, draw a card." })),
+            card(json!({
+                "name": "Vault Skirge",
+                "oracle_text": "Flying\nLifelink (Damage dealt by this creature also causes you to gain that much life.)",
+            })),
+        ])
+    }
+
+    fn names(cards: Vec<&Card>) -> Vec<&str> {
+        cards.into_iter().map(|card| card.name.as_str()).collect()
+    }
+
+    #[rstest]
+    #[case::text("DRAW A CARD", false, vec!["Elvish Visionary"])]
+    #[case::name("when ~ enters", false, vec!["Elvish Visionary"])]
+    #[case::reminder("gain that much life", false, vec!["Vault Skirge"])]
+    #[case::reminder_excluded("gain that much life", true, vec![])]
+    fn oracle_text_should_be_searched(index: CardIndex, #[case] text: &str, #[case] exclude_reminder: bool, #[case] expected: Vec<&str>) {
+        assert_eq!(expected, names(index.search_oracle(text, exclude_reminder)));
+    }
+
+    #[rstest]
+    fn stats_should_report_cards_and_texts(index: CardIndex) {
+        let stats = index.stats();
+        let serialized: usize = index.cards().iter().map(|card| serde_json::to_vec(card).unwrap().len()).sum();
+
+        assert_eq!(3, stats.cards);
+        assert!(stats.card_bytes >= serialized + 3 * size_of::<Card>());
+        assert!(stats.text_bytes >= "{t}: add {g}.".len() + 3 * size_of::<IndexedText>());
+        assert_eq!(stats.card_bytes + stats.text_bytes, stats.heap_bytes());
+        assert_eq!(IndexStats::default(), CardIndex::default().stats());
+    }
+
+    #[rstest]
+    #[case::plain("flying\nlifelink (damage", "flying\nlifelink")]
+    #[case::nested("a (b (c) d) e", "a e")]
+    fn reminder_text_should_be_stripped(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(expected, strip_reminder(text));
+    }
+
+    #[cfg(feature = "regex")]
+    #[rstest]
+    #[case::delimited("/^\\{t\\}: add/", vec!["Llanowar Elves"])]
+    #[case::bare("^lifelink$", vec!["Vault Skirge"])]
+    fn oracle_text_should_be_searched_by_regex(index: CardIndex, #[case] pattern: &str, #[case] expected: Vec<&str>) {
+        assert_eq!(expected, names(index.search_oracle_regex(pattern, true).unwrap()));
+    }
+}
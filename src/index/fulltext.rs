@@ -0,0 +1,200 @@
+//! Ranked full-text search
+//!
+//! [LocalSearch](LocalSearch) is a small local search engine over the names, type lines
+//! and oracle texts of cards (e.g. from bulk data). Queries are split into words and
+//! results are ranked with BM25, with matches in names weighing more than matches
+//! in type lines, and matches in type lines more than matches in oracle texts.
+//!
+//! Results can be narrowed down with any predicate (e.g. legality or colors)
+//! using [query_filtered](LocalSearch::query_filtered).
+//!
+//! The search has to be enabled by the **fulltext** optional feature.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::index::fulltext::LocalSearch;
+//! use scryfall_sdk_rust::resources::cards::Card;
+//!
+//! # fn example(cards: Vec<Card>) {
+//! let search = LocalSearch::new(cards);
+//!
+//! for (card, score) in search.query("counter target spell").into_iter().take(10) {
+//!     println!("{} ({score:.2})", card.name);
+//! }
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use crate::resources::cards::Card;
+
+/// Weights of the name, type line and oracle text of cards
+const FIELD_WEIGHTS: [f64; 3] = [3.0, 2.0, 1.0];
+
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+
+/// Local full-text search over cards
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocalSearch {
+    cards: Vec<Card>,
+
+    /// Weighted term frequencies of each card, by term
+    postings: HashMap<String, Vec<(usize, f64)>>,
+
+    /// Weighted length of each card
+    lengths: Vec<f64>,
+}
+
+impl LocalSearch {
+    /// Indexes cards
+    pub fn new(cards: Vec<Card>) -> Self {
+        let mut postings: HashMap<String, Vec<(usize, f64)>> = HashMap::new();
+        let mut lengths = Vec::with_capacity(cards.len());
+
+        for (doc, card) in cards.iter().enumerate() {
+            let mut frequencies: HashMap<String, f64> = HashMap::new();
+            let mut length = 0.0;
+
+            for (text, weight) in fields(card).iter().zip(FIELD_WEIGHTS) {
+                for term in tokenize(text) {
+                    *frequencies.entry(term).or_default() += weight;
+                    length += weight;
+                }
+            }
+
+            for (term, frequency) in frequencies {
+                postings.entry(term).or_default().push((doc, frequency));
+            }
+            lengths.push(length);
+        }
+
+        LocalSearch { cards, postings, lengths }
+    }
+
+    /// Gets the indexed cards
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Searches for cards matching any word of the query, best matches first
+    pub fn query(&self, query: &str) -> Vec<(&Card, f64)> {
+        self.query_filtered(query, |_| true)
+    }
+
+    /// Searches for cards matching any word of the query and the filter, best matches first
+    pub fn query_filtered<F>(&self, query: &str, filter: F) -> Vec<(&Card, f64)>
+        where F: Fn(&Card) -> bool
+    {
+        if self.cards.is_empty() {
+            return vec![];
+        }
+
+        let documents = self.cards.len() as f64;
+        let average_length = self.lengths.iter().sum::<f64>() / documents;
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+
+        let mut terms = tokenize(query);
+        terms.sort();
+        terms.dedup();
+
+        for term in terms {
+            let postings = match self.postings.get(&term) {
+                Some(postings) => postings,
+                None => continue,
+            };
+
+            let matching = postings.len() as f64;
+            let idf = ((documents - matching + 0.5) / (matching + 0.5) + 1.0).ln();
+
+            for (doc, frequency) in postings {
+                let norm = 1.0 - B + B * self.lengths[*doc] / average_length.max(f64::EPSILON);
+                *scores.entry(*doc).or_default() += idf * frequency * (K1 + 1.0) / (frequency + K1 * norm);
+            }
+        }
+
+        let mut results: Vec<(&Card, f64)> = scores.into_iter()
+            .map(|(doc, score)| (&self.cards[doc], score))
+            .filter(|(card, _)| filter(card))
+            .collect();
+
+        results.sort_by(|(a, x), (b, y)| y.partial_cmp(x)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.name.cmp(&b.name))
+        );
+        results
+    }
+}
+
+impl From<Vec<Card>> for LocalSearch {
+    fn from(cards: Vec<Card>) -> Self {
+        LocalSearch::new(cards)
+    }
+}
+
+/// Gets the name, type line and oracle text of a card (of all faces)
+fn fields(card: &Card) -> [String; 3] {
+    let faces = card.card_faces.as_deref().unwrap_or_default();
+
+    let oracle_text = match &card.oracle_text {
+        Some(text) => text.clone(),
+        None => faces.iter()
+            .filter_map(|face| face.oracle_text.as_deref())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+
+    [card.name.clone(), card.type_line.clone(), oracle_text]
+}
+
+/// Splits a text into lowercase words
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use rstest::{fixture, rstest};
+    use serde_json::json;
+
+    #[fixture]
+    fn search() -> LocalSearch {
+        LocalSearch::new(vec![
+            card(json!({ "name": "Counterspell", "type_line": "Instant", "oracle_text": "Counter target spell." })),
+            card(json!({ "name": "Negate", "type_line": "Instant", "oracle_text": "Counter target noncreature spell." })),
+            card(json!({ "name": "Shock", "type_line": "Instant", "oracle_text": "Shock deals 2 damage to any target." })),
+            card(json!({ "name": "Grizzly Bears", "type_line": "Creature — Bear", "oracle_text": "" })),
+        ])
+    }
+
+    fn names(results: Vec<(&Card, f64)>) -> Vec<&str> {
+        results.into_iter().map(|(card, _)| card.name.as_str()).collect()
+    }
+
+    #[rstest]
+    fn results_should_be_ranked(search: LocalSearch) {
+        assert_eq!(vec!["Counterspell", "Negate", "Shock"], names(search.query("Counter target spell")));
+    }
+
+    #[rstest]
+    fn names_should_be_searched(search: LocalSearch) {
+        assert_eq!(vec!["Shock"], names(search.query("shock")));
+        assert_eq!(vec!["Grizzly Bears"], names(search.query("bears")));
+    }
+
+    #[rstest]
+    fn results_should_be_filtered(search: LocalSearch) {
+        let results = search.query_filtered("target", |card| card.name != "Shock");
+
+        assert_eq!(vec!["Counterspell", "Negate"], names(results));
+    }
+
+    #[rstest]
+    fn unknown_words_should_match_nothing(search: LocalSearch) {
+        assert!(search.query("xyzzy").is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+//! String interning for bulk data parsing
+//!
+//! Fields like `set`, `artist`, `border_color`, `frame`, `lang` or keywords repeat across
+//! the hundreds of thousands of cards of a bulk data file. Applications keeping a dataset
+//! in memory can parse it into their own lightweight model (see
+//! [parse_partial](crate::client::parse_partial)) with `Arc<str>` fields, deserialized with
+//! the [string](string), [option_string](option_string) and [strings](strings) deserializers:
+//! with [parse_bulk](parse_bulk), equal strings then share a single allocation
+//! of a [StringPool](StringPool).
+//!
+//! Outside of [parse_bulk](parse_bulk) (e.g. with plain `serde_json` calls),
+//! the deserializers allocate every string, as usual.
+//!
+//! # Example
+//! ```
+//! use std::sync::Arc;
+//! use serde::Deserialize;
+//! use scryfall_sdk_rust::intern::{self, StringPool};
+//!
+//! #[derive(Deserialize)]
+//! struct CompactCard {
+//!     name: String,
+//!     #[serde(deserialize_with = "intern::string")]
+//!     set: Arc<str>,
+//!     #[serde(default, deserialize_with = "intern::option_string")]
+//!     artist: Option<Arc<str>>,
+//!     #[serde(deserialize_with = "intern::strings")]
+//!     keywords: Vec<Arc<str>>,
+//! }
+//!
+//! let json = r#"[
+//!     { "name": "Opt", "set": "xln", "artist": "Tyler Jacobson", "keywords": [] },
+//!     { "name": "Shock", "set": "xln", "keywords": ["Flying"] }
+//! ]"#;
+//!
+//! let mut pool = StringPool::default();
+//! let cards: Vec<CompactCard> = intern::parse_bulk(json.as_bytes(), &mut pool).unwrap();
+//!
+//! assert!(Arc::ptr_eq(&cards[0].set, &cards[1].set));
+//! assert_eq!(3, pool.len());
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use serde::de::{DeserializeOwned, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+thread_local! {
+    /// The pool used by the deserializers during [parse_bulk](parse_bulk)
+    static POOL: RefCell<Option<StringPool>> = const { RefCell::new(None) };
+}
+
+/// A set of shared strings
+#[derive(Clone, Debug, Default)]
+pub struct StringPool {
+    strings: HashSet<Arc<str>>,
+}
+
+impl StringPool {
+    /// Gets the shared copy of a string, adding it to the pool if needed
+    pub fn intern(&mut self, value: &str) -> Arc<str> {
+        match self.strings.get(value) {
+            Some(shared) => shared.clone(),
+            None => {
+                let shared: Arc<str> = value.into();
+                self.strings.insert(shared.clone());
+                shared
+            },
+        }
+    }
+
+    /// Gets the number of distinct strings of the pool
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Checks if the pool is empty
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Gets the total length (in bytes) of the distinct strings of the pool
+    pub fn bytes(&self) -> usize {
+        self.strings.iter().map(|s| s.len()).sum()
+    }
+}
+
+/// Parses a json array (e.g. a bulk data file), interning strings in the pool
+///
+/// Strings deserialized with the deserializers of this module are shared through the pool,
+/// which can be reused across files (e.g. to parse cards and rulings).
+/// The reader is buffered, so files can be passed directly.
+pub fn parse_bulk<T, R>(reader: R, pool: &mut StringPool) -> serde_json::Result<Vec<T>>
+    where T: DeserializeOwned,
+          R: Read
+{
+    let _active = ActivePool::new(pool);
+
+    serde_json::from_reader(BufReader::new(reader))
+}
+
+/// The pool of a [parse_bulk](parse_bulk) call, given back to its owner when dropped
+/// (even if the parsing panics)
+struct ActivePool<'a> {
+    pool: &'a mut StringPool,
+    previous: Option<StringPool>,
+}
+
+impl<'a> ActivePool<'a> {
+    /// Makes a pool the active pool of the current thread
+    fn new(pool: &'a mut StringPool) -> Self {
+        let previous = POOL.with(|active| active.borrow_mut().replace(std::mem::take(pool)));
+
+        ActivePool { pool, previous }
+    }
+}
+
+impl Drop for ActivePool<'_> {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+
+        *self.pool = POOL.with(|active| std::mem::replace(&mut *active.borrow_mut(), previous))
+            .unwrap_or_default();
+    }
+}
+
+/// Interns a string in the active pool (if any)
+fn intern(value: &str) -> Arc<str> {
+    POOL.with(|active| match active.borrow_mut().as_mut() {
+        Some(pool) => pool.intern(value),
+        None => value.into(),
+    })
+}
+
+/// Visitor of interned strings
+struct Interned;
+
+impl<'de> Visitor<'de> for Interned {
+    type Value = Arc<str>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Arc<str>, E> {
+        Ok(intern(value))
+    }
+}
+
+/// An interned string (for sequences)
+struct InternedString(Arc<str>);
+
+impl<'de> Deserialize<'de> for InternedString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        string(deserializer).map(InternedString)
+    }
+}
+
+/// Deserializes an interned string
+pub fn string<'de, D>(deserializer: D) -> Result<Arc<str>, D::Error>
+    where D: Deserializer<'de>
+{
+    deserializer.deserialize_str(Interned)
+}
+
+/// Deserializes an optional interned string
+///
+/// Fields using it need `#[serde(default)]` to also accept missing values.
+pub fn option_string<'de, D>(deserializer: D) -> Result<Option<Arc<str>>, D::Error>
+    where D: Deserializer<'de>
+{
+    Ok(Option::<InternedString>::deserialize(deserializer)?.map(|s| s.0))
+}
+
+/// Deserializes a list of interned strings
+pub fn strings<'de, D>(deserializer: D) -> Result<Vec<Arc<str>>, D::Error>
+    where D: Deserializer<'de>
+{
+    struct Strings;
+
+    impl<'de> Visitor<'de> for Strings {
+        type Value = Vec<Arc<str>>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a list of strings")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut strings = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+            while let Some(InternedString(value)) = seq.next_element()? {
+                strings.push(value);
+            }
+            Ok(strings)
+        }
+    }
+
+    deserializer.deserialize_seq(Strings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[derive(Deserialize)]
+    struct Row {
+        #[serde(deserialize_with = "string")]
+        set: Arc<str>,
+        #[serde(default, deserialize_with = "option_string")]
+        artist: Option<Arc<str>>,
+        #[serde(default, deserialize_with = "strings")]
+        keywords: Vec<Arc<str>>,
+    }
+
+    const ROWS: &str = r#"[
+        { "set": "dom", "artist": "A", "keywords": ["Flying", "Haste"] },
+        { "set": "dom", "artist": null, "keywords": ["Flying"] },
+        { "set": "m21" }
+    ]"#;
+
+    #[rstest]
+    fn strings_should_be_shared_in_bulk_parsing() {
+        let mut pool = StringPool::default();
+        let rows: Vec<Row> = parse_bulk(ROWS.as_bytes(), &mut pool).unwrap();
+
+        assert!(Arc::ptr_eq(&rows[0].set, &rows[1].set));
+        assert!(Arc::ptr_eq(&rows[0].keywords[0], &rows[1].keywords[0]));
+        assert_eq!((Some("A"), None), (rows[0].artist.as_deref(), rows[1].artist.as_deref()));
+        assert_eq!(5, pool.len());
+        assert_eq!(18, pool.bytes());
+    }
+
+    #[rstest]
+    fn pools_should_be_reused() {
+        let mut pool = StringPool::default();
+        let first: Vec<Row> = parse_bulk(ROWS.as_bytes(), &mut pool).unwrap();
+        let second: Vec<Row> = parse_bulk(ROWS.as_bytes(), &mut pool).unwrap();
+
+        assert!(Arc::ptr_eq(&first[2].set, &second[2].set));
+        assert!(parse_bulk::<Row, _>(&b"[{}]"[..], &mut pool).is_err());
+        assert_eq!(5, pool.len());
+    }
+
+    #[derive(Debug)]
+    struct Panicking;
+
+    impl<'de> Deserialize<'de> for Panicking {
+        fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+            panic!("Expected a panic")
+        }
+    }
+
+    #[rstest]
+    fn pools_should_be_kept_when_parsing_panics() {
+        let mut pool = StringPool::default();
+        parse_bulk::<Row, _>(ROWS.as_bytes(), &mut pool).unwrap();
+
+        let parsed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            parse_bulk::<Panicking, _>(&b"[1]"[..], &mut pool)
+        }));
+
+        assert!(parsed.is_err());
+        assert_eq!(5, pool.len());
+        assert!(POOL.with(|active| active.borrow().is_none()));
+    }
+
+    #[rstest]
+    fn strings_should_not_be_shared_outside_bulk_parsing() {
+        let rows: Vec<Row> = serde_json::from_str(ROWS).unwrap();
+
+        assert_eq!(rows[0].set, rows[1].set);
+        assert!(!Arc::ptr_eq(&rows[0].set, &rows[1].set));
+    }
+}
@@ -6,6 +6,11 @@
 //! - [Scryfall](Scryfall)
 //! - [ScryfallBlocking](ScryfallBlocking)
 //!
+//! Both can be configured (e.g. connection pool, caching) using [ScryfallBuilder](ScryfallBuilder).
+//!
+//! For quick starts, [prelude](prelude) brings the clients, common resources and the error type
+//! into scope, and [default_client](default_client) creates an async client with the default settings.
+//!
 //! Bindings for the following resources are implemented:
 //!
 //! - <https://api.scryfall.com/cards>
@@ -14,14 +19,140 @@
 //! - <https://api.scryfall.com/symbology>
 //! - <https://api.scryfall.com/sets>
 //! - <https://api.scryfall.com/cards/**/rulings>
+//! - <https://api.scryfall.com/migrations>
+//!
+//! Models are split into optional features (all enabled by default),
+//! so that minimal users can compile only what they need:
+//! **cards** (also enables **symbols**), **sets**, **bulk** and **symbols**.
+//!
+//! Foreign types used in models are re-exported in [types](types).
+//!
+//! On top of the above, the following utilities are provided (with the **cards** feature):
+//!
+//! - [deck](deck): deck model and format validation
+//! - [collection](collection): card inventory and trade matching
+//! - [export](export): multi-page search exports with a manifest of the pages followed
+//! - [watch](watch): polling watcher for newly previewed cards
+//! - [hydrate](hydrate): batched lookups of the sets, rulings and related cards of cards
+//! - [ids](ids): conversions between Scryfall, Arena, MTGO and other card identifiers
+//! - [images](images): card image cache with deterministic file names
+//! - [prints](prints): printing history of oracle cards and grouping of printings by oracle card
+//! - [query](query): parsing of search queries into a typed syntax tree
+//! - [sort](sort): client-side sorting of cards by multiple keys
+//! - [group](group): grouping of cards into sections (by set, color identity or type) and of multilingual results
+//! - [quiz](quiz): building blocks for card name quizzes
+//! - [word_bank](word_bank): card name generation and validation with the word bank catalog
+//! - [booster](booster): booster pack value estimation
+//! - [index](index): in-memory oracle text search (with regular expressions, using the **regex** feature)
+//!
+//! With the **rkyv** feature, models derive rkyv's archive traits for zero-copy loading
+//! (see [archive](resources::archive)).
+//!
+//! With the **store** feature, [store](store) provides a read-only on-disk card store
+//! with in-memory indexes.
+//!
+//! With the **mirror** feature, [mirror](mirror) serves card endpoints of the API from a local store.
+//!
+//! With the **postgres** feature, [postgres](postgres) writes cards as CSV for loading them
+//! into Postgres with `COPY`.
+//!
+//! With the **test-util** feature, [testing](testing) provides seeded generators of random cards
+//! for property tests.
+//!
+//! With the **web** feature, [web](web) provides shared client state and HTTP status mapping
+//! of errors for web services.
+//!
+//! With the **http** feature, [prepared](client::prepared) requests convert to `http::Request`s,
+//! and responses sent by custom transports are decoded with [decode](Scryfall::decode).
+//!
+//! Links to scryfall.com pages (cards, sets, searches and Tagger pages) are provided in [links](links).
+//!
+//! Set icons, symbology SVGs and other static assets can be downloaded with conditional requests
+//! (only when changed) using [assets](assets).
+//!
+//! With the **bulk** feature, [intern](intern) shares repeated strings (e.g. set codes and artists)
+//! between the cards of bulk data files parsed into custom in-memory models.
+//!
+//! With the **sets** feature, [sets](sets) groups related sets (e.g. tokens and promos) under their parent set.
+//!
+//! With the **symbols** feature, [symbology](symbology) provides a symbol lookup table
+//! for resolving mana costs.
 
+pub mod assets;
+#[cfg(feature = "cards")]
+pub mod booster;
 pub mod client;
+#[cfg(feature = "cards")]
+pub mod collection;
+#[cfg(feature = "cards")]
+pub mod deck;
+#[cfg(feature = "cards")]
+pub mod export;
+#[cfg(feature = "cards")]
+pub mod group;
+#[cfg(feature = "cards")]
+pub mod hydrate;
+#[cfg(feature = "cards")]
+pub mod ids;
+#[cfg(feature = "cards")]
+pub mod index;
+#[cfg(feature = "bulk")]
+pub mod intern;
+#[cfg(feature = "cards")]
+pub mod images;
+pub mod links;
+#[cfg(feature = "mirror")]
+pub mod mirror;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+pub mod prelude;
+#[cfg(feature = "cards")]
+pub mod prints;
+#[cfg(feature = "cards")]
+pub mod query;
+#[cfg(feature = "cards")]
+pub mod quiz;
+#[cfg(feature = "cards")]
+mod random;
 pub mod resources;
+#[cfg(feature = "sets")]
+pub mod sets;
+#[cfg(feature = "cards")]
+pub mod sort;
+#[cfg(feature = "store")]
+pub mod store;
+#[cfg(feature = "symbols")]
+pub mod symbology;
+#[cfg(feature = "test-util")]
+pub mod testing;
+pub mod types;
+#[cfg(feature = "cards")]
+pub mod watch;
+#[cfg(feature = "web")]
+pub mod web;
+#[cfg(feature = "cards")]
+pub mod word_bank;
+
+#[cfg(any(test, feature = "test-util"))]
+mod fixtures;
+
+// -- Foreign crates
+#[doc(no_inline)]
+pub use time;
+#[doc(no_inline)]
+pub use url;
+#[doc(no_inline)]
+#[cfg(feature = "cards")]
+pub use uuid;
 
 // -- Clients
 #[doc(inline)]
 pub use client::Scryfall;
 #[doc(inline)]
+pub use client::builder::ScryfallBuilder;
+#[doc(inline)]
+pub use prelude::default_client;
+#[doc(inline)]
 #[cfg(feature = "blocking")]
 pub use client::blocking::Scryfall as ScryfallBlocking;
 
@@ -29,24 +160,39 @@ pub use client::blocking::Scryfall as ScryfallBlocking;
 #[doc(inline)]
 pub use resources::HttpResource;
 #[doc(inline)]
+#[cfg(feature = "cards")]
 pub use resources::cards::CardResource;
 #[doc(inline)]
+#[cfg(feature = "cards")]
 pub use resources::cards::CardPageResource;
 #[doc(inline)]
+#[cfg(feature = "cards")]
 pub use resources::cards::CardCatalogResource;
 #[doc(inline)]
+#[cfg(feature = "cards")]
 pub use resources::cards::CardCollectionResource;
 #[doc(inline)]
+#[cfg(feature = "bulk")]
 pub use resources::bulk_data::BulkDataResource;
 #[doc(inline)]
 pub use resources::catalog::CatalogResource;
 #[doc(inline)]
+#[cfg(feature = "symbols")]
 pub use resources::card_symbols::CardSymbolsResource;
 #[doc(inline)]
+#[cfg(feature = "symbols")]
 pub use resources::card_symbols::ManaCostResource;
 #[doc(inline)]
+#[cfg(feature = "sets")]
 pub use resources::card_sets::CardSetResource;
 #[doc(inline)]
+#[cfg(feature = "sets")]
 pub use resources::card_sets::CardSetListResource;
 #[doc(inline)]
 pub use resources::rulings::RulingListResource;
+#[doc(inline)]
+#[cfg(feature = "cards")]
+pub use resources::migrations::MigrationListResource;
+#[doc(inline)]
+#[cfg(feature = "cards")]
+pub use resources::migrations::MigrationResource;
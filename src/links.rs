@@ -0,0 +1,98 @@
+//! Links to scryfall.com web pages
+//!
+//! Canonical URLs for "view on Scryfall" buttons, with path segments
+//! and query parameters properly encoded.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::links;
+//!
+//! assert_eq!("https://scryfall.com/card/dmu/107", links::card("DMU", "107").as_str());
+//! assert_eq!("https://scryfall.com/sets/dmu", links::set("dmu").as_str());
+//! ```
+
+use url::Url;
+#[cfg(feature = "cards")]
+use crate::resources::cards::{Card, SearchQueryParams};
+
+/// Base URL of the Scryfall website
+pub const SCRYFALL_URL: &str = "https://scryfall.com";
+
+/// Base URL of the Scryfall Tagger website
+pub const TAGGER_URL: &str = "https://tagger.scryfall.com";
+
+/// Gets the page of a card printing by its set code and collector number
+pub fn card(set_code: &str, collector_number: &str) -> Url {
+    page(SCRYFALL_URL, &["card", &set_code.to_lowercase(), collector_number])
+}
+
+/// Gets the page of a card printing
+#[cfg(feature = "cards")]
+pub fn card_of(card: &Card) -> Url {
+    self::card(&card.set, &card.collector_number)
+}
+
+/// Gets the page of a set by its code
+pub fn set(set_code: &str) -> Url {
+    page(SCRYFALL_URL, &["sets", &set_code.to_lowercase()])
+}
+
+/// Gets the results page of a search
+#[cfg(feature = "cards")]
+pub fn search(params: &SearchQueryParams) -> Url {
+    params.to_url(&page(SCRYFALL_URL, &[]))
+}
+
+/// Gets the Tagger page of a card printing by its set code and collector number
+pub fn tagger_card(set_code: &str, collector_number: &str) -> Url {
+    page(TAGGER_URL, &["card", &set_code.to_lowercase(), collector_number])
+}
+
+/// Gets the Tagger page of an oracle (card function) tag (e.g. `removal`)
+pub fn tagger_oracle_tag(tag: &str) -> Url {
+    page(TAGGER_URL, &["tags", "card", &slug(tag)])
+}
+
+/// Gets the Tagger page of an art tag (e.g. `squirrel`)
+pub fn tagger_art_tag(tag: &str) -> Url {
+    page(TAGGER_URL, &["tags", "artwork", &slug(tag)])
+}
+
+fn page(base: &str, segments: &[&str]) -> Url {
+    let mut url = Url::parse(base).expect("Expected a valid base URL");
+
+    if let Ok(mut path) = url.path_segments_mut() {
+        path.pop_if_empty().extend(segments);
+    }
+
+    url
+}
+
+fn slug(tag: &str) -> String {
+    tag.trim().to_lowercase().replace(' ', "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::card(card("PLST", "ARB-1"), "https://scryfall.com/card/plst/ARB-1")]
+    #[case::card_encoded(card("sld", "1#★"), "https://scryfall.com/card/sld/1%23%E2%98%85")]
+    #[case::set(set("DMU"), "https://scryfall.com/sets/dmu")]
+    #[case::tagger_card(tagger_card("dmu", "107"), "https://tagger.scryfall.com/card/dmu/107")]
+    #[case::oracle_tag(tagger_oracle_tag("Mana Rock"), "https://tagger.scryfall.com/tags/card/mana-rock")]
+    #[case::art_tag(tagger_art_tag("squirrel"), "https://tagger.scryfall.com/tags/artwork/squirrel")]
+    fn links_should_be_generated(#[case] url: Url, #[case] expected: &str) {
+        assert_eq!(expected, url.as_str());
+    }
+
+    #[cfg(feature = "cards")]
+    #[rstest]
+    fn search_link_should_be_generated() {
+        let url = search(&SearchQueryParams::with_q("t:goblin"));
+
+        assert_eq!("https://scryfall.com/search?q=t%3Agoblin", url.as_str());
+    }
+}
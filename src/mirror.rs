@@ -0,0 +1,539 @@
+//! Read-only HTTP mirror of the card endpoints
+//!
+//! A [Mirror](Mirror) answers a subset of the card endpoints of the API from a local
+//! [card backend](crate::store::backend::CardBackend) (e.g. a [CardStore](crate::store::CardStore)
+//! imported from bulk data), in the same JSON shape as Scryfall, so that internal services
+//! and tests can point any Scryfall client (including this one, using a custom base URL)
+//! to the mirror instead of the API:
+//!
+//! - `/cards/:id`: a card by its Scryfall id
+//! - `/cards/named?exact=...` and `/cards/named?fuzzy=...`: a card by name
+//!   (the most recent printing)
+//! - `/cards/search?q=...&page=...`: cards whose names contain all words of the query
+//!   (search keywords such as `t:creature` are not supported)
+//!
+//! Errors are returned as Scryfall [error objects](ErrorBody).
+//!
+//! Requests can be answered by any web framework through [handle](Mirror::handle),
+//! or by the minimal (one thread per connection) HTTP/1.1 server of [serve](Mirror::serve).
+//! The server handles up to [64](Mirror::with_max_connections) connections at a time,
+//! and closes connections idle for [10 seconds](Mirror::with_timeout) (or whose request head
+//! is not received within that time).
+//!
+//! The mirror has to be enabled by the **mirror** optional feature.
+//!
+//! # Example
+//! ```no_run
+//! use std::net::TcpListener;
+//! use scryfall_sdk_rust::mirror::Mirror;
+//! use scryfall_sdk_rust::store::CardStore;
+//!
+//! # fn example() -> std::io::Result<()> {
+//! let store = CardStore::open("cards.store")?;
+//! let listener = TcpListener::bind("127.0.0.1:8080")?;
+//!
+//! Mirror::new(store).serve(listener)
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use url::Url;
+use uuid::Uuid;
+use crate::resources::cards::{Card, CardPage};
+use crate::resources::errors::ErrorBody;
+use crate::resources::ResourceKind;
+use crate::store::backend::CardBackend;
+
+/// Number of cards of a search page (like the API)
+const PAGE_SIZE: usize = 175;
+
+/// Maximum length of the head of a request
+const MAX_HEAD_LENGTH: u64 = 8 * 1024;
+
+/// Default maximum number of connections handled at a time
+const MAX_CONNECTIONS: usize = 64;
+
+/// Default timeout of reads and writes on a connection
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Read-only mirror of the card endpoints over a card backend
+#[derive(Clone, Debug)]
+pub struct Mirror<B> {
+    backend: B,
+    base_url: Url,
+    page_size: usize,
+    max_connections: usize,
+    timeout: Duration,
+}
+
+/// Response of the mirror
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MirrorResponse {
+    /// The HTTP status code
+    pub status: u16,
+
+    /// The JSON body
+    pub body: String,
+}
+
+impl<B: CardBackend> Mirror<B> where B::Error: Display {
+    /// Creates a mirror of the cards of a backend
+    pub fn new(backend: B) -> Self {
+        Mirror {
+            backend,
+            base_url: Url::parse("http://localhost/").expect("valid base url"),
+            page_size: PAGE_SIZE,
+            max_connections: MAX_CONNECTIONS,
+            timeout: TIMEOUT,
+        }
+    }
+
+    /// Sets the public URL of the mirror, used for the `next_page` links of searches
+    ///
+    /// The URL can have a path prefix (e.g. `https://proxy.internal/scryfall`),
+    /// with or without trailing slash.
+    pub fn with_base_url(self, mut base_url: Url) -> Self {
+        if !base_url.path().ends_with('/') {
+            let path = format!("{}/", base_url.path());
+            base_url.set_path(&path);
+        }
+
+        Mirror { base_url, ..self }
+    }
+
+    /// Sets the number of cards of a search page
+    pub fn with_page_size(self, page_size: usize) -> Self {
+        Mirror { page_size: page_size.max(1), ..self }
+    }
+
+    /// Sets the maximum number of connections handled at a time by [serve](Mirror::serve)
+    ///
+    /// Further connections wait in the backlog of the listener until a connection is closed.
+    pub fn with_max_connections(self, max_connections: usize) -> Self {
+        Mirror { max_connections: max_connections.max(1), ..self }
+    }
+
+    /// Sets the timeout of reads and writes on the connections of [serve](Mirror::serve),
+    /// after which idle or stalled connections are closed
+    ///
+    /// This is also the total time allowed for receiving the head of a request,
+    /// so that slow clients cannot hold a connection by sending it byte by byte.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Mirror { timeout, ..self }
+    }
+
+    /// Answers a `GET` request, given its path and query (e.g. `/cards/named?exact=Shock`)
+    pub fn handle(&self, path_and_query: &str) -> MirrorResponse {
+        let (path, query) = path_and_query.split_once('?').unwrap_or((path_and_query, ""));
+        let params: HashMap<String, String> = url::form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect();
+
+        let result = match path.trim_end_matches('/') {
+            "/cards/named" => self.named(&params),
+            "/cards/search" => self.search(&params),
+            path => match path.strip_prefix("/cards/") {
+                Some(id) if !id.contains('/') => self.card(id),
+                _ => Err(error(404, "not_found", format!("No endpoint at {path}"))),
+            },
+        };
+
+        match result {
+            Ok(body) => MirrorResponse { status: 200, body },
+            Err(e) => MirrorResponse {
+                status: e.status as u16,
+                body: serde_json::to_string(&e).unwrap_or_default(),
+            },
+        }
+    }
+
+    /// Serves the mirror over HTTP/1.1 on a listener, until accepting a connection fails
+    ///
+    /// Each connection is handled by a new thread, and closed after the response.
+    /// Connections are only accepted while less than the [maximum](Mirror::with_max_connections)
+    /// number of connections are handled.
+    pub fn serve(self, listener: TcpListener) -> io::Result<()>
+        where B: Send + Sync + 'static
+    {
+        let connections = Arc::new(Connections::default());
+        let mirror = Arc::new(self);
+
+        loop {
+            let connection = connections.open(mirror.max_connections);
+            let (stream, _) = listener.accept()?;
+            let mirror = mirror.clone();
+
+            thread::spawn(move || {
+                let _connection = connection;
+                // a failed connection only affects its client
+                let _ = mirror.respond(stream);
+            });
+        }
+    }
+
+    fn card(&self, id: &str) -> Result<String, ErrorBody> {
+        let card = Uuid::parse_str(id).ok()
+            .map(|id| self.backend.get_by_id(&id))
+            .transpose()
+            .map_err(internal_error)?
+            .flatten()
+            .ok_or_else(|| error(404, "not_found", format!("No card found with the given ID `{id}`")))?;
+
+        to_json(&card)
+    }
+
+    fn named(&self, params: &HashMap<String, String>) -> Result<String, ErrorBody> {
+        let printings = match (params.get("exact"), params.get("fuzzy")) {
+            (Some(name), _) => self.backend.get_by_name(name).map_err(internal_error)?,
+            (None, Some(name)) => self.fuzzy(name)?,
+            (None, None) => return Err(error(400, "bad_request", "Either `exact` or `fuzzy` must be provided")),
+        };
+
+        let card = printings.into_iter()
+            .max_by_key(|card| card.released_at)
+            .ok_or_else(|| error(404, "not_found", "No cards found matching the given name"))?;
+
+        to_json(&card)
+    }
+
+    fn fuzzy(&self, name: &str) -> Result<Vec<Card>, ErrorBody> {
+        let exact = self.backend.get_by_name(name).map_err(internal_error)?;
+        if !exact.is_empty() {
+            return Ok(exact);
+        }
+
+        let mut cards = self.matching(name)?;
+        cards.sort_by(|a, b| a.name.cmp(&b.name));
+
+        match cards.first() {
+            Some(first) if cards.iter().any(|card| card.name != first.name) => Err(ErrorBody {
+                error_type: Some("ambiguous".into()),
+                ..error(404, "not_found", "Too many cards match ambiguous name")
+            }),
+            _ => Ok(cards),
+        }
+    }
+
+    fn search(&self, params: &HashMap<String, String>) -> Result<String, ErrorBody> {
+        let q = params.get("q")
+            .map(|q| q.trim())
+            .filter(|q| !q.is_empty())
+            .ok_or_else(|| error(400, "bad_request", "You didn't provide any query"))?;
+
+        if q.contains(&[':', '=', '<', '>', '(', ')'][..]) {
+            return Err(error(422, "bad_request", "Only searches by name are supported by this mirror"));
+        }
+
+        let page = match params.get("page").map(|p| p.parse::<usize>()) {
+            None => 1,
+            Some(Ok(page)) if page > 0 => page,
+            Some(_) => return Err(error(422, "bad_request", "Invalid page")),
+        };
+
+        let mut cards = self.matching(q)?;
+        if cards.is_empty() {
+            return Err(error(404, "not_found", "Your query didn't match any cards"));
+        }
+        cards.sort_by(|a, b| a.name.cmp(&b.name).then(b.released_at.cmp(&a.released_at)));
+
+        let start = (page - 1).saturating_mul(self.page_size);
+        let has_more = cards.len() > start.saturating_add(self.page_size);
+        let total_cards = cards.len() as i64;
+
+        to_json(&CardPage {
+            data: cards.into_iter().skip(start).take(self.page_size).collect(),
+            has_more,
+            kind: ResourceKind::List,
+            next_page: match has_more {
+                true => Some(self.next_page(q, page + 1)),
+                false => None,
+            },
+            total_cards,
+        })
+    }
+
+    /// Gets the cards whose names contain all words of a text (case insensitive)
+    fn matching(&self, text: &str) -> Result<Vec<Card>, ErrorBody> {
+        let words: Vec<String> = text.split_whitespace().map(str::to_lowercase).collect();
+        let first = match words.first() {
+            Some(first) => first,
+            None => return Ok(vec![]),
+        };
+
+        Ok(self.backend.search_basic(first).map_err(internal_error)?
+            .into_iter()
+            .filter(|card| {
+                let name = card.name.to_lowercase();
+                words.iter().all(|word| name.contains(word.as_str()))
+            })
+            .collect())
+    }
+
+    fn next_page(&self, q: &str, page: usize) -> Url {
+        let mut url = self.base_url.join("cards/search").unwrap_or_else(|_| self.base_url.clone());
+        url.query_pairs_mut()
+            .append_pair("q", q)
+            .append_pair("page", &page.to_string());
+        url
+    }
+
+    fn respond(&self, mut stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let head = HeadReader { stream: stream.try_clone()?, deadline: Instant::now() + self.timeout };
+        let mut reader = BufReader::new(head.take(MAX_HEAD_LENGTH));
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+
+        // the headers are not used, but have to be read before responding
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 2 {
+            line.clear();
+        }
+
+        let response = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+            ["GET", target, _] => self.handle(target),
+            [_, _, _] => MirrorResponse {
+                status: 405,
+                body: serde_json::to_string(&error(405, "method_not_allowed", "Only GET requests are supported"))
+                    .unwrap_or_default(),
+            },
+            _ => return Ok(()),
+        };
+
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response.status,
+            reason(response.status),
+            response.body.len(),
+            response.body,
+        )?;
+        stream.flush()
+    }
+}
+
+/// Reader of the head of a request, failing once its deadline has passed
+struct HeadReader {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl Read for HeadReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.deadline.checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::TimedOut, "request head not received in time"))?;
+
+        self.stream.set_read_timeout(Some(remaining))?;
+        self.stream.read(buf)
+    }
+}
+
+/// Number of connections handled by a server
+#[derive(Default)]
+struct Connections {
+    count: Mutex<usize>,
+    closed: Condvar,
+}
+
+impl Connections {
+    /// Waits until less than `max` connections are handled, and counts a new connection
+    fn open(self: &Arc<Self>, max: usize) -> Connection {
+        let mut count = self.count.lock().unwrap_or_else(|e| e.into_inner());
+        while *count >= max {
+            count = self.closed.wait(count).unwrap_or_else(|e| e.into_inner());
+        }
+
+        *count += 1;
+        Connection(self.clone())
+    }
+}
+
+/// A handled connection, no longer counted once dropped (even if its thread panics)
+struct Connection(Arc<Connections>);
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        *self.0.count.lock().unwrap_or_else(|e| e.into_inner()) -= 1;
+        self.0.closed.notify_one();
+    }
+}
+
+fn error(status: i16, code: &str, details: impl Into<String>) -> ErrorBody {
+    ErrorBody {
+        code: code.into(),
+        status,
+        ..ErrorBody::client_error(details)
+    }
+}
+
+fn internal_error(e: impl Display) -> ErrorBody {
+    error(500, "internal_error", e.to_string())
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, ErrorBody> {
+    serde_json::to_string(value).map_err(internal_error)
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use crate::store::backend::MemoryStore;
+    use rstest::{fixture, rstest};
+    use serde_json::{json, Value};
+
+    #[fixture]
+    fn mirror() -> Mirror<MemoryStore> {
+        Mirror::new(MemoryStore::from(vec![
+            card(json!({ "id": "00000000-0000-0000-0000-000000000001", "name": "Shock", "released_at": "2018-01-01" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000002", "name": "Shock", "released_at": "2020-01-01" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000003", "name": "Shock Troops" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000004", "name": "Lightning Bolt" })),
+        ])).with_page_size(2)
+    }
+
+    fn json(response: &MirrorResponse) -> Value {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    #[rstest]
+    fn card_should_be_found_by_id(mirror: Mirror<MemoryStore>) {
+        let response = mirror.handle("/cards/00000000-0000-0000-0000-000000000004");
+
+        assert_eq!(200, response.status);
+        assert_eq!("Lightning Bolt", json(&response)["name"]);
+    }
+
+    #[rstest]
+    #[case::exact("/cards/named?exact=shock", "2020-01-01")]
+    #[case::fuzzy("/cards/named?fuzzy=bol", "2022-06-10")]
+    fn latest_printing_should_be_found_by_name(mirror: Mirror<MemoryStore>, #[case] path: &str, #[case] released_at: &str) {
+        let response = mirror.handle(path);
+
+        assert_eq!(200, response.status);
+        assert_eq!(released_at, json(&response)["released_at"]);
+    }
+
+    #[rstest]
+    #[case::unknown_id("/cards/00000000-0000-0000-0000-000000000009", 404, "not_found")]
+    #[case::invalid_id("/cards/abc", 404, "not_found")]
+    #[case::ambiguous("/cards/named?fuzzy=sho", 404, "not_found")]
+    #[case::no_name("/cards/named", 400, "bad_request")]
+    #[case::keywords("/cards/search?q=t%3Acreature", 422, "bad_request")]
+    #[case::no_match("/cards/search?q=counterspell", 404, "not_found")]
+    #[case::unknown_path("/sets", 404, "not_found")]
+    fn errors_should_be_returned_as_error_objects(mirror: Mirror<MemoryStore>, #[case] path: &str, #[case] status: u16, #[case] code: &str) {
+        let response = mirror.handle(path);
+
+        assert_eq!(status, response.status);
+        assert_eq!(json!({ "object": "error", "status": status, "code": code }), json!({
+            "object": json(&response)["object"],
+            "status": json(&response)["status"],
+            "code": json(&response)["code"],
+        }));
+    }
+
+    #[rstest]
+    fn search_should_be_paginated(mirror: Mirror<MemoryStore>) {
+        let first = json(&mirror.handle("/cards/search?q=shock"));
+        let second = json(&mirror.handle("/cards/search?q=shock&page=2"));
+
+        assert_eq!(json!([3, true, "http://localhost/cards/search?q=shock&page=2"]),
+            json!([first["total_cards"], first["has_more"], first["next_page"]]));
+        assert_eq!(json!(["Shock", "Shock"]), json!([first["data"][0]["name"], first["data"][1]["name"]]));
+        assert_eq!(json!([false, "Shock Troops"]), json!([second["has_more"], second["data"][0]["name"]]));
+    }
+
+    #[rstest]
+    #[case::prefix("https://proxy.internal/scryfall")]
+    #[case::trailing_slash("https://proxy.internal/scryfall/")]
+    fn next_pages_should_keep_base_url_prefix(mirror: Mirror<MemoryStore>, #[case] base_url: &str) {
+        let mirror = mirror.with_base_url(base_url.parse().unwrap());
+        let first = json(&mirror.handle("/cards/search?q=shock"));
+
+        assert_eq!(json!("https://proxy.internal/scryfall/cards/search?q=shock&page=2"), first["next_page"]);
+    }
+
+    #[rstest]
+    fn mirror_should_be_served_over_http(mirror: Mirror<MemoryStore>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || mirror.serve(listener));
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "GET /cards/named?exact=Lightning%20Bolt HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains(r#""name":"Lightning Bolt""#));
+    }
+
+    #[rstest]
+    fn idle_connections_should_time_out(mirror: Mirror<MemoryStore>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let mirror = mirror.with_max_connections(1).with_timeout(Duration::from_millis(100));
+        thread::spawn(move || mirror.serve(listener));
+
+        let start = Instant::now();
+        let mut idle = TcpStream::connect(address).unwrap();
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "GET /cards/named?exact=Lightning%20Bolt HTTP/1.1\r\n\r\n").unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        let mut closed = String::new();
+        idle.read_to_string(&mut closed).unwrap();
+        assert!(closed.is_empty());
+    }
+
+    #[rstest]
+    fn slow_request_heads_should_time_out(mirror: Mirror<MemoryStore>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let mirror = mirror.with_timeout(Duration::from_millis(200));
+        thread::spawn(move || mirror.serve(listener));
+
+        let start = Instant::now();
+        let mut stream = TcpStream::connect(address).unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        thread::spawn(move || {
+            for byte in b"GET /cards/named?exact=Lightning%20Bolt HTTP/1.1\r\n\r\n" {
+                if writer.write_all(&[*byte]).is_err() {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(50));
+            }
+        });
+
+        let mut response = String::new();
+        let _ = stream.read_to_string(&mut response);
+
+        assert!(response.is_empty());
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,128 @@
+//! Postgres bulk loading
+//!
+//! Cards (e.g. the `default_cards` bulk data) can be loaded into Postgres with `COPY`,
+//! by creating the [SCHEMA](SCHEMA) and streaming the cards as CSV with [write_copy](write_copy):
+//!
+//! ```sql
+//! COPY cards FROM STDIN WITH (FORMAT csv)
+//! ```
+//!
+//! Commonly queried fields are stored in columns, while the whole card is kept in the
+//! `data` (`jsonb`) column, so that it can be deserialized back into a [Card](Card).
+//!
+//! Postgres loading has to be enabled by the **postgres** optional feature.
+//!
+//! # Example
+//! ```no_run
+//! use std::fs::File;
+//! use scryfall_sdk_rust::postgres::write_copy;
+//! use scryfall_sdk_rust::resources::cards::Card;
+//!
+//! # fn example(cards: Vec<Card>) -> std::io::Result<()> {
+//! // then: psql -c "\copy cards FROM 'cards.csv' WITH (FORMAT csv)"
+//! let rows = write_copy(File::create("cards.csv")?, &cards)?;
+//!
+//! println!("{rows} cards written");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::{self, BufWriter, Write};
+use crate::resources::cards::Card;
+
+/// Schema of the `cards` table (columns in the order written by [write_copy](write_copy))
+pub const SCHEMA: &str = r#"CREATE TABLE IF NOT EXISTS cards (
+    id               uuid PRIMARY KEY,
+    oracle_id        uuid NOT NULL,
+    name             text NOT NULL,
+    set_code         text NOT NULL,
+    collector_number text NOT NULL,
+    lang             text NOT NULL,
+    released_at      date NOT NULL,
+    rarity           text NOT NULL,
+    type_line        text NOT NULL,
+    mana_cost        text,
+    cmc              double precision NOT NULL,
+    digital          boolean NOT NULL,
+    data             jsonb NOT NULL
+);
+CREATE INDEX IF NOT EXISTS cards_oracle_id_idx ON cards (oracle_id);
+CREATE INDEX IF NOT EXISTS cards_name_idx ON cards (lower(name));"#;
+
+/// Writes cards as CSV rows for `COPY cards FROM STDIN WITH (FORMAT csv)`
+///
+/// Returns the number of written rows.
+pub fn write_copy<'a, W, I>(writer: W, cards: I) -> io::Result<usize>
+    where W: Write,
+          I: IntoIterator<Item = &'a Card>
+{
+    let mut writer = BufWriter::new(writer);
+    let mut rows = 0;
+
+    for card in cards {
+        writeln!(writer, "{}", row(card)?)?;
+        rows += 1;
+    }
+
+    writer.flush()?;
+    Ok(rows)
+}
+
+/// Formats a card as a CSV row (missing values are unquoted empty fields, i.e. `NULL`)
+fn row(card: &Card) -> io::Result<String> {
+    let rarity = serde_json::to_value(&card.rarity)?;
+
+    let fields = [
+        Some(card.id.to_string()),
+        Some(card.oracle_id.to_string()),
+        Some(card.name.clone()),
+        Some(card.set.clone()),
+        Some(card.collector_number.clone()),
+        Some(card.lang.clone()),
+        Some(card.released_at.to_string()),
+        rarity.as_str().map(String::from),
+        Some(card.type_line.clone()),
+        card.mana_cost.clone(),
+        Some(card.cmc.to_string()),
+        Some(card.digital.to_string()),
+        Some(serde_json::to_string(card)?),
+    ];
+
+    Ok(fields.iter()
+        .map(|field| field.as_deref().map(quote).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// Quotes a CSV field (quoted empty strings are not `NULL`)
+fn quote(field: &str) -> String {
+    format!("\"{}\"", field.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    #[rstest]
+    fn cards_should_be_written_as_csv_rows() {
+        let card = card(json!({
+            "id": "00000000-0000-0000-0000-000000000001",
+            "name": "\"Ach! Hans, Run!\"",
+            "mana_cost": null,
+            "rarity": "rare",
+        }));
+        let mut csv = vec![];
+
+        let rows = write_copy(&mut csv, [&card]).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+
+        assert_eq!(1, rows);
+        assert!(csv.starts_with("\"00000000-0000-0000-0000-000000000001\","));
+        assert!(csv.contains(",\"\"\"Ach! Hans, Run!\"\"\","));
+        assert!(csv.contains(",\"rare\","));
+        assert_eq!(1, csv.lines().count());
+    }
+}
@@ -0,0 +1,46 @@
+//! Commonly used types, for glob importing
+//!
+//! Brings the clients, the most common resources, the search parameters
+//! and the error type into scope with a single import.
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::prelude::*;
+//!
+//! # async fn example() -> Result<(), ErrorBody> {
+//! let card = default_client().request(&CardResource::NamedExact("Lightning Bolt".into())).await?;
+//!
+//! println!("{}", card.name);
+//! # Ok(())
+//! # }
+//! ```
+
+#[doc(no_inline)]
+pub use crate::{HttpResource, Scryfall, ScryfallBuilder};
+#[doc(no_inline)]
+#[cfg(feature = "blocking")]
+pub use crate::ScryfallBlocking;
+#[doc(no_inline)]
+pub use crate::resources::errors::ErrorBody;
+#[doc(no_inline)]
+pub use crate::{CatalogResource, RulingListResource};
+#[doc(no_inline)]
+#[cfg(feature = "cards")]
+pub use crate::{CardCollectionResource, CardPageResource, CardResource};
+#[doc(no_inline)]
+#[cfg(feature = "cards")]
+pub use crate::resources::cards::{Card, CardIdentifier, CardIdentifiers, SearchQueryParams, UniqueMode};
+#[doc(no_inline)]
+#[cfg(feature = "sets")]
+pub use crate::{CardSetListResource, CardSetResource};
+#[doc(no_inline)]
+#[cfg(feature = "sets")]
+pub use crate::resources::card_sets::CardSet;
+
+/// Creates an async client for the Scryfall API with the default settings
+///
+/// Shorthand for `Scryfall::default()`.
+#[must_use]
+pub fn default_client() -> Scryfall<'static> {
+    Scryfall::default()
+}
@@ -0,0 +1,207 @@
+//! Printing history of cards
+//!
+//! All printings of an oracle card can be fetched (oldest first) as a list of
+//! [Printing](Printing) summaries, using `print_history` of both clients,
+//! e.g. for reprint trackers and price analysis.
+//!
+//! Originals can be distinguished from reprints with [Card::is_first_printing](Card::is_first_printing),
+//! or fetched directly with `original_printing`.
+//!
+//! A `default_cards` bulk dump can be grouped into one [OracleCard](OracleCard) per gameplay card
+//! (with all its printings) using [group_by_oracle](group_by_oracle).
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::Scryfall;
+//! use uuid::uuid;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let oracle_id = uuid!("7bc3f92f-68a2-4934-afc4-89f6d0e8cf98");
+//!
+//! for printing in Scryfall::default().print_history(&oracle_id).await? {
+//!     println!("{} {} ({:?})", printing.released_at, printing.set_name, printing.rarity);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use time::Date;
+use uuid::Uuid;
+use crate::resources::cards::{Card, CardFinish, OrderDirection, OrderField, Rarity, SearchQueryParams, UniqueMode};
+
+/// Summary of a card printing
+#[derive(Clone, Debug, PartialEq)]
+pub struct Printing {
+    pub id: Uuid,
+    pub set: String,
+    pub set_name: String,
+    pub collector_number: String,
+    pub released_at: Date,
+    pub rarity: Rarity,
+    pub finishes: Vec<CardFinish>,
+    pub digital: bool,
+}
+
+impl From<&Card> for Printing {
+    fn from(card: &Card) -> Self {
+        Printing {
+            id: card.id,
+            set: card.set.clone(),
+            set_name: card.set_name.clone(),
+            collector_number: card.collector_number.clone(),
+            released_at: card.released_at,
+            rarity: card.rarity.clone(),
+            finishes: card.finishes.clone(),
+            digital: card.digital,
+        }
+    }
+}
+
+/// Search for all printings of an oracle card, oldest first
+pub fn prints_search(oracle_id: &Uuid) -> SearchQueryParams {
+    let mut params = SearchQueryParams::with_q(&format!("oracleid:{oracle_id}"));
+    params.unique = Some(UniqueMode::Prints);
+    params.order = Some(OrderField::Released);
+    params.dir = Some(OrderDirection::Asc);
+    params
+}
+
+/// Summarizes printings ordered by release date (then set and collector number)
+pub fn timeline<'a>(cards: impl IntoIterator<Item = &'a Card>) -> Vec<Printing> {
+    let mut printings: Vec<Printing> = cards.into_iter().map(Printing::from).collect();
+
+    printings.sort_by(|a, b| a.released_at.cmp(&b.released_at)
+        .then_with(|| a.set.cmp(&b.set))
+        .then_with(|| a.collector_number.cmp(&b.collector_number))
+    );
+
+    printings
+}
+
+/// Gets the original (earliest) printing
+pub fn original(printings: &[Printing]) -> Option<&Printing> {
+    printings.iter().min_by_key(|p| p.released_at)
+}
+
+impl Card {
+    /// Checks if the card is the original printing, given the printing history of its oracle card
+    ///
+    /// The history can be fetched with `print_history` of both clients.
+    pub fn is_first_printing(&self, history: &[Printing]) -> bool {
+        matches!(original(history), Some(p) if p.id == self.id)
+    }
+}
+
+/// A gameplay card with all its printings
+#[derive(Clone, Debug, PartialEq)]
+pub struct OracleCard {
+    /// The printing representing the card (see [group_by_oracle](group_by_oracle))
+    pub canonical: Card,
+
+    /// All printings of the card (including the canonical one), ordered by release date
+    pub printings: Vec<Card>,
+}
+
+impl OracleCard {
+    /// Gets the oracle id of the card
+    pub fn oracle_id(&self) -> Uuid {
+        self.canonical.oracle_id
+    }
+}
+
+/// Groups printings (e.g. a `default_cards` bulk dump) by their oracle id
+///
+/// Cards are returned in the order their oracle id first appears. The canonical printing
+/// is the most recent English, non-promo, non-variation paper printing (when available),
+/// similar to the `oracle_cards` bulk file.
+pub fn group_by_oracle(cards: impl IntoIterator<Item = Card>) -> Vec<OracleCard> {
+    let mut order = vec![];
+    let mut groups: HashMap<Uuid, Vec<Card>> = HashMap::new();
+
+    for card in cards {
+        let printings = groups.entry(card.oracle_id).or_insert_with(|| {
+            order.push(card.oracle_id);
+            vec![]
+        });
+        printings.push(card);
+    }
+
+    order.into_iter()
+        .filter_map(|oracle_id| groups.remove(&oracle_id))
+        .filter_map(|mut printings| {
+            printings.sort_by_key(|c| c.released_at);
+
+            let canonical = printings.iter()
+                .max_by_key(|c| (c.lang == "en", !c.variation, !c.promo, !c.digital, c.released_at))?
+                .clone();
+
+            Some(OracleCard { canonical, printings })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    #[rstest]
+    fn printings_should_be_ordered_by_release_date() {
+        let cards = vec![
+            card(json!({ "set": "m10", "released_at": "2009-07-17", "rarity": "uncommon" })),
+            card(json!({ "set": "lea", "released_at": "1993-08-05", "finishes": ["nonfoil"] })),
+            card(json!({ "set": "2x2", "released_at": "2022-07-08", "finishes": ["nonfoil", "foil"] })),
+        ];
+
+        let printings = timeline(&cards);
+
+        assert_eq!(vec!["lea", "m10", "2x2"], printings.iter().map(|p| p.set.as_str()).collect::<Vec<_>>());
+        assert_eq!(Rarity::Uncommon, printings[1].rarity);
+        assert_eq!(vec![CardFinish::NonFoil, CardFinish::Foil], printings[2].finishes);
+    }
+
+    #[rstest]
+    fn first_printing_should_be_detected() {
+        let reprint = card(json!({ "id": "00000000-0000-0000-0000-000000000002", "released_at": "2009-07-17" }));
+        let first = card(json!({ "id": "00000000-0000-0000-0000-000000000001", "released_at": "1993-08-05" }));
+
+        let history = timeline([&reprint, &first]);
+
+        assert!(first.is_first_printing(&history));
+        assert!(!reprint.is_first_printing(&history));
+        assert!(!first.is_first_printing(&[]));
+    }
+
+    #[rstest]
+    fn printings_should_be_grouped_by_oracle_id() {
+        let other = "00000000-0000-0000-0000-000000000009";
+        let cards = vec![
+            card(json!({ "id": "00000000-0000-0000-0000-000000000001", "released_at": "1993-08-05" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000002", "oracle_id": other })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000003", "released_at": "2023-01-01", "promo": true })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000004", "released_at": "2024-01-01", "lang": "ja" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000005", "released_at": "2009-07-17" })),
+        ];
+
+        let grouped = group_by_oracle(cards);
+        let ids = |c: &OracleCard| c.printings.iter().map(|p| p.id.as_u128()).collect::<Vec<_>>();
+
+        assert_eq!(2, grouped.len());
+        assert_eq!(vec![1, 5, 3, 4], ids(&grouped[0]));
+        assert_eq!(5, grouped[0].canonical.id.as_u128());
+        assert_eq!(other, grouped[1].oracle_id().to_string());
+    }
+
+    #[rstest]
+    fn prints_search_should_be_ordered_by_release_date() {
+        let params = prints_search(&Uuid::nil());
+
+        assert_eq!(
+            "?q=oracleid:00000000-0000-0000-0000-000000000000&unique=prints&order=released&dir=asc",
+            params.as_query_str()
+        );
+    }
+}
@@ -0,0 +1,490 @@
+//! Typed Scryfall search queries
+//!
+//! A [SearchQuery](SearchQuery) is the syntax tree of a query written in the
+//! [Scryfall search syntax](https://scryfall.com/docs/syntax), e.g. `t:goblin (c:r or c:b) -is:promo`.
+//! Queries can be parsed from user input, inspected or modified, and rendered back
+//! (normalized) with `Display`:
+//!
+//! ```
+//! use scryfall_sdk_rust::query::{Operator, SearchQuery};
+//!
+//! let query: SearchQuery = "t:goblin  (c:r OR c:b) -is:promo".parse().unwrap();
+//!
+//! assert!(matches!(&query, SearchQuery::And(terms) if terms.len() == 3));
+//! assert_eq!("t:goblin (c:r or c:b) -is:promo", query.to_string());
+//!
+//! let cmc = SearchQuery::filter("cmc", Operator::LessOrEqual, "3");
+//! assert_eq!("t:goblin (c:r or c:b) -is:promo cmc<=3", query.and(cmc).to_string());
+//! ```
+//!
+//! Queries can be checked for common mistakes before they are sent with [lint](lint)
+//! (including misspelled creature types, against the creature types catalog),
+//! and persisted as parameterized [saved searches](saved).
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use crate::resources::cards::SearchQueryParams;
+
+pub mod lint;
+pub mod saved;
+
+/// A parsed search query
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SearchQuery {
+    /// Search by card name, e.g. `bolt`, `"lightning bolt"` or (exact) `!"Lightning Bolt"`
+    Name {
+        value: String,
+        exact: bool,
+    },
+
+    /// Search by a field, e.g. `t:goblin`, `cmc>=3` or `o:"draw a card"`
+    ///
+    /// Values are kept as written (without the surrounding quotes),
+    /// including regular expressions (e.g. `/^{T}:/`).
+    Filter {
+        field: String,
+        operator: Operator,
+        value: String,
+    },
+
+    /// Negated query, e.g. `-is:promo` or `-(c:r or c:b)`
+    Not(Box<SearchQuery>),
+
+    /// All queries must match (terms separated by whitespace or `and`)
+    And(Vec<SearchQuery>),
+
+    /// Any query must match (terms separated by `or`)
+    Or(Vec<SearchQuery>),
+}
+
+/// Comparison operator of a [filter](SearchQuery::Filter)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operator {
+    /// `:` (field dependent, e.g. contains for text fields)
+    Colon,
+    /// `=`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+}
+
+impl Operator {
+    /// Gets the operator as written in queries
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Colon => ":",
+            Operator::Equal => "=",
+            Operator::NotEqual => "!=",
+            Operator::Less => "<",
+            Operator::LessOrEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterOrEqual => ">=",
+        }
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl SearchQuery {
+    /// Creates a filter query
+    pub fn filter(field: &str, operator: Operator, value: &str) -> Self {
+        SearchQuery::Filter { field: field.into(), operator, value: value.into() }
+    }
+
+    /// Creates a creature type filter (`t:<type>`)
+    ///
+    /// Types can be checked against the creature types catalog with
+    /// [checked_creature_type](SearchQuery::checked_creature_type).
+    pub fn creature_type(value: &str) -> Self {
+        SearchQuery::filter("t", Operator::Colon, value.trim())
+    }
+
+    /// Combines the query with another query (both must match)
+    #[must_use]
+    pub fn and(self, other: SearchQuery) -> Self {
+        match self {
+            SearchQuery::And(mut terms) => {
+                terms.push(other);
+                SearchQuery::And(terms)
+            },
+            query => SearchQuery::And(vec![query, other]),
+        }
+    }
+
+    /// Combines the query with another query (any must match)
+    #[must_use]
+    pub fn or(self, other: SearchQuery) -> Self {
+        match self {
+            SearchQuery::Or(mut terms) => {
+                terms.push(other);
+                SearchQuery::Or(terms)
+            },
+            query => SearchQuery::Or(vec![query, other]),
+        }
+    }
+
+    /// Negates the query
+    #[allow(clippy::should_implement_trait)]
+    #[must_use]
+    pub fn not(self) -> Self {
+        match self {
+            SearchQuery::Not(query) => *query,
+            query => SearchQuery::Not(Box::new(query)),
+        }
+    }
+
+    /// Iterates over all name and filter terms of the query (depth first)
+    pub fn terms(&self) -> Vec<&SearchQuery> {
+        match self {
+            SearchQuery::Name { .. } | SearchQuery::Filter { .. } => vec![self],
+            SearchQuery::Not(query) => query.terms(),
+            SearchQuery::And(queries) | SearchQuery::Or(queries) => queries.iter()
+                .flat_map(SearchQuery::terms)
+                .collect(),
+        }
+    }
+}
+
+impl Display for SearchQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchQuery::Name { value, exact } => {
+                if *exact {
+                    f.write_str("!")?;
+                }
+                f.write_str(&quote(value))
+            },
+            SearchQuery::Filter { field, operator, value } => {
+                write!(f, "{field}{operator}{}", quote(value))
+            },
+            SearchQuery::Not(query) => match query.as_ref() {
+                SearchQuery::And(_) | SearchQuery::Or(_) => write!(f, "-({query})"),
+                query => write!(f, "-{query}"),
+            },
+            SearchQuery::And(queries) => {
+                let terms: Vec<String> = queries.iter()
+                    .map(|query| match query {
+                        SearchQuery::Or(_) => format!("({query})"),
+                        query => query.to_string(),
+                    })
+                    .collect();
+
+                f.write_str(&terms.join(" "))
+            },
+            SearchQuery::Or(queries) => {
+                let terms: Vec<String> = queries.iter().map(ToString::to_string).collect();
+
+                f.write_str(&terms.join(" or "))
+            },
+        }
+    }
+}
+
+impl FromStr for SearchQuery {
+    type Err = ParseQueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(s)?;
+        let mut parser = Parser { tokens: &tokens, position: 0 };
+
+        let query = parser.or()?;
+        match parser.tokens.get(parser.position) {
+            Some((position, _)) => Err(ParseQueryError::new(*position, "unmatched `)`")),
+            None => Ok(query),
+        }
+    }
+}
+
+impl From<&SearchQuery> for SearchQueryParams {
+    fn from(query: &SearchQuery) -> Self {
+        SearchQueryParams::with_q(&query.to_string())
+    }
+}
+
+impl SearchQueryParams {
+    /// Parses the query of the search
+    pub fn query(&self) -> Result<SearchQuery, ParseQueryError> {
+        self.q.parse()
+    }
+}
+
+/// Error when a query cannot be parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseQueryError {
+    /// The (0-based) character position of the error in the query
+    pub position: usize,
+
+    /// Description of the error
+    pub message: String,
+}
+
+impl ParseQueryError {
+    fn new(position: usize, message: &str) -> Self {
+        ParseQueryError { position, message: message.into() }
+    }
+}
+
+impl Display for ParseQueryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid query at position {}: {}", self.position, self.message)
+    }
+}
+
+impl Error for ParseQueryError { }
+
+/// Token of a query (with its character position)
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    Minus,
+    Word(String),
+}
+
+/// Splits a query into parentheses, negations and words (keeping quoted and regex values together)
+fn tokenize(q: &str) -> Result<Vec<(usize, Token)>, ParseQueryError> {
+    let chars: Vec<char> = q.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push((i, Token::Open));
+                i += 1;
+            },
+            ')' => {
+                tokens.push((i, Token::Close));
+                i += 1;
+            },
+            '-' if matches!(chars.get(i + 1), Some(c) if !c.is_whitespace()) => {
+                tokens.push((i, Token::Minus));
+                i += 1;
+            },
+            _ => {
+                let start = i;
+                let mut word = String::new();
+                let mut delimiter = None;
+
+                while i < chars.len() {
+                    let c = chars[i];
+                    match delimiter {
+                        Some(d) if c == d => delimiter = None,
+                        Some(_) => {},
+                        None if c == '"' => delimiter = Some('"'),
+                        None if c == '/' && word.ends_with(|p: char| ":=<>".contains(p)) => delimiter = Some('/'),
+                        None if c.is_whitespace() || c == '(' || c == ')' => break,
+                        None => {},
+                    }
+                    word.push(c);
+                    i += 1;
+                }
+
+                if let Some(d) = delimiter {
+                    let message = if d == '"' { "unmatched quote" } else { "unterminated regular expression" };
+                    return Err(ParseQueryError::new(start, message));
+                }
+                tokens.push((start, Token::Word(word)));
+            },
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [(usize, Token)],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position).map(|(_, token)| token)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn end_position(&self) -> usize {
+        self.tokens.get(self.position)
+            .or_else(|| self.tokens.last())
+            .map_or(0, |(position, _)| *position)
+    }
+
+    fn or(&mut self) -> Result<SearchQuery, ParseQueryError> {
+        let mut queries = vec![self.and()?];
+
+        while self.is_keyword("or") {
+            self.position += 1;
+            queries.push(self.and()?);
+        }
+
+        Ok(single_or(queries, SearchQuery::Or))
+    }
+
+    fn and(&mut self) -> Result<SearchQuery, ParseQueryError> {
+        let mut queries = vec![];
+
+        loop {
+            match self.peek() {
+                None | Some(Token::Close) => break,
+                Some(Token::Word(_)) if self.is_keyword("or") => break,
+                Some(Token::Word(_)) if self.is_keyword("and") && !queries.is_empty() => self.position += 1,
+                _ => queries.push(self.unary()?),
+            }
+        }
+
+        if queries.is_empty() {
+            return Err(ParseQueryError::new(self.end_position(), "expected a search term"));
+        }
+
+        Ok(single_or(queries, SearchQuery::And))
+    }
+
+    fn unary(&mut self) -> Result<SearchQuery, ParseQueryError> {
+        let (position, token) = &self.tokens[self.position];
+        self.position += 1;
+
+        match token {
+            Token::Minus => Ok(SearchQuery::Not(Box::new(self.unary()?))),
+            Token::Open => {
+                let query = self.or()?;
+                match self.peek() {
+                    Some(Token::Close) => {
+                        self.position += 1;
+                        Ok(query)
+                    },
+                    _ => Err(ParseQueryError::new(*position, "unmatched `(`")),
+                }
+            },
+            Token::Close => Err(ParseQueryError::new(*position, "unmatched `)`")),
+            Token::Word(word) => Ok(term(word)),
+        }
+    }
+}
+
+/// Unwraps single queries instead of grouping them
+fn single_or<F>(mut queries: Vec<SearchQuery>, group: F) -> SearchQuery
+    where F: Fn(Vec<SearchQuery>) -> SearchQuery
+{
+    match queries.len() {
+        1 => queries.remove(0),
+        _ => group(queries),
+    }
+}
+
+/// Parses a name or filter term
+fn term(word: &str) -> SearchQuery {
+    if let Some(name) = word.strip_prefix('!') {
+        return SearchQuery::Name { value: unquote(name), exact: true };
+    }
+
+    let field_len = word.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(word.len());
+    let (field, rest) = word.split_at(field_len);
+
+    let operators = [
+        Operator::NotEqual, Operator::LessOrEqual, Operator::GreaterOrEqual,
+        Operator::Colon, Operator::Equal, Operator::Less, Operator::Greater,
+    ];
+    let operator = operators.iter().find(|op| rest.starts_with(op.as_str()));
+
+    match operator {
+        Some(operator) if !field.is_empty() => SearchQuery::Filter {
+            field: field.to_lowercase(),
+            operator: *operator,
+            value: unquote(&rest[operator.as_str().len()..]),
+        },
+        _ => SearchQuery::Name { value: unquote(word), exact: false },
+    }
+}
+
+fn unquote(value: &str) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(unquoted) => unquoted.into(),
+        None => value.into(),
+    }
+}
+
+pub(crate) fn quote(value: &str) -> String {
+    let regex = value.len() > 1 && value.starts_with('/') && value.ends_with('/');
+    // values with quotes (e.g. `a")b"`) can already form a single word, and cannot be quoted again
+    let single_word = value.contains('"')
+        && matches!(tokenize(value).as_deref(), Ok([(0, Token::Word(word))]) if word == value);
+
+    if !regex && !single_word && (value.is_empty() || value.contains(|c: char| c.is_whitespace() || c == '(' || c == ')')) {
+        format!("\"{value}\"")
+    } else {
+        value.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn filter(field: &str, operator: Operator, value: &str) -> SearchQuery {
+        SearchQuery::filter(field, operator, value)
+    }
+
+    #[rstest]
+    #[case::filter("t:goblin", filter("t", Operator::Colon, "goblin"))]
+    #[case::comparison("cmc>=3", filter("cmc", Operator::GreaterOrEqual, "3"))]
+    #[case::not_equal("c!=r", filter("c", Operator::NotEqual, "r"))]
+    #[case::quoted("o:\"draw a card\"", filter("o", Operator::Colon, "draw a card"))]
+    #[case::regex("o:/^{T}: add/", filter("o", Operator::Colon, "/^{T}: add/"))]
+    #[case::name("bolt", SearchQuery::Name { value: "bolt".into(), exact: false })]
+    #[case::exact("!\"Lightning Bolt\"", SearchQuery::Name { value: "Lightning Bolt".into(), exact: true })]
+    #[case::negated("-is:promo", filter("is", Operator::Colon, "promo").not())]
+    #[case::and("t:goblin and c:r", filter("t", Operator::Colon, "goblin").and(filter("c", Operator::Colon, "r")))]
+    #[case::or("c:r OR c:b", filter("c", Operator::Colon, "r").or(filter("c", Operator::Colon, "b")))]
+    fn query_should_be_parsed(#[case] q: &str, #[case] expected: SearchQuery) {
+        assert_eq!(Ok(expected), q.parse());
+    }
+
+    #[rstest]
+    fn or_should_bind_looser_than_and() {
+        let query: SearchQuery = "t:goblin c:r or t:elf".parse().unwrap();
+
+        assert_eq!(
+            filter("t", Operator::Colon, "goblin")
+                .and(filter("c", Operator::Colon, "r"))
+                .or(filter("t", Operator::Colon, "elf")),
+            query
+        );
+    }
+
+    #[rstest]
+    #[case::normalized("T:goblin  AND (c:r OR c:b)", "t:goblin (c:r or c:b)")]
+    #[case::negated_group("-(c:r or c:b) o:\"draw a card\"", "-(c:r or c:b) o:\"draw a card\"")]
+    #[case::exact_name("!\"Fire // Ice\"", "!\"Fire // Ice\"")]
+    #[case::inner_quotes("a\")b\"", "a\")b\"")]
+    fn query_should_be_rendered(#[case] q: &str, #[case] expected: &str) {
+        assert_eq!(expected, q.parse::<SearchQuery>().unwrap().to_string());
+    }
+
+    #[rstest]
+    #[case::quote("o:\"draw", 0, "unmatched quote")]
+    #[case::open("t:goblin (c:r", 9, "unmatched `(`")]
+    #[case::close("c:r)", 3, "unmatched `)`")]
+    #[case::empty("", 0, "expected a search term")]
+    #[case::regex("o:/draw", 0, "unterminated regular expression")]
+    fn invalid_query_should_fail_to_parse(#[case] q: &str, #[case] position: usize, #[case] message: &str) {
+        assert_eq!(Err(ParseQueryError::new(position, message)), q.parse::<SearchQuery>());
+    }
+}
@@ -0,0 +1,371 @@
+//! Query linting
+//!
+//! Checks search queries for common mistakes before they are sent:
+//! syntax errors (e.g. unmatched quotes), unknown fields (with the closest known field
+//! as a suggestion) and impossible numeric constraints (e.g. `cmc>3 cmc<2`).
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::query::lint::{lint, Severity};
+//!
+//! let diagnostics = lint("typ:goblin cmc>3 cmc<2");
+//!
+//! assert_eq!(2, diagnostics.len());
+//! assert_eq!(Some("type".into()), diagnostics[0].suggestion);
+//! assert_eq!(Severity::Error, diagnostics[1].severity);
+//! ```
+//!
+//! Types can be checked against a [type catalog](TypeCatalog) while building queries,
+//! e.g. creature types with [checked_creature_type](SearchQuery::checked_creature_type):
+//!
+//! ```no_run
+//! use scryfall_sdk_rust::{CatalogResource, Scryfall};
+//! use scryfall_sdk_rust::query::SearchQuery;
+//!
+//! # async fn example(client: Scryfall<'_>) -> Result<(), scryfall_sdk_rust::resources::errors::ErrorBody> {
+//! let creature_types = client.type_catalog(CatalogResource::CreatureTypes).await?;
+//!
+//! match SearchQuery::checked_creature_type("Elff", &creature_types) {
+//!     Ok(query) => println!("searching {query}"),
+//!     Err(diagnostic) => println!("{diagnostic}"), // error: unknown creature type `Elff` (did you mean `Elf`?)
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::{Display, Formatter};
+use crate::query::{Operator, SearchQuery};
+use crate::resources::catalog::TypeCatalog;
+
+/// Fields (and their aliases) known to the search syntax
+pub const FIELDS: &[&str] = &[
+    "a", "art", "artist", "artists", "atag", "arttag", "b", "banned", "block", "border",
+    "c", "cardmarket", "ci", "cmc", "cn", "color", "commander", "cube", "date", "devotion",
+    "dir", "direction", "display", "e", "edition", "eur", "f", "flavor", "fo", "format",
+    "frame", "ft", "fulloracle", "function", "game", "has", "id", "identity", "illustrations",
+    "in", "include", "is", "keyword", "kw", "lang", "language", "legal", "loy", "loyalty",
+    "m", "mana", "manavalue", "mtgo", "multiverse", "mv", "name", "new", "not", "number", "o",
+    "oracle", "oracletag", "order", "otag", "paperprints", "papersets", "pow", "power",
+    "powtou", "prefer", "prints", "produces", "pt", "r", "rarity", "restricted", "s", "set",
+    "sets", "st", "stamp", "t", "tix", "tou", "toughness", "type", "unique", "usd", "watermark",
+    "wm", "year",
+];
+
+/// Severity of a [diagnostic](Diagnostic)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The query is likely to fail or match nothing
+    Error,
+    /// The query is valid, but possibly not what was intended
+    Warning,
+}
+
+/// A problem found in a query
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+
+    /// Description of the problem
+    pub message: String,
+
+    /// The term with the problem (as rendered), if it can be attributed to one
+    pub term: Option<String>,
+
+    /// The (0-based) character position of syntax errors
+    pub position: Option<usize>,
+
+    /// A suggested replacement (e.g. for a misspelled field)
+    pub suggestion: Option<String>,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{severity}: {}", self.message)?;
+
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean `{suggestion}`?)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Lints a query string
+///
+/// Queries that cannot be parsed produce a single error with the position of the problem.
+pub fn lint(q: &str) -> Vec<Diagnostic> {
+    match q.parse::<SearchQuery>() {
+        Ok(query) => query.lint(),
+        Err(err) => {
+            let suggestion = match err.message.as_str() {
+                "unmatched quote" => Some(format!("{q}\"")),
+                "unmatched `(`" => Some(format!("{q})")),
+                _ => None,
+            };
+
+            vec![Diagnostic {
+                severity: Severity::Error,
+                message: err.message,
+                term: None,
+                position: Some(err.position),
+                suggestion,
+            }]
+        },
+    }
+}
+
+impl SearchQuery {
+    /// Lints the query (see [lint](crate::query::lint))
+    pub fn lint(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+
+        for term in self.terms() {
+            if let SearchQuery::Filter { field, .. } = term {
+                if !FIELDS.contains(&field.as_str()) {
+                    diagnostics.push(Diagnostic {
+                        severity: Severity::Warning,
+                        message: format!("unknown field `{field}`"),
+                        term: Some(term.to_string()),
+                        position: None,
+                        suggestion: closest_field(field).map(String::from),
+                    });
+                }
+            }
+        }
+
+        check_ranges(self, &mut diagnostics);
+        diagnostics
+    }
+
+    /// Creates a creature type filter (see [creature_type](SearchQuery::creature_type)),
+    /// checked against the creature types catalog
+    ///
+    /// Known types are written as spelled in the catalog. Unknown types produce an error,
+    /// with the closest type of the catalog as a suggestion.
+    pub fn checked_creature_type(value: &str, types: &TypeCatalog) -> Result<SearchQuery, Diagnostic> {
+        match types.get(value) {
+            Some(known) => Ok(SearchQuery::creature_type(known)),
+            None => Err(Diagnostic {
+                severity: Severity::Error,
+                message: format!("unknown creature type `{}`", value.trim()),
+                term: Some(SearchQuery::creature_type(value).to_string()),
+                position: None,
+                suggestion: closest_type(types, value).map(String::from),
+            }),
+        }
+    }
+}
+
+/// Finds the known field with the smallest edit distance (at most 2)
+fn closest_field(field: &str) -> Option<&'static str> {
+    FIELDS.iter()
+        .map(|known| (distance(field, known), *known))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, known)| (*distance, known.len()))
+        .map(|(_, known)| known)
+}
+
+/// Finds the type of a catalog with the smallest edit distance (at most 2, ignoring case)
+fn closest_type<'a>(types: &'a TypeCatalog, value: &str) -> Option<&'a str> {
+    let value = value.trim().to_lowercase();
+
+    types.values().iter()
+        .map(|known| (distance(&value, &known.to_lowercase()), known.as_str()))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, known)| (*distance, known.len()))
+        .map(|(_, known)| known)
+}
+
+/// Levenshtein distance of two strings
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(current)
+            };
+            previous = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Gets the canonical name of numeric fields
+fn numeric_field(field: &str) -> Option<&'static str> {
+    match field {
+        "cmc" | "mv" | "manavalue" => Some("cmc"),
+        "pow" | "power" => Some("pow"),
+        "tou" | "toughness" => Some("tou"),
+        "loy" | "loyalty" => Some("loy"),
+        "usd" => Some("usd"),
+        "eur" => Some("eur"),
+        "tix" => Some("tix"),
+        "year" => Some("year"),
+        _ => None,
+    }
+}
+
+/// Bound of a numeric range (value and whether it is inclusive)
+type Bound = Option<(f64, bool)>;
+
+/// Checks the numeric filters of every group of terms that must all match
+fn check_ranges(query: &SearchQuery, diagnostics: &mut Vec<Diagnostic>) {
+    match query {
+        SearchQuery::And(queries) => {
+            let mut ranges: Vec<(&str, Bound, Bound, Vec<String>)> = vec![];
+
+            for query in queries {
+                if let SearchQuery::Filter { field, operator, value } = query {
+                    let (field, value) = match (numeric_field(field), value.parse::<f64>()) {
+                        (Some(field), Ok(value)) => (field, value),
+                        _ => continue,
+                    };
+
+                    let index = match ranges.iter().position(|(f, ..)| *f == field) {
+                        Some(index) => index,
+                        None => {
+                            ranges.push((field, None, None, vec![]));
+                            ranges.len() - 1
+                        },
+                    };
+                    let (_, lower, upper, terms) = &mut ranges[index];
+
+                    match operator {
+                        Operator::Colon | Operator::Equal => {
+                            *lower = tighter(*lower, (value, true), true);
+                            *upper = tighter(*upper, (value, true), false);
+                        },
+                        Operator::Greater => *lower = tighter(*lower, (value, false), true),
+                        Operator::GreaterOrEqual => *lower = tighter(*lower, (value, true), true),
+                        Operator::Less => *upper = tighter(*upper, (value, false), false),
+                        Operator::LessOrEqual => *upper = tighter(*upper, (value, true), false),
+                        Operator::NotEqual => continue,
+                    }
+                    terms.push(query.to_string());
+                } else {
+                    check_ranges(query, diagnostics);
+                }
+            }
+
+            for (_, lower, upper, terms) in ranges {
+                if let (Some((low, low_inclusive)), Some((high, high_inclusive))) = (lower, upper) {
+                    if low > high || (low == high && !(low_inclusive && high_inclusive)) {
+                        diagnostics.push(Diagnostic {
+                            severity: Severity::Error,
+                            message: format!("impossible constraint `{}` never matches", terms.join(" ")),
+                            term: Some(terms.join(" ")),
+                            position: None,
+                            suggestion: None,
+                        });
+                    }
+                }
+            }
+        },
+        SearchQuery::Or(queries) => queries.iter().for_each(|query| check_ranges(query, diagnostics)),
+        SearchQuery::Not(query) => check_ranges(query, diagnostics),
+        SearchQuery::Name { .. } | SearchQuery::Filter { .. } => {},
+    }
+}
+
+/// Gets the tighter of two lower (or upper) bounds
+fn tighter(current: Bound, bound: (f64, bool), lower: bool) -> Bound {
+    let (value, inclusive) = bound;
+
+    match current {
+        Some((current_value, current_inclusive)) => {
+            let replace = if lower { value > current_value } else { value < current_value };
+
+            if replace || (value == current_value && !inclusive) {
+                Some(bound)
+            } else {
+                Some((current_value, current_inclusive))
+            }
+        },
+        None => Some(bound),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::valid("t:goblin cmc<=3 (c:r or c:b) -is:promo")]
+    #[case::alias("mv>=2 manavalue<=2")]
+    #[case::alternatives("cmc>3 or cmc<2")]
+    #[case::not_numeric("cmc>x cmc<2")]
+    fn valid_query_should_have_no_diagnostics(#[case] q: &str) {
+        assert_eq!(Vec::<Diagnostic>::new(), lint(q));
+    }
+
+    #[rstest]
+    #[case::misspelled("typ:goblin", Some("type"))]
+    #[case::unknown("quux:bar", None)]
+    fn unknown_field_should_be_reported(#[case] q: &str, #[case] suggestion: Option<&str>) {
+        let diagnostics = lint(q);
+
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(Severity::Warning, diagnostics[0].severity);
+        assert_eq!(Some(q.into()), diagnostics[0].term);
+        assert_eq!(suggestion.map(String::from), diagnostics[0].suggestion);
+    }
+
+    #[rstest]
+    #[case::disjoint("cmc>3 cmc<2", "cmc>3 cmc<2")]
+    #[case::exclusive("pow>2 power<=2", "pow>2 power<=2")]
+    #[case::equal("t:elf (cmc=1 mv=2)", "cmc=1 mv=2")]
+    fn impossible_constraint_should_be_reported(#[case] q: &str, #[case] term: &str) {
+        let diagnostics = lint(q);
+
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(Severity::Error, diagnostics[0].severity);
+        assert_eq!(Some(term.into()), diagnostics[0].term);
+    }
+
+    #[rstest]
+    #[case::exact("Elf", Ok("t:Elf"))]
+    #[case::case_insensitive("time lord", Ok("t:\"Time Lord\""))]
+    #[case::misspelled("Elff", Err(Some("Elf")))]
+    #[case::misspelled_case("gobiln", Err(Some("Goblin")))]
+    #[case::unknown("Squirrelfolk", Err(None))]
+    fn creature_types_should_be_checked(#[case] value: &str, #[case] expected: Result<&str, Option<&str>>) {
+        let types = TypeCatalog::new(["Elf", "Goblin", "Time Lord"]);
+
+        let checked = SearchQuery::checked_creature_type(value, &types)
+            .map(|query| query.to_string())
+            .map_err(|diagnostic| diagnostic.suggestion);
+
+        assert_eq!(expected.map(String::from).map_err(|s| s.map(String::from)), checked);
+    }
+
+    #[rstest]
+    fn unknown_creature_type_should_be_reported() {
+        let diagnostic = SearchQuery::checked_creature_type("Elff", &TypeCatalog::new(["Elf"])).unwrap_err();
+
+        assert_eq!(Some("t:Elff".into()), diagnostic.term);
+        assert_eq!("error: unknown creature type `Elff` (did you mean `Elf`?)", diagnostic.to_string());
+    }
+
+    #[rstest]
+    fn syntax_error_should_be_reported_with_position() {
+        let diagnostics = lint("t:goblin o:\"draw");
+
+        assert_eq!(1, diagnostics.len());
+        assert_eq!(Some(9), diagnostics[0].position);
+        assert_eq!(Some("t:goblin o:\"draw\"".into()), diagnostics[0].suggestion);
+        assert_eq!("error: unmatched quote (did you mean `t:goblin o:\"draw\"`?)", diagnostics[0].to_string());
+    }
+}
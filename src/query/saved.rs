@@ -0,0 +1,199 @@
+//! Saved searches
+//!
+//! A [SavedSearch](SavedSearch) bundles a query template with default parameter values
+//! and search options, so that applications can persist user searches (it is serializable)
+//! and re-render them later with other values.
+//!
+//! Placeholders are written as `$name` (letters, digits and underscores).
+//! Values containing whitespace are quoted when substituted.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::query::saved::SavedSearch;
+//! use scryfall_sdk_rust::resources::cards::{CardPageResource, UniqueMode};
+//!
+//! let mut search = SavedSearch::new("Cheap tribal", "t:$tribe cmc<=$cmc")
+//!     .with_default("cmc", "3");
+//! search.unique = Some(UniqueMode::Prints);
+//!
+//! assert_eq!(Ok("t:goblin cmc<=3".to_string()), search.render(&[("tribe", "goblin")]));
+//!
+//! let params = search.params(&[("tribe", "goblin"), ("cmc", "2")]).unwrap();
+//! let resource = CardPageResource::Search(params);
+//! // client.request(&resource) ...
+//! ```
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::query::quote;
+use crate::resources::cards::{OrderDirection, OrderField, SearchQueryParams, UniqueMode};
+
+/// A named, parameterized search
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub name: String,
+
+    /// The query, with `$name` placeholders
+    pub template: String,
+
+    /// Values of placeholders that are not given when rendering
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub defaults: BTreeMap<String, String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unique: Option<UniqueMode>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<OrderField>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dir: Option<OrderDirection>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_extras: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_multilingual: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_variations: Option<bool>,
+}
+
+impl SavedSearch {
+    /// Creates a saved search without defaults and options
+    pub fn new(name: &str, template: &str) -> Self {
+        SavedSearch {
+            name: name.into(),
+            template: template.into(),
+            ..SavedSearch::default()
+        }
+    }
+
+    /// Sets the default value of a placeholder
+    #[must_use]
+    pub fn with_default(mut self, placeholder: &str, value: &str) -> Self {
+        self.defaults.insert(placeholder.into(), value.into());
+        self
+    }
+
+    /// Gets the placeholders of the template (in order of first appearance)
+    pub fn placeholders(&self) -> Vec<&str> {
+        let mut placeholders = vec![];
+
+        for (_, name) in placeholders_of(&self.template) {
+            if !placeholders.contains(&name) {
+                placeholders.push(name);
+            }
+        }
+        placeholders
+    }
+
+    /// Renders the query, substituting placeholders with the given values (or their defaults)
+    pub fn render(&self, values: &[(&str, &str)]) -> Result<String, MissingParameterError> {
+        let mut query = String::new();
+        let mut end = 0;
+
+        for (start, name) in placeholders_of(&self.template) {
+            let value = values.iter()
+                .find(|(placeholder, _)| *placeholder == name)
+                .map(|(_, value)| *value)
+                .or_else(|| self.defaults.get(name).map(String::as_str))
+                .ok_or_else(|| MissingParameterError { name: name.into() })?;
+
+            query.push_str(&self.template[end..start]);
+            query.push_str(&quote(value));
+            end = start + 1 + name.len();
+        }
+        query.push_str(&self.template[end..]);
+
+        Ok(query)
+    }
+
+    /// Renders the search parameters (query and options) to execute the search
+    pub fn params(&self, values: &[(&str, &str)]) -> Result<SearchQueryParams, MissingParameterError> {
+        let mut params = SearchQueryParams::with_q(&self.render(values)?);
+
+        params.unique = self.unique;
+        params.order = self.order;
+        params.dir = self.dir;
+        params.include_extras = self.include_extras;
+        params.include_multilingual = self.include_multilingual;
+        params.include_variations = self.include_variations;
+
+        Ok(params)
+    }
+}
+
+/// Finds the `$name` placeholders of a template (with the byte position of the `$`)
+fn placeholders_of(template: &str) -> Vec<(usize, &str)> {
+    template.match_indices('$')
+        .filter_map(|(start, _)| {
+            let rest = &template[start + 1..];
+            let len = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len());
+
+            match len {
+                0 => None,
+                _ => Some((start, &rest[..len])),
+            }
+        })
+        .collect()
+}
+
+/// Error when a placeholder has neither a value nor a default
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingParameterError {
+    /// The name of the placeholder
+    pub name: String,
+}
+
+impl Display for MissingParameterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "missing value of parameter `{}`", self.name)
+    }
+}
+
+impl Error for MissingParameterError { }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[rstest]
+    #[case::given(&[("tribe", "goblin"), ("cmc", "2")], "t:goblin cmc<=2 -t:goblin")]
+    #[case::default(&[("tribe", "elf")], "t:elf cmc<=3 -t:elf")]
+    #[case::quoted(&[("tribe", "elf druid")], "t:\"elf druid\" cmc<=3 -t:\"elf druid\"")]
+    fn search_should_be_rendered(#[case] values: &[(&str, &str)], #[case] expected: &str) {
+        let search = SavedSearch::new("tribal", "t:$tribe cmc<=$cmc -t:$tribe").with_default("cmc", "3");
+
+        assert_eq!(vec!["tribe", "cmc"], search.placeholders());
+        assert_eq!(Ok(expected.to_string()), search.render(values));
+    }
+
+    #[rstest]
+    fn missing_parameter_should_fail() {
+        let search = SavedSearch::new("tribal", "t:$tribe");
+
+        assert_eq!(Err(MissingParameterError { name: "tribe".into() }), search.render(&[]));
+    }
+
+    #[rstest]
+    fn search_should_be_serializable() {
+        let mut search = SavedSearch::new("cheap", "usd<$price").with_default("price", "1");
+        search.order = Some(OrderField::Usd);
+
+        let value = serde_json::to_value(&search).unwrap();
+        assert_eq!(json!({
+            "name": "cheap",
+            "template": "usd<$price",
+            "defaults": { "price": "1" },
+            "order": "usd",
+        }), value);
+
+        assert_eq!(search, serde_json::from_value(value).unwrap());
+        assert_eq!("?q=usd<1&order=usd", search.params(&[]).unwrap().as_query_str());
+    }
+}
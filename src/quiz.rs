@@ -0,0 +1,205 @@
+//! Building blocks for card name quizzes
+//!
+//! Helpers for trivia bots and guessing games:
+//! - [redacted_text](redacted_text) blanks out the name of a card in its oracle text
+//!   (with `~`, like Scryfall does), including the short names of legendary cards
+//! - [distractors](distractors) picks wrong answers from a list of card names
+//!   (e.g. the `card-names` catalog)
+//! - [art_crop](art_crop) gets the URL of the art of a card, without its title
+//!
+//! A multiple-choice [Question](Question) combines all of them.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::quiz::redact;
+//!
+//! assert_eq!(
+//!     "When ~ enters, draw a card.",
+//!     redact("When Elvish Visionary enters, draw a card.", "Elvish Visionary", false)
+//! );
+//! ```
+
+use url::Url;
+use crate::images::ImageSize;
+use crate::random::Rng;
+use crate::resources::cards::Card;
+
+/// A multiple-choice question about the name of a card
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Question {
+    /// The name of the card
+    pub answer: String,
+
+    /// The oracle text of the card, with its name blanked out (if any)
+    pub text: Option<String>,
+
+    /// The art of the card (if any)
+    pub art_crop: Option<Url>,
+
+    /// The answer and the distractors, in random order
+    pub choices: Vec<String>,
+}
+
+impl Question {
+    /// Creates a question about a card, with `choices` choices picked from the given names
+    pub fn new(card: &Card, names: &[String], choices: usize) -> Self {
+        let mut choices: Vec<String> = distractors(names, &card.name, choices.saturating_sub(1))
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let position = Rng::new().below(choices.len() + 1);
+        choices.insert(position, card.name.clone());
+
+        Question {
+            answer: card.name.clone(),
+            text: redacted_text(card),
+            art_crop: art_crop(card).cloned(),
+            choices,
+        }
+    }
+
+    /// Checks an answer (ignoring letter case and surrounding whitespace)
+    pub fn is_correct(&self, answer: &str) -> bool {
+        self.answer.eq_ignore_ascii_case(answer.trim())
+    }
+}
+
+/// Replaces a name in a text with `~`
+///
+/// Only whole-word occurrences are replaced. For legendary cards, the short name
+/// (the part before the comma, e.g. `Urza` for `Urza, Lord High Artificer`) is replaced too.
+pub fn redact(text: &str, name: &str, legendary: bool) -> String {
+    let mut text = replace_words(text, name);
+
+    if legendary {
+        if let Some((short, _)) = name.split_once(", ") {
+            text = replace_words(&text, short);
+        }
+    }
+    text
+}
+
+/// Gets the oracle text of a card with its name (and the names of its faces) blanked out
+///
+/// The texts of multi-faced cards are joined with `//` lines.
+pub fn redacted_text(card: &Card) -> Option<String> {
+    let legendary = card.type_line.contains("Legendary");
+
+    let texts: Vec<String> = match &card.card_faces {
+        Some(faces) if card.oracle_text.is_none() => faces.iter()
+            .filter_map(|face| face.oracle_text.as_deref())
+            .map(|text| faces.iter().fold(
+                redact(text, &card.name, legendary),
+                |text, face| redact(&text, &face.name, legendary),
+            ))
+            .collect(),
+        _ => card.oracle_text.iter()
+            .map(|text| redact(text, &card.name, legendary))
+            .collect(),
+    };
+
+    match texts.is_empty() {
+        true => None,
+        false => Some(texts.join("\n//\n")),
+    }
+}
+
+/// Picks `n` distinct random names (other than the answer)
+pub fn distractors<'a>(names: &'a [String], answer: &str, n: usize) -> Vec<&'a str> {
+    let candidates: Vec<&str> = names.iter()
+        .map(String::as_str)
+        .filter(|name| !name.eq_ignore_ascii_case(answer))
+        .collect();
+
+    Rng::new().sample(candidates.len(), n).into_iter()
+        .map(|i| candidates[i])
+        .collect()
+}
+
+/// Gets the art crop (art without title and frame) of the front face of a card
+pub fn art_crop(card: &Card) -> Option<&Url> {
+    card.face_image_uris(0).map(|uris| ImageSize::ArtCrop.uri(uris))
+}
+
+/// Replaces whole-word occurrences of a word in a text with `~`
+fn replace_words(text: &str, word: &str) -> String {
+    if word.is_empty() {
+        return text.into();
+    }
+
+    let is_word_char = |c: Option<char>| matches!(c, Some(c) if c.is_alphanumeric());
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(word) {
+        let end = start + word.len();
+        let before = rest[..start].chars().next_back();
+        let after = rest[end..].chars().next();
+
+        result.push_str(&rest[..start]);
+        if is_word_char(before) || is_word_char(after) {
+            result.push_str(word);
+        } else {
+            result.push('~');
+        }
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[rstest]
+    #[case::name("Shock deals 2 damage to any target.", "Shock", false, "~ deals 2 damage to any target.")]
+    #[case::whole_words("Shockwave: Shock", "Shock", false, "Shockwave: ~")]
+    #[case::legendary("Urza, Lord High Artificer enters. Urza gets +1/+1.", "Urza, Lord High Artificer", true,
+        "~ enters. ~ gets +1/+1.")]
+    #[case::not_legendary("Urza's Tower", "Urza, Lord High Artificer", false, "Urza's Tower")]
+    fn name_should_be_redacted(#[case] text: &str, #[case] name: &str, #[case] legendary: bool, #[case] expected: &str) {
+        assert_eq!(expected, redact(text, name, legendary));
+    }
+
+    #[rstest]
+    fn faces_should_be_redacted() {
+        let card = card(json!({
+            "name": "Fire // Ice",
+            "card_faces": [
+                { "object": "card_face", "name": "Fire", "mana_cost": "{1}{R}", "oracle_text": "Fire deals 2 damage." },
+                { "object": "card_face", "name": "Ice", "mana_cost": "{1}{U}", "oracle_text": "Tap target permanent." },
+            ],
+        }));
+
+        assert_eq!(Some("~ deals 2 damage.\n//\nTap target permanent.".into()), redacted_text(&card));
+    }
+
+    #[rstest]
+    fn distractors_should_exclude_answer() {
+        let names: Vec<String> = ["Shock", "Opt", "Duress", "Giant Growth"].iter().map(|n| n.to_string()).collect();
+
+        let picked = distractors(&names, "shock", 5);
+
+        assert_eq!(3, picked.len());
+        assert!(!picked.contains(&"Shock"));
+    }
+
+    #[rstest]
+    fn question_should_contain_answer() {
+        let names: Vec<String> = ["Opt", "Duress", "Giant Growth"].iter().map(|n| n.to_string()).collect();
+        let card = card(json!({ "name": "Shock", "oracle_text": "Shock deals 2 damage to any target." }));
+
+        let question = Question::new(&card, &names, 3);
+
+        assert_eq!(3, question.choices.len());
+        assert!(question.choices.contains(&"Shock".to_string()));
+        assert_eq!(Some("~ deals 2 damage to any target.".into()), question.text);
+        assert!(question.is_correct(" shock "));
+    }
+}
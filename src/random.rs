@@ -0,0 +1,73 @@
+//! Random number generation for sampling helpers
+//!
+//! A small SplitMix64 generator seeded from the (randomly keyed) std hasher,
+//! which is good enough for picking cards and avoids a dependency on `rand`.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    /// Creates a randomly seeded generator
+    pub(crate) fn new() -> Self {
+        Rng(RandomState::new().build_hasher().finish())
+    }
+
+    /// Creates a generator with a fixed seed (e.g. for tests)
+    #[cfg(any(test, feature = "test-util"))]
+    pub(crate) fn with_seed(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Gets a number in `0..n` (`n` must not be zero)
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// Picks `n` distinct numbers in `0..total` (all of them if `n >= total`), in random order
+    pub(crate) fn sample(&mut self, total: usize, n: usize) -> Vec<usize> {
+        // Partial Fisher-Yates shuffle, keeping only the swapped positions
+        let mut swapped: HashMap<usize, usize> = HashMap::new();
+
+        (0..n.min(total))
+            .map(|i| {
+                let j = i + self.below(total - i);
+                let picked = *swapped.get(&j).unwrap_or(&j);
+                let current = *swapped.get(&i).unwrap_or(&i);
+                swapped.insert(j, current);
+                picked
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::some(100, 10)]
+    #[case::all(10, 10)]
+    #[case::more_than_total(5, 10)]
+    fn sample_should_be_distinct_and_in_range(#[case] total: usize, #[case] n: usize) {
+        let sample = Rng::with_seed(42).sample(total, n);
+        let distinct: HashSet<&usize> = sample.iter().collect();
+
+        assert_eq!(n.min(total), sample.len());
+        assert_eq!(sample.len(), distinct.len());
+        assert!(sample.iter().all(|i| *i < total));
+    }
+}
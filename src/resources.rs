@@ -3,19 +3,57 @@
 use crate::resources::errors::ErrorBody;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use url::Url;
 
+/// Implements `Display` for resources, rendering their [encoded path](encode_path)
+macro_rules! display_path {
+    ($($resource:ident $(<$lt:lifetime>)?),+ $(,)?) => {$(
+        impl$(<$lt>)? std::fmt::Display for $resource$(<$lt>)? {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&crate::resources::encode_path(&HttpResource::path(self)))
+            }
+        }
+    )+};
+}
+
+pub(crate) use display_path;
+
+#[cfg(feature = "rkyv")]
+pub mod archive;
+#[cfg(feature = "bulk")]
 pub mod bulk_data;
+#[cfg(feature = "sets")]
 pub mod card_sets;
+#[cfg(feature = "symbols")]
 pub mod card_symbols;
+#[cfg(feature = "cards")]
 pub mod cards;
 pub mod catalog;
 pub mod errors;
+pub mod lenient;
+#[cfg(feature = "cards")]
+pub mod migrations;
 pub mod rulings;
+pub mod validation;
 
 /// Represents an HTTP resource (endpoint)
 ///
 /// This is used as a parameter to [Scryfall](super::Scryfall)
 /// in order to make a request to the api.
+///
+/// All resources of the SDK implement `Display`, rendering the exact relative URL
+/// (with the encoded query) they request:
+///
+/// ```
+/// use scryfall_sdk_rust::CardResource;
+///
+/// let resource = CardResource::NamedExact("Lightning Bolt".into());
+/// assert_eq!("cards/named?exact=Lightning%20Bolt", resource.to_string());
+/// ```
 pub trait HttpResource<R: for<'de> Deserialize<'de>> {
     /// Defines the HTTP method for the endpoint
     fn method(&self) -> Method {
@@ -35,6 +73,29 @@ pub trait HttpResource<R: for<'de> Deserialize<'de>> {
         None
     }
 
+    /// Gets the expected `object` kind of the endpoint's response (if known)
+    ///
+    /// Used by the clients to report [unexpected objects](ErrorBody::unexpected_object).
+    fn kind(&self) -> Option<ResourceKind> {
+        None
+    }
+
+    /// Validates the parsed model of the endpoint
+    ///
+    /// Returns the violations found (see [validation](crate::resources::validation)).
+    /// By default, models are not validated.
+    fn validate(&self, _model: &R) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Gets the normalized key of a search endpoint (if the endpoint is a search)
+    ///
+    /// Searches are cached under this key in the search cache of the clients (if enabled),
+    /// so that equivalent searches share the same cached pages.
+    fn search_key(&self) -> Option<String> {
+        None
+    }
+
     /// Strips the query parameters (if any) from the endpoint path
     /// 
     /// # Example
@@ -63,6 +124,63 @@ pub trait HttpResource<R: for<'de> Deserialize<'de>> {
     }
 }
 
+/// Converts a resource parameter into an owned one (see e.g. `CardResource::into_owned`)
+pub(crate) fn owned(value: Cow<str>) -> Cow<'static, str> {
+    Cow::Owned(value.into_owned())
+}
+
+/// Checks a set code parameter (2 to 6 alphanumeric characters, e.g. `clb` or `pmh2`)
+pub(crate) fn set_code_param<'a>(code: impl Into<Cow<'a, str>>) -> Result<Cow<'a, str>, ErrorBody> {
+    let code = code.into();
+
+    match (2..=6).contains(&code.len()) && code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        true => Ok(code),
+        false => Err(ErrorBody::invalid_input(format!("Invalid set code `{code}`"))),
+    }
+}
+
+/// Checks a collector number parameter
+///
+/// Collector numbers are strings (e.g. `123a`, `★12` or `1-2`), which cannot be empty
+/// nor contain whitespace or URL delimiters (`/`, `?`, `#`).
+pub(crate) fn collector_number_param<'a>(number: impl Into<Cow<'a, str>>) -> Result<Cow<'a, str>, ErrorBody> {
+    let number = number.into();
+    let invalid = number.is_empty() || number.chars().any(|c| c.is_whitespace() || matches!(c, '/' | '?' | '#' | '%'));
+
+    match invalid {
+        false => Ok(number),
+        true => Err(ErrorBody::invalid_input(format!("Invalid collector number `{number}`"))),
+    }
+}
+
+/// Checks a card name parameter (not blank)
+#[cfg(feature = "cards")]
+pub(crate) fn name_param<'a>(name: impl Into<Cow<'a, str>>) -> Result<Cow<'a, str>, ErrorBody> {
+    let name = name.into();
+
+    match name.trim().is_empty() {
+        false => Ok(name),
+        true => Err(ErrorBody::invalid_input("Card names cannot be blank")),
+    }
+}
+
+/// Encodes an endpoint path (relative to the base url) as it is sent by the clients
+///
+/// Paths are percent-encoded the same way `reqwest` encodes the request URL,
+/// e.g. `cards/named?exact=Lightning Bolt` is sent as `cards/named?exact=Lightning%20Bolt`.
+pub(crate) fn encode_path(path: &str) -> String {
+    let encoded = Url::parse("https://api.scryfall.com/")
+        .and_then(|base| base.join(path));
+
+    match encoded {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{query}", &url.path()[1..]),
+            None => url.path()[1..].to_string(),
+        },
+        Err(_) => path.into(),
+    }
+}
+
 /// Represents a Response with two different states:
 /// 
 /// - Ok -> containing a Model representation for the resource (e.g. Card)
@@ -86,7 +204,11 @@ pub enum Response<M>{
 ///
 /// Essentially this includes all the available core resources from the API
 /// plus `list` which refers to a collection of resources.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+///
+/// Object types not known to this SDK are deserialized as [Unknown](ResourceKind::Unknown),
+/// so that new API resources do not break existing models.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 #[serde(rename_all = "snake_case")]
 pub enum ResourceKind {
     /// `object` -> `bulk_data`
@@ -113,6 +235,9 @@ pub enum ResourceKind {
     /// `object` -> `mana_cost`
     ManaCost,
 
+    /// `object` -> `migration`
+    Migration,
+
     /// `object` -> `related_card`
     #[serde(rename = "related_card")]
     Related,
@@ -122,4 +247,125 @@ pub enum ResourceKind {
 
     /// `object` -> `set`
     Set,
+
+    /// Any other `object` value
+    #[serde(other)]
+    Unknown,
+}
+
+impl ResourceKind {
+    /// Gets the `object` value of the kind
+    ///
+    /// # Example
+    /// ```
+    /// use scryfall_sdk_rust::resources::ResourceKind;
+    ///
+    /// assert_eq!("related_card", ResourceKind::Related.as_str());
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResourceKind::BulkData => "bulk_data",
+            ResourceKind::Card => "card",
+            ResourceKind::CardFace => "card_face",
+            ResourceKind::CardSymbol => "card_symbol",
+            ResourceKind::Catalog => "catalog",
+            ResourceKind::Error => "error",
+            ResourceKind::List => "list",
+            ResourceKind::ManaCost => "mana_cost",
+            ResourceKind::Migration => "migration",
+            ResourceKind::Related => "related_card",
+            ResourceKind::Ruling => "ruling",
+            ResourceKind::Set => "set",
+            ResourceKind::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for ResourceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses an `object` value, falling back to [Unknown](ResourceKind::Unknown)
+impl FromStr for ResourceKind {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "bulk_data" => ResourceKind::BulkData,
+            "card" => ResourceKind::Card,
+            "card_face" => ResourceKind::CardFace,
+            "card_symbol" => ResourceKind::CardSymbol,
+            "catalog" => ResourceKind::Catalog,
+            "error" => ResourceKind::Error,
+            "list" => ResourceKind::List,
+            "mana_cost" => ResourceKind::ManaCost,
+            "migration" => ResourceKind::Migration,
+            "related_card" => ResourceKind::Related,
+            "ruling" => ResourceKind::Ruling,
+            "set" => ResourceKind::Set,
+            _ => ResourceKind::Unknown,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::code("clb", true)]
+    #[case::promo_code("pmh2", true)]
+    #[case::empty("", false)]
+    #[case::too_long("toolong", false)]
+    #[case::traversal("../x", false)]
+    fn set_codes_should_be_checked(#[case] code: &str, #[case] valid: bool) {
+        assert_eq!(valid, set_code_param(code).is_ok());
+    }
+
+    #[rstest]
+    #[case::number("691", true)]
+    #[case::suffix("123a", true)]
+    #[case::star("★12", true)]
+    #[case::dash("1-2", true)]
+    #[case::empty("", false)]
+    #[case::slash("12/3", false)]
+    #[case::query("1?q=x", false)]
+    #[case::space("1 2", false)]
+    fn collector_numbers_should_be_checked(#[case] number: &str, #[case] valid: bool) {
+        let result = collector_number_param(number);
+
+        assert_eq!(valid, result.is_ok());
+        if let Err(e) = result {
+            assert_eq!(("INVALID_INPUT", 400), (e.code.as_str(), e.status));
+        }
+    }
+
+    #[rstest]
+    #[case::card("card", ResourceKind::Card)]
+    #[case::related("related_card", ResourceKind::Related)]
+    #[case::unknown("vanguard_thing", ResourceKind::Unknown)]
+    fn resource_kind_should_parse_object_values(#[case] value: &str, #[case] expected: ResourceKind) {
+        assert_eq!(expected, value.parse().unwrap());
+        assert_eq!(expected, serde_json::from_str(&format!("\"{value}\"")).unwrap());
+    }
+
+    #[rstest]
+    #[case::bulk_data(ResourceKind::BulkData, "bulk_data")]
+    #[case::related(ResourceKind::Related, "related_card")]
+    fn resource_kind_should_display_object_value(#[case] kind: ResourceKind, #[case] expected: &str) {
+        assert_eq!(expected, kind.to_string());
+        assert_eq!(format!("\"{expected}\""), serde_json::to_string(&kind).unwrap());
+    }
+
+    #[rstest]
+    #[case::plain("cards/123", "cards/123")]
+    #[case::space("cards/named?exact=Lightning Bolt", "cards/named?exact=Lightning%20Bolt")]
+    #[case::encoded("cards/search?q=t%3Aelf", "cards/search?q=t%3Aelf")]
+    #[case::query("cards/search?q=c:r t:instant&page=2", "cards/search?q=c:r%20t:instant&page=2")]
+    fn encode_path_should_encode_as_sent(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(expected, encode_path(path));
+    }
 }
@@ -0,0 +1,183 @@
+//! Zero-copy archives of models
+//!
+//! With the **rkyv** feature, the API models (cards, sets, symbols, rulings, catalogs,
+//! bulk data and migrations) derive [rkyv](https://docs.rs/rkyv/0.7)'s `Archive`, `Serialize`
+//! and `Deserialize`, so that applications can persist them and load them back without
+//! going through JSON, reading fields in place from the archived bytes (e.g. a memory-mapped file).
+//!
+//! Archived models are checked with `bytecheck` (`archive(check_bytes)`), so that archives
+//! read from disk can be validated with `rkyv::check_archived_root` before being accessed.
+//!
+//! Fields of foreign types are archived with the wrappers of this module:
+//! - URLs as strings ([AsUrl](AsUrl))
+//! - dates as Julian day numbers ([AsJulianDay](AsJulianDay))
+//! - timestamps as Unix timestamps in nanoseconds ([AsUnixNanos](AsUnixNanos))
+//!
+//! Values that cannot be restored (only possible with archives not written by the SDK)
+//! are deserialized as `about:invalid` URLs, [unknown dates](super::lenient::UNKNOWN_DATE)
+//! and the Unix epoch respectively.
+//!
+//! # Example
+//! ```
+//! use rkyv::Deserialize;
+//! use scryfall_sdk_rust::resources::catalog::Catalog;
+//! use scryfall_sdk_rust::resources::ResourceKind;
+//!
+//! let catalog = Catalog {
+//!     kind: ResourceKind::Catalog,
+//!     uri: None,
+//!     total_values: 1,
+//!     data: vec!["Goblin".into()],
+//! };
+//!
+//! let bytes = rkyv::to_bytes::<_, 256>(&catalog).unwrap();
+//! let archived = rkyv::check_archived_root::<Catalog>(&bytes).unwrap();
+//! assert_eq!("Goblin", archived.data[0].as_str());
+//!
+//! let deserialized: Catalog = archived.deserialize(&mut rkyv::Infallible).unwrap();
+//! assert_eq!(catalog, deserialized);
+//! ```
+
+use rkyv::string::{ArchivedString, StringResolver};
+use rkyv::with::{ArchiveWith, DeserializeWith, SerializeWith};
+use rkyv::{Archive, Archived, Fallible, Resolver, Serialize, SerializeUnsized};
+use time::{Date, OffsetDateTime};
+use url::Url;
+use super::lenient::UNKNOWN_DATE;
+
+/// Archives a [Url](Url) as a string
+pub struct AsUrl;
+
+/// Archives a [Date](Date) as its Julian day number
+pub struct AsJulianDay;
+
+/// Archives an [OffsetDateTime](OffsetDateTime) as a Unix timestamp in nanoseconds (in UTC)
+pub struct AsUnixNanos;
+
+impl ArchiveWith<Url> for AsUrl {
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    unsafe fn resolve_with(field: &Url, pos: usize, resolver: StringResolver, out: *mut ArchivedString) {
+        ArchivedString::resolve_from_str(field.as_str(), pos, resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<Url, S> for AsUrl
+    where str: SerializeUnsized<S>
+{
+    fn serialize_with(field: &Url, serializer: &mut S) -> Result<StringResolver, S::Error> {
+        ArchivedString::serialize_from_str(field.as_str(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<ArchivedString, Url, D> for AsUrl {
+    fn deserialize_with(field: &ArchivedString, _: &mut D) -> Result<Url, D::Error> {
+        Ok(Url::parse(field.as_str())
+            .unwrap_or_else(|_| Url::parse("about:invalid").expect("Expected a valid placeholder URL")))
+    }
+}
+
+impl ArchiveWith<Date> for AsJulianDay {
+    type Archived = Archived<i32>;
+    type Resolver = Resolver<i32>;
+
+    unsafe fn resolve_with(field: &Date, pos: usize, resolver: Resolver<i32>, out: *mut Archived<i32>) {
+        field.to_julian_day().resolve(pos, resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<Date, S> for AsJulianDay {
+    fn serialize_with(field: &Date, serializer: &mut S) -> Result<Resolver<i32>, S::Error> {
+        field.to_julian_day().serialize(serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<Archived<i32>, Date, D> for AsJulianDay {
+    fn deserialize_with(field: &Archived<i32>, _: &mut D) -> Result<Date, D::Error> {
+        Ok(Date::from_julian_day(*field).unwrap_or(UNKNOWN_DATE))
+    }
+}
+
+impl ArchiveWith<OffsetDateTime> for AsUnixNanos {
+    type Archived = Archived<i128>;
+    type Resolver = Resolver<i128>;
+
+    unsafe fn resolve_with(field: &OffsetDateTime, pos: usize, resolver: Resolver<i128>, out: *mut Archived<i128>) {
+        field.unix_timestamp_nanos().resolve(pos, resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<OffsetDateTime, S> for AsUnixNanos {
+    fn serialize_with(field: &OffsetDateTime, serializer: &mut S) -> Result<Resolver<i128>, S::Error> {
+        field.unix_timestamp_nanos().serialize(serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<Archived<i128>, OffsetDateTime, D> for AsUnixNanos {
+    fn deserialize_with(field: &Archived<i128>, _: &mut D) -> Result<OffsetDateTime, D::Error> {
+        Ok(OffsetDateTime::from_unix_timestamp_nanos(*field).unwrap_or(OffsetDateTime::UNIX_EPOCH))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rkyv::Deserialize;
+    use rstest::rstest;
+
+    #[rstest]
+    #[cfg(feature = "cards")]
+    fn cards_should_round_trip() {
+        use crate::resources::cards::Card;
+
+        let card = crate::fixtures::card(serde_json::json!({
+            "name": "Lightning Bolt",
+            "released_at": "2009-07-17",
+            "card_faces": [{ "object": "card_face", "name": "Lightning Bolt", "mana_cost": "{R}" }]
+        }));
+
+        let bytes = rkyv::to_bytes::<_, 4096>(&card).unwrap();
+        let archived = rkyv::check_archived_root::<Card>(&bytes).unwrap();
+
+        assert_eq!("Lightning Bolt", archived.name.as_str());
+        assert_eq!(card, archived.deserialize(&mut rkyv::Infallible).unwrap());
+    }
+
+    #[rstest]
+    #[cfg(feature = "bulk")]
+    fn timestamps_should_round_trip() {
+        use crate::resources::bulk_data::BulkData;
+
+        let bulk: BulkData = serde_json::from_value(serde_json::json!({
+            "object": "bulk_data",
+            "id": "27bf3214-1271-490b-bdfe-c0be6c23d02e",
+            "type": "oracle_cards",
+            "updated_at": "2022-06-18T09:02:10.928+00:00",
+            "uri": "https://some-url.com",
+            "name": "Oracle Cards",
+            "description": "A description",
+            "compressed_size": 13976935,
+            "download_uri": "https://some-url.com",
+            "content_type": "application/json",
+            "content_encoding": "gzip",
+        })).unwrap();
+
+        let bytes = rkyv::to_bytes::<_, 1024>(&bulk).unwrap();
+        let archived = rkyv::check_archived_root::<BulkData>(&bytes).unwrap();
+
+        assert_eq!(bulk, archived.deserialize(&mut rkyv::Infallible).unwrap());
+    }
+
+    #[rstest]
+    fn unrestorable_values_should_be_replaced() {
+        let bytes = rkyv::to_bytes::<_, 64>(&String::from("not a url")).unwrap();
+        let invalid_url = rkyv::check_archived_root::<String>(&bytes).unwrap();
+
+        let date = <AsJulianDay as DeserializeWith<_, Date, _>>::deserialize_with(&i32::MAX, &mut rkyv::Infallible);
+        let url = <AsUrl as DeserializeWith<_, Url, _>>::deserialize_with(invalid_url, &mut rkyv::Infallible);
+
+        assert_eq!(Ok(UNKNOWN_DATE), date);
+        assert_eq!("about:invalid", url.unwrap().as_str());
+    }
+}
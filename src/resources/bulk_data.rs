@@ -2,6 +2,7 @@
 //!
 //! See [Scryfall api documentation](https://scryfall.com/docs/api/bulk-data)
 
+use std::borrow::Cow;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use time::serde::iso8601;
@@ -9,7 +10,8 @@ use url::Url;
 
 use BulkDataResource::*;
 
-use crate::resources::{HttpResource, ResourceKind};
+use crate::resources::{owned, HttpResource, ResourceKind};
+use crate::resources::validation::{expect_kind, Validate};
 
 /// Endpoints for `/bulk-data` resource (list)
 pub enum BulkDataListResource {
@@ -18,6 +20,9 @@ pub enum BulkDataListResource {
 }
 
 /// Endpoints for `/bulk-data/*` resource (single)
+///
+/// Parameters are either borrowed or owned (see [CardResource](crate::CardResource)).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum BulkDataResource<'a> {
     /// Binding for endpoints:
     /// - `GET /bulk-data/:id`
@@ -26,12 +31,38 @@ pub enum BulkDataResource<'a> {
     /// The Scryfall api exposes two different endpoints,
     /// but since they provide the same functionality (filter-by-value),
     /// they both are covered by this binding.
-    Filter(&'a str),
+    Filter(Cow<'a, str>),
 }
 
 impl HttpResource<BulkDataList> for BulkDataListResource {
     fn path(&self) -> String {
-        format!("bulk-data")
+        "bulk-data".into()
+    }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::List)
+    }
+
+    fn validate(&self, model: &BulkDataList) -> Vec<String> {
+        model.violations()
+    }
+}
+
+impl BulkDataResource<'_> {
+    /// Gets a bulk data item by its kind (e.g. `oracle_cards`)
+    pub fn by_kind(kind: &BulkDataKind) -> BulkDataResource<'static> {
+        let kind = serde_json::to_value(kind).ok()
+            .and_then(|kind| kind.as_str().map(String::from))
+            .unwrap_or_default();
+
+        Filter(kind.into())
+    }
+
+    /// Converts the resource into one owning its parameters
+    pub fn into_owned(self) -> BulkDataResource<'static> {
+        match self {
+            Filter(by) => Filter(owned(by)),
+        }
     }
 }
 
@@ -41,10 +72,21 @@ impl<'a> HttpResource<BulkData> for BulkDataResource<'a> {
             Filter(by) => format!("bulk-data/{}", by),
         }
     }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::BulkData)
+    }
+
+    fn validate(&self, model: &BulkData) -> Vec<String> {
+        model.violations()
+    }
 }
 
+crate::resources::display_path!(BulkDataListResource, BulkDataResource<'a>);
+
 /// Basic struct representing bulk data list
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct BulkDataList {
     #[serde(rename = "object")]
     pub kind: ResourceKind,
@@ -52,8 +94,17 @@ pub struct BulkDataList {
     pub data: Vec<BulkData>,
 }
 
+impl Validate for BulkDataList {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::List).into_iter()
+            .chain(self.data.iter().flat_map(Validate::violations))
+            .collect()
+    }
+}
+
 /// A bulk data entry
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct BulkData {
     #[serde(rename = "object")]
     pub item_kind: ResourceKind,
@@ -61,20 +112,82 @@ pub struct BulkData {
     #[serde(rename = "type")]
     pub kind: BulkDataKind,
     #[serde(with = "iso8601")]
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUnixNanos))]
     pub updated_at: OffsetDateTime,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub uri: Url,
     pub name: String,
     pub description: String,
     pub compressed_size: i64,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub download_uri: Url,
     pub content_type: String,
     pub content_encoding: String,
 }
 
+impl BulkData {
+    /// Checks if the file at `download_uri` is listed as gzip-encoded (`content_encoding` is `gzip`)
+    ///
+    /// This is what the bulk data entry announces: whether a downloaded file is actually
+    /// compressed is told by the `Content-Encoding` of its response (see [BulkDownload]).
+    pub fn is_gzip(&self) -> bool {
+        self.content_encoding.eq_ignore_ascii_case("gzip")
+    }
+
+    /// Gets the name of the downloaded file, after its kind (e.g. `oracle_cards.json`)
+    ///
+    /// See [BulkDownload::file_name] for the name of a compressed download.
+    pub fn file_name(&self) -> String {
+        let kind = serde_json::to_value(&self.kind).ok()
+            .and_then(|kind| kind.as_str().map(String::from))
+            .unwrap_or_else(|| self.id.clone());
+
+        format!("{kind}.json")
+    }
+}
+
+/// A downloaded bulk data file (see [download_bulk](crate::Scryfall::download_bulk))
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BulkDownload {
+    /// The number of bytes written
+    pub bytes: u64,
+
+    /// The `Content-Encoding` header of the response, if any
+    pub content_encoding: Option<String>,
+}
+
+impl BulkDownload {
+    /// Checks if the file was served (and written) gzip-compressed
+    ///
+    /// The SDK has no gzip decoder, so compressed files have to be decompressed before parsing.
+    pub fn is_gzip(&self) -> bool {
+        self.content_encoding.as_deref()
+            .map(|encoding| encoding.eq_ignore_ascii_case("gzip"))
+            .unwrap_or_default()
+    }
+
+    /// Gets the name of the written file, with a `.gz` extension if it is [gzip-compressed](BulkDownload::is_gzip)
+    ///
+    /// The extension is not added twice (e.g. to `oracle_cards.json.gz`).
+    pub fn file_name(&self, file: &str) -> String {
+        match self.is_gzip() && !file.ends_with(".gz") {
+            true => format!("{file}.gz"),
+            false => file.into(),
+        }
+    }
+}
+
+impl Validate for BulkData {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.item_kind, ResourceKind::BulkData).into_iter().collect()
+    }
+}
+
 /// Kind of bulk data
 ///
 /// This refers to Scryfall `bulk_data.type` field
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 #[serde(rename_all = "snake_case")]
 pub enum BulkDataKind {
     /// `type` -> `all_cards`
@@ -92,3 +205,47 @@ pub enum BulkDataKind {
     /// `type` -> `unique_artwork`
     UniqueArtwork,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::by_kind(BulkDataResource::by_kind(&BulkDataKind::OracleCards), "bulk-data/oracle_cards")]
+    fn bulk_data_resource_constructors_should_build_paths(#[case] resource: BulkDataResource, #[case] expected: &str) {
+        assert_eq!(expected, resource.path());
+    }
+
+    #[rstest]
+    #[case::gzip(Some("gzip"), "oracle_cards.json", "oracle_cards.json.gz")]
+    #[case::gzip_uppercase(Some("GZIP"), "oracle_cards.json", "oracle_cards.json.gz")]
+    #[case::gz_extension(Some("gzip"), "oracle_cards.json.gz", "oracle_cards.json.gz")]
+    #[case::identity(None, "oracle_cards.json", "oracle_cards.json")]
+    fn download_file_names_should_follow_content_encoding(#[case] content_encoding: Option<&str>, #[case] file: &str, #[case] expected: &str) {
+        let download = BulkDownload { bytes: 0, content_encoding: content_encoding.map(String::from) };
+
+        assert_eq!(expected, download.file_name(file));
+    }
+
+    #[rstest]
+    #[case::gzip("gzip")]
+    #[case::identity("")]
+    fn file_names_should_ignore_listed_content_encoding(#[case] content_encoding: &str) {
+        let bulk: BulkData = serde_json::from_value(serde_json::json!({
+            "object": "bulk_data",
+            "id": "27bf3214-1271-490b-bdfe-c0be6c23d02e",
+            "type": "oracle_cards",
+            "updated_at": "2022-06-18T09:02:10.928+00:00",
+            "uri": "https://some-url.com",
+            "name": "Oracle Cards",
+            "description": "A description",
+            "compressed_size": 13976935,
+            "download_uri": "https://some-url.com",
+            "content_type": "application/json",
+            "content_encoding": content_encoding,
+        })).unwrap();
+
+        assert_eq!("oracle_cards.json", bulk.file_name());
+    }
+}
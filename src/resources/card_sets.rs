@@ -2,13 +2,17 @@
 //!
 //! See [Scryfall api documentation](https://scryfall.com/docs/api/sets)
 
+use std::borrow::Cow;
 use serde::{Deserialize, Serialize};
 use time::Date;
 use url::Url;
 use CardSetResource::{Filter, WithTcgPlayerId};
 
 use crate::HttpResource;
-use crate::resources::ResourceKind;
+use crate::resources::{owned, set_code_param, ResourceKind};
+use crate::resources::errors::ErrorBody;
+use crate::resources::lenient::UNKNOWN_DATE;
+use crate::resources::validation::{expect_kind, Validate};
 
 /// Endpoints for `/sets` resource (list)
 pub enum CardSetListResource {
@@ -17,6 +21,9 @@ pub enum CardSetListResource {
 }
 
 /// Endpoints for `/sets/*` resource (single)
+///
+/// Parameters are either borrowed or owned (see [CardResource](crate::CardResource)).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CardSetResource<'a> {
     /// Binding for endpoints:
     /// - `GET /sets/:code`
@@ -25,16 +32,44 @@ pub enum CardSetResource<'a> {
     /// The Scryfall api exposes two different endpoints,
     /// but since they provide the same functionality (filter-by-value),
     /// they both are covered by this binding.
-    Filter(&'a str),
+    Filter(Cow<'a, str>),
 
     /// Binding for endpoint `GET /sets/tcgplayer/:id`
-    WithTcgPlayerId(&'a str),
+    WithTcgPlayerId(Cow<'a, str>),
 }
 
 impl HttpResource<CardSetList> for CardSetListResource {
     fn path(&self) -> String {
+        "sets".into()
+    }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::List)
+    }
+
+    fn validate(&self, model: &CardSetList) -> Vec<String> {
+        model.violations()
+    }
+}
+
+impl<'a> CardSetResource<'a> {
+    /// Gets a set by its code (e.g. `clb`)
+    ///
+    /// Fails with an `INVALID_INPUT` error for invalid set codes.
+    pub fn by_code(code: impl Into<Cow<'a, str>>) -> Result<Self, ErrorBody> {
+        set_code_param(code).map(Filter)
+    }
+
+    /// Gets a set by its Tcgplayer group id
+    pub fn by_tcgplayer_id(id: u32) -> CardSetResource<'static> {
+        WithTcgPlayerId(id.to_string().into())
+    }
+
+    /// Converts the resource into one owning its parameters
+    pub fn into_owned(self) -> CardSetResource<'static> {
         match self {
-            _ => format!("sets")
+            Filter(by) => Filter(owned(by)),
+            WithTcgPlayerId(id) => WithTcgPlayerId(owned(id)),
         }
     }
 }
@@ -48,10 +83,21 @@ impl<'a> HttpResource<CardSet> for CardSetResource<'a> {
             WithTcgPlayerId(id) => format!("{}/tcgplayer/{}", path, id),
         }
     }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::Set)
+    }
+
+    fn validate(&self, model: &CardSet) -> Vec<String> {
+        model.violations()
+    }
 }
 
+crate::resources::display_path!(CardSetListResource, CardSetResource<'a>);
+
 /// Basic struct representing card set list
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct CardSetList {
     #[serde(rename = "object")]
     pub kind: ResourceKind,
@@ -59,8 +105,17 @@ pub struct CardSetList {
     pub data: Vec<CardSet>,
 }
 
+impl Validate for CardSetList {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::List).into_iter()
+            .chain(self.data.iter().flat_map(Validate::violations))
+            .collect()
+    }
+}
+
 /// A card set
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct CardSet {
     #[serde(rename = "object")]
     pub item_kind: ResourceKind,
@@ -69,16 +124,23 @@ pub struct CardSet {
     pub mtgo_code: Option<String>,
     pub arena_code: Option<String>,
     pub name: String,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub uri: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub scryfall_uri: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub search_uri: Url,
+    #[serde(default = "crate::resources::lenient::unknown_date", deserialize_with = "crate::resources::lenient::date")]
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsJulianDay))]
     pub released_at: Date,
     #[serde(rename = "set_type")]
     pub kind: SetKind,
     pub card_count: i64,
+    pub printed_size: Option<i64>,
     pub digital: bool,
     pub nonfoil_only: bool,
     pub foil_only: bool,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub icon_svg_uri: Url,
     pub tcgplayer_id: Option<i64>,
     pub parent_set_code: Option<String>,
@@ -86,10 +148,31 @@ pub struct CardSet {
     pub block: Option<String>,
 }
 
+impl Validate for CardSet {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.item_kind, ResourceKind::Set).into_iter()
+            .chain((self.released_at == UNKNOWN_DATE).then(|| "released_at is missing or invalid".to_string()))
+            .collect()
+    }
+}
+
+impl CardSet {
+    /// Gets the number of days until the release of the set (negative if already released)
+    pub fn days_until_release(&self) -> i64 {
+        self.days_until_release_from(time::OffsetDateTime::now_utc().date())
+    }
+
+    /// Gets the number of days from the given date until the release of the set
+    pub fn days_until_release_from(&self, today: Date) -> i64 {
+        (self.released_at - today).whole_days()
+    }
+}
+
 /// Kind of card set
 ///
 /// This refers to Scryfall `set.set_type` field
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 #[serde(rename_all = "snake_case")]
 pub enum SetKind {
 
@@ -175,8 +258,10 @@ mod tests {
     }
 
     #[rstest]
-    #[case::filter(CardSetResource::Filter("id"), "sets/id")]
-    #[case::with_tcgplayerid(CardSetResource::WithTcgPlayerId("id"), "sets/tcgplayer/id")]
+    #[case::filter(CardSetResource::Filter("id".into()), "sets/id")]
+    #[case::with_tcgplayerid(CardSetResource::WithTcgPlayerId("id".into()), "sets/tcgplayer/id")]
+    #[case::by_code(CardSetResource::by_code("clb").unwrap(), "sets/clb")]
+    #[case::by_tcgplayer_id(CardSetResource::by_tcgplayer_id(123), "sets/tcgplayer/123")]
     fn card_set_resource_should_return_path_and_method(
         #[case] resource: CardSetResource,
         #[case] expected: &str
@@ -2,31 +2,75 @@
 //!
 //! See [Scryfall api documentation](https://scryfall.com/docs/api/card-symbols)
 
+use std::borrow::Cow;
 use serde::{Deserialize, Serialize};
-use url::Url;
+use url::{form_urlencoded, Url};
 use crate::HttpResource;
 use crate::resources::ResourceKind;
+use crate::resources::validation::{expect_kind, Validate};
+use crate::symbology::cost::Cost;
 
 /// Binding for endpoint `GET /symbology`
 pub struct CardSymbolsResource;
 
 /// Binding for endpoint `GET /symbology/parse-mana?cost=x`
-pub struct ManaCostResource<'a>(pub &'a str);
+///
+/// The cost is a raw string (e.g. `{2/W}{U/P}` or `2WU`), encoded as a query parameter,
+/// or a [parsed cost](crate::symbology::cost::Cost):
+///
+/// ```
+/// use scryfall_sdk_rust::{HttpResource, ManaCostResource};
+/// use scryfall_sdk_rust::symbology::cost::Cost;
+///
+/// let cost: Cost = "{2/W}{U/P}".parse().unwrap();
+///
+/// assert_eq!("symbology/parse-mana?cost=%7B2%2FW%7D%7BU%2FP%7D", ManaCostResource::from(&cost).path());
+/// assert_eq!(ManaCostResource("{2/W}{U/P}".into()), ManaCostResource::from(&cost));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManaCostResource<'a>(pub Cow<'a, str>);
+
+impl From<&Cost> for ManaCostResource<'static> {
+    fn from(cost: &Cost) -> Self {
+        ManaCostResource(cost.to_string().into())
+    }
+}
 
 impl HttpResource<CardSymbolList> for CardSymbolsResource {
     fn path(&self) -> String {
         "symbology".into()
     }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::List)
+    }
+
+    fn validate(&self, model: &CardSymbolList) -> Vec<String> {
+        model.violations()
+    }
 }
 
 impl<'a> HttpResource<ManaCost> for ManaCostResource<'a> {
     fn path(&self) -> String {
-        format!("symbology/parse-mana?cost={}", self.0)
+        let cost: String = form_urlencoded::byte_serialize(self.0.as_bytes()).collect();
+
+        format!("symbology/parse-mana?cost={cost}")
+    }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::ManaCost)
+    }
+
+    fn validate(&self, model: &ManaCost) -> Vec<String> {
+        model.violations()
     }
 }
 
+crate::resources::display_path!(CardSymbolsResource, ManaCostResource<'a>);
+
 /// Basic struct representing card symbol list
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct CardSymbolList {
     #[serde(rename = "object")]
     pub kind: ResourceKind,
@@ -34,26 +78,44 @@ pub struct CardSymbolList {
     pub data: Vec<CardSymbol>,
 }
 
+impl Validate for CardSymbolList {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::List).into_iter()
+            .chain(self.data.iter().flat_map(Validate::violations))
+            .collect()
+    }
+}
+
 /// A card symbol
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct CardSymbol {
     #[serde(rename = "object")]
     pub kind: ResourceKind,
     pub symbol: String,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub svg_uri: Url,
     pub loose_variant: Option<String>,
     pub english: String,
     pub transposable: bool,
     pub represents_mana: bool,
     pub appears_in_mana_costs: bool,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub cmc: Option<f64>,
     pub funny: bool,
     pub colors: Vec<ColorSymbol>,
     pub gatherer_alternates: Option<Vec<String>>,
 }
 
+impl Validate for CardSymbol {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::CardSymbol).into_iter().collect()
+    }
+}
+
 /// A mana cost entry
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct ManaCost {
     #[serde(rename = "object")]
     pub kind: ResourceKind,
@@ -65,8 +127,15 @@ pub struct ManaCost {
     pub multicolored: bool,
 }
 
+impl Validate for ManaCost {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::ManaCost).into_iter().collect()
+    }
+}
+
 /// A color symbol (single color)
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub enum ColorSymbol {
     /// Black
     B,
@@ -91,9 +160,17 @@ mod tests {
 
     #[test]
     fn mana_cost_resource_should_return_path_and_method() {
-        let resource = ManaCostResource("test");
+        let resource = ManaCostResource("test".into());
 
         assert_eq!("symbology/parse-mana?cost=test", resource.path());
         assert_eq!(Method::GET, resource.method());
     }
+
+    #[test]
+    fn mana_cost_resource_should_encode_symbols() {
+        let resource = ManaCostResource("{2/W} {U/P}+".into());
+
+        assert_eq!("symbology/parse-mana?cost=%7B2%2FW%7D+%7BU%2FP%7D%2B", resource.path());
+        assert_eq!(resource.path(), resource.to_string());
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! See [Scryfall api documentation](https://scryfall.com/docs/api/cards)
 
+use std::borrow::Cow;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use time::Date;
@@ -15,66 +16,74 @@ use CardCollectionResource::*;
 use crate::HttpResource;
 use crate::resources::card_symbols::ColorSymbol;
 use crate::resources::catalog::Catalog;
-use crate::resources::ResourceKind;
+use crate::resources::{collector_number_param, name_param, set_code_param, ResourceKind};
+use crate::resources::errors::ErrorBody;
+use crate::resources::lenient::UNKNOWN_DATE;
+use crate::resources::validation::{expect_kind, Validate};
 
 // ---------------------------------------
 // --  HTTP resources  -------------------
 // ---------------------------------------
 
 /// Endpoints for `/cards/*` resource (single card)
+///
+/// Parameters are either borrowed (e.g. `NamedExact("Opt".into())`) or owned
+/// (e.g. `NamedExact(name.into())` with a `String` read at runtime).
+/// Resources can be detached from borrowed data with [into_owned](CardResource::into_owned).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CardResource<'a> {
     /// Binding for endpoint `GET /cards/:id`
     ///
     /// Get a single card by its Scryfall id
-    ById(&'a str),
+    ById(Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/arena/:id`
     ///
     /// Get a single card by its Arena id
-    ByArenaId(&'a str),
+    ByArenaId(Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/cardmarket/:id`
     ///
     /// Get a single card by its Cardmarket id
-    ByCardmarketId(&'a str),
+    ByCardmarketId(Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/:code/:number`
     ///
     /// Get a single card by its code and collector number
-    ByCode(&'a str, &'a str),
+    ByCode(Cow<'a, str>, Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/mtgo/:id`
     ///
     /// Get a single card by its MTGO id
-    ByMtgoId(&'a str),
+    ByMtgoId(Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/multiverse/:id`
     ///
     /// Get a single card by its Multiverse id
-    ByMultiverseId(&'a str),
+    ByMultiverseId(Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/tcgplayer/:id`
     ///
     /// Get a single card by its Tcgplayer id
-    ByTcgplayerId(&'a str),
+    ByTcgplayerId(Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/named?exact={name}`
     ///
     /// Get a single card by its exact name
-    NamedExact(&'a str),
+    NamedExact(Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/named?fuzzy={name}`
     ///
     /// Get a single card by fuzzy searching its name.
     /// If exact match is found it is returned instead
-    NamedFuzzy(&'a str),
+    NamedFuzzy(Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/random`
     ///
     /// Get a single card at random.
     /// An optional `q` query parameter can provided
     /// in order to limit the pool of cards.
-    Random(Option<&'a str>),
+    Random(Option<Cow<'a, str>>),
 }
 
 /// Endpoints for `/cards/*` resource (page)
@@ -157,6 +166,77 @@ pub enum CardCollectionResource {
     WithIdentifiers(CardIdentifiers),
 }
 
+impl<'a> CardResource<'a> {
+    /// Gets a card by its Scryfall id
+    pub fn by_id(id: &Uuid) -> CardResource<'static> {
+        ById(id.to_string().into())
+    }
+
+    /// Gets a card by its Arena id
+    pub fn by_arena_id(id: u32) -> CardResource<'static> {
+        ByArenaId(id.to_string().into())
+    }
+
+    /// Gets a card by its Cardmarket id
+    pub fn by_cardmarket_id(id: u32) -> CardResource<'static> {
+        ByCardmarketId(id.to_string().into())
+    }
+
+    /// Gets a card by its set code and collector number (e.g. `("clb", "123a")`)
+    ///
+    /// Fails with an `INVALID_INPUT` error for invalid set codes or collector numbers.
+    pub fn by_code(code: impl Into<Cow<'a, str>>, number: impl Into<Cow<'a, str>>) -> Result<Self, ErrorBody> {
+        Ok(ByCode(set_code_param(code)?, collector_number_param(number)?))
+    }
+
+    /// Gets a card by its MTGO id
+    pub fn by_mtgo_id(id: u32) -> CardResource<'static> {
+        ByMtgoId(id.to_string().into())
+    }
+
+    /// Gets a card by its Multiverse id
+    pub fn by_multiverse_id(id: u32) -> CardResource<'static> {
+        ByMultiverseId(id.to_string().into())
+    }
+
+    /// Gets a card by its Tcgplayer id
+    pub fn by_tcgplayer_id(id: u32) -> CardResource<'static> {
+        ByTcgplayerId(id.to_string().into())
+    }
+
+    /// Gets a card by its exact name
+    ///
+    /// Fails with an `INVALID_INPUT` error for blank names.
+    pub fn named_exact(name: impl Into<Cow<'a, str>>) -> Result<Self, ErrorBody> {
+        name_param(name).map(NamedExact)
+    }
+
+    /// Gets a card by fuzzy searching its name
+    ///
+    /// Fails with an `INVALID_INPUT` error for blank names.
+    pub fn named_fuzzy(name: impl Into<Cow<'a, str>>) -> Result<Self, ErrorBody> {
+        name_param(name).map(NamedFuzzy)
+    }
+
+    /// Converts the resource into one owning its parameters
+    pub fn into_owned(self) -> CardResource<'static> {
+        use crate::resources::owned;
+
+        match self {
+            ById(id) => ById(owned(id)),
+            ByArenaId(id) => ByArenaId(owned(id)),
+            ByCardmarketId(id) => ByCardmarketId(owned(id)),
+            ByCode(code, number) => ByCode(owned(code), owned(number)),
+            ByMtgoId(id) => ByMtgoId(owned(id)),
+            ByMultiverseId(id) => ByMultiverseId(owned(id)),
+            ByTcgplayerId(id) => ByTcgplayerId(owned(id)),
+            NamedExact(name) => NamedExact(owned(name)),
+            NamedFuzzy(name) => NamedFuzzy(owned(name)),
+            Random(query) => Random(query.map(owned)),
+        }
+    }
+}
+
 impl<'a> HttpResource<Card> for CardResource<'a> {
     fn path(&self) -> String {
         format!("cards/{}", match self {
@@ -187,12 +267,20 @@ impl<'a> HttpResource<Card> for CardResource<'a> {
                 "named?fuzzy={name}"
             ),
             Random(query) => format!(
-                "random{}", query
+                "random{}", query.as_ref()
                     .map(|q| format!("?q={q}"))
                     .unwrap_or("".into())
             ),
         })
     }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::Card)
+    }
+
+    fn validate(&self, model: &Card) -> Vec<String> {
+        model.violations()
+    }
 }
 
 impl HttpResource<CardPage> for CardPageResource {
@@ -201,6 +289,20 @@ impl HttpResource<CardPage> for CardPageResource {
             Search(params) => params.as_query_str()
         })
     }
+
+    fn search_key(&self) -> Option<String> {
+        match self {
+            Search(params) => Some(params.normalized()),
+        }
+    }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::List)
+    }
+
+    fn validate(&self, model: &CardPage) -> Vec<String> {
+        model.violations()
+    }
 }
 
 impl<'a> HttpResource<Catalog> for CardCatalogResource<'a> {
@@ -209,6 +311,14 @@ impl<'a> HttpResource<Catalog> for CardCatalogResource<'a> {
             Autocomplete(q) => format!("autocomplete?q={q}")
         })
     }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::Catalog)
+    }
+
+    fn validate(&self, model: &Catalog) -> Vec<String> {
+        model.violations()
+    }
 }
 
 impl HttpResource<CardCollection> for CardCollectionResource {
@@ -229,31 +339,51 @@ impl HttpResource<CardCollection> for CardCollectionResource {
             WithIdentifiers(r) => serde_json::to_string(r).ok(),
         }
     }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::List)
+    }
+
+    fn validate(&self, model: &CardCollection) -> Vec<String> {
+        model.violations()
+    }
 }
 
+crate::resources::display_path!(CardResource<'a>, CardPageResource, CardCatalogResource<'a>, CardCollectionResource);
+
 // ---------------------------------------
 // --  Model definitions  ----------------
 // ---------------------------------------
 
 /// Basic struct representing a card
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+///
+/// Reversible cards have no top-level Oracle id, mana value and type line
+/// (they are on the [faces](Card::card_faces)): these fields are then the nil uuid, `0` and empty.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct Card {
     pub all_parts: Option<Vec<RelatedCard>>,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub arena_id: Option<i32>,
     pub artist: Option<String>,
     pub artist_ids: Vec<Uuid>,
+    pub attraction_lights: Option<Vec<u8>>,
     pub booster: bool,
     pub border_color: String,
     pub card_back_id: Option<Uuid>,
     pub card_faces: Option<Vec<CardFace>>,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub cardmarket_id: Option<i32>,
+    #[serde(default, deserialize_with = "crate::resources::lenient::number")]
     pub cmc: f64,
     pub collector_number: String,
     pub color_identity: Vec<ColorSymbol>,
     pub color_indicator: Option<Vec<ColorSymbol>>,
     pub colors: Option<Vec<ColorSymbol>>,
     pub content_warning: Option<bool>,
+    pub defense: Option<String>,
     pub digital: bool,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub edhrec_rank: Option<i64>,
     pub finishes: Vec<CardFinish>,
     pub flavor_name: Option<String>,
@@ -277,20 +407,26 @@ pub struct Card {
     pub life_modifier: Option<String>,
     pub loyalty: Option<String>,
     pub mana_cost: Option<String>,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub mtgo_id: Option<i32>,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub mtgo_foil_id: Option<i32>,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_numbers")]
     pub multiverse_ids: Option<Vec<i32>>,
     pub name: String,
     pub nonfoil: bool,
+    #[serde(default)]
     pub oracle_id: Uuid,
     pub oracle_text: Option<String>,
     pub oversized: bool,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub penny_rank: Option<i64>,
     pub power: Option<String>,
     pub prices: Prices,
     pub printed_name: Option<String>,
     pub printed_text: Option<String>,
     pub printed_type_line: Option<String>,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub prints_search_uri: Url,
     pub produced_mana: Option<Vec<ColorSymbol>>,
     pub promo: bool,
@@ -298,31 +434,161 @@ pub struct Card {
     pub purchase_uris: Option<PurchaseUris>,
     pub rarity: Rarity,
     pub related_uris: Option<RelatedUris>,
+    #[serde(default = "crate::resources::lenient::unknown_date", deserialize_with = "crate::resources::lenient::date")]
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsJulianDay))]
     pub released_at: Date,
     pub reprint: bool,
     pub reserved: bool,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub rulings_uri: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub scryfall_set_uri: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub scryfall_uri: Url,
     pub security_stamp: Option<String>,
     pub set: String,
     pub set_id: String,
     pub set_name: String,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub set_search_uri: Url,
     pub set_type: String,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub set_uri: Url,
     pub story_spotlight: bool,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub tcgplayer_id: Option<i32>,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub tcgplayer_etched_id: Option<i32>,
     pub textless: bool,
     pub toughness: Option<String>,
+    #[serde(default)]
     pub type_line: String,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub uri: Url,
     pub variation: bool,
     pub variation_of: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+impl Validate for Card {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::Card).into_iter()
+            .chain((self.released_at == UNKNOWN_DATE).then(|| "released_at is missing or invalid".to_string()))
+            .collect()
+    }
+}
+
+impl Card {
+    /// Gets the printed name (for non-English cards), falling back to the oracle name
+    pub fn localized_name(&self) -> &str {
+        self.printed_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Gets the printed text (for non-English cards), falling back to the oracle text
+    pub fn localized_text(&self) -> Option<&str> {
+        self.printed_text.as_deref().or(self.oracle_text.as_deref())
+    }
+
+    /// Gets the printed type line (for non-English cards), falling back to the oracle type line
+    pub fn localized_type_line(&self) -> &str {
+        self.printed_type_line.as_deref().unwrap_or(&self.type_line)
+    }
+
+    /// Gets the mana value, with exact equality, ordering and hashing (unlike the `cmc` float)
+    pub fn mana_value(&self) -> ManaValue {
+        ManaValue::from_f64(self.cmc)
+    }
+
+    /// Gets the Oracle id of the card, or of its first face for cards without
+    /// a top-level one (reversible cards)
+    pub fn effective_oracle_id(&self) -> Uuid {
+        self.card_faces.as_ref()
+            .filter(|_| self.oracle_id.is_nil())
+            .and_then(|faces| faces.first()?.oracle_id)
+            .unwrap_or(self.oracle_id)
+    }
+
+    /// Checks if two cards are the same printing (same Scryfall id),
+    /// whatever their other fields (e.g. prices fetched at different times)
+    ///
+    /// Unlike the derived `PartialEq`, which compares all fields (including the `cmc` float),
+    /// this is reliable for comparing cards from different sources, e.g. in tests and caches.
+    pub fn same_printing(&self, other: &Card) -> bool {
+        self.id == other.id
+    }
+
+    /// Checks if two cards are printings of the same gameplay card (same Oracle id)
+    pub fn same_oracle(&self, other: &Card) -> bool {
+        self.effective_oracle_id() == other.effective_oracle_id()
+    }
+
+    /// Checks if the printing has a special status
+    pub fn has_status(&self, status: CardStatus) -> bool {
+        match status {
+            CardStatus::Reserved => self.reserved,
+            CardStatus::Promo => self.promo,
+            CardStatus::FullArt => self.full_art,
+            CardStatus::Textless => self.textless,
+            CardStatus::Oversized => self.oversized,
+            CardStatus::StorySpotlight => self.story_spotlight,
+        }
+    }
+
+    /// Checks if the printing is a regular card collectible in paper
+    ///
+    /// Digital-only and oversized printings, tokens, emblems and art series cards are excluded.
+    pub fn is_collectible_paper_card(&self) -> bool {
+        let non_card = self.is_token() || self.is_emblem() || self.is_art_series();
+
+        !non_card && !self.digital && !self.oversized && self.games.contains(&GameKind::Paper)
+    }
+
+    /// Checks if the card is a token (including double-faced tokens and token set printings)
+    pub fn is_token(&self) -> bool {
+        match self.layout {
+            Layout::Token | Layout::DoubleFacedToken => true,
+            Layout::Emblem => false,
+            _ => self.set_type == "token",
+        }
+    }
+
+    /// Checks if the card is an Art Series card
+    pub fn is_art_series(&self) -> bool {
+        self.layout == Layout::ArtSeries
+    }
+
+    /// Checks if the card is an emblem
+    pub fn is_emblem(&self) -> bool {
+        self.layout == Layout::Emblem
+    }
+
+    /// Checks if the card can be included in a deck
+    ///
+    /// Tokens, emblems, Art Series cards and casual-variant cards
+    /// (planes, schemes and Vanguard avatars) are excluded.
+    pub fn is_playable_in_deck(&self) -> bool {
+        let variant = matches!(self.layout, Layout::Planar | Layout::Scheme | Layout::Vanguard);
+
+        !variant && !self.is_token() && !self.is_emblem() && !self.is_art_series()
+    }
+
+    /// Gets the parsed power (if any)
+    pub fn power_stat(&self) -> Option<Stat> {
+        self.power.as_deref().map(Stat::parse)
+    }
+
+    /// Gets the parsed toughness (if any)
+    pub fn toughness_stat(&self) -> Option<Stat> {
+        self.toughness.as_deref().map(Stat::parse)
+    }
+
+    /// Gets the parsed loyalty (if any)
+    pub fn loyalty_stat(&self) -> Option<Stat> {
+        self.loyalty.as_deref().map(Stat::parse)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct RelatedCard {
     pub component: String,
     pub id: Uuid,
@@ -330,10 +596,12 @@ pub struct RelatedCard {
     pub kind: ResourceKind,
     pub name: String,
     pub type_line: String,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub uri: Url,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 #[serde(rename_all = "lowercase")]
 pub enum CardFinish {
     Etched,
@@ -342,7 +610,8 @@ pub enum CardFinish {
     NonFoil, 
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 #[serde(rename_all = "lowercase")]
 pub enum GameKind {
     Arena,
@@ -350,7 +619,8 @@ pub enum GameKind {
     Paper,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 #[serde(rename_all = "snake_case")]
 pub enum ImageStatus {
     HighresScan,
@@ -359,12 +629,15 @@ pub enum ImageStatus {
     Placeholder,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 #[serde(rename_all = "snake_case")]
 pub enum Layout {
     Adventure,
     ArtSeries,
     Augment,
+    Battle,
+    Case,
     Class,
     DoubleFacedToken,
     Emblem,
@@ -373,8 +646,10 @@ pub enum Layout {
     Leveler,
     Meld,
     ModalDfc,
+    Mutate,
     Normal,
     Planar,
+    Prototype,
     ReversibleCard,
     Saga,
     Scheme,
@@ -384,7 +659,8 @@ pub enum Layout {
     Vanguard,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 #[serde(rename_all = "lowercase")]
 pub enum Rarity {
     Bonus,
@@ -395,24 +671,50 @@ pub enum Rarity {
     Uncommon,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct CardPage {
     pub data: Vec<Card>,
     pub has_more: bool,
     #[serde(rename = "object")]
     pub kind: ResourceKind,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<crate::resources::archive::AsUrl>))]
     pub next_page: Option<Url>,
     pub total_cards: i64,
 }
 
+impl Validate for CardPage {
+    fn violations(&self) -> Vec<String> {
+        let mut violations: Vec<String> = expect_kind(&self.kind, ResourceKind::List)
+            .into_iter()
+            .chain(self.data.iter().flat_map(Validate::violations))
+            .collect();
+
+        if self.has_more && self.next_page.is_none() {
+            violations.push("page has more results but no next_page".into());
+        }
+        if self.total_cards < self.data.len() as i64 {
+            violations.push(format!(
+                "total_cards ({}) is less than the cards of the page ({})",
+                self.total_cards, self.data.len()
+            ));
+        }
+
+        violations
+    }
+}
+
 /// A struct representing the face of a card
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct CardFace {
     pub artist: Option<String>,
     pub artist_id: Option<Uuid>,
+    #[serde(default, deserialize_with = "crate::resources::lenient::option_number")]
     pub cmc: Option<f64>,
     pub color_indicator: Option<Vec<ColorSymbol>>,
     pub colors: Option<Vec<ColorSymbol>>,
+    pub defense: Option<String>,
     pub flavor_name: Option<String>,
     pub flavor_text: Option<String>,
     pub illustration_id: Option<Uuid>,
@@ -434,19 +736,189 @@ pub struct CardFace {
     pub watermark: Option<String>,
 }
 
+impl CardFace {
+    /// Gets the printed name (for non-English cards), falling back to the oracle name
+    pub fn localized_name(&self) -> &str {
+        self.printed_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Gets the printed text (for non-English cards), falling back to the oracle text
+    pub fn localized_text(&self) -> Option<&str> {
+        self.printed_text.as_deref().or(self.oracle_text.as_deref())
+    }
+
+    /// Gets the printed type line (for non-English cards), falling back to the oracle type line
+    pub fn localized_type_line(&self) -> Option<&str> {
+        self.printed_type_line.as_deref().or(self.type_line.as_deref())
+    }
+
+    /// Gets the parsed power (if any)
+    pub fn power_stat(&self) -> Option<Stat> {
+        self.power.as_deref().map(Stat::parse)
+    }
+
+    /// Gets the parsed toughness (if any)
+    pub fn toughness_stat(&self) -> Option<Stat> {
+        self.toughness.as_deref().map(Stat::parse)
+    }
+
+    /// Gets the parsed loyalty (if any)
+    pub fn loyalty_stat(&self) -> Option<Stat> {
+        self.loyalty.as_deref().map(Stat::parse)
+    }
+}
+
+/// An exact mana value, stored in thousandths
+///
+/// Mana values are floats in the API (e.g. `0.5` for half-mana cards), which makes
+/// them awkward to compare and unusable as map keys. Mana values are rounded to
+/// the nearest thousandth, and invalid values (negative, NaN) are zero.
+///
+/// # Example
+/// ```
+/// use scryfall_sdk_rust::resources::cards::ManaValue;
+///
+/// assert_eq!(ManaValue::from_f64(0.5), ManaValue::from_f64(0.1 + 0.4));
+/// assert!(ManaValue::from_f64(0.5) < ManaValue::from(1));
+/// assert_eq!("2.5", ManaValue::from_f64(2.5).to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ManaValue(u64);
+
+impl ManaValue {
+    /// Converts a float mana value
+    pub fn from_f64(value: f64) -> Self {
+        match value.is_finite() && value > 0.0 {
+            true => ManaValue((value * 1000.0).round() as u64),
+            false => ManaValue(0),
+        }
+    }
+
+    /// Gets the mana value as a float
+    pub fn as_f64(&self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+
+    /// Gets the mana value in thousandths
+    pub fn thousandths(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u32> for ManaValue {
+    fn from(value: u32) -> Self {
+        ManaValue(u64::from(value) * 1000)
+    }
+}
+
+impl std::fmt::Display for ManaValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_f64())
+    }
+}
+
+/// A parsed power, toughness or loyalty value
+///
+/// These are strings in the API, since they are not always numbers (e.g. `*` or `1+*`).
+///
+/// # Example
+/// ```
+/// use scryfall_sdk_rust::resources::cards::Stat;
+///
+/// assert_eq!(Stat::StarPlus(1), Stat::parse("1+*"));
+/// assert!(Stat::Fixed(2) > Stat::Fixed(1));
+/// assert_eq!(None, Stat::Star.partial_cmp(&Stat::Fixed(1)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Stat {
+    /// A number (e.g. `3` or `-1`)
+    Fixed(i32),
+
+    /// A characteristic-defining value (`*`)
+    Star,
+
+    /// A characteristic-defining value plus a number (e.g. `1+*`)
+    StarPlus(i32),
+
+    /// A variable value (`X`)
+    X,
+
+    /// Any other value (e.g. `?`, `∞`, `1.5` or `+2`)
+    Other(String),
+}
+
+impl Stat {
+    /// Parses a stat value
+    pub fn parse(value: &str) -> Stat {
+        let value = value.trim();
+        let number = |n: &str| n.parse::<i32>().ok().filter(|_| !n.starts_with('+'));
+
+        match value {
+            "*" => Stat::Star,
+            "X" | "x" => Stat::X,
+            _ => number(value).map(Stat::Fixed)
+                .or_else(|| value.strip_suffix("+*").and_then(number).map(Stat::StarPlus))
+                .or_else(|| value.strip_prefix("*+").and_then(number).map(Stat::StarPlus))
+                .unwrap_or_else(|| Stat::Other(value.into())),
+        }
+    }
+
+    /// Gets the numeric value of the stat (fixed values only)
+    pub fn value(&self) -> Option<i32> {
+        match self {
+            Stat::Fixed(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Stat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stat::Fixed(n) => write!(f, "{n}"),
+            Stat::Star => f.write_str("*"),
+            Stat::StarPlus(n) => write!(f, "{n}+*"),
+            Stat::X => f.write_str("X"),
+            Stat::Other(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Compares stats where a comparison is defined
+///
+/// Fixed values are compared numerically, as are the numbers of `1+*`-like values.
+/// Other stats are only equal to themselves.
+impl PartialOrd for Stat {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (Stat::Fixed(a), Stat::Fixed(b)) | (Stat::StarPlus(a), Stat::StarPlus(b)) => a.partial_cmp(b),
+            _ if self == other => Some(std::cmp::Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
 /// Container for image URLs
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct ImageUris {
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub art_crop: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub border_crop: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub large: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub normal: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub png: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub small: Url,
 }
 
 /// Container for card legalities
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct Legalities {
     pub alchemy: Legality,
     pub brawl: Legality,
@@ -470,7 +942,8 @@ pub struct Legalities {
 }
 
 /// Container for card prices
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct Prices {
     pub eur: Option<String>,
     pub eur_foil: Option<String>,
@@ -480,25 +953,48 @@ pub struct Prices {
     pub usd_foil: Option<String>,
 }
 
+impl Prices {
+    /// Gets the USD price for a card finish (if any)
+    ///
+    /// Glossy cards are priced as non-foil.
+    pub fn usd_of(&self, finish: CardFinish) -> Option<f64> {
+        match finish {
+            CardFinish::Etched => &self.usd_etched,
+            CardFinish::Foil => &self.usd_foil,
+            CardFinish::Glossy | CardFinish::NonFoil => &self.usd,
+        }.as_ref().and_then(|p| p.parse().ok())
+    }
+}
+
 /// Container for card purchase URLs
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct PurchaseUris {
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub cardhoarder: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub cardmarket: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
     pub tcgplayer: Url,
 }
 
 /// Container for other card related URLs
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct RelatedUris {
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<crate::resources::archive::AsUrl>))]
     pub edhrec: Option<Url>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<crate::resources::archive::AsUrl>))]
     pub gatherer: Option<Url>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<crate::resources::archive::AsUrl>))]
     pub tcgplayer_infinite_articles: Option<Url>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<crate::resources::archive::AsUrl>))]
     pub tcgplayer_infinite_decks: Option<Url>,
 }
 
 /// Card legality enum
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub enum Legality {
     #[serde(rename = "banned")]
     Banned,
@@ -513,6 +1009,61 @@ pub enum Legality {
     Restricted,
 }
 
+/// Game format enum
+///
+/// Each variant corresponds to a field of [Legalities](Legalities).
+#[derive(Clone, Copy, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Format {
+    Alchemy,
+    Brawl,
+    Commander,
+    Duel,
+    Explorer,
+    Future,
+    Gladiator,
+    Historic,
+    HistoricBrawl,
+    Legacy,
+    Modern,
+    OldSchool,
+    Pauper,
+    PauperCommander,
+    Penny,
+    Pioneer,
+    Premodern,
+    Standard,
+    Vintage,
+}
+
+impl Legalities {
+    /// Gets the legality of a card in the given format
+    pub fn of(&self, format: Format) -> &Legality {
+        match format {
+            Format::Alchemy => &self.alchemy,
+            Format::Brawl => &self.brawl,
+            Format::Commander => &self.commander,
+            Format::Duel => &self.duel,
+            Format::Explorer => &self.explorer,
+            Format::Future => &self.future,
+            Format::Gladiator => &self.gladiator,
+            Format::Historic => &self.historic,
+            Format::HistoricBrawl => &self.historicbrawl,
+            Format::Legacy => &self.legacy,
+            Format::Modern => &self.modern,
+            Format::OldSchool => &self.oldschool,
+            Format::Pauper => &self.pauper,
+            Format::PauperCommander => &self.paupercommander,
+            Format::Penny => &self.penny,
+            Format::Pioneer => &self.pioneer,
+            Format::Premodern => &self.premodern,
+            Format::Standard => &self.standard,
+            Format::Vintage => &self.vintage,
+        }
+    }
+}
+
 pub struct SearchQueryParams {
     pub dir: Option<OrderDirection>,
     pub include_extras: Option<bool>,
@@ -573,6 +1124,127 @@ impl SearchQueryParams {
         query
     }
 
+    /// Renders the search as a single Scryfall query (dry-run)
+    ///
+    /// Options are rendered using the inline search syntax (e.g. `unique:prints`),
+    /// so that the result can be pasted in the Scryfall search box.
+    /// Variations and pagination have no inline equivalent and are omitted.
+    ///
+    /// # Example
+    /// ```
+    /// use scryfall_sdk_rust::resources::cards::{OrderField, SearchQueryParams, UniqueMode};
+    ///
+    /// let mut params = SearchQueryParams::with_q("t:goblin");
+    /// params.unique = Some(UniqueMode::Prints);
+    /// params.order = Some(OrderField::Usd);
+    ///
+    /// assert_eq!("t:goblin unique:prints order:usd", params.render());
+    /// ```
+    pub fn render(&self) -> String {
+        let mut query = vec![self.q.clone()];
+
+        query.extend(self.unique.as_ref().map(|mode| format!("unique:{mode}")));
+        query.extend(self.order.as_ref().map(|field| format!("order:{field}")));
+        query.extend(self.dir.as_ref()
+            .filter(|dir| !matches!(dir, OrderDirection::Auto))
+            .map(|dir| format!("direction:{dir}"))
+        );
+        query.extend(self.include_extras.filter(|b| *b).map(|_| "include:extras".to_string()));
+        query.extend(self.include_multilingual.filter(|b| *b).map(|_| "lang:any".to_string()));
+
+        query.join(" ")
+    }
+
+    /// Gets the URL of the search in the Scryfall website (e.g. for "open in Scryfall" links)
+    ///
+    /// # Example
+    /// ```
+    /// use url::Url;
+    /// use scryfall_sdk_rust::resources::cards::SearchQueryParams;
+    ///
+    /// let base = Url::parse("https://scryfall.com").unwrap();
+    /// let url = SearchQueryParams::with_q("t:goblin c:r").to_url(&base);
+    ///
+    /// assert_eq!("https://scryfall.com/search?q=t%3Agoblin+c%3Ar", url.as_str());
+    /// ```
+    pub fn to_url(&self, base: &Url) -> Url {
+        let mut url = base.clone();
+
+        if let Ok(mut segments) = url.path_segments_mut() {
+            segments.pop_if_empty().push("search");
+        }
+
+        {
+            let mut query = url.query_pairs_mut();
+            query.clear().append_pair("q", &self.q);
+
+            if let Some(mode) = &self.unique {
+                query.append_pair("unique", &mode.to_string());
+            }
+            if let Some(field) = &self.order {
+                query.append_pair("order", &field.to_string());
+            }
+            if let Some(dir) = &self.dir {
+                query.append_pair("dir", &dir.to_string());
+            }
+            if let Some(b) = self.include_extras {
+                query.append_pair("include_extras", &b.to_string());
+            }
+            if let Some(b) = self.include_multilingual {
+                query.append_pair("include_multilingual", &b.to_string());
+            }
+            if let Some(b) = self.include_variations {
+                query.append_pair("include_variations", &b.to_string());
+            }
+            if let Some(page) = self.page {
+                query.append_pair("page", &page.to_string());
+            }
+        }
+
+        url
+    }
+
+    /// Gets a canonical representation of the search
+    ///
+    /// Whitespace and letter case of the query are normalized, its terms are sorted
+    /// (unless the query uses `or` or parentheses) and parameters with default values
+    /// are omitted, so that equivalent searches have the same representation.
+    ///
+    /// # Example
+    /// ```
+    /// use scryfall_sdk_rust::resources::cards::{SearchQueryParams, UniqueMode};
+    ///
+    /// let mut params = SearchQueryParams::with_q("T:goblin  c:r");
+    /// params.unique = Some(UniqueMode::Cards);
+    ///
+    /// assert_eq!(SearchQueryParams::with_q("c:r t:goblin").normalized(), params.normalized());
+    /// ```
+    pub fn normalized(&self) -> String {
+        let mut terms = query_terms(&self.q.to_lowercase());
+        let grouped = terms.iter().any(|t| t == "or" || t.contains('(') || t.contains(')'));
+        if !grouped {
+            terms.sort();
+        }
+
+        let mut key = format!("q={}", terms.join(" "));
+
+        let options = [
+            self.unique.as_ref().filter(|u| !matches!(u, UniqueMode::Cards)).map(|u| format!("unique={u}")),
+            self.order.as_ref().filter(|o| !matches!(o, OrderField::Name)).map(|o| format!("order={o}")),
+            self.dir.as_ref().filter(|d| !matches!(d, OrderDirection::Auto)).map(|d| format!("dir={d}")),
+            self.include_extras.filter(|b| *b).map(|_| "include_extras=true".to_string()),
+            self.include_multilingual.filter(|b| *b).map(|_| "include_multilingual=true".to_string()),
+            self.include_variations.filter(|b| *b).map(|_| "include_variations=true".to_string()),
+        ];
+        for option in options.into_iter().flatten() {
+            key.push('&');
+            key.push_str(&option);
+        }
+
+        key.push_str(&format!("&page={}", self.page.unwrap_or(1)));
+        key
+    }
+
     pub fn with_q(q: &str) -> Self {
         SearchQueryParams {
             q: q.into(),
@@ -585,9 +1257,155 @@ impl SearchQueryParams {
             page: None,
         }
     }
+
+    /// Search for creatures legal in standard
+    pub fn standard_legal_creatures() -> Self {
+        SearchQueryParams::with_q("t:creature f:standard")
+    }
+
+    /// Search for cards that can be commanders of a deck with the given color identity
+    ///
+    /// An empty identity searches for colorless commanders.
+    ///
+    /// # Example
+    /// ```
+    /// use scryfall_sdk_rust::resources::card_symbols::ColorSymbol;
+    /// use scryfall_sdk_rust::resources::cards::SearchQueryParams;
+    ///
+    /// let params = SearchQueryParams::commanders_in_identity(&[ColorSymbol::W, ColorSymbol::U]);
+    ///
+    /// assert_eq!("is:commander id<=wu", params.q);
+    /// ```
+    pub fn commanders_in_identity(colors: &[ColorSymbol]) -> Self {
+        let identity: String = match colors {
+            [] => "c".into(),
+            _ => colors.iter().map(|c| format!("{c:?}").to_lowercase()).collect(),
+        };
+
+        SearchQueryParams::with_q(&format!("is:commander id<={identity}"))
+    }
+
+    /// Search for cards with a keyword ability (e.g. `Cascade` or `First strike`)
+    pub fn with_keyword(keyword: &str) -> Self {
+        SearchQueryParams::with_q(&format!("keyword:\"{keyword}\""))
+    }
+
+    /// Restricts the search to printings in a language (e.g. `ja`)
+    ///
+    /// # Example
+    /// ```
+    /// use scryfall_sdk_rust::resources::cards::SearchQueryParams;
+    ///
+    /// assert_eq!("t:goblin lang:ja", SearchQueryParams::with_q("t:goblin").lang("ja").q);
+    /// ```
+    #[must_use]
+    pub fn lang(self, lang: &str) -> Self {
+        self.and(&format!("lang:{lang}"))
+    }
+
+    /// Restricts the search to cards with a special status (e.g. on the Reserved List)
+    ///
+    /// # Example
+    /// ```
+    /// use scryfall_sdk_rust::resources::cards::{CardStatus, SearchQueryParams};
+    ///
+    /// let params = SearchQueryParams::with_q("t:land")
+    ///     .is(CardStatus::Reserved)
+    ///     .is_not(CardStatus::Promo);
+    ///
+    /// assert_eq!("t:land is:reserved -is:promo", params.q);
+    /// ```
+    #[must_use]
+    pub fn is(self, status: CardStatus) -> Self {
+        self.and(&format!("is:{status}"))
+    }
+
+    /// Excludes cards with a special status from the search
+    #[must_use]
+    pub fn is_not(self, status: CardStatus) -> Self {
+        self.and(&format!("-is:{status}"))
+    }
+
+    fn and(mut self, term: &str) -> Self {
+        self.q = format!("{} {term}", self.q).trim_start().into();
+        self
+    }
+
+    /// Search for cards with a Tagger tag (e.g. art tag `squirrel`)
+    ///
+    /// Multi-word tags are written with hyphens (e.g. `mana rock` -> `mana-rock`).
+    ///
+    /// # Example
+    /// ```
+    /// use scryfall_sdk_rust::resources::cards::{SearchQueryParams, TagKind};
+    ///
+    /// assert_eq!("art:squirrel", SearchQueryParams::with_tag(TagKind::Art, "squirrel").q);
+    /// assert_eq!("otag:mana-rock", SearchQueryParams::with_tag(TagKind::Oracle, "Mana Rock").q);
+    /// ```
+    pub fn with_tag(kind: TagKind, tag: &str) -> Self {
+        let tag = tag.trim().to_lowercase().replace(' ', "-");
+
+        SearchQueryParams::with_q(&format!("{kind}:{tag}"))
+    }
+}
+
+/// Splits a query into whitespace separated terms, keeping quoted text together
+fn query_terms(q: &str) -> Vec<String> {
+    let mut terms = vec![];
+    let mut term = String::new();
+    let mut quoted = false;
+
+    for c in q.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                term.push(c);
+            },
+            c if c.is_whitespace() && !quoted => {
+                if !term.is_empty() {
+                    terms.push(std::mem::take(&mut term));
+                }
+            },
+            c => term.push(c),
+        }
+    }
+
+    if !term.is_empty() {
+        terms.push(term);
+    }
+
+    terms
 }
 
-#[derive(Default, Display)]
+/// Special status of a card printing
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "snake_case")]
+pub enum CardStatus {
+    /// On the Reserved List
+    Reserved,
+    Promo,
+    #[strum(serialize = "full")]
+    FullArt,
+    Textless,
+    Oversized,
+    #[strum(serialize = "spotlight")]
+    StorySpotlight,
+}
+
+/// Kind of a [Tagger](https://tagger.scryfall.com) tag
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash)]
+pub enum TagKind {
+    /// Tags describing the artwork of a printing (`art:`)
+    #[strum(serialize = "art")]
+    Art,
+
+    /// Tags describing the function of an oracle card (`otag:`)
+    #[strum(serialize = "otag")]
+    Oracle,
+}
+
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum UniqueMode {
     Art,
@@ -596,7 +1414,8 @@ pub enum UniqueMode {
     Prints,
 }
 
-#[derive(Default, Display)]
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum OrderField {
     Artist,
@@ -612,12 +1431,14 @@ pub enum OrderField {
     Released,
     Review,
     Set,
+    Spoiled,
     Tix,
     Toughness,
     Usd,
 }
 
-#[derive(Default, Display)]
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum OrderDirection {
     #[default]
@@ -626,7 +1447,7 @@ pub enum OrderDirection {
     Desc,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct CardCollection {
     #[serde(rename = "object")]
     pub kind: ResourceKind,
@@ -637,12 +1458,20 @@ pub struct CardCollection {
     pub cards: Vec<Card>,
 }
 
-#[derive(Debug, Serialize, PartialEq)]
+impl Validate for CardCollection {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::List).into_iter()
+            .chain(self.cards.iter().flat_map(Validate::violations))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
 pub struct CardIdentifiers {
     pub identifiers: Vec<CardIdentifier>
 }
 
-#[derive(Debug, Display, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Display, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
 pub enum CardIdentifier {
     IllustrationId { 
@@ -682,22 +1511,23 @@ pub enum CardIdentifier {
 }
 
 #[cfg(test)]
+#[allow(clippy::too_many_arguments)]
 mod tests {
     use super::*;
     use rstest::rstest;
 
     #[rstest]
-    #[case::by_id(CardResource::ById("123"), "cards/123")]
-    #[case::by_arena_id(CardResource::ByArenaId("123"), "cards/arena/123")]
-    #[case::by_cardmarket_id(CardResource::ByCardmarketId("123"), "cards/cardmarket/123")]
-    #[case::by_code(CardResource::ByCode("123", "456"), "cards/123/456")]
-    #[case::by_mtgo_id(CardResource::ByMtgoId("123"), "cards/mtgo/123")]
-    #[case::by_multiverse_id(CardResource::ByMultiverseId("123"), "cards/multiverse/123")]
-    #[case::by_tcgplayer_id(CardResource::ByTcgplayerId("123"), "cards/tcgplayer/123")]
-    #[case::named_exact(CardResource::NamedExact("name"), "cards/named?exact=name")]
-    #[case::named_fuzzy(CardResource::NamedFuzzy("name"), "cards/named?fuzzy=name")]
+    #[case::by_id(CardResource::ById("123".into()), "cards/123")]
+    #[case::by_arena_id(CardResource::ByArenaId("123".into()), "cards/arena/123")]
+    #[case::by_cardmarket_id(CardResource::ByCardmarketId("123".into()), "cards/cardmarket/123")]
+    #[case::by_code(CardResource::ByCode("123".into(), "456".into()), "cards/123/456")]
+    #[case::by_mtgo_id(CardResource::ByMtgoId("123".into()), "cards/mtgo/123")]
+    #[case::by_multiverse_id(CardResource::ByMultiverseId("123".into()), "cards/multiverse/123")]
+    #[case::by_tcgplayer_id(CardResource::ByTcgplayerId("123".into()), "cards/tcgplayer/123")]
+    #[case::named_exact(CardResource::NamedExact("name".into()), "cards/named?exact=name")]
+    #[case::named_fuzzy(CardResource::NamedFuzzy("name".into()), "cards/named?fuzzy=name")]
     #[case::random(CardResource::Random(None), "cards/random")]
-    #[case::random(CardResource::Random(Some("name")), "cards/random?q=name")]
+    #[case::random(CardResource::Random(Some("name".into())), "cards/random?q=name")]
     fn card_resource_should_return_path_and_method(
         #[case] resource: CardResource,
         #[case] expected: &str
@@ -706,6 +1536,35 @@ mod tests {
         assert_eq!(Method::GET, resource.method());
     }
 
+    #[rstest]
+    #[case::by_id(CardResource::by_id(&Uuid::nil()), "cards/00000000-0000-0000-0000-000000000000")]
+    #[case::by_arena_id(CardResource::by_arena_id(123), "cards/arena/123")]
+    #[case::by_code(CardResource::by_code("clb", "123a").unwrap(), "cards/clb/123a")]
+    #[case::named_exact(CardResource::named_exact("Opt").unwrap(), "cards/named?exact=Opt")]
+    fn card_resource_constructors_should_build_paths(#[case] resource: CardResource, #[case] expected: &str) {
+        assert_eq!(expected, resource.path());
+    }
+
+    #[rstest]
+    #[case::invalid_code(CardResource::by_code("", "1"))]
+    #[case::invalid_number(CardResource::by_code("clb", "1/2"))]
+    #[case::blank_name(CardResource::named_fuzzy("  "))]
+    fn card_resource_constructors_should_validate_inputs(#[case] resource: Result<CardResource, ErrorBody>) {
+        assert_eq!(Some("INVALID_INPUT".to_string()), resource.err().map(|e| e.code));
+    }
+
+    #[rstest]
+    fn card_resource_should_own_runtime_parameters() {
+        let resource = {
+            let input = String::from(" Opt \n");
+            CardResource::NamedExact(input.trim().into()).into_owned()
+        };
+        let owned = CardResource::ByCode(String::from("clb").into(), format!("{}a", 123).into());
+
+        assert_eq!("cards/named?exact=Opt", resource.path());
+        assert_eq!("cards/clb/123a", owned.path());
+    }
+
     #[rstest]
     #[case::search(CardPageResource::Search(SearchQueryParams::with_q("test")), "cards/search?q=test")]
     fn card_page_resource_should_return_path_and_method(
@@ -774,4 +1633,204 @@ mod tests {
 
         assert_eq!(expected, params.as_query_str())
     }
+
+    #[rstest]
+    #[case::valid(true, Some("https://api.scryfall.com/cards/search?page=2"), 2, 0)]
+    #[case::missing_next_page(true, None, 2, 1)]
+    #[case::wrong_total(false, None, 0, 1)]
+    fn card_page_should_be_validated(
+        #[case] has_more: bool,
+        #[case] next_page: Option<&str>,
+        #[case] total_cards: i64,
+        #[case] violations: usize,
+    ) {
+        let page = CardPage {
+            data: vec![crate::fixtures::card(serde_json::json!({}))],
+            has_more,
+            kind: ResourceKind::List,
+            next_page: next_page.map(|u| u.parse().unwrap()),
+            total_cards,
+        };
+
+        assert_eq!(violations, page.violations().len());
+    }
+
+    #[rstest]
+    #[case::fixed("3", Stat::Fixed(3))]
+    #[case::negative("-1", Stat::Fixed(-1))]
+    #[case::star("*", Stat::Star)]
+    #[case::star_plus("1+*", Stat::StarPlus(1))]
+    #[case::plus_star("*+2", Stat::StarPlus(2))]
+    #[case::x("X", Stat::X)]
+    #[case::modifier("+2", Stat::Other("+2".into()))]
+    #[case::half("1.5", Stat::Other("1.5".into()))]
+    fn stat_should_be_parsed(#[case] value: &str, #[case] expected: Stat) {
+        assert_eq!(expected, Stat::parse(value));
+    }
+
+    #[rstest]
+    fn card_stats_should_be_parsed() {
+        let card = crate::fixtures::card(serde_json::json!({ "power": "*", "toughness": "1+*" }));
+
+        assert_eq!(Some(Stat::Star), card.power_stat());
+        assert_eq!(Some(Stat::StarPlus(1)), card.toughness_stat());
+        assert_eq!(None, card.loyalty_stat());
+    }
+
+    #[rstest]
+    #[case::creatures(SearchQueryParams::standard_legal_creatures(), "t:creature f:standard")]
+    #[case::colorless_commanders(SearchQueryParams::commanders_in_identity(&[]), "is:commander id<=c")]
+    #[case::keyword(SearchQueryParams::with_keyword("First strike"), "keyword:\"First strike\"")]
+    #[case::art_tag(SearchQueryParams::with_tag(TagKind::Art, "squirrel"), "art:squirrel")]
+    #[case::oracle_tag(SearchQueryParams::with_tag(TagKind::Oracle, " Mana Rock "), "otag:mana-rock")]
+    #[case::status(SearchQueryParams::with_q("").is(CardStatus::FullArt).is_not(CardStatus::StorySpotlight), "is:full -is:spotlight")]
+    fn search_shortcuts_should_build_query(#[case] params: SearchQueryParams, #[case] expected: &str) {
+        assert_eq!(expected, params.q);
+    }
+
+    #[rstest]
+    #[case::order_and_whitespace("c:r  T:goblin", "t:goblin c:r", true)]
+    #[case::quoted_whitespace("o:\"draw  a card\"", "o:\"draw a card\"", false)]
+    #[case::grouped("t:goblin or c:r", "c:r or t:goblin", false)]
+    fn search_query_params_should_be_normalized(#[case] first: &str, #[case] second: &str, #[case] equal: bool) {
+        let first = SearchQueryParams::with_q(first).normalized();
+        let second = SearchQueryParams::with_q(second).normalized();
+
+        assert_eq!(equal, first == second);
+    }
+
+    #[rstest]
+    fn search_query_params_should_omit_default_values() {
+        let mut params = SearchQueryParams::with_q("t:goblin");
+        params.unique = Some(UniqueMode::Cards);
+        params.dir = Some(OrderDirection::Auto);
+        params.include_extras = Some(false);
+        params.page = Some(1);
+
+        assert_eq!("q=t:goblin&page=1", params.normalized());
+
+        params.unique = Some(UniqueMode::Prints);
+        params.page = Some(3);
+        assert_eq!("q=t:goblin&unique=prints&page=3", params.normalized());
+    }
+
+    #[rstest]
+    fn search_query_params_should_render_url() {
+        let mut params = SearchQueryParams::with_q("is:commander id<=wu");
+        params.dir = Some(OrderDirection::Desc);
+        params.page = Some(2);
+
+        let url = params.to_url(&"https://scryfall.com/".parse().unwrap());
+
+        assert_eq!("is:commander id<=wu direction:desc", params.render());
+        assert_eq!("https://scryfall.com/search?q=is%3Acommander+id%3C%3Dwu&dir=desc&page=2", url.as_str());
+    }
+
+    #[rstest]
+    #[case::printed(serde_json::json!({ "printed_name": "Nom", "printed_type_line": "Éphémère", "printed_text": "Texte" }), "Nom", "Éphémère", Some("Texte"))]
+    #[case::oracle(serde_json::json!({ "oracle_text": "Text" }), "Test Card", "Instant", Some("Text"))]
+    fn card_should_prefer_printed_fields(
+        #[case] overrides: serde_json::Value,
+        #[case] name: &str,
+        #[case] type_line: &str,
+        #[case] text: Option<&str>,
+    ) {
+        let card = crate::fixtures::card(overrides);
+
+        assert_eq!(name, card.localized_name());
+        assert_eq!(type_line, card.localized_type_line());
+        assert_eq!(text, card.localized_text());
+    }
+
+    #[rstest]
+    fn cards_should_be_compared_semantically() {
+        let card = crate::fixtures::card(serde_json::json!({ "cmc": 0.1 + 0.2, "prices": { "usd": "1.00" } }));
+        let repriced = crate::fixtures::card(serde_json::json!({ "cmc": 0.3, "prices": { "usd": "2.00" } }));
+        let reprint = crate::fixtures::card(serde_json::json!({ "id": "00000000-0000-0000-0000-000000000002" }));
+
+        assert_ne!(card, repriced);
+        assert!(card.same_printing(&repriced) && !card.same_printing(&reprint));
+        assert!(card.same_oracle(&reprint));
+        assert_eq!(card.mana_value(), repriced.mana_value());
+    }
+
+    #[rstest]
+    #[case::half(0.5, 500)]
+    #[case::float_error(0.1 + 0.2, 300)]
+    #[case::large(1_000_000.0, 1_000_000_000)]
+    #[case::negative(-1.0, 0)]
+    #[case::nan(f64::NAN, 0)]
+    fn mana_values_should_be_exact(#[case] cmc: f64, #[case] thousandths: u64) {
+        assert_eq!(thousandths, ManaValue::from_f64(cmc).thousandths());
+    }
+
+    #[rstest]
+    #[case::regular(serde_json::json!({ "reserved": true }), true, true)]
+    #[case::token(serde_json::json!({ "layout": "token" }), false, false)]
+    #[case::art_series(serde_json::json!({ "layout": "art_series" }), false, false)]
+    #[case::digital(serde_json::json!({ "digital": true, "games": ["arena"] }), false, false)]
+    #[case::oversized(serde_json::json!({ "oversized": true }), false, false)]
+    fn card_statuses_should_be_checked(
+        #[case] overrides: serde_json::Value,
+        #[case] reserved: bool,
+        #[case] collectible: bool,
+    ) {
+        let card = crate::fixtures::card(overrides);
+
+        assert_eq!(reserved, card.has_status(CardStatus::Reserved));
+        assert_eq!(collectible, card.is_collectible_paper_card());
+    }
+
+    #[rstest]
+    #[case::normal(serde_json::json!({}), false, false, false, true)]
+    #[case::token(serde_json::json!({ "layout": "token" }), true, false, false, false)]
+    #[case::token_set(serde_json::json!({ "layout": "normal", "set_type": "token" }), true, false, false, false)]
+    #[case::emblem(serde_json::json!({ "layout": "emblem", "set_type": "token" }), false, false, true, false)]
+    #[case::art_series(serde_json::json!({ "layout": "art_series", "set_type": "memorabilia" }), false, true, false, false)]
+    #[case::plane(serde_json::json!({ "layout": "planar" }), false, false, false, false)]
+    fn card_kinds_should_be_derived_from_layout(
+        #[case] overrides: serde_json::Value,
+        #[case] token: bool,
+        #[case] art_series: bool,
+        #[case] emblem: bool,
+        #[case] playable: bool,
+    ) {
+        let card = crate::fixtures::card(overrides);
+
+        assert_eq!(token, card.is_token());
+        assert_eq!(art_series, card.is_art_series());
+        assert_eq!(emblem, card.is_emblem());
+        assert_eq!(playable, card.is_playable_in_deck());
+    }
+
+    #[rstest]
+    fn numeric_fields_should_accept_strings() {
+        let card = crate::fixtures::card(serde_json::json!({
+            "cmc": "9", "edhrec_rank": "1200", "arena_id": 70000.0, "multiverse_ids": ["1", 2],
+        }));
+
+        assert_eq!(9.0, card.cmc);
+        assert_eq!(Some(1200), card.edhrec_rank);
+        assert_eq!(Some(70000), card.arena_id);
+        assert_eq!(Some(vec![1, 2]), card.multiverse_ids);
+        assert_eq!(None, card.penny_rank);
+    }
+
+    #[rstest]
+    fn cards_should_round_trip_through_compact_formats() {
+        let card = crate::fixtures::card(serde_json::json!({
+            "cmc": "9", "edhrec_rank": 1200, "multiverse_ids": [1, 2], "released_at": "2019-06",
+        }));
+        let bytes = crate::fixtures::compact::to_bytes(&card).unwrap();
+
+        assert_eq!(card, crate::fixtures::compact::from_bytes::<Card>(&bytes).unwrap());
+    }
+
+    #[rstest]
+    fn unknown_release_dates_should_be_reported() {
+        let card = crate::fixtures::card(serde_json::json!({ "released_at": "unknown" }));
+
+        assert_eq!(UNKNOWN_DATE, card.released_at);
+        assert_eq!(vec!["released_at is missing or invalid".to_string()], card.violations());
+    }
 }
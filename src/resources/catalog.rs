@@ -1,13 +1,22 @@
 //! Catalog resource definitions
 //!
 //! See [Scryfall api documentation](https://scryfall.com/docs/api/catalogs)
+//!
+//! Catalog values can be used directly in validation code: numeric catalogs (powers,
+//! toughnesses and loyalties) are parsed with [as_numeric](Catalog::as_numeric),
+//! and type catalogs (e.g. creature or land types) are wrapped in a [TypeCatalog](TypeCatalog)
+//! for case-insensitive lookups.
 
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
 use CatalogResource::*;
 
 use crate::resources::{HttpResource, ResourceKind};
+use crate::resources::validation::{expect_kind, Validate};
+#[cfg(feature = "cards")]
+use crate::resources::cards::Stat;
 
 /// Endpoints for `/catalog` resource
 pub enum CatalogResource {
@@ -60,6 +69,16 @@ pub enum CatalogResource {
     WordBank,
 }
 
+impl CatalogResource {
+    /// All catalog endpoints
+    pub const ALL: [CatalogResource; 16] = [
+        AbilityWords, ArtifactTypes, ArtistNames, CardNames,
+        CreatureTypes, EnchantmentTypes, KeywordAbilities, KeywordActions,
+        LandTypes, Loyalties, PlaneswalkerTypes, Powers,
+        SpellTypes, Toughnesses, Watermarks, WordBank,
+    ];
+}
+
 impl HttpResource<Catalog> for CatalogResource {
     fn path(&self) -> String {
         format!("catalog/{}", match self {
@@ -81,14 +100,179 @@ impl HttpResource<Catalog> for CatalogResource {
             WordBank => "word-bank",
         })
     }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::Catalog)
+    }
+
+    fn validate(&self, model: &Catalog) -> Vec<String> {
+        model.violations()
+    }
 }
 
+crate::resources::display_path!(CatalogResource);
+
 /// Basic struct representing a catalog
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct Catalog {
     #[serde(rename = "object")]
     pub kind: ResourceKind,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<crate::resources::archive::AsUrl>))]
     pub uri: Option<Url>,
     pub total_values: i64,
     pub data: Vec<String>,
 }
+
+impl Catalog {
+    /// Gets the numeric values of the catalog (e.g. of powers, toughnesses or loyalties)
+    ///
+    /// Non-numeric values (e.g. `*`, `1+*`, `X`, `?` or `∞`) are skipped.
+    /// Values with a sign or a fraction (e.g. `+2` or `1.5`) are kept.
+    pub fn as_numeric(&self) -> Vec<f64> {
+        self.data.iter()
+            .filter_map(|value| value.trim().parse::<f64>().ok())
+            .filter(|value| value.is_finite())
+            .collect()
+    }
+
+    /// Gets the values of the catalog parsed as [stats](Stat) (e.g. of powers or toughnesses)
+    #[cfg(feature = "cards")]
+    pub fn as_stats(&self) -> Vec<Stat> {
+        self.data.iter().map(|value| Stat::parse(value)).collect()
+    }
+}
+
+/// A catalog of types (e.g. creature or land types), with case-insensitive lookups
+///
+/// # Example
+/// ```
+/// use scryfall_sdk_rust::resources::catalog::{Catalog, TypeCatalog};
+/// use scryfall_sdk_rust::resources::ResourceKind;
+///
+/// let types = TypeCatalog::from(Catalog {
+///     kind: ResourceKind::Catalog,
+///     uri: None,
+///     total_values: 2,
+///     data: vec!["Elf".into(), "Goblin".into()],
+/// });
+///
+/// assert!(types.contains("elf"));
+/// assert_eq!(Some("Goblin"), types.get("GOBLIN"));
+/// assert!(!types.contains("Elves"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TypeCatalog {
+    values: Vec<String>,
+    by_lowercase: HashMap<String, usize>,
+}
+
+impl TypeCatalog {
+    /// Creates a type catalog from its values
+    pub fn new<I, S>(values: I) -> Self
+        where I: IntoIterator<Item = S>,
+              S: Into<String>
+    {
+        let values: Vec<String> = values.into_iter().map(Into::into).collect();
+        let by_lowercase = values.iter()
+            .enumerate()
+            .map(|(i, value)| (value.to_lowercase(), i))
+            .collect();
+
+        TypeCatalog { values, by_lowercase }
+    }
+
+    /// Checks if the catalog contains a type (ignoring case)
+    pub fn contains(&self, value: &str) -> bool {
+        self.get(value).is_some()
+    }
+
+    /// Gets the spelling of a type in the catalog (ignoring case)
+    pub fn get(&self, value: &str) -> Option<&str> {
+        self.by_lowercase.get(&value.trim().to_lowercase())
+            .map(|i| self.values[*i].as_str())
+    }
+
+    /// Gets the types of the catalog (in catalog order)
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    /// Gets the number of types of the catalog
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Checks if the catalog is empty
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl From<Catalog> for TypeCatalog {
+    fn from(catalog: Catalog) -> Self {
+        TypeCatalog::new(catalog.data)
+    }
+}
+
+impl Validate for Catalog {
+    fn violations(&self) -> Vec<String> {
+        let mut violations: Vec<String> = expect_kind(&self.kind, ResourceKind::Catalog)
+            .into_iter()
+            .collect();
+
+        if self.total_values != self.data.len() as i64 {
+            violations.push(format!(
+                "total_values ({}) does not match the catalog values ({})",
+                self.total_values, self.data.len()
+            ));
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn catalog(values: &[&str]) -> Catalog {
+        Catalog {
+            kind: ResourceKind::Catalog,
+            uri: None,
+            total_values: values.len() as i64,
+            data: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+
+    #[rstest]
+    fn numeric_values_should_skip_non_numbers() {
+        let powers = catalog(&["*", "-1", "+2", "1.5", "1+*", "?", "X", "∞", "inf", "3"]);
+
+        assert_eq!(vec![-1.0, 2.0, 1.5, 3.0], powers.as_numeric());
+    }
+
+    #[rstest]
+    #[cfg(feature = "cards")]
+    fn stats_should_be_parsed() {
+        let powers = catalog(&["*", "1+*", "3"]);
+
+        assert_eq!(vec![Stat::Star, Stat::StarPlus(1), Stat::Fixed(3)], powers.as_stats());
+    }
+
+    #[rstest]
+    #[case::exact("Elf", Some("Elf"))]
+    #[case::lowercase("elf", Some("Elf"))]
+    #[case::padded(" ELF ", Some("Elf"))]
+    #[case::multi_word("time lord", Some("Time Lord"))]
+    #[case::unknown("Elves", None)]
+    fn types_should_be_found_ignoring_case(#[case] value: &str, #[case] expected: Option<&str>) {
+        let types = TypeCatalog::from(catalog(&["Elf", "Goblin", "Time Lord"]));
+
+        assert_eq!(expected, types.get(value));
+        assert_eq!(expected.is_some(), types.contains(value));
+        assert_eq!(3, types.len());
+    }
+}
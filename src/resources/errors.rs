@@ -3,6 +3,7 @@
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::result::Result;
+use reqwest::StatusCode;
 use crate::resources::ResourceKind;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// 
 /// See more info in [Scryfall API official documentation](https://scryfall.com/docs/api/errors) 
 /// about the error object.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ErrorBody {
     pub code: String,
     
@@ -24,11 +25,34 @@ pub struct ErrorBody {
     pub status: i16,
     
     pub warnings: Option<Vec<String>>,
+
+    /// Failed request (method and encoded path), set by the clients
+    ///
+    /// This is not part of the error object: it is neither serialized nor deserialized,
+    /// and errors of different requests are still equal.
+    #[serde(skip)]
+    pub(crate) request: Option<Box<str>>,
+}
+
+impl PartialEq for ErrorBody {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+            && self.details == other.details
+            && self.error_type == other.error_type
+            && self.kind == other.kind
+            && self.status == other.status
+            && self.warnings == other.warnings
+    }
 }
 
 impl Display for ErrorBody {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> { 
-        write!(f, "{}: {}", self.code, self.details)
+        write!(f, "{}: {}", self.code, self.details)?;
+
+        match &self.request {
+            Some(request) => write!(f, " ({request})"),
+            None => Ok(()),
+        }
     }
 }
 
@@ -51,13 +75,211 @@ impl ErrorBody {
     /// }
     /// ```
     pub fn from_reqwest_error(e: reqwest::Error) -> Self {
+        ErrorBody::client_error(e.to_string())
+    }
+
+    /// Creates a client-side error body with the given details
+    ///
+    /// This has the same form as errors created by
+    /// [from_reqwest_error](ErrorBody::from_reqwest_error), but is used for errors
+    /// raised by the SDK itself (e.g. unparsable input).
+    pub fn client_error(details: impl Into<String>) -> Self {
         ErrorBody {
             code: "CLIENT_ERR".into(),
-            details: e.to_string(),
+            details: details.into(),
             error_type: None,
             kind: ResourceKind::Error,
             status: 599,
             warnings: None,
+            request: None,
         }
     }
-}
\ No newline at end of file
+
+    /// Creates the error returned while a [circuit breaker](crate::client::circuit_breaker) is open
+    ///
+    /// ```json
+    /// {
+    ///     "code": "CIRCUIT_OPEN",
+    ///     "details": "Circuit breaker is open, retry in 1.5s",
+    ///     "kind": "error",
+    ///     "status": 503
+    /// }
+    /// ```
+    pub fn circuit_open(retry_in: std::time::Duration) -> Self {
+        ErrorBody {
+            code: "CIRCUIT_OPEN".into(),
+            status: 503,
+            ..ErrorBody::client_error(format!("Circuit breaker is open, retry in {:.1?}", retry_in))
+        }
+    }
+
+    /// Creates the error returned when the `object` of a response does not match the endpoint's model
+    ///
+    /// This usually means that a resource was requested into the wrong model
+    /// (e.g. a `list` returned where a `card` was expected):
+    ///
+    /// ```json
+    /// {
+    ///     "code": "UNEXPECTED_OBJECT",
+    ///     "details": "Expected object `card`, got `list`",
+    ///     "kind": "error",
+    ///     "status": 599
+    /// }
+    /// ```
+    pub fn unexpected_object(expected: ResourceKind, got: ResourceKind) -> Self {
+        ErrorBody {
+            code: "UNEXPECTED_OBJECT".into(),
+            ..ErrorBody::client_error(format!("Expected object `{expected}`, got `{got}`"))
+        }
+    }
+
+    /// Creates the error returned when a response fails [validation](crate::resources::validation)
+    ///
+    /// The violations are included as warnings:
+    ///
+    /// ```json
+    /// {
+    ///     "code": "INVALID_RESPONSE",
+    ///     "details": "Response failed validation: total_values (3) does not match ...",
+    ///     "kind": "error",
+    ///     "status": 599,
+    ///     "warnings": [...]
+    /// }
+    /// ```
+    pub fn invalid_response(violations: Vec<String>) -> Self {
+        ErrorBody {
+            code: "INVALID_RESPONSE".into(),
+            warnings: Some(violations.clone()),
+            ..ErrorBody::client_error(format!("Response failed validation: {}", violations.join("; ")))
+        }
+    }
+
+    /// Creates the error returned when a response body exceeds the
+    /// [maximum response size](crate::ScryfallBuilder::max_response_size) of a client
+    ///
+    /// ```json
+    /// {
+    ///     "code": "RESPONSE_TOO_LARGE",
+    ///     "details": "Response body exceeds the limit of 1048576 bytes",
+    ///     "kind": "error",
+    ///     "status": 599
+    /// }
+    /// ```
+    pub fn response_too_large(limit: usize) -> Self {
+        ErrorBody {
+            code: "RESPONSE_TOO_LARGE".into(),
+            ..ErrorBody::client_error(format!("Response body exceeds the limit of {limit} bytes"))
+        }
+    }
+
+    /// Creates the error of a response whose body is not an error object
+    /// (e.g. the HTML page of a proxy, or an empty body), from its HTTP status
+    ///
+    /// ```json
+    /// {
+    ///     "code": "HTTP_ERROR",
+    ///     "details": "502 Bad Gateway",
+    ///     "kind": "error",
+    ///     "status": 502
+    /// }
+    /// ```
+    pub fn http_error(status: StatusCode) -> Self {
+        ErrorBody {
+            code: "HTTP_ERROR".into(),
+            status: status.as_u16() as i16,
+            ..ErrorBody::client_error(status.to_string())
+        }
+    }
+
+    /// Creates the error returned by resource constructors for invalid parameters
+    ///
+    /// These errors are raised before any request is sent:
+    ///
+    /// ```json
+    /// {
+    ///     "code": "INVALID_INPUT",
+    ///     "details": "Invalid collector number `12/3`",
+    ///     "kind": "error",
+    ///     "status": 400
+    /// }
+    /// ```
+    pub fn invalid_input(details: impl Into<String>) -> Self {
+        ErrorBody {
+            code: "INVALID_INPUT".into(),
+            status: 400,
+            ..ErrorBody::client_error(details)
+        }
+    }
+
+    /// Gets the HTTP status matching the kind of the error, for errors caused by the request
+    ///
+    /// Services proxying errors to their own clients can use it to map errors consistently:
+    ///
+    /// | error                                               | status                  |
+    /// |-----------------------------------------------------|-------------------------|
+    /// | `not_found` (e.g. unknown card or ambiguous name)   | `404 Not Found`         |
+    /// | `bad_request` and `INVALID_INPUT`                   | `400 Bad Request`       |
+    /// | `rate_limited`, `too_many_requests` and `throttled` | `429 Too Many Requests` |
+    /// | other codes with a `4xx` status                     | same status             |
+    ///
+    /// Failures of the Scryfall API (`5xx`) or of the client (e.g. timeouts)
+    /// are not caused by the request, and have no hint.
+    /// The `http_status` of the **web** feature uses the hint when there is one.
+    pub fn status_hint(&self) -> Option<StatusCode> {
+        match self.code.as_str() {
+            "not_found" => Some(StatusCode::NOT_FOUND),
+            "bad_request" | "INVALID_INPUT" => Some(StatusCode::BAD_REQUEST),
+            "rate_limited" | "too_many_requests" | "throttled" => Some(StatusCode::TOO_MANY_REQUESTS),
+            _ => match self.status {
+                400..=499 => StatusCode::from_u16(self.status as u16).ok(),
+                _ => None,
+            },
+        }
+    }
+
+    /// Gets the failed request (method and encoded path, e.g. `GET cards/123`), if any
+    pub fn request(&self) -> Option<&str> {
+        self.request.as_deref()
+    }
+
+    /// Sets the failed request (method and encoded path), displayed after the details
+    ///
+    /// e.g. `not_found: No cards found matching “Lightnin Bolt” (GET cards/named?exact=Lightnin%20Bolt)`
+    pub(crate) fn with_request(mut self, request: &str) -> Self {
+        self.request = Some(request.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn error(code: &str, status: i16) -> ErrorBody {
+        ErrorBody { code: code.into(), status, ..ErrorBody::client_error("") }
+    }
+
+    #[rstest]
+    #[case::not_found(error("not_found", 404), Some(StatusCode::NOT_FOUND))]
+    #[case::bad_request(error("bad_request", 422), Some(StatusCode::BAD_REQUEST))]
+    #[case::invalid_input(ErrorBody::invalid_input("blank name"), Some(StatusCode::BAD_REQUEST))]
+    #[case::throttled(error("rate_limited", 429), Some(StatusCode::TOO_MANY_REQUESTS))]
+    #[case::other_client_error(error("conflict", 409), Some(StatusCode::CONFLICT))]
+    #[case::server_error(error("internal", 500), None)]
+    #[case::circuit_open(ErrorBody::circuit_open(std::time::Duration::from_secs(1)), None)]
+    #[case::client_error(ErrorBody::client_error("timeout"), None)]
+    fn errors_should_hint_http_status(#[case] error: ErrorBody, #[case] expected: Option<StatusCode>) {
+        assert_eq!(expected, error.status_hint());
+    }
+
+    #[rstest]
+    fn requests_should_only_be_displayed() {
+        let error = ErrorBody::client_error("No cards found").with_request("GET cards/123");
+
+        assert_eq!("No cards found", error.details);
+        assert_eq!("CLIENT_ERR: No cards found (GET cards/123)", error.to_string());
+        assert!(!serde_json::to_string(&error).unwrap().contains("GET cards/123"));
+        assert_eq!(ErrorBody::client_error("No cards found"), error);
+    }
+}
@@ -0,0 +1,336 @@
+//! Tolerant deserializers for numeric and date fields
+//!
+//! Some historical payloads, mirrors and tools serialize numbers inconsistently
+//! (e.g. a `cmc` of `"9"`, or ids written as `12.0`). The numeric fields of the models
+//! (mana values, ranks and ids) accept:
+//! - numbers, integral floats (e.g. `12.0`) included for integer fields
+//! - strings containing a number (e.g. `"9"` or `" 2.5 "`)
+//! - `null`, missing fields and empty strings (for optional fields)
+//!
+//! Models are still serialized with plain numbers.
+//!
+//! Release dates (e.g. of funny or memorabilia entries) can be partial or invalid.
+//! They are completed when possible (`2019-06` and `2019-06-00` become `2019-06-01`,
+//! `2019-02-30` becomes `2019-02-28`), and otherwise replaced by the [UNKNOWN_DATE](UNKNOWN_DATE)
+//! sentinel, so that a single bad record does not fail the parsing of a whole list.
+//! Unknown dates are reported by [validation](super::validation): clients with the
+//! `Strict` validation mode reject them, and the `Warn` mode reports them as events.
+//! Custom models can use [option_date](option_date) to get `None` instead of the sentinel.
+//!
+//! Leniency only applies to human-readable formats (e.g. JSON). Formats that are not
+//! self-describing (e.g. bincode or postcard) cannot tell numbers and strings apart,
+//! so their values are deserialized strictly, as written by the models.
+//!
+//! The deserializers can be used on custom models with `deserialize_with`:
+//!
+//! ```
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Row {
+//!     #[serde(deserialize_with = "scryfall_sdk_rust::resources::lenient::number")]
+//!     cmc: f64,
+//!
+//!     #[serde(default, deserialize_with = "scryfall_sdk_rust::resources::lenient::option_number")]
+//!     edhrec_rank: Option<i64>,
+//! }
+//!
+//! let row: Row = serde_json::from_str(r#"{ "cmc": "9", "edhrec_rank": 120.0 }"#).unwrap();
+//! assert_eq!((9.0, Some(120)), (row.cmc, row.edhrec_rank));
+//! ```
+
+use std::convert::TryFrom;
+use serde::de::{Error, IgnoredAny};
+use serde::{Deserialize, Deserializer};
+use time::{Date, Month};
+
+/// Date of records whose date is missing or cannot be parsed
+pub const UNKNOWN_DATE: Date = Date::MIN;
+
+/// A number, as accepted by the tolerant deserializers
+pub trait LenientNumber: Sized {
+    /// Converts an integer (if in range)
+    fn from_integer(value: i64) -> Option<Self>;
+
+    /// Converts a float (if in range, and integral for integer types)
+    fn from_float(value: f64) -> Option<Self>;
+}
+
+impl LenientNumber for f64 {
+    fn from_integer(value: i64) -> Option<Self> {
+        Some(value as f64)
+    }
+
+    fn from_float(value: f64) -> Option<Self> {
+        Some(value).filter(|value| value.is_finite())
+    }
+}
+
+macro_rules! lenient_integer {
+    ($($integer:ty),+) => {$(
+        impl LenientNumber for $integer {
+            fn from_integer(value: i64) -> Option<Self> {
+                <$integer>::try_from(value).ok()
+            }
+
+            fn from_float(value: f64) -> Option<Self> {
+                match value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+                    true => Self::from_integer(value as i64),
+                    false => None,
+                }
+            }
+        }
+    )+};
+}
+
+lenient_integer!(i32, i64, u32, u64);
+
+/// A raw numeric value
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Raw {
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl Raw {
+    /// Converts the value, returning `Ok(None)` for empty strings
+    fn convert<T: LenientNumber, E: Error>(self) -> Result<Option<T>, E> {
+        let number = match &self {
+            Raw::Integer(value) => T::from_integer(*value),
+            Raw::Float(value) => T::from_float(*value),
+            Raw::String(value) if value.trim().is_empty() => return Ok(None),
+            Raw::String(value) => match value.trim().parse::<i64>() {
+                Ok(value) => T::from_integer(value),
+                Err(_) => value.trim().parse::<f64>().ok().and_then(T::from_float),
+            },
+        };
+
+        match number {
+            Some(number) => Ok(Some(number)),
+            None => Err(E::custom(format!("invalid number: {}", self))),
+        }
+    }
+}
+
+impl std::fmt::Display for Raw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Raw::Integer(value) => write!(f, "{value}"),
+            Raw::Float(value) => write!(f, "{value}"),
+            Raw::String(value) => write!(f, "{value:?}"),
+        }
+    }
+}
+
+/// Deserializes a number from a number or a string
+pub fn number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where D: Deserializer<'de>,
+          T: LenientNumber + Deserialize<'de>
+{
+    if !deserializer.is_human_readable() {
+        return T::deserialize(deserializer);
+    }
+
+    Raw::deserialize(deserializer)?
+        .convert()?
+        .ok_or_else(|| D::Error::custom("invalid number: \"\""))
+}
+
+/// Deserializes an optional number from a number, a string, an empty string or `null`
+///
+/// Fields using it need `#[serde(default)]` to also accept missing values.
+pub fn option_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where D: Deserializer<'de>,
+          T: LenientNumber + Deserialize<'de>
+{
+    if !deserializer.is_human_readable() {
+        return Option::<T>::deserialize(deserializer);
+    }
+
+    match Option::<Raw>::deserialize(deserializer)? {
+        Some(raw) => raw.convert(),
+        None => Ok(None),
+    }
+}
+
+/// Deserializes an optional list of numbers (each from a number or a string)
+///
+/// Empty strings are skipped. Fields using it need `#[serde(default)]` to also accept missing values.
+pub fn option_numbers<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+    where D: Deserializer<'de>,
+          T: LenientNumber + Deserialize<'de>
+{
+    if !deserializer.is_human_readable() {
+        return Option::<Vec<T>>::deserialize(deserializer);
+    }
+
+    match Option::<Vec<Raw>>::deserialize(deserializer)? {
+        Some(values) => values.into_iter()
+            .filter_map(|raw| raw.convert().transpose())
+            .collect::<Result<Vec<T>, D::Error>>()
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Gets the [UNKNOWN_DATE](UNKNOWN_DATE) sentinel (the default value of lenient date fields)
+pub fn unknown_date() -> Date {
+    UNKNOWN_DATE
+}
+
+/// Parses a (possibly partial) `YYYY-MM-DD` date
+///
+/// Missing or zero months and days default to the first month or day,
+/// and days past the end of the month are clamped to its last day.
+pub fn parse_date(text: &str) -> Option<Date> {
+    let mut parts = text.trim().splitn(3, '-').map(|part| {
+        let digits: String = part.trim().chars().take_while(char::is_ascii_digit).collect();
+        digits.parse::<u16>().ok()
+    });
+
+    let year = i32::from(parts.next().flatten().filter(|year| *year > 0)?);
+    let month = Month::try_from(parts.next().flatten().unwrap_or(1).clamp(1, 12) as u8).ok()?;
+    let day = parts.next().flatten().unwrap_or(1).clamp(1, 31) as u8;
+
+    (1..=day).rev().find_map(|day| Date::from_calendar_date(year, month, day).ok())
+}
+
+/// A raw date value
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawDate {
+    String(String),
+    Other(IgnoredAny),
+}
+
+/// Deserializes a date leniently, using [UNKNOWN_DATE](UNKNOWN_DATE) for missing or invalid dates
+///
+/// Fields using it need `#[serde(default = "scryfall_sdk_rust::resources::lenient::unknown_date")]`
+/// to also accept missing values.
+pub fn date<'de, D>(deserializer: D) -> Result<Date, D::Error>
+    where D: Deserializer<'de>
+{
+    if !deserializer.is_human_readable() {
+        return Date::deserialize(deserializer);
+    }
+
+    option_date(deserializer).map(|date| date.unwrap_or(UNKNOWN_DATE))
+}
+
+/// Deserializes a date leniently, using `None` for missing or invalid dates
+///
+/// Fields using it need `#[serde(default)]` to also accept missing values.
+pub fn option_date<'de, D>(deserializer: D) -> Result<Option<Date>, D::Error>
+    where D: Deserializer<'de>
+{
+    if !deserializer.is_human_readable() {
+        return Option::<Date>::deserialize(deserializer);
+    }
+
+    Ok(match RawDate::deserialize(deserializer)? {
+        RawDate::String(text) => parse_date(&text),
+        RawDate::Other(_) => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::{json, Value};
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Numbers {
+        #[serde(deserialize_with = "number")]
+        cmc: f64,
+
+        #[serde(default, deserialize_with = "option_number")]
+        rank: Option<i64>,
+
+        #[serde(default, deserialize_with = "option_numbers")]
+        ids: Option<Vec<i32>>,
+    }
+
+    fn parse(json: Value) -> Result<Numbers, serde_json::Error> {
+        serde_json::from_value(json)
+    }
+
+    #[rstest]
+    #[case::numbers(json!({ "cmc": 2.5, "rank": 12, "ids": [1, 2] }), 2.5, Some(12), Some(vec![1, 2]))]
+    #[case::strings(json!({ "cmc": "9", "rank": " 12 ", "ids": ["1", 2] }), 9.0, Some(12), Some(vec![1, 2]))]
+    #[case::integral_floats(json!({ "cmc": 3, "rank": 12.0, "ids": [1.0] }), 3.0, Some(12), Some(vec![1]))]
+    #[case::empty(json!({ "cmc": "0.5", "rank": "", "ids": [""] }), 0.5, None, Some(vec![]))]
+    #[case::missing(json!({ "cmc": 0, "rank": null }), 0.0, None, None)]
+    fn numbers_should_be_parsed_leniently(#[case] json: Value, #[case] cmc: f64, #[case] rank: Option<i64>, #[case] ids: Option<Vec<i32>>) {
+        assert_eq!(Numbers { cmc, rank, ids }, parse(json).unwrap());
+    }
+
+    #[rstest]
+    #[case::not_a_number(json!({ "cmc": "X" }))]
+    #[case::fractional_integer(json!({ "cmc": 1, "rank": 1.5 }))]
+    #[case::out_of_range(json!({ "cmc": 1, "ids": [4294967296i64] }))]
+    #[case::empty_required(json!({ "cmc": "" }))]
+    fn invalid_numbers_should_fail(#[case] json: Value) {
+        assert!(parse(json).is_err());
+    }
+
+    #[rstest]
+    #[case::full("2019-06-14", Some((2019, Month::June, 14)))]
+    #[case::month("2019-06", Some((2019, Month::June, 1)))]
+    #[case::zero_day("2019-06-00", Some((2019, Month::June, 1)))]
+    #[case::year("2019-00-00", Some((2019, Month::January, 1)))]
+    #[case::past_month_end("2019-02-30", Some((2019, Month::February, 28)))]
+    #[case::timestamp("2019-06-14T10:00:00Z", Some((2019, Month::June, 14)))]
+    #[case::zero_year("0000-00-00", None)]
+    #[case::text("unknown", None)]
+    #[case::sentinel("-9999-01-01", None)]
+    fn partial_dates_should_be_completed(#[case] text: &str, #[case] expected: Option<(i32, Month, u8)>) {
+        let expected = expected.map(|(year, month, day)| Date::from_calendar_date(year, month, day).unwrap());
+
+        assert_eq!(expected, parse_date(text));
+    }
+
+    #[derive(Debug, Deserialize, serde::Serialize, PartialEq)]
+    struct Dated {
+        #[serde(default = "unknown_date", deserialize_with = "date")]
+        released_at: Date,
+    }
+
+    #[rstest]
+    #[case::invalid(json!({ "released_at": "????" }))]
+    #[case::null(json!({ "released_at": null }))]
+    #[case::number(json!({ "released_at": 2019 }))]
+    #[case::missing(json!({}))]
+    fn invalid_dates_should_be_unknown(#[case] json: Value) {
+        assert_eq!(Dated { released_at: UNKNOWN_DATE }, serde_json::from_value(json).unwrap());
+    }
+
+    #[rstest]
+    fn unknown_dates_should_round_trip() {
+        let json = serde_json::to_value(Dated { released_at: UNKNOWN_DATE }).unwrap();
+
+        assert_eq!(Dated { released_at: UNKNOWN_DATE }, serde_json::from_value(json).unwrap());
+    }
+
+    #[derive(Debug, Deserialize, serde::Serialize, PartialEq)]
+    struct Compact {
+        #[serde(deserialize_with = "number")]
+        cmc: f64,
+
+        #[serde(default, deserialize_with = "option_numbers")]
+        ids: Option<Vec<i32>>,
+
+        #[serde(default = "unknown_date", deserialize_with = "date")]
+        released_at: Date,
+    }
+
+    #[rstest]
+    fn values_should_round_trip_through_compact_formats() {
+        let value = Compact { cmc: 2.5, ids: Some(vec![1, 2]), released_at: UNKNOWN_DATE };
+        let bytes = crate::fixtures::compact::to_bytes(&value).unwrap();
+
+        assert_eq!(value, crate::fixtures::compact::from_bytes(&bytes).unwrap());
+    }
+}
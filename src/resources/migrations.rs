@@ -0,0 +1,179 @@
+//! Migration resource definitions
+//!
+//! Migrations record cards whose Scryfall id was merged into another card or deleted,
+//! so that local copies of the card database can be updated.
+//!
+//! See [Scryfall api documentation](https://scryfall.com/docs/api/migrations)
+
+use serde::{Deserialize, Serialize};
+use time::Date;
+use url::Url;
+use uuid::Uuid;
+
+use crate::HttpResource;
+use crate::resources::ResourceKind;
+use crate::resources::validation::{expect_kind, Validate};
+
+/// Endpoints for `/migrations` resource (list)
+pub enum MigrationListResource {
+    /// Binding for endpoint `GET /migrations`
+    ///
+    /// Get the first page of migrations (newest first).
+    All,
+
+    /// Binding for endpoint `GET /migrations?page=:page`
+    ///
+    /// Get a page of migrations (newest first).
+    Page(u32),
+}
+
+impl HttpResource<MigrationList> for MigrationListResource {
+    fn path(&self) -> String {
+        match self {
+            MigrationListResource::All => "migrations".into(),
+            MigrationListResource::Page(page) => format!("migrations?page={page}"),
+        }
+    }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::List)
+    }
+
+    fn validate(&self, model: &MigrationList) -> Vec<String> {
+        model.violations()
+    }
+}
+
+/// Endpoints for `/migrations/:id` resource (single)
+pub enum MigrationResource<'a> {
+    /// Binding for endpoint `GET /migrations/:id`
+    ///
+    /// Get a migration by its id.
+    ById(&'a str),
+}
+
+impl<'a> HttpResource<Migration> for MigrationResource<'a> {
+    fn path(&self) -> String {
+        match self {
+            MigrationResource::ById(id) => format!("migrations/{id}"),
+        }
+    }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::Migration)
+    }
+
+    fn validate(&self, model: &Migration) -> Vec<String> {
+        model.violations()
+    }
+}
+
+crate::resources::display_path!(MigrationListResource, MigrationResource<'a>);
+
+/// Basic struct representing a (paginated) migration list
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+pub struct MigrationList {
+    #[serde(rename = "object")]
+    pub kind: ResourceKind,
+    pub has_more: bool,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Map<crate::resources::archive::AsUrl>))]
+    pub next_page: Option<Url>,
+    pub data: Vec<Migration>,
+}
+
+impl Validate for MigrationList {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::List).into_iter()
+            .chain(self.data.iter().flat_map(Validate::violations))
+            .collect()
+    }
+}
+
+/// A card migration
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+pub struct Migration {
+    #[serde(rename = "object")]
+    pub kind: ResourceKind,
+    pub id: Uuid,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsUrl))]
+    pub uri: Url,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsJulianDay))]
+    pub performed_at: Date,
+    pub migration_strategy: MigrationStrategy,
+    pub old_scryfall_id: Uuid,
+
+    /// The card the old id was merged into (only for merges)
+    pub new_scryfall_id: Option<Uuid>,
+    pub note: Option<String>,
+}
+
+impl Validate for Migration {
+    fn violations(&self) -> Vec<String> {
+        let mut violations: Vec<String> = expect_kind(&self.kind, ResourceKind::Migration)
+            .into_iter()
+            .collect();
+
+        if self.migration_strategy == MigrationStrategy::Merge && self.new_scryfall_id.is_none() {
+            violations.push(format!("merge migration `{}` has no `new_scryfall_id`", self.id));
+        }
+
+        violations
+    }
+}
+
+/// What happened to the old card id
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationStrategy {
+    /// The old id was merged into `new_scryfall_id`
+    Merge,
+
+    /// The old id was deleted
+    Delete,
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use reqwest::Method;
+
+    #[rstest]
+    #[case::all(MigrationListResource::All, "migrations")]
+    #[case::page(MigrationListResource::Page(2), "migrations?page=2")]
+    fn migration_list_resource_should_return_path_and_method(
+        #[case] resource: MigrationListResource,
+        #[case] expected: &str
+    ) {
+        assert_eq!(expected, resource.path());
+        assert_eq!(Method::GET, resource.method());
+    }
+
+    #[rstest]
+    fn migration_resource_should_return_path_and_method() {
+        let resource = MigrationResource::ById("123");
+
+        assert_eq!("migrations/123", resource.path());
+        assert_eq!(Method::GET, resource.method());
+    }
+
+    #[rstest]
+    fn merge_migration_should_have_new_id() {
+        let migration: Migration = serde_json::from_value(serde_json::json!({
+            "object": "migration",
+            "id": "00000000-0000-0000-0000-000000000001",
+            "uri": "http://some.url",
+            "performed_at": "2024-01-01",
+            "migration_strategy": "merge",
+            "old_scryfall_id": "00000000-0000-0000-0000-000000000002",
+            "new_scryfall_id": null,
+            "note": null
+        })).unwrap();
+
+        assert_eq!(1, migration.violations().len());
+    }
+}
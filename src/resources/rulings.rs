@@ -2,25 +2,33 @@
 //!
 //! See [Scryfall api documentation](https://scryfall.com/docs/api/rulings)
 
+use std::borrow::Cow;
 use serde::{Deserialize, Serialize};
 use time::Date;
+#[cfg(feature = "cards")]
+use uuid::Uuid;
 
 use RulingListResource::{ByArenaId, ByCardId, ByMtgoId, ByMultiverseId, BySetCode};
 
 use crate::HttpResource;
-use crate::resources::ResourceKind;
+use crate::resources::{collector_number_param, owned, set_code_param, ResourceKind};
+use crate::resources::errors::ErrorBody;
+use crate::resources::validation::{expect_kind, Validate};
 
 /// Endpoints for `/cards/**/rulings` resource
+///
+/// String parameters are either borrowed or owned (see [CardResource](crate::CardResource)).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RulingListResource<'a> {
     /// Binding for endpoint `GET /cards/:id/rulings`
     ///
     /// Get ruling list by card's scryfall id.
-    ByCardId(&'a str),
+    ByCardId(Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/:code/:number/rulings`
     ///
-    /// Get ruling list by card set and card number.
-    BySetCode(&'a str, i32),
+    /// Get ruling list by card set and collector number (e.g. `123a`).
+    BySetCode(Cow<'a, str>, Cow<'a, str>),
 
     /// Binding for endpoint `GET /cards/arena/:id/rulings`
     ///
@@ -38,20 +46,57 @@ pub enum RulingListResource<'a> {
     ByMultiverseId(i32),
 }
 
+impl<'a> RulingListResource<'a> {
+    /// Gets the rulings of a card by its Scryfall id
+    #[cfg(feature = "cards")]
+    pub fn by_card_id(id: &Uuid) -> RulingListResource<'static> {
+        ByCardId(id.to_string().into())
+    }
+
+    /// Gets the rulings of a card by its set code and collector number (e.g. `("clb", "691")`)
+    ///
+    /// Fails with an `INVALID_INPUT` error for invalid set codes or collector numbers.
+    pub fn by_set_code(code: impl Into<Cow<'a, str>>, number: impl Into<Cow<'a, str>>) -> Result<Self, ErrorBody> {
+        Ok(BySetCode(set_code_param(code)?, collector_number_param(number)?))
+    }
+
+    /// Converts the resource into one owning its parameters
+    pub fn into_owned(self) -> RulingListResource<'static> {
+        match self {
+            ByCardId(id) => ByCardId(owned(id)),
+            BySetCode(code, number) => BySetCode(owned(code), owned(number)),
+            ByArenaId(id) => ByArenaId(id),
+            ByMtgoId(id) => ByMtgoId(id),
+            ByMultiverseId(id) => ByMultiverseId(id),
+        }
+    }
+}
+
 impl<'a> HttpResource<RulingList> for RulingListResource<'a> {
     fn path(&self) -> String {
         format!("cards/{}/rulings", match self {
-            ByCardId(id) => format!("{id}"),
+            ByCardId(id) => id.to_string(),
             BySetCode(code, num) => format!("{code}/{num}"),
             ByArenaId(id) => format!("arena/{id}"),
             ByMtgoId(id) => format!("mtgo/{id}"),
             ByMultiverseId(id) => format!("multiverse/{id}"),
         })
     }
+
+    fn kind(&self) -> Option<ResourceKind> {
+        Some(ResourceKind::List)
+    }
+
+    fn validate(&self, model: &RulingList) -> Vec<String> {
+        model.violations()
+    }
 }
 
+crate::resources::display_path!(RulingListResource<'a>);
+
 /// Basic struct representing ruling list
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct RulingList {
     #[serde(rename = "object")]
     pub kind: ResourceKind,
@@ -59,17 +104,33 @@ pub struct RulingList {
     pub data: Vec<Ruling>,
 }
 
+impl Validate for RulingList {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::List).into_iter()
+            .chain(self.data.iter().flat_map(Validate::violations))
+            .collect()
+    }
+}
+
 /// A ruling
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
 pub struct Ruling {
     #[serde(rename = "object")]
     pub kind: ResourceKind,
     pub oracle_id: String,
     pub source: String,
+    #[cfg_attr(feature = "rkyv", with(crate::resources::archive::AsJulianDay))]
     pub published_at: Date,
     pub comment: String,
 }
 
+impl Validate for Ruling {
+    fn violations(&self) -> Vec<String> {
+        expect_kind(&self.kind, ResourceKind::Ruling).into_iter().collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -78,8 +139,8 @@ mod tests {
     use reqwest::Method;
 
     #[rstest]
-    #[case::by_card_id(RulingListResource::ByCardId("id"), "cards/id/rulings")]
-    #[case::by_set_code(RulingListResource::BySetCode("code", 123), "cards/code/123/rulings")]
+    #[case::by_card_id(RulingListResource::ByCardId("id".into()), "cards/id/rulings")]
+    #[case::by_set_code(RulingListResource::BySetCode("code".into(), "123".into()), "cards/code/123/rulings")]
     #[case::by_arena_id(RulingListResource::ByArenaId(123), "cards/arena/123/rulings")]
     #[case::by_mtgo_id(RulingListResource::ByMtgoId(123), "cards/mtgo/123/rulings")]
     #[case::by_multiverse_id(RulingListResource::ByMultiverseId(123), "cards/multiverse/123/rulings")]
@@ -90,4 +151,24 @@ mod tests {
         assert_eq!(expected, resource.path());
         assert_eq!(Method::GET, resource.method());
     }
+
+    #[rstest]
+    #[case::valid("clb", "691", Some("cards/clb/691/rulings"))]
+    #[case::suffix("clb", "123a", Some("cards/clb/123a/rulings"))]
+    #[case::star("plst", "★50", Some("cards/plst/★50/rulings"))]
+    #[case::invalid_code("c l b", "691", None)]
+    #[case::invalid_number("clb", "6/91", None)]
+    fn rulings_by_set_code_should_be_validated(#[case] code: &str, #[case] number: &str, #[case] expected: Option<&str>) {
+        let resource = RulingListResource::by_set_code(code, number);
+
+        assert_eq!(expected.map(String::from), resource.ok().map(|r| r.path()));
+    }
+
+    #[rstest]
+    #[cfg(feature = "cards")]
+    fn rulings_by_card_id_should_use_uuid() {
+        let resource = RulingListResource::by_card_id(&Uuid::nil());
+
+        assert_eq!("cards/00000000-0000-0000-0000-000000000000/rulings", resource.path());
+    }
 }
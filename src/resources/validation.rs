@@ -0,0 +1,48 @@
+//! Validation of parsed models
+//!
+//! Models implement [Validate](Validate) to report payloads that parsed
+//! but are subtly wrong (e.g. an unexpected `object` kind, or a page with
+//! `has_more` but without a `next_page`), as may be returned by misbehaving proxies or caches.
+//!
+//! Clients validate responses according to a [ValidationMode](ValidationMode):
+//! violations are either ignored, surfaced as warning events, or turned into errors.
+
+use crate::resources::ResourceKind;
+
+/// Validation hook of a parsed model
+pub trait Validate {
+    /// Gets the violations of the model (empty if valid)
+    fn violations(&self) -> Vec<String>;
+}
+
+/// How clients handle validation violations
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ValidationMode {
+    /// Responses are not validated
+    #[default]
+    Off,
+
+    /// Violations are surfaced as [InvalidResponse](crate::client::events::ClientEvent::InvalidResponse) events
+    Warn,
+
+    /// Violations fail the request with an `INVALID_RESPONSE` error
+    Strict,
+}
+
+/// Checks the `object` kind of a model
+pub(crate) fn expect_kind(got: &ResourceKind, expected: ResourceKind) -> Option<String> {
+    (*got != expected).then(|| format!("expected object `{expected}`, got `{got}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::matching(ResourceKind::Card, None)]
+    #[case::mismatch(ResourceKind::List, Some("expected object `card`, got `list`".to_string()))]
+    fn kind_should_be_checked(#[case] got: ResourceKind, #[case] expected: Option<String>) {
+        assert_eq!(expected, expect_kind(&got, ResourceKind::Card));
+    }
+}
@@ -0,0 +1,265 @@
+//! Set utilities
+//!
+//! A [SetGraph](SetGraph) groups sets under their parent set (using `parent_set_code`),
+//! e.g. tokens, promos and commander decks attached to a main expansion,
+//! which is useful for release-centric views.
+//!
+//! Upcoming releases can be listed with [upcoming](upcoming), while the preview progress
+//! of a set is reported as [PreviewProgress](PreviewProgress).
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::{CardSetListResource, Scryfall};
+//! use scryfall_sdk_rust::sets::SetGraph;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let sets = Scryfall::default().request(&CardSetListResource::All).await?;
+//! let graph = SetGraph::from(sets);
+//!
+//! for related in graph.descendants("dmu") {
+//!     println!("{} ({})", related.name, related.code);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use time::Date;
+use crate::resources::card_sets::{CardSet, CardSetList};
+#[cfg(feature = "cards")]
+use crate::resources::cards::{SearchQueryParams, UniqueMode};
+
+/// Parent to children graph of sets
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SetGraph {
+    sets: HashMap<String, CardSet>,
+    children: HashMap<String, Vec<String>>,
+}
+
+impl From<CardSetList> for SetGraph {
+    fn from(list: CardSetList) -> Self {
+        SetGraph::from_iter(list.data)
+    }
+}
+
+impl FromIterator<CardSet> for SetGraph {
+    fn from_iter<I: IntoIterator<Item = CardSet>>(sets: I) -> Self {
+        let mut graph = SetGraph::default();
+
+        for set in sets {
+            if let Some(parent) = &set.parent_set_code {
+                graph.children.entry(parent.to_lowercase())
+                    .or_default()
+                    .push(set.code.to_lowercase());
+            }
+
+            graph.sets.insert(set.code.to_lowercase(), set);
+        }
+
+        let sets = &graph.sets;
+        for codes in graph.children.values_mut() {
+            codes.sort_by_key(|code| sets.get(code).map(|s| (s.released_at, s.code.clone())));
+        }
+
+        graph
+    }
+}
+
+impl SetGraph {
+    /// Gets a set by its code
+    pub fn get(&self, code: &str) -> Option<&CardSet> {
+        self.sets.get(&code.to_lowercase())
+    }
+
+    /// Gets the parent of a set (if any)
+    pub fn parent(&self, code: &str) -> Option<&CardSet> {
+        self.get(code)?.parent_set_code.as_deref().and_then(|p| self.get(p))
+    }
+
+    /// Gets the direct children of a set, ordered by release date
+    pub fn children(&self, code: &str) -> Vec<&CardSet> {
+        self.children.get(&code.to_lowercase())
+            .map(|codes| codes.iter().filter_map(|c| self.sets.get(c)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Gets all the descendants of a set (children first, depth-first)
+    pub fn descendants(&self, code: &str) -> Vec<&CardSet> {
+        let mut descendants = vec![];
+        let mut visited = HashSet::new();
+        let mut pending: Vec<&CardSet> = self.children(code).into_iter().rev().collect();
+
+        while let Some(set) = pending.pop() {
+            if !visited.insert(set.code.to_lowercase()) {
+                continue;
+            }
+
+            descendants.push(set);
+            pending.extend(self.children(&set.code).into_iter().rev());
+        }
+
+        descendants
+    }
+
+    /// Gets the top-most ancestor of a set (the set itself if it has no parent)
+    pub fn root_of(&self, code: &str) -> Option<&CardSet> {
+        let mut set = self.get(code)?;
+        let mut visited = HashSet::new();
+
+        while let Some(parent) = self.parent(&set.code) {
+            if !visited.insert(parent.code.to_lowercase()) {
+                break;
+            }
+            set = parent;
+        }
+
+        Some(set)
+    }
+
+    /// Gets the sets without a (known) parent, ordered by release date
+    pub fn roots(&self) -> Vec<&CardSet> {
+        let mut roots: Vec<&CardSet> = self.sets.values()
+            .filter(|s| self.parent(&s.code).is_none())
+            .collect();
+
+        roots.sort_by_key(|s| (s.released_at, s.code.clone()));
+        roots
+    }
+
+    /// Gets the number of sets in the graph
+    pub fn len(&self) -> usize {
+        self.sets.len()
+    }
+
+    /// Checks if the graph has no sets
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+}
+
+/// Filters the sets released after the given date, ordered by release date
+///
+/// Sets without cards (announced, but not previewed yet) can be skipped with `skip_empty`.
+pub fn upcoming(sets: impl IntoIterator<Item = CardSet>, today: Date, skip_empty: bool) -> Vec<CardSet> {
+    let mut upcoming: Vec<CardSet> = sets.into_iter()
+        .filter(|s| s.released_at > today)
+        .filter(|s| !skip_empty || s.card_count > 0)
+        .collect();
+
+    upcoming.sort_by(|a, b| a.released_at.cmp(&b.released_at).then_with(|| a.code.cmp(&b.code)));
+    upcoming
+}
+
+/// Preview progress of a set (N of M cards revealed)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreviewProgress {
+    pub set_code: String,
+
+    /// Number of cards of the set currently on Scryfall
+    pub revealed: i64,
+
+    /// Expected number of cards of the set
+    ///
+    /// This is the printed size of the set when known, otherwise its card count.
+    pub total: i64,
+}
+
+impl PreviewProgress {
+    /// Creates the progress of a set from the number of revealed cards
+    pub fn of(set: &CardSet, revealed: i64) -> Self {
+        PreviewProgress {
+            set_code: set.code.clone(),
+            revealed,
+            total: set.printed_size.unwrap_or(set.card_count).max(revealed),
+        }
+    }
+
+    /// Gets the revealed percentage (0 - 100)
+    pub fn percentage(&self) -> f64 {
+        match self.total {
+            0 => 0.0,
+            total => self.revealed as f64 * 100.0 / total as f64,
+        }
+    }
+}
+
+/// Search for the revealed cards of a set (all printings, including extras and variations)
+#[cfg(feature = "cards")]
+pub fn preview_search(set_code: &str) -> SearchQueryParams {
+    let mut params = SearchQueryParams::with_q(&format!("e:{set_code}"));
+    params.unique = Some(UniqueMode::Prints);
+    params.include_extras = Some(true);
+    params.include_variations = Some(true);
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+    use serde_json::json;
+    use crate::fixtures::card_set;
+
+    #[fixture]
+    fn graph() -> SetGraph {
+        SetGraph::from_iter(vec![
+            card_set(json!({ "code": "dmu", "released_at": "2022-09-09" })),
+            card_set(json!({ "code": "tdmu", "parent_set_code": "dmu", "released_at": "2022-09-09" })),
+            card_set(json!({ "code": "dmc", "parent_set_code": "dmu", "released_at": "2022-09-09" })),
+            card_set(json!({ "code": "tdmc", "parent_set_code": "dmc", "released_at": "2022-09-09" })),
+            card_set(json!({ "code": "pdmu", "parent_set_code": "dmu", "released_at": "2022-09-02" })),
+            card_set(json!({ "code": "bro", "released_at": "2022-11-18" })),
+            card_set(json!({ "code": "plst", "parent_set_code": "missing", "released_at": "2020-01-01" })),
+        ])
+    }
+
+    fn codes(sets: Vec<&CardSet>) -> Vec<&str> {
+        sets.into_iter().map(|s| s.code.as_str()).collect()
+    }
+
+    #[rstest]
+    fn graph_should_link_children_to_parents(graph: SetGraph) {
+        assert_eq!(vec!["pdmu", "dmc", "tdmu"], codes(graph.children("DMU")));
+        assert_eq!(vec!["pdmu", "dmc", "tdmc", "tdmu"], codes(graph.descendants("dmu")));
+        assert_eq!(Some("dmc"), graph.parent("tdmc").map(|s| s.code.as_str()));
+        assert_eq!(Some("dmu"), graph.root_of("tdmc").map(|s| s.code.as_str()));
+    }
+
+    #[rstest]
+    fn graph_roots_should_include_orphans(graph: SetGraph) {
+        assert_eq!(vec!["plst", "dmu", "bro"], codes(graph.roots()));
+    }
+
+    #[rstest]
+    #[case::all(false, vec!["dsk", "fdn"])]
+    #[case::skip_empty(true, vec!["dsk"])]
+    fn upcoming_sets_should_be_filtered_and_sorted(#[case] skip_empty: bool, #[case] expected: Vec<&str>) {
+        let today = time::macros::date!(2024 - 09 - 01);
+        let sets = vec![
+            card_set(json!({ "code": "fdn", "released_at": "2024-11-15", "card_count": 0 })),
+            card_set(json!({ "code": "blb", "released_at": "2024-08-02" })),
+            card_set(json!({ "code": "dsk", "released_at": "2024-09-27", "card_count": 286 })),
+        ];
+
+        let upcoming = upcoming(sets, today, skip_empty);
+
+        assert_eq!(expected, upcoming.iter().map(|s| s.code.as_str()).collect::<Vec<_>>());
+        assert_eq!(26, upcoming[0].days_until_release_from(today));
+    }
+
+    #[rstest]
+    #[case::printed_size(json!({ "card_count": 100, "printed_size": 250 }), 100, 250, 40.0)]
+    #[case::card_count(json!({ "card_count": 100 }), 50, 100, 50.0)]
+    #[case::empty(json!({ "card_count": 0 }), 0, 0, 0.0)]
+    fn preview_progress_should_use_printed_size(
+        #[case] set: serde_json::Value,
+        #[case] revealed: i64,
+        #[case] total: i64,
+        #[case] percentage: f64,
+    ) {
+        let progress = PreviewProgress::of(&card_set(set), revealed);
+
+        assert_eq!(total, progress.total);
+        assert_eq!(percentage, progress.percentage());
+    }
+}
@@ -0,0 +1,295 @@
+//! Client-side sorting of cards by multiple keys
+//!
+//! The API sorts search results by a single [field](OrderField). A [CardOrder](CardOrder)
+//! sorts cards by any number of fields, e.g. by color, then by mana value, then by name,
+//! like the Scryfall website does.
+//!
+//! Each key uses the same ordering as Scryfall:
+//! - colors are ordered white, blue, black, red, green, multicolored and colorless
+//! - rarities are ordered common, uncommon, rare, special, mythic and bonus
+//! - sets (and set review order) sort by set code, then by collector number
+//! - [automatic](OrderDirection::Auto) direction is descending for release dates, rarities,
+//!   prices, power and toughness and ascending for all other fields
+//!
+//! Cards missing a value (e.g. a price) always sort last, regardless of the direction.
+//!
+//! Format staples (the best ranked cards on EDHREC or in Penny Dreadful) can be picked
+//! with [top_commander_staples](top_commander_staples) and [top_penny_staples](top_penny_staples).
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::resources::cards::{Card, OrderDirection, OrderField};
+//! use scryfall_sdk_rust::sort::CardOrder;
+//!
+//! # fn example(mut cards: Vec<Card>) {
+//! CardOrder::by(OrderField::Color)
+//!     .then(OrderField::Cmc)
+//!     .then_dir(OrderField::Name, OrderDirection::Desc)
+//!     .sort(&mut cards);
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use crate::resources::card_symbols::ColorSymbol;
+use crate::resources::cards::{Card, OrderDirection, OrderField, Rarity};
+
+/// Sort order of cards by one or more fields
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CardOrder {
+    keys: Vec<(OrderField, OrderDirection)>,
+}
+
+impl CardOrder {
+    /// Sorts by a field (in its automatic direction)
+    pub fn by(field: OrderField) -> Self {
+        CardOrder::by_dir(field, OrderDirection::Auto)
+    }
+
+    /// Sorts by a field in the given direction
+    pub fn by_dir(field: OrderField, dir: OrderDirection) -> Self {
+        CardOrder { keys: vec![(field, dir)] }
+    }
+
+    /// Breaks ties by another field (in its automatic direction)
+    #[must_use]
+    pub fn then(self, field: OrderField) -> Self {
+        self.then_dir(field, OrderDirection::Auto)
+    }
+
+    /// Breaks ties by another field in the given direction
+    #[must_use]
+    pub fn then_dir(mut self, field: OrderField, dir: OrderDirection) -> Self {
+        self.keys.push((field, dir));
+        self
+    }
+
+    /// Gets the fields (and directions) of the order
+    pub fn keys(&self) -> &[(OrderField, OrderDirection)] {
+        &self.keys
+    }
+
+    /// Compares two cards
+    pub fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        self.keys.iter()
+            .map(|(field, dir)| compare_by(*field, *dir, a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Sorts cards (stable, so equal cards keep their order)
+    pub fn sort(&self, cards: &mut [Card]) {
+        cards.sort_by(|a, b| self.compare(a, b));
+    }
+}
+
+/// Gets the `n` best ranked cards on EDHREC (the most popular commander cards)
+///
+/// Cards without a rank are skipped and printings of the same oracle card
+/// (e.g. in a `default_cards` bulk dump) are only returned once.
+pub fn top_commander_staples(cards: &[Card], n: usize) -> Vec<&Card> {
+    top_ranked(cards, n, |card| card.edhrec_rank)
+}
+
+/// Gets the `n` best ranked cards in Penny Dreadful
+///
+/// Cards without a rank are skipped and printings of the same oracle card are only returned once.
+pub fn top_penny_staples(cards: &[Card], n: usize) -> Vec<&Card> {
+    top_ranked(cards, n, |card| card.penny_rank)
+}
+
+fn top_ranked<F>(cards: &[Card], n: usize, rank: F) -> Vec<&Card>
+    where F: Fn(&Card) -> Option<i64>
+{
+    let mut ranked: Vec<(i64, &Card)> = cards.iter()
+        .filter_map(|card| rank(card).map(|r| (r, card)))
+        .collect();
+    ranked.sort_by_key(|(rank, _)| *rank);
+
+    let mut seen = HashSet::new();
+    ranked.into_iter()
+        .filter(|(_, card)| seen.insert(card.oracle_id))
+        .take(n)
+        .map(|(_, card)| card)
+        .collect()
+}
+
+/// Sort key of a card field
+#[derive(PartialEq, PartialOrd)]
+enum Key {
+    Number(f64),
+    Text(String),
+    Rank(Vec<usize>),
+}
+
+fn compare_by(field: OrderField, dir: OrderDirection, a: &Card, b: &Card) -> Ordering {
+    let descending = match dir {
+        OrderDirection::Asc => false,
+        OrderDirection::Desc => true,
+        OrderDirection::Auto => matches!(field,
+            OrderField::Released | OrderField::Spoiled | OrderField::Rarity
+                | OrderField::Usd | OrderField::Eur | OrderField::Tix
+                | OrderField::Power | OrderField::Toughness
+        ),
+    };
+
+    match (key(field, a), key(field, b)) {
+        (Some(a), Some(b)) => {
+            let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            if descending { ordering.reverse() } else { ordering }
+        },
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn key(field: OrderField, card: &Card) -> Option<Key> {
+    let price = |price: &Option<String>| price.as_deref()
+        .and_then(|p| p.parse().ok())
+        .map(Key::Number);
+
+    match field {
+        OrderField::Artist => card.artist.as_ref().map(|artist| Key::Text(artist.to_lowercase())),
+        OrderField::Cmc => Some(Key::Number(card.cmc)),
+        OrderField::Color => Some(Key::Rank(color_rank(card))),
+        OrderField::Edhrec => card.edhrec_rank.map(|rank| Key::Number(rank as f64)),
+        OrderField::Penny => card.penny_rank.map(|rank| Key::Number(rank as f64)),
+        OrderField::Eur => price(&card.prices.eur),
+        OrderField::Usd => price(&card.prices.usd),
+        OrderField::Tix => price(&card.prices.tix),
+        OrderField::Name => Some(Key::Text(card.name.to_lowercase())),
+        OrderField::Power => card.power_stat().and_then(|stat| stat.value()).map(|n| Key::Number(n.into())),
+        OrderField::Toughness => card.toughness_stat().and_then(|stat| stat.value()).map(|n| Key::Number(n.into())),
+        OrderField::Rarity => Some(Key::Rank(vec![rarity_rank(&card.rarity)])),
+        OrderField::Released | OrderField::Spoiled => Some(Key::Number(card.released_at.to_julian_day().into())),
+        OrderField::Set | OrderField::Review => {
+            let number = &card.collector_number;
+            let digits = number.find(|c: char| !c.is_ascii_digit()).unwrap_or(number.len());
+
+            Some(Key::Text(format!("{} {:>10}{}", card.set, &number[..digits], &number[digits..])))
+        },
+    }
+}
+
+/// Ranks the colors of a card
+fn color_rank(card: &Card) -> Vec<usize> {
+    let colors = card.colors.clone()
+        .or_else(|| card.card_faces.as_ref()
+            .and_then(|faces| faces.first())
+            .and_then(|face| face.colors.clone())
+        )
+        .unwrap_or_default();
+
+    colors_rank(&colors)
+}
+
+/// Ranks colors like Scryfall (mono colors in WUBRG order, then multicolored, then colorless)
+pub(crate) fn colors_rank(colors: &[ColorSymbol]) -> Vec<usize> {
+    let mut ranks: Vec<usize> = colors.iter()
+        .map(|color| match color {
+            ColorSymbol::W => 0,
+            ColorSymbol::U => 1,
+            ColorSymbol::B => 2,
+            ColorSymbol::R => 3,
+            ColorSymbol::G => 4,
+        })
+        .collect();
+    ranks.sort_unstable();
+    ranks.dedup();
+
+    match ranks.len() {
+        0 => vec![6],
+        1 => ranks,
+        _ => [vec![5], ranks].concat(),
+    }
+}
+
+fn rarity_rank(rarity: &Rarity) -> usize {
+    match rarity {
+        Rarity::Common => 0,
+        Rarity::Uncommon => 1,
+        Rarity::Rare => 2,
+        Rarity::Special => 3,
+        Rarity::Mythic => 4,
+        Rarity::Bonus => 5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::card;
+    use rstest::rstest;
+    use serde_json::json;
+
+    fn names(cards: &[Card]) -> Vec<&str> {
+        cards.iter().map(|card| card.name.as_str()).collect()
+    }
+
+    #[rstest]
+    fn cards_should_be_sorted_by_multiple_keys() {
+        let mut cards = vec![
+            card(json!({ "name": "Shock", "colors": ["R"], "cmc": 1.0 })),
+            card(json!({ "name": "Ornithopter", "colors": [], "cmc": 0.0 })),
+            card(json!({ "name": "Lightning Helix", "colors": ["W", "R"], "cmc": 2.0 })),
+            card(json!({ "name": "Lava Axe", "colors": ["R"], "cmc": 5.0 })),
+            card(json!({ "name": "Opt", "colors": ["U"], "cmc": 1.0 })),
+            card(json!({ "name": "Burst Lightning", "colors": ["R"], "cmc": 1.0 })),
+        ];
+
+        CardOrder::by(OrderField::Color)
+            .then(OrderField::Cmc)
+            .then(OrderField::Name)
+            .sort(&mut cards);
+
+        assert_eq!(
+            vec!["Opt", "Burst Lightning", "Shock", "Lava Axe", "Lightning Helix", "Ornithopter"],
+            names(&cards)
+        );
+    }
+
+    #[rstest]
+    #[case::auto(OrderDirection::Auto, vec!["Expensive", "Cheap", "Unpriced"])]
+    #[case::asc(OrderDirection::Asc, vec!["Cheap", "Expensive", "Unpriced"])]
+    fn missing_values_should_sort_last(#[case] dir: OrderDirection, #[case] expected: Vec<&str>) {
+        let mut cards = vec![
+            card(json!({ "name": "Unpriced" })),
+            card(json!({ "name": "Cheap", "prices": { "usd": "0.25" } })),
+            card(json!({ "name": "Expensive", "prices": { "usd": "10.00" } })),
+        ];
+
+        CardOrder::by_dir(OrderField::Usd, dir).sort(&mut cards);
+
+        assert_eq!(expected, names(&cards));
+    }
+
+    #[rstest]
+    fn top_staples_should_be_ranked_and_distinct() {
+        let cards = vec![
+            card(json!({ "name": "Third", "oracle_id": "00000000-0000-0000-0000-000000000003", "edhrec_rank": 30 })),
+            card(json!({ "name": "Unranked", "oracle_id": "00000000-0000-0000-0000-000000000004" })),
+            card(json!({ "name": "First", "oracle_id": "00000000-0000-0000-0000-000000000001", "edhrec_rank": 1 })),
+            card(json!({ "name": "Reprint", "oracle_id": "00000000-0000-0000-0000-000000000001", "edhrec_rank": 1 })),
+            card(json!({ "name": "Second", "oracle_id": "00000000-0000-0000-0000-000000000002", "edhrec_rank": 2 })),
+        ];
+
+        let names: Vec<&str> = top_commander_staples(&cards, 2).iter().map(|c| c.name.as_str()).collect();
+
+        assert_eq!(vec!["First", "Second"], names);
+        assert!(top_penny_staples(&cards, 2).is_empty());
+    }
+
+    #[rstest]
+    fn set_order_should_compare_collector_numbers_numerically() {
+        let mut cards = vec![
+            card(json!({ "name": "Ten", "collector_number": "10" })),
+            card(json!({ "name": "Two", "collector_number": "2" })),
+            card(json!({ "name": "Two a", "collector_number": "2a" })),
+        ];
+
+        CardOrder::by(OrderField::Set).sort(&mut cards);
+
+        assert_eq!(vec!["Two", "Two a", "Ten"], names(&cards));
+    }
+}
@@ -0,0 +1,799 @@
+//! Read-only on-disk card store
+//!
+//! A [CardStore](CardStore) keeps the whole card database on disk and only its indexes
+//! (by id and by name) in memory, so that services can look up any card without
+//! holding (or re-parsing) the entire bulk data file. Cards are parsed on access.
+//!
+//! A store file is written once from parsed cards (e.g. the `default_cards` bulk data)
+//! with [CardStore::write](CardStore::write), and opened with [CardStore::open](CardStore::open),
+//! which only reads the indexes.
+//!
+//! Imports can be checked against the expected number of cards and a sample of cards
+//! from the API with [verify](CardStore::verify).
+//!
+//! Small corrections (merged or deleted cards) can be applied without re-importing the
+//! bulk data, using [apply_migrations](CardStore::apply_migrations).
+//!
+//! The memory used by the indexes and the size of the stored cards (e.g. to size a service)
+//! are reported by [stats](CardStore::stats).
+//!
+//! The store is `Send + Sync`. To replace the dataset (e.g. after a bulk data refresh)
+//! while other threads keep reading, wrap it in a [SharedCardStore](SharedCardStore).
+//!
+//! Code that should also work with other storage (e.g. Postgres) can be written against
+//! the [CardBackend](backend::CardBackend) trait, implemented by the store.
+//!
+//! The store has to be enabled by the **store** optional feature.
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::resources::cards::Card;
+//! use scryfall_sdk_rust::store::CardStore;
+//!
+//! # fn example(cards: Vec<Card>) -> std::io::Result<()> {
+//! CardStore::write("cards.store", &cards)?;
+//!
+//! let store = CardStore::open("cards.store")?;
+//! for card in store.by_name("Lightning Bolt")? {
+//!     println!("{} ({})", card.name, card.set);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::mem::size_of;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use serde::{Deserialize, Serialize};
+use time::Date;
+use uuid::Uuid;
+use crate::ids::{collection_requests, CardId};
+use crate::index::card_heap_bytes;
+use crate::resources::cards::Card;
+use crate::resources::errors::ErrorBody;
+use crate::resources::migrations::{Migration, MigrationStrategy};
+use crate::Scryfall;
+
+pub mod backend;
+
+/// Magic bytes at the end of a store file
+const MAGIC: &[u8; 8] = b"SFSTORE1";
+
+/// Length of the footer (index offset and magic bytes)
+const FOOTER_LEN: u64 = 16;
+
+/// Location of a serialized card in the store file
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+struct Entry {
+    offset: u64,
+    len: u64,
+}
+
+/// In-memory indexes of a store file
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+struct Index {
+    ids: HashMap<Uuid, Entry>,
+    names: HashMap<String, Vec<Uuid>>,
+
+    /// Date of the latest applied migration
+    #[serde(default)]
+    synced_at: Option<Date>,
+}
+
+/// Read-only card store, backed by a file
+///
+/// Layout of the file: serialized cards, followed by the serialized indexes
+/// and a footer with the offset of the indexes.
+///
+/// Cards fetched when [applying migrations](CardStore::apply) are kept in memory
+/// until the store is [saved](CardStore::save).
+#[derive(Debug)]
+pub struct CardStore {
+    file: File,
+    index: Index,
+    added: HashMap<Uuid, Card>,
+}
+
+impl CardStore {
+    /// Writes cards to a new store file (replacing any existing file)
+    pub fn write<P: AsRef<Path>>(path: P, cards: &[Card]) -> io::Result<()> {
+        write_store(path.as_ref(), cards.iter().cloned().map(Ok), None)
+    }
+
+    /// Opens a store file, reading only its indexes
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+        let end = file.seek(SeekFrom::End(0))?;
+
+        let mut footer = [0; FOOTER_LEN as usize];
+        if end < FOOTER_LEN {
+            return Err(invalid_data("missing store footer"));
+        }
+        file.seek(SeekFrom::Start(end - FOOTER_LEN))?;
+        file.read_exact(&mut footer)?;
+
+        let (offset, magic) = footer.split_at(8);
+        if magic != MAGIC {
+            return Err(invalid_data("not a card store file"));
+        }
+
+        let mut offset_bytes = [0; 8];
+        offset_bytes.copy_from_slice(offset);
+        let offset = u64::from_le_bytes(offset_bytes);
+
+        let index_len = (end - FOOTER_LEN).checked_sub(offset)
+            .ok_or_else(|| invalid_data("index offset out of range"))?;
+
+        let mut index = vec![];
+        file.seek(SeekFrom::Start(offset))?;
+        Read::by_ref(&mut file).take(index_len).read_to_end(&mut index)?;
+
+        Ok(CardStore {
+            file,
+            index: serde_json::from_slice(&index)?,
+            added: HashMap::new(),
+        })
+    }
+
+    /// Writes all cards of the store (including the ones added by migrations) to a store file
+    ///
+    /// The file is written next to the target path first, so the store can be saved
+    /// over the file it was opened from.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let partial = path.with_extension("partial");
+
+        let stored = self.index.ids.values().map(|entry| self.read(*entry));
+        let added = self.added.values().cloned().map(Ok);
+
+        write_store(&partial, stored.chain(added), self.index.synced_at)?;
+        std::fs::rename(partial, path)
+    }
+
+    /// Gets a card by its Scryfall id (if stored)
+    pub fn get(&self, id: &Uuid) -> io::Result<Option<Card>> {
+        if let Some(card) = self.added.get(id) {
+            return Ok(Some(card.clone()));
+        }
+
+        match self.index.ids.get(id) {
+            Some(entry) => self.read(*entry).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets all stored printings of a card by its exact name (case insensitive)
+    pub fn by_name(&self, name: &str) -> io::Result<Vec<Card>> {
+        self.index.names.get(&name.to_lowercase())
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.get(id).transpose())
+            .collect()
+    }
+
+    /// Checks if a card is stored, without reading it
+    pub fn contains(&self, id: &Uuid) -> bool {
+        self.index.ids.contains_key(id) || self.added.contains_key(id)
+    }
+
+    /// Iterates over the ids of all stored cards
+    pub fn ids(&self) -> impl Iterator<Item = &Uuid> {
+        self.index.ids.keys().chain(self.added.keys())
+    }
+
+    /// Gets the number of stored cards
+    pub fn len(&self) -> usize {
+        self.index.ids.len() + self.added.len()
+    }
+
+    /// Checks if the store has no cards
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reports the number of cards and the approximate memory usage of the store
+    ///
+    /// This walks the indexes and the cards added by migrations (without reading
+    /// the store file), so it is meant for occasional reporting (e.g. at startup).
+    pub fn stats(&self) -> StoreStats {
+        let id_entry = size_of::<Uuid>() + size_of::<Entry>();
+        let name_bytes: usize = self.index.names.iter()
+            .map(|(name, ids)| size_of::<(String, Vec<Uuid>)>() + name.capacity() + ids.capacity() * size_of::<Uuid>())
+            .sum();
+
+        StoreStats {
+            cards: self.len(),
+            added: self.added.len(),
+            names: self.index.names.len(),
+            index_bytes: self.index.ids.capacity() * id_entry + name_bytes,
+            added_bytes: self.added.capacity() * (size_of::<Uuid>() + size_of::<Card>())
+                + self.added.values().map(card_heap_bytes).sum::<usize>(),
+            file_bytes: self.index.ids.values().map(|entry| entry.len).sum(),
+        }
+    }
+
+    /// Gets the date of the latest applied migration (if any)
+    pub fn synced_at(&self) -> Option<Date> {
+        self.index.synced_at
+    }
+
+    /// Gets the ids of the cards that migrations merge into, but are not stored
+    ///
+    /// These cards have to be fetched and passed to [apply](CardStore::apply).
+    pub fn missing_cards(&self, migrations: &[Migration]) -> Vec<Uuid> {
+        let mut missing: Vec<Uuid> = migrations.iter()
+            .filter(|m| m.migration_strategy == MigrationStrategy::Merge)
+            .filter_map(|m| m.new_scryfall_id)
+            .filter(|id| !self.contains(id))
+            .collect();
+
+        missing.sort();
+        missing.dedup();
+        missing
+    }
+
+    /// Applies migrations (in chronological order), adding the cards they merge into
+    ///
+    /// Merged and deleted cards are removed from the store.
+    /// Returns the number of removed cards.
+    pub fn apply(&mut self, migrations: &[Migration], cards: Vec<Card>) -> usize {
+        for card in cards {
+            if self.contains(&card.id) {
+                continue;
+            }
+
+            self.add(card);
+        }
+
+        let mut chronological: Vec<&Migration> = migrations.iter().collect();
+        chronological.sort_by_key(|m| m.performed_at);
+
+        let removed = chronological.iter()
+            .filter(|m| self.remove(&m.old_scryfall_id))
+            .count();
+
+        self.index.synced_at = chronological.last()
+            .map(|m| m.performed_at)
+            .max(self.index.synced_at);
+
+        removed
+    }
+
+    /// Fetches the migrations since the last sync and applies them, using the async client
+    ///
+    /// Returns the number of removed cards.
+    pub async fn apply_migrations(&mut self, client: &Scryfall<'_>) -> Result<usize, ErrorBody> {
+        let migrations = client.migrations_since(self.synced_at()).await?;
+
+        let mut cards = vec![];
+        for resource in collection_requests(&self.missing_ids(&migrations)) {
+            cards.extend(client.request(&resource).await?.cards);
+        }
+
+        Ok(self.apply(&migrations, cards))
+    }
+
+    /// Fetches the migrations since the last sync and applies them, using the blocking client
+    ///
+    /// Returns the number of removed cards.
+    #[cfg(feature = "blocking")]
+    pub fn apply_migrations_blocking(&mut self, client: &crate::ScryfallBlocking<'_>) -> Result<usize, ErrorBody> {
+        let migrations = client.migrations_since(self.synced_at())?;
+
+        let mut cards = vec![];
+        for resource in collection_requests(&self.missing_ids(&migrations)) {
+            cards.extend(client.request(&resource)?.cards);
+        }
+
+        Ok(self.apply(&migrations, cards))
+    }
+
+    /// Verifies the store after an import, against the expected number of cards
+    /// and a sample of cards fetched from the API
+    ///
+    /// Up to `sample` stored cards (evenly spread over the ids) are fetched and compared
+    /// by name, guarding against truncated or corrupted imports.
+    pub async fn verify(&self, client: &Scryfall<'_>, expected: usize, sample: usize) -> Result<Verification, ErrorBody> {
+        let sampled = self.sample_ids(sample);
+
+        let mut cards = vec![];
+        for resource in collection_requests(&scryfall_ids(&sampled)) {
+            cards.extend(client.request(&resource).await?.cards);
+        }
+
+        self.verification(expected, &sampled, &cards)
+    }
+
+    /// Verifies the store after an import, using the blocking client
+    ///
+    /// See [verify](CardStore::verify).
+    #[cfg(feature = "blocking")]
+    pub fn verify_blocking(&self, client: &crate::ScryfallBlocking<'_>, expected: usize, sample: usize) -> Result<Verification, ErrorBody> {
+        let sampled = self.sample_ids(sample);
+
+        let mut cards = vec![];
+        for resource in collection_requests(&scryfall_ids(&sampled)) {
+            cards.extend(client.request(&resource)?.cards);
+        }
+
+        self.verification(expected, &sampled, &cards)
+    }
+
+    /// Picks up to `sample` ids, evenly spread over the sorted ids
+    fn sample_ids(&self, sample: usize) -> Vec<Uuid> {
+        let mut ids: Vec<Uuid> = self.ids().copied().collect();
+        ids.sort();
+
+        if sample == 0 || ids.is_empty() {
+            return vec![];
+        }
+
+        let step = (ids.len() / sample).max(1);
+        ids.into_iter().step_by(step).take(sample).collect()
+    }
+
+    fn verification(&self, expected: usize, sampled: &[Uuid], cards: &[Card]) -> Result<Verification, ErrorBody> {
+        let mut verification = Verification {
+            expected,
+            stored: self.len(),
+            sampled: sampled.len(),
+            not_found: vec![],
+            mismatched: vec![],
+        };
+
+        for id in sampled {
+            let stored = self.get(id).map_err(|e| ErrorBody::client_error(e.to_string()))?;
+
+            match (stored, cards.iter().find(|c| c.id == *id)) {
+                (_, None) => verification.not_found.push(*id),
+                (Some(stored), Some(card)) if stored.name == card.name => {},
+                _ => verification.mismatched.push(*id),
+            }
+        }
+
+        Ok(verification)
+    }
+
+    fn missing_ids(&self, migrations: &[Migration]) -> Vec<CardId> {
+        scryfall_ids(&self.missing_cards(migrations))
+    }
+
+    fn add(&mut self, card: Card) {
+        self.index.names.entry(card.name.to_lowercase()).or_default().push(card.id);
+        self.added.insert(card.id, card);
+    }
+
+    /// Removes a card from the indexes, updating only the name bucket of the card
+    ///
+    /// The name of stored cards is read from the file: cards that cannot be read
+    /// are removed from every name bucket.
+    fn remove(&mut self, id: &Uuid) -> bool {
+        let name = match (self.index.ids.remove(id), self.added.remove(id)) {
+            (_, Some(card)) => Some(card.name),
+            (Some(entry), None) => self.read(entry).ok().map(|card| card.name),
+            (None, None) => return false,
+        };
+
+        match name.map(|name| name.to_lowercase()) {
+            Some(key) => if let Some(ids) = self.index.names.get_mut(&key) {
+                ids.retain(|i| i != id);
+                if ids.is_empty() {
+                    self.index.names.remove(&key);
+                }
+            },
+            None => {
+                for ids in self.index.names.values_mut() {
+                    ids.retain(|i| i != id);
+                }
+                self.index.names.retain(|_, ids| !ids.is_empty());
+            },
+        }
+
+        true
+    }
+
+    fn read(&self, entry: Entry) -> io::Result<Card> {
+        let mut bytes = vec![0; entry.len as usize];
+        read_exact_at(&self.file, &mut bytes, entry.offset)?;
+
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+/// Reads exactly `buf.len()` bytes at an offset, without moving a shared file cursor
+///
+/// Positional reads let concurrent readers share the file handle without locking it.
+#[cfg(unix)]
+fn read_exact_at(file: &File, buf: &mut [u8], offset: u64) -> io::Result<()> {
+    std::os::unix::fs::FileExt::read_exact_at(file, buf, offset)
+}
+
+/// Reads exactly `buf.len()` bytes at an offset, without moving a shared file cursor
+///
+/// Positional reads let concurrent readers share the file handle without locking it.
+#[cfg(windows)]
+fn read_exact_at(file: &File, mut buf: &mut [u8], mut offset: u64) -> io::Result<()> {
+    use std::os::windows::fs::FileExt;
+
+    while !buf.is_empty() {
+        match file.seek_read(buf, offset) {
+            Ok(0) => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "failed to fill whole buffer")),
+            Ok(n) => {
+                buf = &mut buf[n..];
+                offset += n as u64;
+            },
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads exactly `buf.len()` bytes at an offset, with a handle of its own
+#[cfg(not(any(unix, windows)))]
+fn read_exact_at(file: &File, buf: &mut [u8], offset: u64) -> io::Result<()> {
+    let mut file = file.try_clone()?;
+    file.seek(SeekFrom::Start(offset))?;
+    file.read_exact(buf)
+}
+
+/// Memory usage of a [CardStore](CardStore)
+///
+/// Sizes are approximate: the heap data of added cards is estimated by their serialized size,
+/// and hash table and allocator overheads are not counted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StoreStats {
+    /// Number of cards in the store (including the ones added by migrations)
+    pub cards: usize,
+
+    /// Number of cards added by migrations, kept in memory until the store is saved
+    pub added: usize,
+
+    /// Number of distinct (lowercase) names in the name index
+    pub names: usize,
+
+    /// Approximate heap usage of the id and name indexes (in bytes)
+    pub index_bytes: usize,
+
+    /// Approximate heap usage of the cards added by migrations (in bytes)
+    pub added_bytes: usize,
+
+    /// Size of the serialized cards in the store file (in bytes)
+    pub file_bytes: u64,
+}
+
+impl StoreStats {
+    /// Gets the approximate total heap usage (in bytes)
+    pub fn heap_bytes(&self) -> usize {
+        self.index_bytes + self.added_bytes
+    }
+}
+
+/// Report of a [store verification](CardStore::verify)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Verification {
+    /// Number of cards expected in the store (e.g. parsed from the bulk data)
+    pub expected: usize,
+
+    /// Number of cards in the store
+    pub stored: usize,
+
+    /// Number of cards checked against the API
+    pub sampled: usize,
+
+    /// Sampled cards not found by the API
+    pub not_found: Vec<Uuid>,
+
+    /// Sampled cards that differ from the API (e.g. by name)
+    pub mismatched: Vec<Uuid>,
+}
+
+impl Verification {
+    /// Checks if the store has the expected number of cards and all sampled cards match the API
+    pub fn is_ok(&self) -> bool {
+        self.expected == self.stored && self.not_found.is_empty() && self.mismatched.is_empty()
+    }
+}
+
+fn scryfall_ids(ids: &[Uuid]) -> Vec<CardId> {
+    ids.iter().copied().map(CardId::Scryfall).collect()
+}
+
+/// Writes cards, their indexes and the footer to a store file
+fn write_store<I>(path: &Path, cards: I, synced_at: Option<Date>) -> io::Result<()>
+    where I: Iterator<Item = io::Result<Card>>
+{
+    let mut writer = BufWriter::new(File::create(path)?);
+    let mut index = Index { synced_at, ..Index::default() };
+    let mut offset = 0;
+
+    for card in cards {
+        let card = card?;
+        let bytes = serde_json::to_vec(&card)?;
+        writer.write_all(&bytes)?;
+
+        index.ids.insert(card.id, Entry { offset, len: bytes.len() as u64 });
+        index.names.entry(card.name.to_lowercase()).or_default().push(card.id);
+        offset += bytes.len() as u64;
+    }
+
+    serde_json::to_writer(&mut writer, &index)?;
+    writer.write_all(&offset.to_le_bytes())?;
+    writer.write_all(MAGIC)?;
+    writer.flush()
+}
+
+/// Card store that can be replaced atomically while being read
+///
+/// Readers take a [snapshot](SharedCardStore::snapshot) of the current store,
+/// which stays valid (and unchanged) after a [swap](SharedCardStore::swap),
+/// so a background refresh never blocks ongoing lookups.
+#[derive(Debug)]
+pub struct SharedCardStore {
+    current: RwLock<Arc<CardStore>>,
+}
+
+impl From<CardStore> for SharedCardStore {
+    fn from(store: CardStore) -> Self {
+        SharedCardStore { current: RwLock::new(Arc::new(store)) }
+    }
+}
+
+impl SharedCardStore {
+    /// Gets the current store
+    pub fn snapshot(&self) -> Arc<CardStore> {
+        self.current.read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Replaces the current store, returning the previous one
+    pub fn swap(&self, store: CardStore) -> Arc<CardStore> {
+        let mut current = self.current.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        std::mem::replace(&mut *current, Arc::new(store))
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    fn path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("scryfall-sdk-{}-{name}.store", std::process::id()))
+    }
+
+    #[rstest]
+    fn stored_cards_should_be_looked_up() {
+        let path = path("lookup");
+        let cards = vec![
+            card(json!({ "id": "00000000-0000-0000-0000-000000000001", "name": "Lightning Bolt", "set": "lea" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000002", "name": "Lightning Bolt", "set": "m10" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000003", "name": "Shock" })),
+        ];
+
+        CardStore::write(&path, &cards).unwrap();
+        let store = CardStore::open(&path).unwrap();
+
+        assert_eq!(3, store.len());
+        assert_eq!(Some(&cards[2]), store.get(&cards[2].id).unwrap().as_ref());
+        assert_eq!(None, store.get(&Uuid::nil()).unwrap());
+        assert_eq!(vec!["lea", "m10"], store.by_name("lightning bolt").unwrap().iter().map(|c| c.set.as_str()).collect::<Vec<_>>());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn stored_cards_should_be_read_concurrently() {
+        let path = path("concurrent");
+        let cards: Vec<Card> = (1..=8)
+            .map(|i| card(json!({ "id": format!("00000000-0000-0000-0000-00000000000{i}"), "name": format!("Card {i}") })))
+            .collect();
+
+        CardStore::write(&path, &cards).unwrap();
+        let store = Arc::new(CardStore::open(&path).unwrap());
+
+        let readers: Vec<_> = cards.into_iter()
+            .map(|card| {
+                let store = Arc::clone(&store);
+                std::thread::spawn(move || for _ in 0..50 {
+                    assert_eq!(Some(&card), store.get(&card.id).unwrap().as_ref());
+                })
+            })
+            .collect();
+
+        readers.into_iter().for_each(|reader| reader.join().unwrap());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn stats_should_report_indexes_and_added_cards() {
+        let path = path("stats");
+        let cards = vec![
+            card(json!({ "id": "00000000-0000-0000-0000-000000000001", "name": "Lightning Bolt" })),
+            card(json!({ "id": "00000000-0000-0000-0000-000000000002", "name": "Lightning Bolt" })),
+        ];
+        CardStore::write(&path, &cards).unwrap();
+
+        let mut store = CardStore::open(&path).unwrap();
+        let stats = store.stats();
+        let file_bytes: usize = cards.iter().map(|card| serde_json::to_vec(card).unwrap().len()).sum();
+
+        assert_eq!((2, 0, 1, 0), (stats.cards, stats.added, stats.names, stats.added_bytes));
+        assert_eq!(file_bytes as u64, stats.file_bytes);
+        assert!(stats.index_bytes >= 2 * (size_of::<Uuid>() + size_of::<Entry>()) + "lightning bolt".len());
+
+        store.apply(&[], vec![card(json!({ "id": "00000000-0000-0000-0000-000000000003", "name": "Shock" }))]);
+        let stats = store.stats();
+
+        assert_eq!((3, 1, 2), (stats.cards, stats.added, stats.names));
+        assert!(stats.added_bytes > size_of::<Card>());
+        assert_eq!(stats.index_bytes + stats.added_bytes, stats.heap_bytes());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn shared_store_should_be_swapped_without_affecting_snapshots() {
+        let (old_path, new_path) = (path("old"), path("new"));
+        CardStore::write(&old_path, &[card(json!({ "name": "Shock" }))]).unwrap();
+        CardStore::write(&new_path, &[]).unwrap();
+
+        let shared = SharedCardStore::from(CardStore::open(&old_path).unwrap());
+        let snapshot = shared.snapshot();
+        let reader = std::thread::spawn(move || snapshot.by_name("shock").unwrap().len());
+
+        shared.swap(CardStore::open(&new_path).unwrap());
+
+        assert_eq!(1, reader.join().unwrap());
+        assert!(shared.snapshot().is_empty());
+
+        std::fs::remove_file(old_path).unwrap();
+        std::fs::remove_file(new_path).unwrap();
+    }
+
+    fn migration(strategy: &str, old_id: u128, new_id: Option<u128>, performed_at: &str) -> Migration {
+        serde_json::from_value(json!({
+            "object": "migration",
+            "id": Uuid::nil(),
+            "uri": "http://some.url",
+            "performed_at": performed_at,
+            "migration_strategy": strategy,
+            "old_scryfall_id": Uuid::from_u128(old_id),
+            "new_scryfall_id": new_id.map(Uuid::from_u128),
+            "note": null
+        })).unwrap()
+    }
+
+    #[rstest]
+    fn migrations_should_merge_and_delete_cards() {
+        let path = path("migrations");
+        CardStore::write(&path, &[
+            card(json!({ "id": Uuid::from_u128(1), "name": "Bolt" })),
+            card(json!({ "id": Uuid::from_u128(2), "name": "Shock" })),
+        ]).unwrap();
+        let mut store = CardStore::open(&path).unwrap();
+        let migrations = vec![
+            migration("delete", 2, None, "2024-02-01"),
+            migration("merge", 1, Some(3), "2024-01-01"),
+        ];
+
+        assert_eq!(vec![Uuid::from_u128(3)], store.missing_cards(&migrations));
+
+        let merged = card(json!({ "id": Uuid::from_u128(3), "name": "Bolt" }));
+        assert_eq!(2, store.apply(&migrations, vec![merged.clone()]));
+
+        assert_eq!(vec![merged], store.by_name("bolt").unwrap());
+        assert!(store.by_name("shock").unwrap().is_empty());
+        assert_eq!(Some(time::macros::date!(2024 - 02 - 01)), store.synced_at());
+
+        store.save(&path).unwrap();
+        let saved = CardStore::open(&path).unwrap();
+        assert_eq!(1, saved.len());
+        assert_eq!(store.synced_at(), saved.synced_at());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn invalid_store_files_should_be_rejected() {
+        let path = path("invalid");
+        std::fs::write(&path, b"{}").unwrap();
+
+        let error = CardStore::open(&path).unwrap_err();
+
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn out_of_range_index_offsets_should_be_rejected() {
+        let path = path("offset");
+        let mut footer = u64::MAX.to_le_bytes().to_vec();
+        footer.extend_from_slice(MAGIC);
+        std::fs::write(&path, footer).unwrap();
+
+        let error = CardStore::open(&path).unwrap_err();
+
+        assert_eq!((io::ErrorKind::InvalidData, "index offset out of range".into()), (error.kind(), error.to_string()));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn verification_should_report_missing_and_mismatched_cards() {
+        let path = path("verify");
+        let cards: Vec<Card> = (1..=4)
+            .map(|id| card(json!({ "id": Uuid::from_u128(id), "name": format!("Card {id}") })))
+            .collect();
+        CardStore::write(&path, &cards).unwrap();
+        let store = CardStore::open(&path).unwrap();
+
+        let sampled = store.sample_ids(2);
+        let fetched = vec![card(json!({ "id": Uuid::from_u128(1), "name": "Renamed" }))];
+        let verification = store.verification(5, &sampled, &fetched).unwrap();
+
+        assert_eq!(vec![Uuid::from_u128(1), Uuid::from_u128(3)], sampled);
+        assert_eq!(vec![Uuid::from_u128(1)], verification.mismatched);
+        assert_eq!(vec![Uuid::from_u128(3)], verification.not_found);
+        assert!(!verification.is_ok());
+        assert!(store.verification(4, &sampled, &cards).unwrap().is_ok());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn upserted_cards_should_replace_stored_cards() {
+        use backend::CardBackend;
+
+        let path = path("upsert");
+        CardStore::write(&path, &[card(json!({ "id": Uuid::from_u128(1), "name": "Shock" }))]).unwrap();
+        let mut store = CardStore::open(&path).unwrap();
+
+        let inserted = store.upsert_batch(vec![
+            card(json!({ "id": Uuid::from_u128(1), "name": "Shock", "set": "m19" })),
+            card(json!({ "id": Uuid::from_u128(2), "name": "Shocker" })),
+        ]).unwrap();
+
+        assert_eq!(1, inserted);
+        assert_eq!(2, store.len());
+        assert_eq!(vec!["m19"], store.get_by_name("shock").unwrap().iter().map(|c| c.set.as_str()).collect::<Vec<_>>());
+        assert_eq!(2, store.search_basic("shock").unwrap().len());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn removed_cards_should_only_leave_their_name_bucket() {
+        use backend::CardBackend;
+
+        let path = path("rename");
+        CardStore::write(&path, &[
+            card(json!({ "id": Uuid::from_u128(1), "name": "Shock" })),
+            card(json!({ "id": Uuid::from_u128(2), "name": "Shock" })),
+        ]).unwrap();
+        let mut store = CardStore::open(&path).unwrap();
+
+        store.upsert_batch(vec![card(json!({ "id": Uuid::from_u128(1), "name": "Lightning Bolt" }))]).unwrap();
+        store.upsert_batch(vec![card(json!({ "id": Uuid::from_u128(1), "name": "Lightning Helix" }))]).unwrap();
+
+        let ids = |name: &str| store.by_name(name).unwrap().iter().map(|c| c.id).collect::<Vec<_>>();
+        assert_eq!(vec![Uuid::from_u128(2)], ids("shock"));
+        assert_eq!(Vec::<Uuid>::new(), ids("lightning bolt"));
+        assert_eq!(vec![Uuid::from_u128(1)], ids("lightning helix"));
+        assert_eq!(2, store.stats().names);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,167 @@
+//! Pluggable storage backends
+//!
+//! [CardBackend](CardBackend) abstracts the lookups and updates of a local card database,
+//! so that applications can keep cards in their own storage (e.g. Postgres or redb)
+//! and still share code written against the trait.
+//!
+//! Two backends are provided: the file-backed [CardStore](super::CardStore)
+//! and the in-memory [MemoryStore](MemoryStore).
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::store::backend::{CardBackend, MemoryStore};
+//!
+//! fn shocks<B: CardBackend>(backend: &B) -> Result<usize, B::Error> {
+//!     Ok(backend.get_by_name("Shock")?.len())
+//! }
+//!
+//! assert_eq!(Ok(0), shocks(&MemoryStore::default()));
+//! ```
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::io;
+use uuid::Uuid;
+use crate::resources::cards::Card;
+use super::CardStore;
+
+/// Storage backend of a local card database
+pub trait CardBackend {
+    /// The error of the backend's operations
+    type Error;
+
+    /// Gets a card by its Scryfall id (if stored)
+    fn get_by_id(&self, id: &Uuid) -> Result<Option<Card>, Self::Error>;
+
+    /// Gets all stored printings of a card by its exact name (case insensitive)
+    fn get_by_name(&self, name: &str) -> Result<Vec<Card>, Self::Error>;
+
+    /// Gets all stored cards whose name contains the given text (case insensitive)
+    fn search_basic(&self, text: &str) -> Result<Vec<Card>, Self::Error>;
+
+    /// Inserts cards, replacing stored cards with the same id
+    ///
+    /// Returns the number of cards that were not stored before.
+    fn upsert_batch(&mut self, cards: Vec<Card>) -> Result<usize, Self::Error>;
+}
+
+/// In-memory card backend
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryStore {
+    cards: HashMap<Uuid, Card>,
+}
+
+impl MemoryStore {
+    /// Gets the number of stored cards
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Checks if the store has no cards
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    fn matching<P: Fn(&str) -> bool>(&self, predicate: P) -> Vec<Card> {
+        let mut cards: Vec<Card> = self.cards.values()
+            .filter(|card| predicate(&card.name.to_lowercase()))
+            .cloned()
+            .collect();
+
+        cards.sort_by_key(|card| card.released_at);
+        cards
+    }
+}
+
+impl From<Vec<Card>> for MemoryStore {
+    fn from(cards: Vec<Card>) -> Self {
+        MemoryStore { cards: cards.into_iter().map(|card| (card.id, card)).collect() }
+    }
+}
+
+impl CardBackend for MemoryStore {
+    type Error = Infallible;
+
+    fn get_by_id(&self, id: &Uuid) -> Result<Option<Card>, Self::Error> {
+        Ok(self.cards.get(id).cloned())
+    }
+
+    fn get_by_name(&self, name: &str) -> Result<Vec<Card>, Self::Error> {
+        let name = name.to_lowercase();
+        Ok(self.matching(|n| n == name))
+    }
+
+    fn search_basic(&self, text: &str) -> Result<Vec<Card>, Self::Error> {
+        let text = text.to_lowercase();
+        Ok(self.matching(|n| n.contains(&text)))
+    }
+
+    fn upsert_batch(&mut self, cards: Vec<Card>) -> Result<usize, Self::Error> {
+        Ok(cards.into_iter()
+            .map(|card| self.cards.insert(card.id, card))
+            .filter(Option::is_none)
+            .count())
+    }
+}
+
+/// Upserted cards are kept in memory until the store is [saved](CardStore::save)
+impl CardBackend for CardStore {
+    type Error = io::Error;
+
+    fn get_by_id(&self, id: &Uuid) -> Result<Option<Card>, Self::Error> {
+        self.get(id)
+    }
+
+    fn get_by_name(&self, name: &str) -> Result<Vec<Card>, Self::Error> {
+        self.by_name(name)
+    }
+
+    fn search_basic(&self, text: &str) -> Result<Vec<Card>, Self::Error> {
+        let text = text.to_lowercase();
+
+        self.index.names.iter()
+            .filter(|(name, _)| name.contains(&text))
+            .flat_map(|(_, ids)| ids)
+            .filter_map(|id| self.get(id).transpose())
+            .collect()
+    }
+
+    fn upsert_batch(&mut self, cards: Vec<Card>) -> Result<usize, Self::Error> {
+        let mut inserted = 0;
+
+        for card in cards {
+            if !self.remove(&card.id) {
+                inserted += 1;
+            }
+            self.add(card);
+        }
+
+        Ok(inserted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    #[rstest]
+    fn memory_store_should_upsert_and_search_cards() {
+        let mut store = MemoryStore::from(vec![
+            card(json!({ "id": Uuid::from_u128(1), "name": "Lightning Bolt" })),
+        ]);
+
+        let inserted = store.upsert_batch(vec![
+            card(json!({ "id": Uuid::from_u128(1), "name": "Lightning Bolt", "set": "m10" })),
+            card(json!({ "id": Uuid::from_u128(2), "name": "Lightning Helix" })),
+        ]).unwrap();
+
+        assert_eq!(1, inserted);
+        assert_eq!(2, store.len());
+        assert_eq!("m10", store.get_by_id(&Uuid::from_u128(1)).unwrap().unwrap().set);
+        assert_eq!(1, store.get_by_name("lightning bolt").unwrap().len());
+        assert_eq!(2, store.search_basic("LIGHTNING").unwrap().len());
+    }
+}
@@ -0,0 +1,209 @@
+//! Symbology utilities
+//!
+//! A [SymbolTable](SymbolTable) indexes the card symbols returned by the
+//! [symbology endpoint](crate::CardSymbolsResource), so that mana costs
+//! (e.g. `{2}{W/U}{W/U}`) can be resolved into converted mana cost and colors.
+//!
+//! Mana cost arithmetic and payability checks are provided in [cost](cost).
+//!
+//! # Example
+//! ```no_run
+//! use scryfall_sdk_rust::{CardSymbolsResource, Scryfall};
+//! use scryfall_sdk_rust::symbology::SymbolTable;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let symbols = Scryfall::default().request(&CardSymbolsResource).await?;
+//! let table = SymbolTable::from(symbols);
+//!
+//! assert_eq!(Some(4.0), table.cmc_of("{2}{U/W}{W/U}"));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use crate::resources::card_symbols::{CardSymbol, CardSymbolList, ColorSymbol};
+
+pub mod cost;
+
+/// Lookup table of card symbols
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SymbolTable {
+    symbols: HashMap<String, CardSymbol>,
+    aliases: HashMap<String, String>,
+}
+
+impl From<CardSymbolList> for SymbolTable {
+    fn from(list: CardSymbolList) -> Self {
+        SymbolTable::from_iter(list.data)
+    }
+}
+
+impl FromIterator<CardSymbol> for SymbolTable {
+    fn from_iter<I: IntoIterator<Item = CardSymbol>>(symbols: I) -> Self {
+        let mut table = SymbolTable::default();
+
+        for symbol in symbols {
+            let alternates = symbol.loose_variant.iter()
+                .chain(symbol.gatherer_alternates.iter().flatten());
+
+            for alternate in alternates {
+                table.aliases.insert(alternate.clone(), symbol.symbol.clone());
+            }
+
+            table.symbols.insert(symbol.symbol.clone(), symbol);
+        }
+
+        table
+    }
+}
+
+impl SymbolTable {
+    /// Looks up a symbol (e.g. `{W/P}`)
+    ///
+    /// Loose variants (e.g. `W`) and Gatherer alternates are resolved as well,
+    /// and hybrid symbols written in the opposite order (e.g. `{U/W}` for `{W/U}`)
+    /// are resolved when the symbol is `transposable`.
+    pub fn lookup(&self, symbol: &str) -> Option<&CardSymbol> {
+        self.symbols.get(symbol)
+            .or_else(|| self.aliases.get(symbol).and_then(|s| self.symbols.get(s)))
+            .or_else(|| self.symbols
+                .get(&transposed(symbol)?)
+                .filter(|s| s.transposable)
+            )
+    }
+
+    /// Gets the converted mana cost of a mana cost (e.g. `{2}{W}{W}`)
+    ///
+    /// Returns `None` if any of the symbols is unknown.
+    pub fn cmc_of(&self, cost: &str) -> Option<f64> {
+        split_cost(cost).iter()
+            .map(|s| self.lookup(s).map(|s| s.cmc.unwrap_or(0.0)))
+            .sum()
+    }
+
+    /// Gets the colors of a mana cost (e.g. `{2}{W/U}` -> `[U, W]`)
+    ///
+    /// Returns `None` if any of the symbols is unknown.
+    pub fn colors_of(&self, cost: &str) -> Option<Vec<ColorSymbol>> {
+        let mut colors = BTreeSet::new();
+
+        for symbol in split_cost(cost) {
+            colors.extend(&self.lookup(symbol)?.colors);
+        }
+
+        Some(colors.into_iter().collect())
+    }
+
+    /// Iterates over all symbols of the table
+    pub fn iter(&self) -> impl Iterator<Item = &CardSymbol> {
+        self.symbols.values()
+    }
+
+    /// Gets the number of symbols in the table
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Checks if the table has no symbols
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+/// Splits a mana cost into its symbols
+///
+/// # Example
+/// ```
+/// use scryfall_sdk_rust::symbology::split_cost;
+///
+/// assert_eq!(vec!["{2}", "{W/U}", "{G}"], split_cost("{2}{W/U} {G}"));
+/// ```
+pub fn split_cost(cost: &str) -> Vec<&str> {
+    let mut symbols = vec![];
+    let mut start = None;
+
+    for (i, c) in cost.char_indices() {
+        match (c, start) {
+            ('{', _) => start = Some(i),
+            ('}', Some(s)) => {
+                symbols.push(&cost[s..=i]);
+                start = None;
+            },
+            _ => {},
+        }
+    }
+
+    symbols
+}
+
+/// Swaps the halves of a hybrid symbol (e.g. `{U/W}` -> `{W/U}`)
+fn transposed(symbol: &str) -> Option<String> {
+    let inner = symbol.strip_prefix('{')?.strip_suffix('}')?;
+    let (first, second) = inner.split_once('/')?;
+
+    Some(format!("{{{second}/{first}}}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+    use serde_json::json;
+
+    fn symbol(symbol: &str, cmc: f64, colors: &[&str], transposable: bool) -> serde_json::Value {
+        json!({
+            "object": "card_symbol",
+            "symbol": symbol,
+            "svg_uri": "http://some.url",
+            "loose_variant": symbol.trim_matches(|c| c == '{' || c == '}'),
+            "english": "",
+            "transposable": transposable,
+            "represents_mana": true,
+            "appears_in_mana_costs": true,
+            "cmc": cmc,
+            "funny": false,
+            "colors": colors,
+            "gatherer_alternates": null,
+        })
+    }
+
+    #[fixture]
+    fn table() -> SymbolTable {
+        let list: CardSymbolList = serde_json::from_value(json!({
+            "object": "list",
+            "has_more": false,
+            "data": [
+                symbol("{2}", 2.0, &[], false),
+                symbol("{W}", 1.0, &["W"], false),
+                symbol("{W/U}", 1.0, &["W", "U"], true),
+                symbol("{W/P}", 1.0, &["W"], false),
+            ],
+        })).unwrap();
+
+        SymbolTable::from(list)
+    }
+
+    #[rstest]
+    #[case::exact("{W/P}", Some("{W/P}"))]
+    #[case::loose("W", Some("{W}"))]
+    #[case::transposed("{U/W}", Some("{W/U}"))]
+    #[case::not_transposable("{P/W}", None)]
+    #[case::unknown("{Z}", None)]
+    fn symbols_should_be_looked_up(table: SymbolTable, #[case] symbol: &str, #[case] expected: Option<&str>) {
+        assert_eq!(expected, table.lookup(symbol).map(|s| s.symbol.as_str()));
+    }
+
+    #[rstest]
+    #[case::generic_and_hybrid("{2}{U/W}{W/P}", Some(4.0), Some(vec![ColorSymbol::U, ColorSymbol::W]))]
+    #[case::empty("", Some(0.0), Some(vec![]))]
+    #[case::unknown("{2}{Z}", None, None)]
+    fn costs_should_be_resolved(
+        table: SymbolTable,
+        #[case] cost: &str,
+        #[case] cmc: Option<f64>,
+        #[case] colors: Option<Vec<ColorSymbol>>,
+    ) {
+        assert_eq!(cmc, table.cmc_of(cost));
+        assert_eq!(colors, table.colors_of(cost));
+    }
+}
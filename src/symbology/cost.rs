@@ -0,0 +1,410 @@
+//! Mana cost arithmetic and payability
+//!
+//! A [Cost](Cost) is a mana cost parsed from its textual form (e.g. `{2}{W/U}{B/P}`),
+//! which can be reduced or increased by generic mana, checked against a [ManaPool](ManaPool)
+//! and ordered by converted mana cost and then by colors.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::symbology::cost::{Cost, ManaPool};
+//!
+//! let cost: Cost = "{3}{U}{U}".parse().unwrap();
+//! let reduced = cost - 2;
+//!
+//! assert_eq!("{1}{U}{U}", reduced.to_string());
+//! assert!(reduced.can_pay(&ManaPool { blue: 2, colorless: 1, ..ManaPool::default() }));
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+use crate::resources::card_symbols::ColorSymbol;
+use crate::symbology::split_cost;
+
+/// A non-generic symbol of a mana cost
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CostSymbol {
+    /// A colored mana symbol (e.g. `{W}`)
+    Color(ColorSymbol),
+
+    /// Colorless mana (`{C}`)
+    Colorless,
+
+    /// Hybrid mana payable with either color (e.g. `{W/U}`)
+    Hybrid(ColorSymbol, ColorSymbol),
+
+    /// Hybrid mana payable with the color or two generic mana (e.g. `{2/W}`)
+    TwoHybrid(ColorSymbol),
+
+    /// Phyrexian mana payable with the color or two life (e.g. `{W/P}`)
+    Phyrexian(ColorSymbol),
+
+    /// Hybrid phyrexian mana payable with either color or two life (e.g. `{W/U/P}`)
+    HybridPhyrexian(ColorSymbol, ColorSymbol),
+
+    /// Snow mana (`{S}`), treated as generic mana when paying
+    Snow,
+
+    /// Variable mana (`{X}`, `{Y}` or `{Z}`), treated as zero
+    Variable(char),
+}
+
+impl CostSymbol {
+    /// Gets the converted mana cost of the symbol
+    pub fn cmc(&self) -> u32 {
+        match self {
+            CostSymbol::TwoHybrid(_) => 2,
+            CostSymbol::Variable(_) => 0,
+            _ => 1,
+        }
+    }
+
+    /// Gets the colors of the symbol
+    pub fn colors(&self) -> Vec<ColorSymbol> {
+        match *self {
+            CostSymbol::Color(c) | CostSymbol::TwoHybrid(c) | CostSymbol::Phyrexian(c) => vec![c],
+            CostSymbol::Hybrid(a, b) | CostSymbol::HybridPhyrexian(a, b) => vec![a, b],
+            _ => vec![],
+        }
+    }
+}
+
+impl Display for CostSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CostSymbol::Color(c) => write!(f, "{{{c:?}}}"),
+            CostSymbol::Colorless => f.write_str("{C}"),
+            CostSymbol::Hybrid(a, b) => write!(f, "{{{a:?}/{b:?}}}"),
+            CostSymbol::TwoHybrid(c) => write!(f, "{{2/{c:?}}}"),
+            CostSymbol::Phyrexian(c) => write!(f, "{{{c:?}/P}}"),
+            CostSymbol::HybridPhyrexian(a, b) => write!(f, "{{{a:?}/{b:?}/P}}"),
+            CostSymbol::Snow => f.write_str("{S}"),
+            CostSymbol::Variable(x) => write!(f, "{{{x}}}"),
+        }
+    }
+}
+
+impl FromStr for CostSymbol {
+    type Err = ParseCostError;
+
+    /// Parses a symbol, with or without braces (e.g. `{W/P}` or `W/P`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseCostError { symbol: s.into() };
+        let inner = s.trim_start_matches('{').trim_end_matches('}').to_uppercase();
+        let parts: Vec<&str> = inner.split('/').collect();
+
+        let symbol = match parts.as_slice() {
+            ["C"] => CostSymbol::Colorless,
+            ["S"] => CostSymbol::Snow,
+            [x @ ("X" | "Y" | "Z")] => CostSymbol::Variable(x.chars().next().ok_or_else(err)?),
+            [c] => CostSymbol::Color(color(c).ok_or_else(err)?),
+            ["2", c] => CostSymbol::TwoHybrid(color(c).ok_or_else(err)?),
+            [c, "P"] => CostSymbol::Phyrexian(color(c).ok_or_else(err)?),
+            [a, b] => CostSymbol::Hybrid(color(a).ok_or_else(err)?, color(b).ok_or_else(err)?),
+            [a, b, "P"] => CostSymbol::HybridPhyrexian(color(a).ok_or_else(err)?, color(b).ok_or_else(err)?),
+            _ => return Err(err()),
+        };
+
+        Ok(symbol)
+    }
+}
+
+fn color(symbol: &str) -> Option<ColorSymbol> {
+    match symbol {
+        "W" => Some(ColorSymbol::W),
+        "U" => Some(ColorSymbol::U),
+        "B" => Some(ColorSymbol::B),
+        "R" => Some(ColorSymbol::R),
+        "G" => Some(ColorSymbol::G),
+        _ => None,
+    }
+}
+
+/// A parsed mana cost
+///
+/// Generic mana is kept as a single amount, while the rest of the symbols
+/// keep their original order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Cost {
+    pub generic: u32,
+    pub symbols: Vec<CostSymbol>,
+}
+
+impl Cost {
+    /// Gets the converted mana cost (variable mana counts as zero), saturating at `u32::MAX`
+    pub fn cmc(&self) -> u32 {
+        self.symbols.iter()
+            .map(CostSymbol::cmc)
+            .fold(self.generic, u32::saturating_add)
+    }
+
+    /// Gets the colors of the cost (sorted)
+    pub fn colors(&self) -> Vec<ColorSymbol> {
+        self.symbols.iter()
+            .flat_map(CostSymbol::colors)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Checks if the cost can be paid with the given mana pool
+    ///
+    /// Hybrid symbols can be paid with either of their halves, `{2/W}`-like symbols
+    /// with two generic mana and phyrexian symbols with two life.
+    /// Variable mana is treated as zero and snow mana as generic mana.
+    pub fn can_pay(&self, pool: &ManaPool) -> bool {
+        can_pay(&self.symbols, self.generic, *pool, &mut HashSet::new())
+    }
+}
+
+/// Checks if the symbols (and generic mana) can be paid, trying each way of paying a symbol
+///
+/// States (remaining symbols, generic mana and pool) that cannot be paid are remembered,
+/// so that costs with many hybrid or phyrexian symbols are checked in polynomial time.
+fn can_pay(symbols: &[CostSymbol], generic: u32, pool: ManaPool, unpayable: &mut HashSet<(usize, u32, ManaPool)>) -> bool {
+    let (symbol, rest) = match symbols.split_first() {
+        Some(split) => split,
+        None => return pool.total() >= generic,
+    };
+
+    let state = (symbols.len(), generic, pool);
+    if unpayable.contains(&state) {
+        return false;
+    }
+
+    let with_color = |c: ColorSymbol| pool.spend(c).map(|p| (generic, p));
+    let with_life = || (pool.life >= 2).then(|| (generic, ManaPool { life: pool.life - 2, ..pool }));
+
+    let options = match *symbol {
+        CostSymbol::Color(c) => vec![with_color(c)],
+        CostSymbol::Colorless => vec![(pool.colorless > 0)
+            .then(|| (generic, ManaPool { colorless: pool.colorless - 1, ..pool }))],
+        CostSymbol::Hybrid(a, b) => vec![with_color(a), with_color(b)],
+        CostSymbol::TwoHybrid(c) => vec![with_color(c), Some((generic.saturating_add(2), pool))],
+        CostSymbol::Phyrexian(c) => vec![with_color(c), with_life()],
+        CostSymbol::HybridPhyrexian(a, b) => vec![with_color(a), with_color(b), with_life()],
+        CostSymbol::Snow => vec![Some((generic.saturating_add(1), pool))],
+        CostSymbol::Variable(_) => vec![Some((generic, pool))],
+    };
+
+    let payable = options.into_iter()
+        .flatten()
+        .any(|(generic, pool)| can_pay(rest, generic, pool, unpayable));
+
+    if !payable {
+        unpayable.insert(state);
+    }
+
+    payable
+}
+
+impl Display for Cost {
+    /// Formats the cost with generic mana first (e.g. `{2}{W}{W}`)
+    ///
+    /// Empty costs are formatted as an empty string.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.generic > 0 {
+            write!(f, "{{{}}}", self.generic)?;
+        }
+
+        self.symbols.iter().try_for_each(|s| write!(f, "{s}"))
+    }
+}
+
+impl FromStr for Cost {
+    type Err = ParseCostError;
+
+    /// Parses a cost, failing on unsupported symbols and on generic mana overflowing `u32`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cost = Cost::default();
+
+        for symbol in split_cost(s) {
+            match symbol.trim_matches(|c| c == '{' || c == '}').parse::<u32>() {
+                Ok(generic) => cost.generic = cost.generic.checked_add(generic)
+                    .ok_or_else(|| ParseCostError { symbol: symbol.into() })?,
+                Err(_) => cost.symbols.push(symbol.parse()?),
+            }
+        }
+
+        Ok(cost)
+    }
+}
+
+/// Adds generic mana to the cost (up to `u32::MAX`)
+impl Add<u32> for Cost {
+    type Output = Cost;
+
+    fn add(self, generic: u32) -> Cost {
+        Cost { generic: self.generic.saturating_add(generic), ..self }
+    }
+}
+
+/// Subtracts generic mana from the cost (down to zero)
+///
+/// Only generic mana is reduced, as with most cost reduction effects.
+impl Sub<u32> for Cost {
+    type Output = Cost;
+
+    fn sub(self, generic: u32) -> Cost {
+        Cost { generic: self.generic.saturating_sub(generic), ..self }
+    }
+}
+
+impl PartialOrd for Cost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders costs by converted mana cost, then by number of colors and then by colors
+impl Ord for Cost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (colors, other_colors) = (self.colors(), other.colors());
+
+        self.cmc().cmp(&other.cmc())
+            .then(colors.len().cmp(&other_colors.len()))
+            .then(colors.cmp(&other_colors))
+            .then_with(|| self.to_string().cmp(&other.to_string()))
+    }
+}
+
+/// Available mana (and life) for paying costs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ManaPool {
+    pub white: u32,
+    pub blue: u32,
+    pub black: u32,
+    pub red: u32,
+    pub green: u32,
+    pub colorless: u32,
+
+    /// Life available for paying phyrexian mana
+    pub life: u32,
+}
+
+impl ManaPool {
+    /// Gets the available mana of a color
+    pub fn of(&self, color: ColorSymbol) -> u32 {
+        match color {
+            ColorSymbol::W => self.white,
+            ColorSymbol::U => self.blue,
+            ColorSymbol::B => self.black,
+            ColorSymbol::R => self.red,
+            ColorSymbol::G => self.green,
+        }
+    }
+
+    /// Gets the total available mana (excluding life), saturating at `u32::MAX`
+    pub fn total(&self) -> u32 {
+        [self.white, self.blue, self.black, self.red, self.green, self.colorless]
+            .into_iter()
+            .fold(0, u32::saturating_add)
+    }
+
+    /// Gets the pool left after spending one mana of a color (if available)
+    fn spend(self, color: ColorSymbol) -> Option<ManaPool> {
+        let mut pool = self;
+        let mana = match color {
+            ColorSymbol::W => &mut pool.white,
+            ColorSymbol::U => &mut pool.blue,
+            ColorSymbol::B => &mut pool.black,
+            ColorSymbol::R => &mut pool.red,
+            ColorSymbol::G => &mut pool.green,
+        };
+
+        *mana = mana.checked_sub(1)?;
+        Some(pool)
+    }
+}
+
+/// Error when a mana cost symbol cannot be parsed
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseCostError {
+    /// The unsupported symbol
+    pub symbol: String,
+}
+
+impl Display for ParseCostError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsupported mana cost symbol: {}", self.symbol)
+    }
+}
+
+impl Error for ParseCostError { }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn cost(s: &str) -> Cost {
+        s.parse().unwrap()
+    }
+
+    #[rstest]
+    #[case::generic("{2}{1}", 3, vec![])]
+    #[case::hybrid("{1}{w/u}{U}", 3, vec![ColorSymbol::U, ColorSymbol::W])]
+    #[case::two_hybrid("{2/G}{2/G}", 4, vec![ColorSymbol::G])]
+    #[case::phyrexian("{X}{B/P}{G/U/P}", 2, vec![ColorSymbol::B, ColorSymbol::G, ColorSymbol::U])]
+    #[case::empty("", 0, vec![])]
+    fn cost_should_be_parsed(#[case] s: &str, #[case] cmc: u32, #[case] colors: Vec<ColorSymbol>) {
+        let cost = cost(s);
+
+        assert_eq!(cmc, cost.cmc());
+        assert_eq!(colors, cost.colors());
+    }
+
+    #[rstest]
+    fn cost_should_reject_unsupported_symbols() {
+        assert_eq!(Err(ParseCostError { symbol: "{HW}".into() }), "{1}{HW}".parse::<Cost>());
+    }
+
+    #[rstest]
+    fn cost_should_reject_overflowing_generic_mana() {
+        assert_eq!(Err(ParseCostError { symbol: "{1}".into() }), "{4294967295}{1}".parse::<Cost>());
+        assert_eq!(u32::MAX, cost("{4294967295}").cmc());
+        assert_eq!(u32::MAX, cost("{4294967295}{2/G}").cmc());
+        assert_eq!(u32::MAX, (cost("{4294967295}") + 1).generic);
+    }
+
+    #[rstest]
+    fn generic_mana_should_be_added_and_subtracted() {
+        assert_eq!("{4}{R}", (cost("{2}{R}") + 2).to_string());
+        assert_eq!("{R}", (cost("{2}{R}") - 3).to_string());
+    }
+
+    #[rstest]
+    #[case::exact("{1}{W}{W}", ManaPool { white: 2, green: 1, ..ManaPool::default() }, true)]
+    #[case::missing_color("{1}{W}{W}", ManaPool { white: 1, green: 2, ..ManaPool::default() }, false)]
+    #[case::hybrid("{W/U}{W/U}", ManaPool { white: 1, blue: 1, ..ManaPool::default() }, true)]
+    #[case::two_hybrid("{2/G}", ManaPool { colorless: 2, ..ManaPool::default() }, true)]
+    #[case::colorless("{C}", ManaPool { red: 1, ..ManaPool::default() }, false)]
+    #[case::phyrexian_life("{1}{B/P}", ManaPool { red: 1, life: 2, ..ManaPool::default() }, true)]
+    #[case::phyrexian_no_life("{1}{B/P}", ManaPool { red: 1, life: 1, ..ManaPool::default() }, false)]
+    #[case::hybrid_phyrexian("{G/U/P}{G/U/P}", ManaPool { blue: 1, life: 2, ..ManaPool::default() }, true)]
+    fn cost_payability_should_be_checked(#[case] s: &str, #[case] pool: ManaPool, #[case] expected: bool) {
+        assert_eq!(expected, cost(s).can_pay(&pool));
+    }
+
+    #[rstest]
+    #[case::payable(16, true)]
+    #[case::one_short(14, false)]
+    fn many_hybrid_symbols_should_be_checked_quickly(#[case] life: u32, #[case] expected: bool) {
+        let cost = cost(&"{W/U/P}".repeat(20));
+        let pool = ManaPool { white: 6, blue: 6, life, ..ManaPool::default() };
+
+        assert_eq!(expected, cost.can_pay(&pool));
+    }
+
+    #[rstest]
+    fn costs_should_be_ordered_by_cmc_then_colors() {
+        let mut costs = [cost("{U}{R}"), cost("{3}"), cost("{1}{G}"), cost("{B}")];
+        costs.sort();
+
+        let sorted: Vec<String> = costs.iter().map(Cost::to_string).collect();
+        assert_eq!(vec!["{B}", "{1}{G}", "{U}{R}", "{3}"], sorted);
+    }
+}
@@ -0,0 +1,302 @@
+//! Test utilities
+//!
+//! [CardGenerator](CardGenerator) generates random (but valid) cards and random texts
+//! from a seed, so that the SDK and downstream applications can run property tests
+//! (e.g. serialization round-trips with [round_trip](round_trip)) and catch panics
+//! in parsing and rendering code, without depending on a property testing framework.
+//!
+//! Generated cards cover the variety of real cards: optional fields missing or present,
+//! multi-faced cards, unusual power/toughness (`*`, `1+*`), non-ASCII names and texts.
+//!
+//! Fixed cards can be built from a minimal valid card with [card](card) and [card_json](card_json),
+//! overriding only the fields a test is about (these are the fixtures of the SDK's own tests).
+//!
+//! The [corpus](corpus) module provides golden payloads of unusual cards (meld, battle,
+//! reversible cards, emblems...) to test card handling against.
+//!
+//! The utilities have to be enabled by the **test-util** optional feature.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::testing::{round_trip, CardGenerator};
+//!
+//! let mut generator = CardGenerator::with_seed(42);
+//!
+//! for card in generator.cards(100) {
+//!     assert_eq!(card, round_trip(&card).unwrap());
+//! }
+//! ```
+
+use serde_json::{json, Value};
+use crate::random::Rng;
+use crate::resources::cards::Card;
+
+pub use crate::fixtures::{card, card_json};
+
+pub mod corpus;
+
+const SYLLABLES: &[&str] = &["ka", "thor", "mir", "el", "ven", "gra", "zu", "lith", "on", "é", "ø", "drak", "-"];
+const WORDS: &[&str] = &[
+    "target", "creature", "draw", "a", "card", "~", "deals", "damage", "{T}:", "Add", "{G}.", "(", ")",
+    "\n", "—", "+1/+1", "counter", "\"", "\\", "Æther", "X", "{2/W}", "{U/P}", "when", "enters,",
+];
+const SYMBOLS: &[&str] = &["{W}", "{U}", "{B}", "{R}", "{G}", "{C}", "{X}", "{1}", "{2}", "{W/U}", "{2/B}", "{G/P}", "{S}", "{½}", "{∞}"];
+const TYPES: &[&str] = &[
+    "Creature — Elf Druid", "Instant", "Sorcery", "Legendary Planeswalker — Urza", "Artifact — Equipment",
+    "Enchantment Creature — God", "Land", "Basic Land — Forest", "Battle — Siege", "Token Creature — Zombie",
+];
+const STATS: &[&str] = &["0", "1", "2", "5", "*", "1+*", "*²", "-1", "∞", "?"];
+const LAYOUTS: &[&str] = &["normal", "transform", "modal_dfc", "split", "adventure", "flip", "meld", "token", "art_series"];
+const RARITIES: &[&str] = &["common", "uncommon", "rare", "mythic", "special", "bonus"];
+const COLORS: &[&str] = &["W", "U", "B", "R", "G"];
+const FORMATS: &[&str] = &[
+    "standard", "future", "historic", "gladiator", "pioneer", "explorer", "modern", "legacy", "pauper", "vintage",
+    "penny", "commander", "brawl", "historicbrawl", "alchemy", "paupercommander", "duel", "oldschool", "premodern",
+];
+const LEGALITIES: &[&str] = &["legal", "not_legal", "banned", "restricted"];
+
+/// Seeded generator of random cards and texts
+pub struct CardGenerator {
+    rng: Rng,
+    generated: u64,
+}
+
+impl CardGenerator {
+    /// Creates a generator with a fixed seed (generating the same values on every run)
+    pub fn with_seed(seed: u64) -> Self {
+        CardGenerator { rng: Rng::with_seed(seed), generated: 0 }
+    }
+
+    /// Creates a randomly seeded generator
+    pub fn random() -> Self {
+        CardGenerator { rng: Rng::new(), generated: 0 }
+    }
+
+    /// Generates a random card
+    pub fn card(&mut self) -> Card {
+        serde_json::from_value(self.card_json()).expect("generated cards are valid")
+    }
+
+    /// Generates `n` random cards
+    pub fn cards(&mut self, n: usize) -> Vec<Card> {
+        (0..n).map(|_| self.card()).collect()
+    }
+
+    /// Generates a random text of up to `max_words` words (e.g. for fuzzing parsers)
+    ///
+    /// Texts mix words of oracle texts, mana symbols and search syntax
+    /// (quotes, parentheses, operators and regular expressions).
+    pub fn text(&mut self, max_words: usize) -> String {
+        const SYNTAX: &[&str] = &["t:", "o:", "-", "or", "(", ")", "\"", "/", "!", "<=", ">", "=", "cmc", "c:rg", "name:"];
+        let words = self.rng.below(max_words + 1);
+
+        (0..words)
+            .map(|_| match self.rng.below(3) {
+                0 => self.pick(SYNTAX),
+                1 => self.pick(SYMBOLS),
+                _ => self.pick(WORDS),
+            })
+            .collect::<Vec<_>>()
+            .join(if self.flip() { " " } else { "" })
+    }
+
+    /// Generates the json of a random card
+    pub fn card_json(&mut self) -> Value {
+        self.generated += 1;
+
+        let name = self.name();
+        let layout = self.pick(LAYOUTS);
+        let faced = matches!(layout, "transform" | "modal_dfc" | "split" | "adventure" | "flip");
+        let colors = self.colors();
+
+        let mut card = card_json(json!({
+            "id": self.uuid(),
+            "oracle_id": self.uuid(),
+            "name": name,
+            "lang": self.pick(&["en", "ja", "de", "ph"]),
+            "released_at": format!("{}-{:02}-{:02}", 1993 + self.rng.below(33), 1 + self.rng.below(12), 1 + self.rng.below(28)),
+            "layout": layout,
+            "highres_image": self.flip(),
+            "image_status": self.pick(&["highres_scan", "lowres", "missing", "placeholder"]),
+            "cmc": self.rng.below(17) as f64 / if self.rng.below(10) == 0 { 2.0 } else { 1.0 },
+            "type_line": self.pick(TYPES),
+            "color_identity": colors,
+            "keywords": (0..self.rng.below(3)).map(|_| self.name()).collect::<Vec<_>>(),
+            "legalities": FORMATS.iter().map(|format| (format.to_string(), json!(self.pick(LEGALITIES)))).collect::<serde_json::Map<_, _>>(),
+        }));
+        let printing = json!({
+            "reserved": self.flip(),
+            "foil": self.flip(),
+            "nonfoil": self.flip(),
+            "finishes": [self.pick(&["nonfoil", "foil", "etched"])],
+            "promo": self.flip(),
+            "reprint": self.flip(),
+            "set_id": self.uuid(),
+            "set": format!("t{}", self.rng.below(100)),
+            "set_name": self.name(),
+            "set_type": self.pick(&["expansion", "core", "masters", "funny", "token", "memorabilia"]),
+            "collector_number": format!("{}{}", self.rng.below(400), self.pick(&["", "a", "★", "s", "†"])),
+            "digital": self.flip(),
+            "rarity": self.pick(RARITIES),
+            "border_color": self.pick(&["black", "white", "borderless", "silver"]),
+            "frame": self.pick(&["1993", "1997", "2003", "2015", "future"]),
+            "full_art": self.flip(),
+            "booster": self.flip(),
+            "prices": self.prices(),
+        });
+
+        let fields = card.as_object_mut().expect("cards are objects");
+        if let Value::Object(printing) = printing {
+            fields.extend(printing);
+        }
+        if faced {
+            let faces: Vec<Value> = name.split(" // ").map(|face| self.face(face)).collect();
+            fields.insert("card_faces".into(), json!(faces));
+        } else {
+            fields.insert("mana_cost".into(), json!(self.mana_cost()));
+            fields.insert("oracle_text".into(), json!(self.text(30)));
+            fields.insert("colors".into(), json!(colors));
+            if self.flip() {
+                fields.insert("power".into(), json!(self.pick(STATS)));
+                fields.insert("toughness".into(), json!(self.pick(STATS)));
+            }
+        }
+        if self.flip() {
+            fields.insert("edhrec_rank".into(), json!(self.rng.below(30000)));
+        }
+        if self.flip() {
+            fields.insert("penny_rank".into(), json!(self.rng.below(30000)));
+        }
+        if self.flip() {
+            fields.insert("flavor_text".into(), json!(self.text(10)));
+        }
+
+        card
+    }
+
+    fn face(&mut self, name: &str) -> Value {
+        let mut face = json!({
+            "object": "card_face",
+            "name": name,
+            "mana_cost": self.mana_cost(),
+            "type_line": self.pick(TYPES),
+            "oracle_text": self.text(20),
+        });
+
+        if self.flip() {
+            let fields = face.as_object_mut().expect("faces are objects");
+            fields.insert("power".into(), json!(self.pick(STATS)));
+            fields.insert("toughness".into(), json!(self.pick(STATS)));
+        }
+        face
+    }
+
+    fn name(&mut self) -> String {
+        let words = 1 + self.rng.below(3);
+        let mut name: Vec<String> = (0..words)
+            .map(|_| {
+                let word: String = (0..1 + self.rng.below(3)).map(|_| self.pick(SYLLABLES)).collect();
+                let mut chars = word.chars();
+                chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+            })
+            .collect();
+
+        if self.rng.below(5) == 0 {
+            name.push("//".into());
+            name.push(self.pick(SYLLABLES).to_uppercase());
+        } else if self.rng.below(5) == 0 {
+            name[0].push(',');
+        }
+        name.join(" ")
+    }
+
+    fn mana_cost(&mut self) -> String {
+        (0..self.rng.below(6)).map(|_| self.pick(SYMBOLS)).collect()
+    }
+
+    fn colors(&mut self) -> Vec<&'static str> {
+        COLORS.iter().copied().filter(|_| self.rng.below(3) == 0).collect()
+    }
+
+    fn prices(&mut self) -> Value {
+        let price = |rng: &mut Rng| match rng.below(3) {
+            0 => Value::Null,
+            _ => json!(format!("{}.{:02}", rng.below(100), rng.below(100))),
+        };
+
+        json!({ "usd": price(&mut self.rng), "usd_foil": price(&mut self.rng), "eur": price(&mut self.rng), "tix": price(&mut self.rng) })
+    }
+
+    fn uuid(&mut self) -> String {
+        format!("00000000-0000-4000-8000-{:012x}", (self.generated << 24) + self.rng.below(1 << 24) as u64)
+    }
+
+    fn flip(&mut self) -> bool {
+        self.rng.below(2) == 0
+    }
+
+    fn pick(&mut self, values: &[&'static str]) -> &'static str {
+        values[self.rng.below(values.len())]
+    }
+}
+
+/// Serializes and deserializes a card, as done when caching or storing cards
+pub fn round_trip(card: &Card) -> Result<Card, serde_json::Error> {
+    serde_json::from_str(&serde_json::to_string(card)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use rstest::rstest;
+    use crate::group::group_by_type;
+    use crate::query::SearchQuery;
+    use crate::quiz::redacted_text;
+    use crate::resources::validation::Validate;
+    use crate::resources::cards::OrderField;
+    use crate::sort::CardOrder;
+
+    #[rstest]
+    fn generated_cards_should_round_trip() {
+        let mut generator = CardGenerator::with_seed(1);
+
+        for card in generator.cards(500) {
+            assert_eq!(card, round_trip(&card).unwrap(), "{}", serde_json::to_string(&card).unwrap());
+            assert!(card.violations().is_empty());
+        }
+    }
+
+    #[rstest]
+    fn generators_should_be_deterministic() {
+        assert_eq!(CardGenerator::with_seed(7).cards(5), CardGenerator::with_seed(7).cards(5));
+    }
+
+    #[rstest]
+    fn card_utilities_should_not_panic() {
+        let mut generator = CardGenerator::with_seed(2);
+        let mut cards = generator.cards(300);
+
+        for card in &cards {
+            let _ = redacted_text(card);
+            let _ = card.localized_text();
+        }
+        let _ = group_by_type(&cards);
+        CardOrder::by(OrderField::Color).then(OrderField::Power).then(OrderField::Usd).sort(&mut cards);
+    }
+
+    #[rstest]
+    fn query_parser_should_not_panic() {
+        let mut generator = CardGenerator::with_seed(3);
+
+        for _ in 0..2000 {
+            let text = generator.text(12);
+
+            if let Ok(query) = SearchQuery::from_str(&text) {
+                let rendered = query.to_string();
+                let reparsed = SearchQuery::from_str(&rendered).map(|query| query.to_string());
+                assert_eq!(Ok(&rendered), reparsed.as_ref(), "{text:?} rendered as {rendered:?}");
+            }
+        }
+    }
+}
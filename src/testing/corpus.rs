@@ -0,0 +1,152 @@
+//! Golden corpus of unusual cards
+//!
+//! The [CORPUS](CORPUS) gathers full card payloads of layouts and card types
+//! that are easy to get wrong when handling cards: meld cards, battles, prototype cards,
+//! reversible cards (without top-level Oracle id, mana value and type line), Art Series cards,
+//! emblems, Vanguard avatars, double-faced planeswalkers and Unfinity attractions.
+//!
+//! The SDK checks that the whole corpus deserializes and round-trips, and downstream
+//! applications can run their own card handling against it. Applications with their own
+//! golden files can chain [Fixture](Fixture)s of their own with the corpus.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::testing::corpus::{self, CORPUS};
+//!
+//! for fixture in CORPUS {
+//!     let card = fixture.card().unwrap();
+//!     assert!(!card.name.is_empty(), "{}", fixture.name);
+//! }
+//!
+//! let battle = corpus::get("battle").unwrap().card().unwrap();
+//! assert_eq!(Some("3"), battle.card_faces.unwrap()[0].defense.as_deref());
+//! ```
+
+use crate::resources::cards::Card;
+
+/// A golden card payload
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fixture {
+    /// The name of the fixture (e.g. `meld`)
+    pub name: &'static str,
+
+    /// The card json, as served by the API
+    pub json: &'static str,
+}
+
+impl Fixture {
+    /// Deserializes the card of the fixture
+    pub fn card(&self) -> Result<Card, serde_json::Error> {
+        serde_json::from_str(self.json)
+    }
+}
+
+macro_rules! fixtures {
+    ($($name:literal),+ $(,)?) => {
+        &[$(Fixture { name: $name, json: include_str!(concat!("corpus/", $name, ".json")) }),+]
+    };
+}
+
+/// The fixtures of the corpus
+pub const CORPUS: &[Fixture] = fixtures![
+    "art_series",
+    "attraction",
+    "battle",
+    "emblem",
+    "meld",
+    "planeswalker_dfc",
+    "prototype",
+    "reversible",
+    "vanguard",
+];
+
+/// Gets a fixture of the corpus by name
+pub fn get(name: &str) -> Option<&'static Fixture> {
+    CORPUS.iter().find(|fixture| fixture.name == name)
+}
+
+/// Deserializes the cards of the corpus
+///
+/// # Panics
+/// If a fixture cannot be deserialized (which the SDK tests rule out).
+pub fn cards() -> Vec<Card> {
+    CORPUS.iter()
+        .map(|fixture| fixture.card().unwrap_or_else(|e| panic!("invalid {} fixture: {e}", fixture.name)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use uuid::Uuid;
+    use crate::resources::cards::Layout;
+    use crate::resources::validation::Validate;
+    use crate::testing::round_trip;
+
+    fn card(name: &str) -> Card {
+        get(name).expect("Expected a fixture").card().unwrap()
+    }
+
+    #[rstest]
+    fn corpus_should_deserialize_and_round_trip() {
+        for fixture in CORPUS {
+            let card = fixture.card().unwrap_or_else(|e| panic!("{}: {e}", fixture.name));
+
+            assert_eq!(card, round_trip(&card).unwrap(), "{}", fixture.name);
+            assert_eq!(Vec::<String>::new(), card.violations(), "{}", fixture.name);
+        }
+        assert_eq!(CORPUS.len(), cards().len());
+    }
+
+    #[rstest]
+    #[case::art_series("art_series", Layout::ArtSeries, 2)]
+    #[case::attraction("attraction", Layout::Normal, 0)]
+    #[case::battle("battle", Layout::Transform, 2)]
+    #[case::emblem("emblem", Layout::Emblem, 0)]
+    #[case::meld("meld", Layout::Meld, 0)]
+    #[case::planeswalker_dfc("planeswalker_dfc", Layout::Transform, 2)]
+    #[case::prototype("prototype", Layout::Prototype, 0)]
+    #[case::reversible("reversible", Layout::ReversibleCard, 2)]
+    #[case::vanguard("vanguard", Layout::Vanguard, 0)]
+    fn fixtures_should_have_their_layout(#[case] name: &str, #[case] layout: Layout, #[case] faces: usize) {
+        let card = card(name);
+
+        assert_eq!(layout, card.layout);
+        assert_eq!(faces, card.card_faces.map(|faces| faces.len()).unwrap_or_default());
+    }
+
+    #[rstest]
+    fn meld_cards_should_have_their_parts() {
+        let components: Vec<_> = card("meld").all_parts.unwrap().into_iter().map(|part| part.component).collect();
+
+        assert_eq!(vec!["meld_part", "meld_part", "meld_result"], components);
+    }
+
+    #[rstest]
+    fn reversible_cards_should_have_oracle_ids_on_faces() {
+        let card = card("reversible");
+
+        assert_eq!(Uuid::nil(), card.oracle_id);
+        assert!(card.card_faces.unwrap().iter().all(|face| face.oracle_id.is_some()));
+    }
+
+    #[rstest]
+    fn variant_fields_should_be_parsed() {
+        assert_eq!(Some(vec![2, 6]), card("attraction").attraction_lights);
+        assert_eq!(Some("3"), card("planeswalker_dfc").card_faces.unwrap()[1].loyalty.as_deref());
+
+        let vanguard = card("vanguard");
+        assert_eq!((Some("-1"), Some("+4")), (vanguard.hand_modifier.as_deref(), vanguard.life_modifier.as_deref()));
+    }
+
+    #[rstest]
+    fn non_cards_should_not_be_playable() {
+        for name in ["art_series", "emblem", "vanguard"] {
+            assert!(!card(name).is_playable_in_deck(), "{name}");
+        }
+        for name in ["attraction", "battle", "meld", "planeswalker_dfc", "prototype", "reversible"] {
+            assert!(card(name).is_playable_in_deck(), "{name}");
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Re-exports of foreign types used in models
+//!
+//! Model fields use types of the [url](url), [uuid](uuid) and [time](time) crates.
+//! Using these re-exports (or the re-exported crates, e.g. `scryfall_sdk_rust::time`)
+//! avoids mismatches between the versions of these crates in the SDK and in your project.
+//!
+//! | crate  | version |
+//! |--------|---------|
+//! | `url`  | 2.x     |
+//! | `uuid` | 1.x     |
+//! | `time` | 0.3.x   |
+//!
+//! Dates can also be converted to plain components with [to_ymd](to_ymd) and [to_unix](to_unix),
+//! for use with other date libraries (e.g. chrono).
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::types::{Date, Uuid};
+//!
+//! let id: Uuid = "f295b713-1d6a-43fd-910d-fb35414bf58a".parse().unwrap();
+//! let date = Date::from_calendar_date(2022, scryfall_sdk_rust::time::Month::September, 9).unwrap();
+//!
+//! assert_eq!("f295b713-1d6a-43fd-910d-fb35414bf58a", id.to_string());
+//! assert_eq!(2022, date.year());
+//! ```
+
+pub use time::{Date, OffsetDateTime};
+pub use url::Url;
+#[cfg(feature = "cards")]
+pub use uuid::Uuid;
+
+/// Converts a date to its `(year, month, day)` components
+///
+/// This allows converting model dates (e.g. `released_at`) to other date libraries
+/// without depending on them, e.g. with chrono:
+///
+/// ```ignore
+/// let (year, month, day) = to_ymd(card.released_at);
+/// let date = chrono::NaiveDate::from_ymd_opt(year, month, day);
+/// ```
+#[must_use]
+pub fn to_ymd(date: Date) -> (i32, u32, u32) {
+    (date.year(), u8::from(date.month()) as u32, date.day() as u32)
+}
+
+/// Converts a date-time to unix `(seconds, nanoseconds)`
+///
+/// This allows converting model date-times (e.g. `updated_at`) to other date libraries
+/// without depending on them, e.g. with chrono:
+///
+/// ```ignore
+/// let (secs, nanos) = to_unix(bulk_data.updated_at);
+/// let date_time = chrono::DateTime::from_timestamp(secs, nanos);
+/// ```
+#[must_use]
+pub fn to_unix(date_time: OffsetDateTime) -> (i64, u32) {
+    (date_time.unix_timestamp(), date_time.nanosecond())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use time::Month;
+
+    #[rstest]
+    fn date_should_convert_to_components() {
+        let date = Date::from_calendar_date(2022, Month::September, 9).unwrap();
+
+        assert_eq!((2022, 9, 9), to_ymd(date));
+    }
+
+    #[rstest]
+    fn date_time_should_convert_to_unix() {
+        let date_time = OffsetDateTime::from_unix_timestamp_nanos(1_662_681_600_000_000_500).unwrap();
+
+        assert_eq!((1_662_681_600, 500), to_unix(date_time));
+    }
+}
@@ -0,0 +1,110 @@
+//! Spoiler/preview watcher
+//!
+//! A [Watcher](Watcher) polls a card search ordered by preview date
+//! and reports cards that were not seen in previous polls.
+//!
+//! More event sources (and sinks) are available in [feed](feed).
+//!
+//! # Example
+//! ```no_run
+//! use std::time::Duration;
+//! use scryfall_sdk_rust::Scryfall;
+//! use scryfall_sdk_rust::watch::Watcher;
+//!
+//! # async fn example() {
+//! let client = Scryfall::default();
+//!
+//! Watcher::set(&client, "dmu")
+//!     .with_interval(Duration::from_secs(300))
+//!     .run(|cards| cards.iter().for_each(|c| println!("New preview: {}", c.name)))
+//!     .await;
+//! # }
+//! ```
+
+use std::collections::HashSet;
+use std::time::Duration;
+use uuid::Uuid;
+use crate::{CardPageResource, Scryfall};
+use crate::resources::cards::{Card, OrderDirection, OrderField, SearchQueryParams};
+use crate::resources::errors::ErrorBody;
+
+pub mod feed;
+
+/// Polls a card search for newly previewed cards
+pub struct Watcher<'a, 'b> {
+    client: &'b Scryfall<'a>,
+    query: String,
+    interval: Duration,
+    seen: HashSet<Uuid>,
+}
+
+impl<'a, 'b> Watcher<'a, 'b> {
+    /// Creates a watcher for a search query
+    ///
+    /// Results are ordered by preview date (newest first)
+    /// and only the first page is fetched on each poll.
+    #[must_use]
+    pub fn new(client: &'b Scryfall<'a>, query: &str) -> Self {
+        Watcher {
+            client,
+            query: query.into(),
+            interval: Duration::from_secs(600),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Creates a watcher for the cards of a set
+    #[must_use]
+    pub fn set(client: &'b Scryfall<'a>, set_code: &str) -> Self {
+        Watcher::new(client, &format!("e:{set_code}"))
+    }
+
+    /// Sets the polling interval (defaults to 10 minutes)
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Marks cards as already seen, so that they are never reported
+    pub fn mark_seen<'c>(&mut self, cards: impl IntoIterator<Item = &'c Card>) {
+        self.seen.extend(cards.into_iter().map(|c| c.id));
+    }
+
+    /// Polls the search once and returns the cards not seen before
+    ///
+    /// Returned cards are marked as seen.
+    pub async fn poll(&mut self) -> Result<Vec<Card>, ErrorBody> {
+        let mut params = SearchQueryParams::with_q(&self.query);
+        params.order = Some(OrderField::Spoiled);
+        params.dir = Some(OrderDirection::Desc);
+
+        let page = self.client.request(&CardPageResource::Search(params)).await?;
+
+        let new_cards: Vec<Card> = page.data.into_iter()
+            .filter(|c| !self.seen.contains(&c.id))
+            .collect();
+        self.mark_seen(&new_cards);
+
+        Ok(new_cards)
+    }
+
+    /// Polls the search forever, invoking the callback with newly previewed cards
+    ///
+    /// The first poll only records the currently known cards.
+    /// Failed polls are skipped and retried on the next interval.
+    pub async fn run<F>(mut self, mut callback: F)
+        where F: FnMut(Vec<Card>)
+    {
+        let _ = self.poll().await;
+
+        loop {
+            futures_timer::Delay::new(self.interval).await;
+
+            match self.poll().await {
+                Ok(cards) if !cards.is_empty() => callback(cards),
+                _ => continue,
+            }
+        }
+    }
+}
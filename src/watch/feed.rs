@@ -0,0 +1,254 @@
+//! Change feeds of Scryfall-derived events
+//!
+//! A [ChangeFeed](ChangeFeed) is a pollable source of [events](FeedEvent)
+//! and a [FeedSink](FeedSink) is where those events are delivered.
+//! Use [subscribe](subscribe) to connect the two on an interval.
+//!
+//! The following sources are provided:
+//!
+//! - [Watcher](super::Watcher): newly previewed cards
+//! - [PriceFeed](PriceFeed): card prices crossing a threshold
+//! - [BulkFeed](BulkFeed): bulk data files being updated (with the **bulk** feature)
+//!
+//! Sinks are implemented for closures and `std::sync::mpsc::Sender`,
+//! as well as `tokio::sync::mpsc::UnboundedSender` with the **tokio** optional feature.
+//!
+//! # Example
+//! ```no_run
+//! use std::time::Duration;
+//! use scryfall_sdk_rust::Scryfall;
+//! use scryfall_sdk_rust::watch::feed::{subscribe, BulkFeed, FeedEvent};
+//!
+//! # async fn example() {
+//! let client = Scryfall::default();
+//! let (sender, receiver) = std::sync::mpsc::channel::<FeedEvent>();
+//!
+//! std::thread::spawn(move || {
+//!     for event in receiver {
+//!         println!("{event:?}");
+//!     }
+//! });
+//!
+//! subscribe(BulkFeed::new(&client), Duration::from_secs(3600), sender).await;
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+#[cfg(feature = "bulk")]
+use time::OffsetDateTime;
+use uuid::Uuid;
+use crate::{CardCollectionResource, Scryfall};
+#[cfg(feature = "bulk")]
+use crate::resources::bulk_data::{BulkData, BulkDataListResource};
+use crate::resources::cards::{Card, CardFinish, CardIdentifier, CardIdentifiers};
+use crate::resources::errors::ErrorBody;
+use crate::watch::Watcher;
+
+/// Future returned by [ChangeFeed::poll](ChangeFeed::poll)
+pub type FeedFuture<'f> = Pin<Box<dyn Future<Output = Result<Vec<FeedEvent>, ErrorBody>> + Send + 'f>>;
+
+/// An event derived from Scryfall data
+#[derive(Clone, Debug, PartialEq)]
+pub enum FeedEvent {
+    /// A card was seen for the first time
+    NewCard(Card),
+
+    /// The price of a card crossed a threshold (upwards if `above`)
+    PriceCrossed {
+        card: Card,
+        threshold: f64,
+        above: bool,
+    },
+
+    /// A bulk data file was updated
+    #[cfg(feature = "bulk")]
+    BulkUpdated(BulkData),
+}
+
+/// A pollable source of events
+pub trait ChangeFeed {
+    /// Polls the source once and returns the events since the previous poll
+    fn poll(&mut self) -> FeedFuture<'_>;
+}
+
+/// A destination of events
+pub trait FeedSink {
+    /// Delivers an event
+    fn send(&mut self, event: FeedEvent);
+}
+
+impl<F> FeedSink for F where F: FnMut(FeedEvent) {
+    fn send(&mut self, event: FeedEvent) {
+        self(event)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl FeedSink for tokio::sync::mpsc::UnboundedSender<FeedEvent> {
+    fn send(&mut self, event: FeedEvent) {
+        // a closed receiver means nobody listens anymore
+        let _ = tokio::sync::mpsc::UnboundedSender::send(self, event);
+    }
+}
+
+impl FeedSink for std::sync::mpsc::Sender<FeedEvent> {
+    fn send(&mut self, event: FeedEvent) {
+        let _ = std::sync::mpsc::Sender::send(self, event);
+    }
+}
+
+/// Polls a feed forever, delivering its events to a sink
+///
+/// The first poll only records the current state of the source.
+/// Failed polls are skipped and retried on the next interval.
+pub async fn subscribe<C, S>(mut feed: C, interval: Duration, mut sink: S)
+    where C: ChangeFeed,
+          S: FeedSink
+{
+    let _ = feed.poll().await;
+
+    loop {
+        futures_timer::Delay::new(interval).await;
+
+        if let Ok(events) = feed.poll().await {
+            events.into_iter().for_each(|e| sink.send(e));
+        }
+    }
+}
+
+impl<'a, 'b> ChangeFeed for Watcher<'a, 'b> {
+    fn poll(&mut self) -> FeedFuture<'_> {
+        Box::pin(async move {
+            let cards = Watcher::poll(self).await?;
+            Ok(cards.into_iter().map(FeedEvent::NewCard).collect())
+        })
+    }
+}
+
+/// Watches USD prices of cards against a threshold
+pub struct PriceFeed<'a, 'b> {
+    client: &'b Scryfall<'a>,
+    ids: Vec<Uuid>,
+    threshold: f64,
+    finish: CardFinish,
+    above: HashMap<Uuid, bool>,
+}
+
+impl<'a, 'b> PriceFeed<'a, 'b> {
+    /// Creates a feed for the (non-foil) prices of the given cards
+    #[must_use]
+    pub fn new(client: &'b Scryfall<'a>, ids: Vec<Uuid>, threshold: f64) -> Self {
+        PriceFeed { client, ids, threshold, finish: CardFinish::NonFoil, above: HashMap::new() }
+    }
+
+    /// Sets the finish whose price is watched
+    #[must_use]
+    pub fn with_finish(mut self, finish: CardFinish) -> Self {
+        self.finish = finish;
+        self
+    }
+
+    fn crossings(&mut self, cards: Vec<Card>) -> Vec<FeedEvent> {
+        let mut events = vec![];
+
+        for card in cards {
+            let price = match card.prices.usd_of(self.finish) {
+                Some(price) => price,
+                None => continue,
+            };
+
+            let above = price >= self.threshold;
+            let previous = self.above.insert(card.id, above);
+
+            if matches!(previous, Some(was_above) if was_above != above) {
+                events.push(FeedEvent::PriceCrossed { card, threshold: self.threshold, above });
+            }
+        }
+
+        events
+    }
+}
+
+impl<'a, 'b> ChangeFeed for PriceFeed<'a, 'b> {
+    fn poll(&mut self) -> FeedFuture<'_> {
+        Box::pin(async move {
+            let mut cards = vec![];
+
+            for chunk in self.ids.chunks(75) {
+                let identifiers = chunk.iter()
+                    .map(|id| CardIdentifier::ScryfallId { val: id.to_string() })
+                    .collect();
+                let resource = CardCollectionResource::WithIdentifiers(
+                    CardIdentifiers { identifiers }
+                );
+
+                cards.extend(self.client.request(&resource).await?.cards);
+            }
+
+            Ok(self.crossings(cards))
+        })
+    }
+}
+
+/// Watches bulk data files for updates
+#[cfg(feature = "bulk")]
+pub struct BulkFeed<'a, 'b> {
+    client: &'b Scryfall<'a>,
+    updated_at: HashMap<String, OffsetDateTime>,
+}
+
+#[cfg(feature = "bulk")]
+impl<'a, 'b> BulkFeed<'a, 'b> {
+    /// Creates a feed for all bulk data files
+    #[must_use]
+    pub fn new(client: &'b Scryfall<'a>) -> Self {
+        BulkFeed { client, updated_at: HashMap::new() }
+    }
+}
+
+#[cfg(feature = "bulk")]
+impl<'a, 'b> ChangeFeed for BulkFeed<'a, 'b> {
+    fn poll(&mut self) -> FeedFuture<'_> {
+        Box::pin(async move {
+            let list = self.client.request(&BulkDataListResource::All).await?;
+
+            let events = list.data.into_iter()
+                .filter(|bulk| {
+                    let previous = self.updated_at.insert(bulk.id.clone(), bulk.updated_at);
+                    matches!(previous, Some(updated_at) if updated_at != bulk.updated_at)
+                })
+                .map(FeedEvent::BulkUpdated)
+                .collect();
+
+            Ok(events)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use crate::fixtures::card;
+
+    #[rstest]
+    fn price_feed_should_report_only_crossings() {
+        let client = Scryfall::default();
+        let id: Uuid = "00000000-0000-0000-0000-000000000001".parse().unwrap();
+        let mut feed = PriceFeed::new(&client, vec![id], 10.0);
+        let priced = |usd: &str| card(json!({ "id": id, "prices": { "usd": usd } }));
+
+        assert!(feed.crossings(vec![priced("5.00")]).is_empty());
+        assert!(feed.crossings(vec![priced("8.00")]).is_empty());
+
+        let events = feed.crossings(vec![priced("12.00")]);
+        assert!(matches!(&events[..], [FeedEvent::PriceCrossed { above: true, .. }]));
+
+        let events = feed.crossings(vec![priced("9.99")]);
+        assert!(matches!(&events[..], [FeedEvent::PriceCrossed { above: false, .. }]));
+    }
+}
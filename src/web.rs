@@ -0,0 +1,113 @@
+//! Integration helpers for web services
+//!
+//! [ScryfallState](ScryfallState) is a cheaply clonable, thread-safe handle to a client,
+//! which can be used directly as shared application state by web frameworks
+//! (e.g. axum's `State` or actix's `web::Data`).
+//!
+//! Errors can be forwarded to HTTP clients using [ErrorBody::http_status](ErrorBody::http_status)
+//! with the (serializable) error body as the response body. Errors caused by the request
+//! get the status of their [hint](ErrorBody::status_hint), and other errors an upstream status.
+//!
+//! Web helpers have to be enabled by the **web** optional feature.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::CardResource;
+//! use scryfall_sdk_rust::resources::errors::ErrorBody;
+//! use scryfall_sdk_rust::web::ScryfallState;
+//!
+//! async fn card_name(state: ScryfallState, name: &str) -> Result<String, (u16, String)> {
+//!     state.request(&CardResource::NamedFuzzy(name.into())).await
+//!         .map(|card| card.name)
+//!         .map_err(|e: ErrorBody| (e.http_status().as_u16(), serde_json::to_string(&e).unwrap_or_default()))
+//! }
+//! ```
+
+use std::ops::Deref;
+use std::sync::Arc;
+use reqwest::StatusCode;
+use crate::resources::errors::ErrorBody;
+use crate::Scryfall;
+
+/// Shared client state for web services
+#[derive(Clone)]
+pub struct ScryfallState(Arc<Scryfall<'static>>);
+
+impl ScryfallState {
+    /// Creates a state sharing the given client
+    pub fn new(client: Scryfall<'static>) -> Self {
+        ScryfallState(Arc::new(client))
+    }
+}
+
+impl Default for ScryfallState {
+    fn default() -> Self {
+        ScryfallState::new(Scryfall::default())
+    }
+}
+
+impl From<Scryfall<'static>> for ScryfallState {
+    fn from(client: Scryfall<'static>) -> Self {
+        ScryfallState::new(client)
+    }
+}
+
+impl Deref for ScryfallState {
+    type Target = Scryfall<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ErrorBody {
+    /// Gets the HTTP status a web service should respond with for this error
+    ///
+    /// Errors caused by the request get their [status hint](ErrorBody::status_hint)
+    /// (e.g. `404` for unknown cards, `400` for bad requests and `429` for throttling),
+    /// while failures of the Scryfall API or the client are reported as upstream errors:
+    ///
+    /// | error                                          | status                    |
+    /// |------------------------------------------------|---------------------------|
+    /// | caused by the request                          | status hint               |
+    /// | open circuit breaker and other `503` errors    | `503 Service Unavailable` |
+    /// | other `5xx` and client errors (`599`)          | `502 Bad Gateway`         |
+    pub fn http_status(&self) -> StatusCode {
+        match self.status_hint() {
+            Some(status) => status,
+            None if self.status == 503 => StatusCode::SERVICE_UNAVAILABLE,
+            None => StatusCode::BAD_GATEWAY,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::time::Duration;
+
+    fn error(code: &str, status: i16) -> ErrorBody {
+        ErrorBody { code: code.into(), status, ..ErrorBody::client_error("") }
+    }
+
+    #[rstest]
+    #[case::not_found(error("not_found", 404), StatusCode::NOT_FOUND)]
+    #[case::bad_request(error("bad_request", 422), StatusCode::BAD_REQUEST)]
+    #[case::other_client_error(error("conflict", 409), StatusCode::CONFLICT)]
+    #[case::rate_limited(error("rate_limited", 429), StatusCode::TOO_MANY_REQUESTS)]
+    #[case::circuit_open(ErrorBody::circuit_open(Duration::from_secs(1)), StatusCode::SERVICE_UNAVAILABLE)]
+    #[case::server_error(error("internal_error", 500), StatusCode::BAD_GATEWAY)]
+    #[case::client_error(ErrorBody::client_error("timeout"), StatusCode::BAD_GATEWAY)]
+    fn errors_should_map_to_http_status(#[case] error: ErrorBody, #[case] expected: StatusCode) {
+        assert_eq!(expected, error.http_status());
+        assert_eq!(error.status_hint().unwrap_or(expected), expected);
+    }
+
+    #[rstest]
+    fn state_should_be_shared_between_threads() {
+        fn assert_send_sync<T: Send + Sync + Clone + 'static>() {}
+
+        assert_send_sync::<ScryfallState>();
+    }
+}
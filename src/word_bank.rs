@@ -0,0 +1,254 @@
+//! Card name generation and validation with the word bank
+//!
+//! The [word bank catalog](crate::CatalogResource::WordBank) lists the (lowercase) words
+//! of two letters or more appearing in card names. A [WordBank](WordBank) keeps these words
+//! in memory, for custom-card and parody tools:
+//! - [random_name](WordBank::random_name) generates names from random words (in title case)
+//! - [unknown_words](WordBank::unknown_words) checks that the words of a (e.g. token) name
+//!   are all used in card names
+//!
+//! The word bank is large and rarely changes, so [fetch](WordBank::fetch) keeps a copy in a
+//! local file: generations and checks only need to fetch the catalog once the copy is too old.
+//!
+//! # Example
+//! ```
+//! use scryfall_sdk_rust::word_bank::WordBank;
+//!
+//! let bank = WordBank::new(["goblin", "of", "the", "pit", "lord"]);
+//!
+//! assert_eq!(3, bank.random_name(3).split(' ').count());
+//! assert!(bank.is_valid_name("Lord of the Pit"));
+//! assert_eq!(vec!["Elf"], bank.unknown_words("Elf Lord"));
+//! ```
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use crate::random::Rng;
+use crate::resources::catalog::Catalog;
+use crate::resources::errors::ErrorBody;
+use crate::{CatalogResource, Scryfall};
+
+/// Words written in lowercase in generated names (unless first or last)
+const MINOR_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "by", "for", "from", "in", "into", "of", "on", "or", "the", "to", "with",
+];
+
+/// Words used in card names
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordBank {
+    words: Vec<String>,
+    lookup: HashSet<String>,
+}
+
+impl WordBank {
+    /// Creates a word bank (words are lowercased, without duplicates)
+    pub fn new<I, S>(words: I) -> Self
+        where I: IntoIterator<Item = S>,
+              S: AsRef<str>
+    {
+        let mut bank = WordBank::default();
+
+        for word in words {
+            let word = normalize(word.as_ref());
+            if !word.is_empty() && bank.lookup.insert(word.clone()) {
+                bank.words.push(word);
+            }
+        }
+
+        bank
+    }
+
+    /// Gets the word bank from a local file, fetching it (using the async client)
+    /// if the file is missing or older than `max_age`
+    ///
+    /// Fetched word banks are saved to the file (as a json list of words).
+    pub async fn fetch<P: AsRef<Path>>(client: &Scryfall<'_>, path: P, max_age: Duration) -> Result<WordBank, ErrorBody> {
+        let path = path.as_ref();
+
+        if let Some(bank) = WordBank::load(path, max_age) {
+            return Ok(bank);
+        }
+
+        let bank = WordBank::from(client.request(&CatalogResource::WordBank).await?);
+        bank.save(path).map_err(|e| ErrorBody::client_error(e.to_string()))?;
+
+        Ok(bank)
+    }
+
+    /// Gets the word bank from a local file, fetching it (using the blocking client)
+    /// if the file is missing or older than `max_age`
+    ///
+    /// See [fetch](WordBank::fetch).
+    #[cfg(feature = "blocking")]
+    pub fn fetch_blocking<P: AsRef<Path>>(client: &crate::ScryfallBlocking<'_>, path: P, max_age: Duration) -> Result<WordBank, ErrorBody> {
+        client.block_on(WordBank::fetch(client.as_async(), path, max_age))
+    }
+
+    /// Saves the words to a file (as a json list of words)
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let path = path.as_ref();
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_vec(&self.words)?)
+    }
+
+    /// Loads the words saved to a file, unless it is missing, unreadable or older than `max_age`
+    fn load(path: &Path, max_age: Duration) -> Option<WordBank> {
+        let modified = fs::metadata(path).and_then(|metadata| metadata.modified()).ok()?;
+        let age = SystemTime::now().duration_since(modified).unwrap_or_default();
+
+        if age > max_age {
+            return None;
+        }
+
+        let words: Vec<String> = serde_json::from_slice(&fs::read(path).ok()?).ok()?;
+        Some(WordBank::new(words))
+    }
+
+    /// Checks if a word is used in card names (ignoring case)
+    pub fn contains(&self, word: &str) -> bool {
+        self.lookup.contains(&normalize(word))
+    }
+
+    /// Gets the words (in catalog order)
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Gets the number of words
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Checks if the word bank is empty
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Generates a name of `words` random words, in title case (e.g. `Lord of the Pit`)
+    ///
+    /// Returns an empty name if the word bank is empty.
+    pub fn random_name(&self, words: usize) -> String {
+        self.name_with(&mut Rng::new(), words)
+    }
+
+    /// Generates a name with the given generator
+    fn name_with(&self, rng: &mut Rng, words: usize) -> String {
+        if self.words.is_empty() {
+            return String::new();
+        }
+
+        (0..words)
+            .map(|i| title_case(&self.words[rng.below(self.words.len())], i == 0 || i == words - 1))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Gets the words of a name (e.g. of a custom token) that are not used in card names
+    ///
+    /// Single letters, numbers and punctuation are ignored. Hyphenated words
+    /// (e.g. `Half-Elf`) are accepted if all their parts are known.
+    pub fn unknown_words<'a>(&self, name: &'a str) -> Vec<&'a str> {
+        name.split_whitespace()
+            .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+            .filter(|word| word.chars().filter(|c| c.is_alphabetic()).count() >= 2)
+            .filter(|word| !self.is_known(word))
+            .collect()
+    }
+
+    /// Checks if all words of a name are used in card names (see [unknown_words](WordBank::unknown_words))
+    pub fn is_valid_name(&self, name: &str) -> bool {
+        self.unknown_words(name).is_empty()
+    }
+
+    /// Checks if a word, its singular possessive form or all its hyphenated parts are known
+    fn is_known(&self, word: &str) -> bool {
+        let word = normalize(word);
+        let parts = || word.split('-').filter(|part| part.chars().count() >= 2);
+
+        self.lookup.contains(&word)
+            || matches!(word.strip_suffix("'s"), Some(stem) if self.lookup.contains(stem))
+            || (word.contains('-') && parts().count() > 0 && parts().all(|part| self.lookup.contains(part)))
+    }
+}
+
+impl From<Catalog> for WordBank {
+    fn from(catalog: Catalog) -> Self {
+        WordBank::new(catalog.data)
+    }
+}
+
+/// Lowercases a word, with straight apostrophes
+fn normalize(word: &str) -> String {
+    word.trim().to_lowercase().replace('\u{2019}', "'")
+}
+
+/// Capitalizes a word, except minor words (e.g. `of`) inside names
+fn title_case(word: &str, edge: bool) -> String {
+    if !edge && MINOR_WORDS.contains(&word) {
+        return word.into();
+    }
+
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn bank() -> WordBank {
+        WordBank::new(["Goblin", "lord", "of", "the", "pit", "half", "elf", "jace", "wasn't", "goblin"])
+    }
+
+    #[rstest]
+    fn words_should_be_lowercased_without_duplicates() {
+        let bank = bank();
+
+        assert_eq!(9, bank.len());
+        assert!(bank.contains("GOBLIN"));
+        assert!(bank.contains("Wasn\u{2019}t"));
+    }
+
+    #[rstest]
+    fn names_should_be_generated_in_title_case() {
+        let bank = WordBank::new(["of"]);
+
+        assert_eq!("Of of Of", bank.name_with(&mut Rng::with_seed(1), 3));
+        assert_eq!("", WordBank::default().random_name(2));
+        assert_eq!(4, bank.random_name(4).split(' ').count());
+    }
+
+    #[rstest]
+    #[case::known("Lord of the Pit", vec![])]
+    #[case::unknown("Elvish Lord", vec!["Elvish"])]
+    #[case::punctuation("Jace, Goblin Lord (Token)", vec!["Token"])]
+    #[case::hyphenated("Half-Elf Goblin", vec![])]
+    #[case::possessive("Jace's Pit", vec![])]
+    #[case::ignored("2/2 A Goblin // Pit", vec![])]
+    fn unknown_words_should_be_reported(#[case] name: &str, #[case] expected: Vec<&str>) {
+        assert_eq!(expected, bank().unknown_words(name));
+        assert_eq!(expected.is_empty(), bank().is_valid_name(name));
+    }
+
+    #[rstest]
+    fn saved_word_banks_should_be_loaded_until_too_old() {
+        let path = std::env::temp_dir().join(format!("scryfall-sdk-{}-word-bank.json", std::process::id()));
+        bank().save(&path).unwrap();
+
+        assert_eq!(Some(bank()), WordBank::load(&path, Duration::from_secs(60)));
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(None, WordBank::load(&path, Duration::ZERO));
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,44 @@
+//! Runs the async client on the async-std runtime
+//!
+//! reqwest needs a tokio reactor for its I/O, which async-std
+//! provides with its `tokio1` feature.
+
+use std::time::Duration;
+use httpmock::Method::GET;
+use httpmock::MockServer;
+
+use scryfall_sdk_rust::{
+    client::rate_limit::RateLimiter,
+    client::retry::RetryPolicy,
+    resources::catalog::Catalog,
+    CatalogResource,
+    ScryfallBuilder,
+};
+
+#[async_std::test]
+async fn test_async_std_request() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{ "object": "catalog", "total_values": 1, "data": ["1"] }"#);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new()
+        .base_url(&url)
+        .rate_limiter(RateLimiter::new(Duration::from_millis(10)))
+        .retry_policy(RetryPolicy::default())
+        .build();
+
+    for _ in 0..2 {
+        let catalog: Catalog = client.request(&CatalogResource::Powers).await
+            .expect("Expected a catalog");
+        assert_eq!(vec!["1"], catalog.data);
+    }
+
+    endpoint.assert_hits(2);
+}
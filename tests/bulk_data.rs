@@ -171,7 +171,7 @@ mod filter {
         let client = ScryfallBlocking::from_url(&url);
 
         let response = client
-            .request(&BulkDataResource::Filter("id"))
+            .request(&BulkDataResource::Filter("id".into()))
             .expect("Expected a valid BulkData response");
 
         endpoint.assert();
@@ -194,10 +194,95 @@ mod filter {
         let client = Scryfall::from_url(&url);
 
         let response = client
-            .request(&BulkDataResource::Filter("id")).await
+            .request(&BulkDataResource::Filter("id".into())).await
             .expect("Expected a valid BulkData response");
 
         endpoint.assert();
         assert_eq!(bulk_data, &response)
     }
 }
+
+// -- Bulk file download tests
+mod download {
+    use super::*;
+    use scryfall_sdk_rust::client::retry::RetryPolicy;
+
+    fn bulk_data(download_uri: &str) -> BulkData {
+        BulkData {
+            item_kind: ResourceKind::BulkData,
+            id: "27bf3214-1271-490b-bdfe-c0be6c23d02e".into(),
+            kind: BulkDataKind::OracleCards,
+            updated_at: OffsetDateTime::parse("2022-06-18T09:02:10.928+00:00", &Iso8601::PARSING).unwrap(),
+            uri: "https://some-url.com".parse::<Url>().unwrap(),
+            name: "Oracle Cards".into(),
+            description: "A description".into(),
+            compressed_size: 13976935,
+            download_uri: download_uri.parse::<Url>().unwrap(),
+            content_type: "application/json".into(),
+            content_encoding: "gzip".into(),
+        }
+    }
+
+    #[rstest]
+    fn test_blocking_download_bulk() {
+        let server = MockServer::start();
+
+        let endpoint = server.mock(|when, then| {
+            when.method(GET).path("/file/oracle-cards.json")
+                .header("user-agent", "test-agent");
+            then.status(200).body("[]");
+        });
+
+        let client = scryfall_sdk_rust::ScryfallBuilder::new().user_agent("test-agent").build_blocking();
+        let mut file = vec![];
+
+        let download = client.download_bulk(&bulk_data(&server.url("/file/oracle-cards.json")), &mut file).unwrap();
+
+        endpoint.assert();
+        assert_eq!((2, b"[]".to_vec()), (download.bytes, file));
+        assert!(!download.is_gzip());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_download_bulk_gzip() {
+        let server = MockServer::start_async().await;
+        let compressed = [0x1f, 0x8b, 0x08, 0x00];
+
+        server.mock(|when, then| {
+            when.method(GET).path("/file/oracle-cards.json");
+            then.status(200)
+                .header("content-encoding", "gzip")
+                .body(compressed);
+        });
+
+        let client = Scryfall::default();
+        let mut file = vec![];
+
+        let download = client.download_bulk(&bulk_data(&server.url("/file/oracle-cards.json")), &mut file).await.unwrap();
+
+        assert_eq!(compressed.to_vec(), file);
+        assert!(download.is_gzip());
+        assert_eq!("oracle_cards.json.gz", download.file_name("oracle_cards.json"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_download_bulk() {
+        let server = MockServer::start_async().await;
+
+        let endpoint = server.mock(|when, then| {
+            when.method(GET).path("/file/oracle-cards.json");
+            then.status(503);
+        });
+
+        let client = Scryfall::default()
+            .with_retry_policy(RetryPolicy { base_delay: std::time::Duration::from_millis(1), ..RetryPolicy::new(2) });
+        let mut file = vec![];
+
+        let error = client.download_bulk(&bulk_data(&server.url("/file/oracle-cards.json")), &mut file).await.unwrap_err();
+
+        endpoint.assert_hits(3);
+        assert_eq!((599, 0), (error.status, file.len()));
+    }
+}
@@ -0,0 +1,229 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use rstest::rstest;
+use std::time::Duration;
+
+use scryfall_sdk_rust::{
+    client::cache::ResponseCache,
+    client::refresher::Refresher,
+    resources::catalog::Catalog,
+    resources::cards::SearchQueryParams,
+    CardPageResource,
+    CardResource,
+    CatalogResource,
+    CardSymbolsResource,
+    Scryfall,
+    ScryfallBlocking,
+};
+
+const LIST: &str = r#"{ "object": "list", "has_more": false, "data": [] }"#;
+const CATALOG: &str = r#"{ "object": "catalog", "total_values": 1, "data": ["Value"] }"#;
+
+#[rstest]
+fn test_blocking_cached_request() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/watermarks");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CATALOG);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBlocking::from_url(&url).with_cache(ResponseCache::default());
+
+    for _ in 0..3 {
+        let catalog: Catalog = client.clone()
+            .request(&CatalogResource::Watermarks)
+            .expect("Expected a catalog");
+        assert_eq!(vec!["Value"], catalog.data);
+    }
+
+    endpoint.assert_hits(1);
+}
+
+#[rstest]
+fn test_blocking_cache_control() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/watermarks");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("cache-control", "no-store")
+            .body(CATALOG);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBlocking::from_url(&url).with_cache(ResponseCache::default());
+
+    for _ in 0..2 {
+        let (_, meta): (Catalog, _) = client.request_with_meta(&CatalogResource::Watermarks)
+            .expect("Expected a catalog");
+        assert!(meta.freshness.no_store && !meta.cached);
+    }
+
+    endpoint.assert_hits(2);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_cache_control() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/watermarks");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("cache-control", "public, max-age=600")
+            .body(CATALOG);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url).with_cache(ResponseCache::default());
+
+    let (_, first): (Catalog, _) = client.request_with_meta(&CatalogResource::Watermarks).await
+        .expect("Expected a catalog");
+    let (_, second): (Catalog, _) = client.request_with_meta(&CatalogResource::Watermarks).await
+        .expect("Expected a catalog");
+
+    endpoint.assert_hits(1);
+    assert_eq!((false, Some(Duration::from_secs(600))), (first.cached, first.freshness.max_age));
+    assert!(second.cached && matches!(second.freshness.max_age, Some(ttl) if ttl <= Duration::from_secs(600)));
+}
+
+const PAGE: &str = r#"{ "object": "list", "total_cards": 0, "has_more": false, "data": [] }"#;
+
+#[rstest]
+fn test_blocking_search_cache() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/search");
+        then.status(200).header("content-type", "application/json").body(PAGE);
+    });
+
+    let url = server.base_url();
+    let cache = ResponseCache::default();
+    let client = ScryfallBlocking::from_url(&url).with_search_cache(cache.clone());
+
+    for q in ["t:goblin c:r", "c:r  t:goblin", "C:R T:Goblin"] {
+        client.request(&CardPageResource::Search(SearchQueryParams::with_q(q)))
+            .expect("Expected a card page");
+    }
+
+    endpoint.assert_hits(1);
+    assert!(cache.get("q=c:r t:goblin&page=1").is_some());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_search_cache() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/search");
+        then.status(200).header("content-type", "application/json").body(PAGE);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url)
+        .with_cache(ResponseCache::default())
+        .with_search_cache(ResponseCache::default());
+
+    for q in ["t:goblin c:r", "c:r  t:goblin"] {
+        client.request(&CardPageResource::Search(SearchQueryParams::with_q(q))).await
+            .expect("Expected a card page");
+    }
+
+    endpoint.assert_hits(1);
+    assert_eq!(Some(0), client.cache().map(|c| c.len()));
+    assert_eq!(Some(1), client.search_cache().map(|c| c.len()));
+}
+
+const NOT_FOUND: &str = r#"{ "object": "error", "code": "not_found", "status": 404, "details": "No card found" }"#;
+
+#[rstest]
+fn test_blocking_not_found_cache() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/named");
+        then.status(404).header("content-type", "application/json").body(NOT_FOUND);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBlocking::from_url(&url).with_not_found_cache(ResponseCache::default());
+    let resource = CardResource::NamedExact("Lightnin Bolt".into());
+
+    for _ in 0..2 {
+        let error = client.request(&resource).expect_err("Expected a not found error");
+        assert_eq!(404, error.status);
+    }
+    endpoint.assert_hits(1);
+
+    client.invalidate_not_found(&resource);
+    client.request(&resource).expect_err("Expected a not found error");
+    endpoint.assert_hits(2);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_not_found_cache() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/named");
+        then.status(404).header("content-type", "application/json").body(NOT_FOUND);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url).with_not_found_cache(ResponseCache::default());
+    let resource = CardResource::NamedExact("Lightnin Bolt".into());
+
+    for _ in 0..2 {
+        let error = client.request(&resource).await.expect_err("Expected a not found error");
+        assert_eq!(404, error.status);
+    }
+
+    endpoint.assert_hits(1);
+    assert_eq!(Some(1), client.not_found_cache().map(|c| c.len()));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_refresher() {
+    let server = MockServer::start_async().await;
+
+    let symbology = server.mock(|when, then| {
+        when.method(GET).path("/symbology");
+        then.status(200).header("content-type", "application/json").body(LIST);
+    });
+    let sets = server.mock(|when, then| {
+        when.method(GET).path("/sets");
+        then.status(200).header("content-type", "application/json").body(LIST);
+    });
+    let catalogs = server.mock(|when, then| {
+        when.method(GET).path_contains("/catalog/");
+        then.status(200).header("content-type", "application/json").body(CATALOG);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url).with_cache(ResponseCache::default());
+
+    Refresher::new(client.clone())
+        .refresh().await
+        .expect("Expected a refresh");
+
+    client.request(&CardSymbolsResource).await.expect("Expected symbology");
+    client.request(&CatalogResource::CardNames).await.expect("Expected a catalog");
+
+    symbology.assert_hits(1);
+    sets.assert_hits(1);
+    catalogs.assert_hits(16);
+    assert_eq!(18, client.cache().map(|c| c.len()).unwrap_or_default());
+}
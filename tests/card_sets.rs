@@ -93,6 +93,7 @@ mod list {
                 released_at: date!(2022-11-18),
                 kind: SetKind::Expansion,
                 card_count: 0,
+                printed_size: None,
                 digital: false,
                 nonfoil_only: true,
                 foil_only: true,
@@ -115,6 +116,7 @@ mod list {
                 released_at: date!(2022-10-07),
                 kind: SetKind::Funny,
                 card_count: 26,
+                printed_size: None,
                 digital: false,
                 nonfoil_only: false,
                 foil_only: false,
@@ -218,6 +220,7 @@ mod single {
             released_at: date!(2022-11-18),
             kind: SetKind::Expansion,
             card_count: 0,
+            printed_size: None,
             digital: false,
             nonfoil_only: true,
             foil_only: true,
@@ -229,8 +232,8 @@ mod single {
     }
 
     #[rstest]
-    #[case::filter(CardSetResource::Filter("id"))]
-    #[case::with_tcgplayerid(CardSetResource::WithTcgPlayerId("id"))]
+    #[case::filter(CardSetResource::Filter("id".into()))]
+    #[case::with_tcgplayerid(CardSetResource::WithTcgPlayerId("id".into()))]
     fn test_blocking_request(
         response: &String,
         card_set: &CardSet,
@@ -257,8 +260,8 @@ mod single {
     }
 
     #[rstest]
-    #[case::filter(CardSetResource::Filter("id"))]
-    #[case::with_tcgplayerid(CardSetResource::WithTcgPlayerId("id"))]
+    #[case::filter(CardSetResource::Filter("id".into()))]
+    #[case::with_tcgplayerid(CardSetResource::WithTcgPlayerId("id".into()))]
     #[tokio::test]
     async fn test_async_request<'a>(
         response: &String,
@@ -285,3 +288,92 @@ mod single {
         assert_eq!(card_set, &response)
     }
 }
+
+// -- Preview progress tests
+mod progress {
+    use super::*;
+    use scryfall_sdk_rust::sets::PreviewProgress;
+
+    const SET: &str = r#"{
+        "object": "set",
+        "id": "4219a14e-6701-4ddd-a185-21dc054ab19b",
+        "code": "bro",
+        "name": "The Brothers' War",
+        "uri": "https://some-url.com",
+        "scryfall_uri": "https://some-url.com",
+        "search_uri": "https://some-url.com",
+        "released_at": "2022-11-18",
+        "set_type": "expansion",
+        "card_count": 70,
+        "printed_size": 287,
+        "digital": false,
+        "nonfoil_only": false,
+        "foil_only": false,
+        "icon_svg_uri": "https://some-url.com"
+    }"#;
+
+    const PAGE: &str = r#"{ "object": "list", "total_cards": 70, "has_more": false, "data": [] }"#;
+
+    fn expected() -> PreviewProgress {
+        PreviewProgress { set_code: "bro".into(), revealed: 70, total: 287 }
+    }
+
+    #[rstest]
+    fn test_blocking_preview_progress() {
+        let server = MockServer::start();
+
+        let set_endpoint = server.mock(|when, then| {
+            when.method(GET).path("/sets/bro");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(SET);
+        });
+        let search_endpoint = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("q", "e:bro");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PAGE);
+        });
+
+        let url = server.base_url();
+        let client = ScryfallBlocking::from_url(&url);
+
+        let progress = client
+            .preview_progress("bro")
+            .expect("Expected a valid preview progress");
+
+        set_endpoint.assert();
+        search_endpoint.assert();
+        assert_eq!(expected(), progress)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_preview_progress() {
+        let server = MockServer::start_async().await;
+
+        let set_endpoint = server.mock(|when, then| {
+            when.method(GET).path("/sets/bro");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(SET);
+        });
+        let search_endpoint = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("q", "e:bro");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(PAGE);
+        });
+
+        let url = server.base_url();
+        let client = Scryfall::from_url(&url);
+
+        let progress = client
+            .preview_progress("bro").await
+            .expect("Expected a valid preview progress");
+
+        set_endpoint.assert();
+        search_endpoint.assert();
+        assert_eq!(expected(), progress)
+    }
+}
@@ -195,13 +195,15 @@ mod mana {
     }
 
     #[rstest]
-    fn test_blocking_request(response: &String, symbology_mana: &ManaCost) {
+    #[case::raw("1b")]
+    #[case::symbols("{2/W}{U/P}")]
+    fn test_blocking_request(response: &String, symbology_mana: &ManaCost, #[case] cost: &str) {
         let server = MockServer::start();
 
             let endpoint = server.mock(|when, then| {
                 when.method(GET)
-                    .path(format!("/{}", ManaCostResource("1b").path_without_query()))
-                    .query_param("cost", "1b");
+                    .path(format!("/{}", ManaCostResource(cost.into()).path_without_query()))
+                    .query_param("cost", cost);
 
                 then.status(200)
                     .header("content-type", "application/json")
@@ -212,7 +214,7 @@ mod mana {
         let client = ScryfallBlocking::from_url(&url);
 
         let response = client
-            .request(&ManaCostResource("1b"))
+            .request(&ManaCostResource(cost.into()))
             .expect("Expected a valid SymbologyMana response");
 
         endpoint.assert();
@@ -220,14 +222,16 @@ mod mana {
     }
 
     #[rstest]
+    #[case::raw("1b")]
+    #[case::symbols("{2/W}{U/P}")]
     #[tokio::test]
-    async fn test_async_request(response: &String, symbology_mana: &ManaCost) {
+    async fn test_async_request(response: &String, symbology_mana: &ManaCost, #[case] cost: &str) {
         let server = MockServer::start_async().await;
 
         let endpoint = server.mock(|when, then| {
             when.method(GET)
-                .path(format!("/{}", ManaCostResource("1b").path_without_query()))
-                .query_param("cost", "1b");
+                .path(format!("/{}", ManaCostResource(cost.into()).path_without_query()))
+                .query_param("cost", cost);
 
             then.status(200)
                 .header("content-type", "application/json")
@@ -238,7 +242,7 @@ mod mana {
         let client = Scryfall::from_url(&url);
 
         let response = client
-            .request(&ManaCostResource("1b")).await
+            .request(&ManaCostResource(cost.into())).await
             .expect("Expected a valid BulkData response");
 
         endpoint.assert();
@@ -2,7 +2,7 @@ use httpmock::Method::{GET, POST};
 use httpmock::MockServer;
 use indoc::indoc;
 use rstest::{fixture, rstest};
-use time::macros::date;
+use time::macros::{date, datetime};
 use url::Url;
 use uuid::uuid;
 
@@ -19,6 +19,10 @@ use scryfall_sdk_rust::{
     Scryfall,
     ScryfallBlocking,
     CardResource,
+    ids::{CardId, IdKind},
+    booster::PackModel,
+    export::ExportManifest,
+    hydrate::HydrateOptions,
 };
 
 // -- CardResource tests
@@ -199,6 +203,7 @@ mod single {
             color_identity: vec![ColorSymbol::W],
             color_indicator: None,
             content_warning: None,
+            defense: None,
             keywords: vec!["Aftermath".into()],
             card_faces: Some(vec![
                 CardFace {
@@ -214,6 +219,7 @@ mod single {
                     cmc: None,
                     color_indicator: None,
                     colors: None,
+                    defense: None,
                     flavor_text: None,
                     image_uris: None,
                     layout: None,
@@ -279,6 +285,7 @@ mod single {
             card_back_id: Some(uuid!("0aeebaf5-8c7d-4636-9e82-8c27447861f7")),
             artist: Some("Kasia 'Kafis' Zielińska".into()),
             artist_ids: vec![uuid!("a662cb71-4770-4b49-8b03-2cf8497049a7")],
+            attraction_lights: None,
             illustration_id: Some(uuid!("3134f77c-7a7d-48e0-99a6-4f323868e1ef")),
             border_color: "black".into(),
             frame: "2015".into(),
@@ -319,17 +326,17 @@ mod single {
     }
 
     #[rstest]
-    #[case::by_id(CardResource::ById("123"))]
-    #[case::by_arena_id(CardResource::ByArenaId("123"))]
-    #[case::by_cardmarket_id(CardResource::ByCardmarketId("123"))]
-    #[case::by_code(CardResource::ByCode("123", "456"))]
-    #[case::by_mtgo_id(CardResource::ByMtgoId("123"))]
-    #[case::by_multiverse_id(CardResource::ByMultiverseId("123"))]
-    #[case::by_tcgplayer_id(CardResource::ByTcgplayerId("123"))]
-    #[case::named_exact(CardResource::NamedExact("exact"))]
-    #[case::named_fuzzy(CardResource::NamedFuzzy("fuzzy"))]
+    #[case::by_id(CardResource::ById("123".into()))]
+    #[case::by_arena_id(CardResource::ByArenaId("123".into()))]
+    #[case::by_cardmarket_id(CardResource::ByCardmarketId("123".into()))]
+    #[case::by_code(CardResource::ByCode("123".into(), "456".into()))]
+    #[case::by_mtgo_id(CardResource::ByMtgoId("123".into()))]
+    #[case::by_multiverse_id(CardResource::ByMultiverseId("123".into()))]
+    #[case::by_tcgplayer_id(CardResource::ByTcgplayerId("123".into()))]
+    #[case::named_exact(CardResource::NamedExact("exact".into()))]
+    #[case::named_fuzzy(CardResource::NamedFuzzy("fuzzy".into()))]
     #[case::random(CardResource::Random(None))]
-    #[case::random_with_name(CardResource::Random(Some("name")))]
+    #[case::random_with_name(CardResource::Random(Some("name".into())))]
     fn test_blocking_request(response: &String, card: &Card, #[case] resource: CardResource) {
         let server = MockServer::start();
 
@@ -352,17 +359,17 @@ mod single {
     }
 
     #[rstest]
-    #[case::by_id(CardResource::ById("123"))]
-    #[case::by_arena_id(CardResource::ByArenaId("123"))]
-    #[case::by_cardmarket_id(CardResource::ByCardmarketId("123"))]
-    #[case::by_code(CardResource::ByCode("123", "456"))]
-    #[case::by_mtgo_id(CardResource::ByMtgoId("123"))]
-    #[case::by_multiverse_id(CardResource::ByMultiverseId("123"))]
-    #[case::by_tcgplayer_id(CardResource::ByTcgplayerId("123"))]
-    #[case::named_exact(CardResource::NamedExact("exact"))]
-    #[case::named_fuzzy(CardResource::NamedFuzzy("fuzzy"))]
+    #[case::by_id(CardResource::ById("123".into()))]
+    #[case::by_arena_id(CardResource::ByArenaId("123".into()))]
+    #[case::by_cardmarket_id(CardResource::ByCardmarketId("123".into()))]
+    #[case::by_code(CardResource::ByCode("123".into(), "456".into()))]
+    #[case::by_mtgo_id(CardResource::ByMtgoId("123".into()))]
+    #[case::by_multiverse_id(CardResource::ByMultiverseId("123".into()))]
+    #[case::by_tcgplayer_id(CardResource::ByTcgplayerId("123".into()))]
+    #[case::named_exact(CardResource::NamedExact("exact".into()))]
+    #[case::named_fuzzy(CardResource::NamedFuzzy("fuzzy".into()))]
     #[case::random(CardResource::Random(None))]
-    #[case::random_with_name(CardResource::Random(Some("name")))]
+    #[case::random_with_name(CardResource::Random(Some("name".into())))]
     #[tokio::test]
     async fn test_async_request<'a>(response: &String, card: &Card, #[case] resource: CardResource<'a>) {
         let server = MockServer::start_async().await;
@@ -384,6 +391,83 @@ mod single {
         endpoint.assert();
         assert_eq!(card, &response)
     }
+
+    const RULINGS: &str = r#"{
+        "object": "list",
+        "has_more": false,
+        "data": [{
+            "object": "ruling",
+            "oracle_id": "7bc3f92f-68a2-4934-afc4-89f6d0e8cf98",
+            "source": "wotc",
+            "published_at": "2022-06-10",
+            "comment": "A ruling"
+        }]
+    }"#;
+
+    const NOT_FOUND: &str = r#"{ "object": "error", "code": "not_found", "status": 404, "details": "No set found" }"#;
+
+    #[rstest]
+    fn test_blocking_hydrate(card: &Card) {
+        let server = MockServer::start();
+
+        let rulings = server.mock(|when, then| {
+            when.method(GET).path("/cards/f295b713-1d6a-43fd-910d-fb35414bf58a/rulings");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(RULINGS);
+        });
+        let set = server.mock(|when, then| {
+            when.method(GET).path("/sets/clb");
+            then.status(404)
+                .header("content-type", "application/json")
+                .body(NOT_FOUND);
+        });
+
+        let url = server.base_url();
+        let client = ScryfallBlocking::from_url(&url);
+
+        let hydrated = client
+            .hydrate(&[card.clone(), card.clone()], HydrateOptions::all())
+            .expect("Expected hydrated cards");
+
+        rulings.assert();
+        set.assert();
+        assert_eq!(vec!["A ruling"], hydrated.rulings(card).iter().map(|r| r.comment.as_str()).collect::<Vec<_>>());
+        assert_eq!(None, hydrated.set(card));
+        assert!(hydrated.parts(card).is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_hydrate(card: &Card) {
+        let server = MockServer::start_async().await;
+
+        let rulings = server.mock(|when, then| {
+            when.method(GET).path("/cards/f295b713-1d6a-43fd-910d-fb35414bf58a/rulings");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(RULINGS);
+        });
+        let set = server.mock(|when, then| {
+            when.method(GET).path("/sets/clb");
+            then.status(404)
+                .header("content-type", "application/json")
+                .body(NOT_FOUND);
+        });
+
+        let url = server.base_url();
+        let client = Scryfall::from_url(&url);
+
+        let hydrated = client
+            .hydrate(&[card.clone(), card.clone()], HydrateOptions::all()).await
+            .expect("Expected hydrated cards");
+
+        rulings.assert();
+        set.assert();
+        assert_eq!(vec!["A ruling"], hydrated.rulings(card).iter().map(|r| r.comment.as_str()).collect::<Vec<_>>());
+        assert_eq!(None, hydrated.set(card));
+        assert!(hydrated.parts(card).is_empty());
+    }
 }
 
 // -- CardPageResource tests
@@ -576,6 +660,7 @@ mod page {
                   color_identity: vec![ColorSymbol::W],
                   color_indicator: None,
                   content_warning: None,
+                  defense: None,
                   keywords: vec!["Aftermath".into()],
                   card_faces: Some(vec![
                       CardFace {
@@ -591,6 +676,7 @@ mod page {
                           cmc: None,
                           color_indicator: None,
                           colors: None,
+                          defense: None,
                           flavor_text: None,
                           image_uris: None,
                           layout: None,
@@ -656,6 +742,7 @@ mod page {
                   card_back_id: Some(uuid!("0aeebaf5-8c7d-4636-9e82-8c27447861f7")),
                   artist: Some("Kasia 'Kafis' Zielińska".into()),
                   artist_ids: vec![uuid!("a662cb71-4770-4b49-8b03-2cf8497049a7")],
+                  attraction_lights: None,
                   illustration_id: Some(uuid!("3134f77c-7a7d-48e0-99a6-4f323868e1ef")),
                   border_color: "black".into(),
                   frame: "2015".into(),
@@ -731,7 +818,7 @@ mod page {
 
     #[rstest]
     #[tokio::test]
-    async fn test_async_request<'a>(response: &String, card_page: &CardPage) {
+    async fn test_async_request(response: &String, card_page: &CardPage) {
         let server = MockServer::start_async().await;
 
         let resource = CardPageResource::Search(SearchQueryParams {
@@ -762,6 +849,339 @@ mod page {
         endpoint.assert();
         assert_eq!(card_page, &response)
     }
+
+    #[rstest]
+    fn test_blocking_cards_with_keyword(response: &String, card_page: &CardPage) {
+        let server = MockServer::start();
+
+        let endpoint = server.mock(|when, then| {
+            when.method(GET)
+                .path("/cards/search")
+                .query_param("q", "keyword:\"Cascade\"");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = ScryfallBlocking::from_url(&url);
+
+        let cards = client
+            .cards_with_keyword("Cascade")
+            .expect("Expected valid cards");
+
+        endpoint.assert();
+        assert_eq!(card_page.data, cards)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_cards_with_keyword(response: &String, card_page: &CardPage) {
+        let server = MockServer::start_async().await;
+
+        let endpoint = server.mock(|when, then| {
+            when.method(GET)
+                .path("/cards/search")
+                .query_param("q", "keyword:\"Cascade\"");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = Scryfall::from_url(&url);
+
+        let cards = client
+            .cards_with_keyword("Cascade").await
+            .expect("Expected valid cards");
+
+        endpoint.assert();
+        assert_eq!(card_page.data, cards)
+    }
+
+    #[rstest]
+    fn test_blocking_pack_value(response: &String) {
+        let server = MockServer::start();
+
+        let endpoint = server.mock(|when, then| {
+            when.method(GET)
+                .path("/cards/search")
+                .query_param("q", "e:tst is:booster");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = ScryfallBlocking::from_url(&url);
+
+        let value = client
+            .pack_value("tst", &PackModel::new(vec![(Rarity::Rare, 1.0)]))
+            .expect("Expected a valid pack value");
+
+        endpoint.assert();
+        assert_eq!(0.13, value.total)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_pack_value(response: &String) {
+        let server = MockServer::start_async().await;
+
+        let endpoint = server.mock(|when, then| {
+            when.method(GET)
+                .path("/cards/search")
+                .query_param("q", "e:tst is:booster");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = Scryfall::from_url(&url);
+
+        let value = client
+            .pack_value("tst", &PackModel::new(vec![(Rarity::Rare, 1.0)])).await
+            .expect("Expected a valid pack value");
+
+        endpoint.assert();
+        assert_eq!(0.13, value.total)
+    }
+
+    fn paged(response: &str) -> String {
+        response
+            .replace("\"total_cards\": 1", "\"total_cards\": 2")
+            .replace("\"has_more\": false", "\"has_more\": true")
+    }
+
+    #[rstest]
+    fn test_blocking_random_sample(response: &String, card_page: &CardPage) {
+        let server = MockServer::start();
+
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("page", "1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(paged(response));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("page", "2");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = ScryfallBlocking::from_url(&url);
+
+        let cards = client
+            .random_sample(SearchQueryParams::with_q("test"), 5)
+            .expect("Expected valid cards");
+
+        first.assert();
+        second.assert();
+        assert_eq!(vec![card_page.data[0].clone(), card_page.data[0].clone()], cards)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_random_sample(response: &String, card_page: &CardPage) {
+        let server = MockServer::start_async().await;
+
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("page", "1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(paged(response));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("page", "2");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = Scryfall::from_url(&url);
+
+        let cards = client
+            .random_sample(SearchQueryParams::with_q("test"), 5).await
+            .expect("Expected valid cards");
+
+        first.assert();
+        second.assert();
+        assert_eq!(vec![card_page.data[0].clone(), card_page.data[0].clone()], cards)
+    }
+
+    const BULK_DATA: &str = r#"{
+        "object": "bulk_data",
+        "id": "27bf3214-1271-490b-bdfe-c0be6c23d02e",
+        "type": "default_cards",
+        "updated_at": "2022-06-18T09:02:10.928+00:00",
+        "uri": "https://some-url.com",
+        "name": "Default Cards",
+        "description": "A description",
+        "compressed_size": 13976935,
+        "download_uri": "https://some-url.com",
+        "content_type": "application/json",
+        "content_encoding": "gzip"
+    }"#;
+
+    fn linked(response: &str, next_page: &str) -> String {
+        paged(response).replace("\"next_page\": \"http://some.url\"", &format!("\"next_page\": \"{next_page}\""))
+    }
+
+    #[rstest]
+    fn test_blocking_search_export(response: &String, card_page: &CardPage) {
+        let server = MockServer::start();
+        let next_page = server.url("/cards/search?page=2&q=test");
+
+        let bulk = server.mock(|when, then| {
+            when.method(GET).path("/bulk-data/default_cards");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(BULK_DATA);
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("page", "2");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("q", "test");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(linked(response, &next_page));
+        });
+
+        let url = server.base_url();
+        let client = ScryfallBlocking::from_url(&url);
+
+        let export = client
+            .search_export(SearchQueryParams::with_q("test"))
+            .expect("Expected a valid export");
+        let replayed = client
+            .replay_export(&export.manifest)
+            .expect("Expected valid cards");
+
+        bulk.assert();
+        first.assert_hits(2);
+        second.assert_hits(2);
+        assert_eq!(card_page.data, export.cards);
+        assert_eq!(vec![card_page.data[0].clone(), card_page.data[0].clone()], replayed);
+        assert_eq!(next_page, export.manifest.pages[1].as_str());
+        assert_eq!(Some(datetime!(2022-06-18 09:02:10.928 UTC)), export.manifest.bulk_updated_at);
+        assert_eq!((2, 1), (export.manifest.total_cards, export.manifest.cards));
+        assert!(export.manifest.is_complete());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_search_export(response: &String, card_page: &CardPage) {
+        let server = MockServer::start_async().await;
+        let next_page = server.url("/cards/search?page=2&q=test");
+
+        let bulk = server.mock(|when, then| {
+            when.method(GET).path("/bulk-data/default_cards");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(BULK_DATA);
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("page", "2");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("q", "test");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(linked(response, &next_page));
+        });
+
+        let url = server.base_url();
+        let client = Scryfall::from_url(&url);
+
+        let export = client
+            .search_export(SearchQueryParams::with_q("test")).await
+            .expect("Expected a valid export");
+        let replayed = client
+            .replay_export(&export.manifest).await
+            .expect("Expected valid cards");
+
+        bulk.assert();
+        first.assert_hits(2);
+        second.assert_hits(2);
+        assert_eq!(card_page.data, export.cards);
+        assert_eq!(vec![card_page.data[0].clone(), card_page.data[0].clone()], replayed);
+        assert_eq!(next_page, export.manifest.pages[1].as_str());
+        assert_eq!(Some(datetime!(2022-06-18 09:02:10.928 UTC)), export.manifest.bulk_updated_at);
+        assert_eq!((2, 1), (export.manifest.total_cards, export.manifest.cards));
+        assert!(export.manifest.is_complete());
+    }
+
+    fn interrupted(server: &MockServer) -> ExportManifest {
+        serde_json::from_value(serde_json::json!({
+            "query": "q=test",
+            "bulk_updated_at": null,
+            "total_cards": 2,
+            "pages": [server.url("/cards/search?q=test")],
+            "next_page": server.url("/cards/search?page=2&q=test"),
+            "cards": 1,
+            "seen": ["f295b713-1d6a-43fd-910d-fb35414bf58a"]
+        })).unwrap()
+    }
+
+    #[rstest]
+    fn test_blocking_resume_export(response: &String) {
+        let server = MockServer::start();
+
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("page", "2");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = ScryfallBlocking::from_url(&url);
+
+        let export = client
+            .resume_export(interrupted(&server))
+            .expect("Expected a valid export");
+
+        second.assert();
+        assert_eq!(Vec::<Card>::new(), export.cards);
+        assert_eq!((2, 1), (export.manifest.pages.len(), export.manifest.cards));
+        assert!(export.manifest.is_complete());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_resume_export(response: &String) {
+        let server = MockServer::start_async().await;
+
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/cards/search").query_param("page", "2");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = Scryfall::from_url(&url);
+
+        let export = client
+            .resume_export(interrupted(&server)).await
+            .expect("Expected a valid export");
+
+        second.assert();
+        assert_eq!(Vec::<Card>::new(), export.cards);
+        assert_eq!((2, 1), (export.manifest.pages.len(), export.manifest.cards));
+        assert!(export.manifest.is_complete());
+    }
 }
 
 // -- CardCatalogResource tests
@@ -819,7 +1239,7 @@ mod catalog {
 
     #[rstest]
     #[tokio::test]
-    async fn test_async_request<'a>(response: &String, card_catalog: &Catalog) {
+    async fn test_async_request(response: &String, card_catalog: &Catalog) {
         let server = MockServer::start_async().await;
         let resource = CardCatalogResource::Autocomplete("thallid s");
 
@@ -1028,6 +1448,7 @@ mod collection {
                   color_identity: vec![ColorSymbol::W],
                   color_indicator: None,
                   content_warning: None,
+                  defense: None,
                   keywords: vec!["Aftermath".into()],
                   card_faces: Some(vec![
                       CardFace {
@@ -1043,6 +1464,7 @@ mod collection {
                           cmc: None,
                           color_indicator: None,
                           colors: None,
+                          defense: None,
                           flavor_text: None,
                           image_uris: None,
                           layout: None,
@@ -1108,6 +1530,7 @@ mod collection {
                   card_back_id: Some(uuid!("0aeebaf5-8c7d-4636-9e82-8c27447861f7")),
                   artist: Some("Kasia 'Kafis' Zielińska".into()),
                   artist_ids: vec![uuid!("a662cb71-4770-4b49-8b03-2cf8497049a7")],
+                  attraction_lights: None,
                   illustration_id: Some(uuid!("3134f77c-7a7d-48e0-99a6-4f323868e1ef")),
                   border_color: "black".into(),
                   frame: "2015".into(),
@@ -1179,7 +1602,7 @@ mod collection {
 
     #[rstest]
     #[tokio::test]
-    async fn test_async_request<'a>(response: &String, card_collection: &CardCollection) {
+    async fn test_async_request(response: &String, card_collection: &CardCollection) {
         let server = MockServer::start_async().await;
         let resource = CardCollectionResource::WithIdentifiers(
           CardIdentifiers {
@@ -1206,4 +1629,49 @@ mod collection {
         endpoint.assert();
         assert_eq!(card_collection, &response)
     }
+
+    #[rstest]
+    fn test_blocking_convert_ids(response: &String) {
+        let server = MockServer::start();
+
+        let endpoint = server.mock(|when, then| {
+            when.method(POST).path("/cards/collection");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = ScryfallBlocking::from_url(&url);
+
+        let converted = client
+            .convert_ids(&[CardId::Multiverse(567508), CardId::Mtgo(1)], IdKind::Tcgplayer)
+            .expect("Expected converted ids");
+
+        endpoint.assert();
+        assert_eq!(vec![Some(CardId::Tcgplayer(273737)), None], converted)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_async_convert_ids(response: &String) {
+        let server = MockServer::start_async().await;
+
+        let endpoint = server.mock(|when, then| {
+            when.method(POST).path("/cards/collection");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(response);
+        });
+
+        let url = server.base_url();
+        let client = Scryfall::from_url(&url);
+
+        let converted = client
+            .convert_ids(&[CardId::Multiverse(567508), CardId::Mtgo(1)], IdKind::Tcgplayer).await
+            .expect("Expected converted ids");
+
+        endpoint.assert();
+        assert_eq!(vec![Some(CardId::Tcgplayer(273737)), None], converted)
+    }
 }
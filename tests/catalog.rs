@@ -1,3 +1,4 @@
+use std::time::Duration;
 use httpmock::Method::GET;
 use httpmock::MockServer;
 use indoc::indoc;
@@ -8,6 +9,8 @@ use scryfall_sdk_rust::{
     Scryfall,
     ScryfallBlocking,
     HttpResource,
+    client::cache::ResponseCache,
+    word_bank::WordBank,
     resources::{
         catalog::Catalog,
         catalog::CatalogResource,
@@ -119,3 +122,104 @@ async fn test_async_request(response: &String, catalog: &Catalog, #[case]resourc
     endpoint.assert();
     assert_eq!(catalog, &response)
 }
+
+const CREATURE_TYPES: &str = r#"{ "object": "catalog", "total_values": 2, "data": ["Elf", "Goblin"] }"#;
+
+#[rstest]
+fn test_blocking_type_catalog() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/creature-types");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CREATURE_TYPES);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBlocking::from_url(&url);
+
+    let types = client.type_catalog(CatalogResource::CreatureTypes).unwrap();
+
+    endpoint.assert();
+    assert_eq!((2, Some("Goblin")), (types.len(), types.get("goblin")));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_type_catalog() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/creature-types");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CREATURE_TYPES);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url).with_cache(ResponseCache::new(Duration::from_secs(60)));
+
+    let first = client.type_catalog(CatalogResource::CreatureTypes).await.unwrap();
+    let second = client.type_catalog(CatalogResource::CreatureTypes).await.unwrap();
+
+    endpoint.assert_hits(1);
+    assert_eq!(first, second);
+    assert!(second.contains("ELF"));
+}
+
+const WORD_BANK: &str = r#"{ "object": "catalog", "total_values": 3, "data": ["goblin", "lord", "of"] }"#;
+
+fn word_bank_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("scryfall-sdk-{}-{name}-word-bank.json", std::process::id()))
+}
+
+#[rstest]
+fn test_blocking_word_bank() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/word-bank");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(WORD_BANK);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBlocking::from_url(&url);
+    let path = word_bank_path("blocking");
+
+    let fetched = WordBank::fetch_blocking(&client, &path, Duration::from_secs(60)).unwrap();
+    let loaded = WordBank::fetch_blocking(&client, &path, Duration::from_secs(60)).unwrap();
+
+    endpoint.assert_hits(1);
+    assert_eq!(fetched, loaded);
+    assert!(loaded.is_valid_name("Goblin Lord"));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_word_bank() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/word-bank");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(WORD_BANK);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url);
+    let path = word_bank_path("async");
+
+    let fetched = WordBank::fetch(&client, &path, Duration::from_secs(60)).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    let refreshed = WordBank::fetch(&client, &path, Duration::ZERO).await.unwrap();
+
+    endpoint.assert_hits(2);
+    assert_eq!((3, fetched.clone()), (refreshed.len(), refreshed));
+    assert_eq!(vec!["Elf"], fetched.unknown_words("Elf Lord"));
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use rstest::rstest;
+
+fn scryfall(server: &MockServer, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_scryfall"))
+        .args(args)
+        .env("SCRYFALL_API_URL", server.base_url())
+        .current_dir(std::env::temp_dir())
+        .output()
+        .expect("Expected the CLI to run")
+}
+
+#[rstest]
+fn test_complete() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/autocomplete").query_param("q", "light");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(r#"{ "object": "catalog", "total_values": 2, "data": ["Lightning Bolt", "Lightning Helix"] }"#);
+    });
+
+    let output = scryfall(&server, &["complete", "light"]);
+
+    endpoint.assert();
+    assert!(output.status.success());
+    assert_eq!("Lightning Bolt\nLightning Helix\n", String::from_utf8_lossy(&output.stdout));
+}
+
+#[rstest]
+fn test_bulk_download() {
+    let server = MockServer::start();
+    let file = std::env::temp_dir().join(format!("scryfall-sdk-{}-cli-oracle-cards.json", std::process::id()));
+
+    let metadata = server.mock(|when, then| {
+        when.method(GET).path("/bulk-data/oracle_cards");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(format!(r#"{{
+                "object": "bulk_data",
+                "id": "27bf3214-1271-490b-bdfe-c0be6c23d02e",
+                "type": "oracle_cards",
+                "updated_at": "2022-06-18T09:02:10.928+00:00",
+                "uri": "https://some-url.com",
+                "name": "Oracle Cards",
+                "description": "A description",
+                "compressed_size": 2,
+                "download_uri": "{}",
+                "content_type": "application/json",
+                "content_encoding": "gzip"
+            }}"#, server.url("/file/oracle-cards.json")));
+    });
+    let download = server.mock(|when, then| {
+        when.method(GET).path("/file/oracle-cards.json");
+        then.status(200)
+            .header("content-encoding", "gzip")
+            .body("[]");
+    });
+
+    let output = scryfall(&server, &["bulk", "download", "oracle_cards", file.to_str().unwrap()]);
+
+    let mut saved = file.into_os_string();
+    saved.push(".gz");
+
+    metadata.assert();
+    download.assert();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!("[]", std::fs::read_to_string(&saved).unwrap());
+    std::fs::remove_file(saved).unwrap();
+}
+
+#[rstest]
+fn test_invalid_api_url() {
+    let output = Command::new(env!("CARGO_BIN_EXE_scryfall"))
+        .args(["sets"])
+        .env("SCRYFALL_API_URL", "ftp://mirror.local")
+        .output()
+        .expect("Expected the CLI to run");
+
+    assert_eq!(Some(2), output.status.code());
+}
@@ -1,3 +1,5 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use scryfall_sdk_rust::resources::errors::ErrorBody;
 use scryfall_sdk_rust::resources::ResourceKind;
 use httpmock::Method::GET;
@@ -9,6 +11,19 @@ use rstest::{fixture, rstest};
 
 use scryfall_sdk_rust::{Scryfall, ScryfallBlocking};
 use scryfall_sdk_rust::resources::HttpResource;
+use scryfall_sdk_rust::{
+    client::circuit_breaker::CircuitBreaker,
+    client::dump::ResponseDump,
+    client::events::ClientEvent,
+    client::retry::RetryPolicy,
+    resources::cards::SearchQueryParams,
+    resources::catalog::Catalog,
+    resources::validation::ValidationMode,
+    CardPageResource,
+    CardResource,
+    CatalogResource,
+    ScryfallBuilder,
+};
 
 struct TestEndpoint;
 
@@ -41,14 +56,10 @@ fn error_json() -> String {
 
 #[fixture]
 fn error() -> ErrorBody {
-    ErrorBody {
-        code: "not_found".into(),
-        details: "No card found with the given ID or set code and collector number.".into(),
-        error_type: None,
-        kind: ResourceKind::Error,
-        status: 404,
-        warnings: None,
-    }
+    let mut error = ErrorBody::client_error("No card found with the given ID or set code and collector number.");
+    error.code = "not_found".into();
+    error.status = 404;
+    error
 }
 
 #[test]
@@ -118,4 +129,660 @@ async fn test_async_request_responding_error(error_json: String, error: ErrorBod
 
     let error_response = client.request(&TestEndpoint).await.unwrap_err();
     assert_eq!(error, error_response)
-}
\ No newline at end of file
+}
+
+#[fixture]
+#[once]
+fn server_error() -> String {
+    r#"{ "object": "error", "code": "internal_error", "status": 500, "details": "Oops" }"#.into()
+}
+
+#[rstest]
+fn test_blocking_circuit_breaker(server_error: &String) {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(server_error);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new()
+        .base_url(&url)
+        .circuit_breaker(CircuitBreaker::new(2, Duration::from_secs(60)))
+        .build_blocking();
+
+    let codes: Vec<String> = (0..3)
+        .map(|_| client.request::<_, Catalog>(&CatalogResource::Powers).unwrap_err().code)
+        .collect();
+
+    endpoint.assert_hits(2);
+    assert_eq!(vec!["internal_error", "internal_error", "CIRCUIT_OPEN"], codes);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_circuit_breaker(server_error: &String) {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(server_error);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new()
+        .base_url(&url)
+        .circuit_breaker(CircuitBreaker::new(2, Duration::from_secs(60)))
+        .build();
+
+    let mut codes = vec![];
+    for _ in 0..3 {
+        let error = client.request::<_, Catalog>(&CatalogResource::Powers).await.unwrap_err();
+        codes.push(error.code);
+    }
+
+    endpoint.assert_hits(2);
+    assert_eq!(vec!["internal_error", "internal_error", "CIRCUIT_OPEN"], codes);
+}
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy { max_retries: 2, base_delay: Duration::from_millis(1), max_delay: Duration::from_millis(1) }
+}
+
+fn assert_retry_events(url: &str, events: &[ClientEvent]) {
+    let started = ClientEvent::RequestStarted { method: "GET".into(), url: url.into() };
+    let retry = |attempt| ClientEvent::RetryScheduled {
+        url: url.into(), attempt, delay: Duration::from_millis(1)
+    };
+
+    assert_eq!(
+        vec![started.clone(), retry(1), started.clone(), retry(2), started],
+        events
+    );
+}
+
+#[rstest]
+fn test_blocking_retry_events(server_error: &String) {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(server_error);
+    });
+
+    let events = Arc::new(Mutex::new(vec![]));
+    let sink = events.clone();
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new()
+        .base_url(&url)
+        .retry_policy(retry_policy())
+        .on_event(move |e| sink.lock().unwrap().push(e.clone()))
+        .build_blocking();
+
+    let error = client.request::<_, Catalog>(&CatalogResource::Powers).unwrap_err();
+
+    endpoint.assert_hits(3);
+    assert_eq!("internal_error", error.code);
+    assert_retry_events(&format!("{url}/catalog/powers"), &events.lock().unwrap());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_retry_events(server_error: &String) {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(server_error);
+    });
+
+    let events = Arc::new(Mutex::new(vec![]));
+    let sink = events.clone();
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new()
+        .base_url(&url)
+        .retry_policy(retry_policy())
+        .on_event(move |e| sink.lock().unwrap().push(e.clone()))
+        .build();
+
+    let error = client.request::<_, Catalog>(&CatalogResource::Powers).await.unwrap_err();
+
+    endpoint.assert_hits(3);
+    assert_eq!("internal_error", error.code);
+    assert_retry_events(&format!("{url}/catalog/powers"), &events.lock().unwrap());
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct CardName {
+    name: String,
+    mana_cost: Option<String>,
+}
+
+const PARTIAL_CARD: &str = r#"{ "object": "card", "name": "Opt", "mana_cost": "{U}", "cmc": 1.0 }"#;
+
+#[rstest]
+fn test_blocking_request_partial() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/123");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(PARTIAL_CARD);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).build_blocking();
+
+    let card: CardName = client
+        .request_partial(&CardResource::ById("123".into()))
+        .expect("Expected a partial card");
+
+    endpoint.assert();
+    assert_eq!(CardName { name: "Opt".into(), mana_cost: Some("{U}".into()) }, card);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_request_partial(server_error: &String) {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/123");
+
+        then.status(500)
+            .header("content-type", "application/json")
+            .body(server_error);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).build();
+
+    let error = client
+        .request_partial::<CardName, _, _>(&CardResource::ById("123".into())).await
+        .expect_err("Expected an error");
+
+    endpoint.assert();
+    assert_eq!("internal_error", error.code);
+}
+
+const WARNED_PAGE: &str = r#"{
+    "object": "list", "total_cards": 0, "has_more": false, "data": [],
+    "warnings": ["Invalid expression \"foo:bar\" was ignored. Unknown keyword \"foo\"."]
+}"#;
+
+#[rstest]
+fn test_blocking_request_warnings() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/search");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(WARNED_PAGE);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).build_blocking();
+
+    let (_, meta) = client
+        .request_with_meta(&CardPageResource::Search(SearchQueryParams::with_q("foo:bar")))
+        .expect("Expected a card page");
+
+    endpoint.assert();
+    assert_eq!(1, meta.warnings.len());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_request_warnings() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/search");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(WARNED_PAGE);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).build();
+
+    let (page, meta) = client
+        .request_with_meta(&CardPageResource::Search(SearchQueryParams::with_q("foo:bar"))).await
+        .expect("Expected a card page");
+
+    endpoint.assert();
+    assert!(page.data.is_empty());
+    assert!(meta.warnings[0].contains("foo:bar"));
+}
+
+const INVALID_CATALOG: &str = r#"{ "object": "catalog", "total_values": 3, "data": ["1", "2"] }"#;
+
+#[rstest]
+fn test_blocking_strict_validation() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(INVALID_CATALOG);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new()
+        .base_url(&url)
+        .validation(ValidationMode::Strict)
+        .build_blocking();
+
+    let error = client.request::<_, Catalog>(&CatalogResource::Powers).unwrap_err();
+
+    endpoint.assert();
+    assert_eq!("INVALID_RESPONSE", error.code);
+    assert_eq!(
+        Some(vec!["total_values (3) does not match the catalog values (2)".to_string()]),
+        error.warnings
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_warn_validation() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(INVALID_CATALOG);
+    });
+
+    let events = Arc::new(Mutex::new(vec![]));
+    let sink = events.clone();
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new()
+        .base_url(&url)
+        .validation(ValidationMode::Warn)
+        .on_event(move |e| sink.lock().unwrap().push(e.clone()))
+        .build();
+
+    let catalog = client.request::<_, Catalog>(&CatalogResource::Powers).await
+        .expect("Expected a catalog");
+
+    endpoint.assert();
+    assert_eq!(2, catalog.data.len());
+    assert!(events.lock().unwrap().contains(&ClientEvent::InvalidResponse {
+        url: format!("{url}/catalog/powers"),
+        violations: vec!["total_values (3) does not match the catalog values (2)".into()],
+    }));
+}
+
+const CARD_LIST: &str = r#"{ "object": "list", "has_more": false, "data": [] }"#;
+
+#[rstest]
+fn test_blocking_unexpected_object() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/123");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CARD_LIST);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).build_blocking();
+
+    let error = client.request(&CardResource::ById("123".into())).unwrap_err();
+
+    endpoint.assert();
+    assert_eq!("UNEXPECTED_OBJECT", error.code);
+    assert_eq!("Expected object `card`, got `list`", error.details);
+    assert_eq!(Some("GET cards/123"), error.request());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_unexpected_object() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/123");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CARD_LIST);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).build();
+
+    let error = client.request(&CardResource::ById("123".into())).await.unwrap_err();
+
+    endpoint.assert();
+    assert_eq!("UNEXPECTED_OBJECT", error.code);
+    assert_eq!("Expected object `card`, got `list`", error.details);
+    assert_eq!(Some("GET cards/123"), error.request());
+}
+
+#[rstest]
+fn test_blocking_timeout() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(Duration::from_millis(500))
+            .body(r#"{ "object": "catalog", "total_values": 0, "data": [] }"#);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new()
+        .base_url(&url)
+        .timeout(Duration::from_millis(50))
+        .build_blocking();
+
+    let error = client.request::<_, Catalog>(&CatalogResource::Powers).unwrap_err();
+
+    endpoint.assert();
+    assert_eq!(599, error.status);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_timeout() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(Duration::from_millis(500))
+            .body(r#"{ "object": "catalog", "total_values": 0, "data": [] }"#);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new()
+        .base_url(&url)
+        .timeout(Duration::from_millis(50))
+        .build();
+
+    let error = client.request::<_, Catalog>(&CatalogResource::Powers).await.unwrap_err();
+
+    endpoint.assert();
+    assert_eq!(599, error.status);
+}
+
+#[rstest]
+fn test_blocking_prepare() {
+    let client = ScryfallBuilder::new().user_agent("test-agent").build_blocking();
+
+    let request = client.prepare(&CardResource::NamedExact("Lightning Bolt".into())).unwrap();
+
+    assert_eq!("GET", request.method.as_str());
+    assert_eq!("https://api.scryfall.com/cards/named?exact=Lightning%20Bolt", request.url);
+    assert_eq!("test-agent", request.headers["user-agent"]);
+    assert_eq!("application/json", request.headers["content-type"]);
+    assert_eq!(None, request.body);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_prepare() {
+    let client = ScryfallBuilder::new().build();
+
+    let request = client.prepare(&CardResource::NamedExact("Lightning Bolt".into())).unwrap();
+
+    assert_eq!("GET", request.method.as_str());
+    assert_eq!("https://api.scryfall.com/cards/named?exact=Lightning%20Bolt", request.url);
+    assert_eq!("Rust-SDK: async-clients", request.headers["user-agent"]);
+    assert_eq!(None, request.body);
+}
+
+#[cfg(feature = "http")]
+#[rstest]
+fn test_blocking_decode(server_error: &String) {
+    let client = ScryfallBuilder::new().build_blocking();
+
+    let request = client.prepare(&CatalogResource::Powers).unwrap().to_http().unwrap();
+    let ok = http::Response::new(br#"{ "object": "catalog", "total_values": 1, "data": ["1"] }"#.to_vec());
+    let err = http::Response::builder().status(500).body(server_error.as_bytes().to_vec()).unwrap();
+
+    assert_eq!("https://api.scryfall.com/catalog/powers", request.uri().to_string());
+    assert_eq!(vec!["1"], client.decode::<_, Catalog>(&CatalogResource::Powers, ok).unwrap().data);
+    assert_eq!("internal_error", client.decode::<_, Catalog>(&CatalogResource::Powers, err).unwrap_err().code);
+}
+
+#[cfg(feature = "http")]
+#[rstest]
+#[tokio::test]
+async fn test_async_decode() {
+    let client = ScryfallBuilder::new()
+        .validation(ValidationMode::Strict)
+        .max_response_size(64)
+        .build();
+
+    let request = client.prepare(&CatalogResource::Powers).unwrap().to_http().unwrap();
+    let invalid = http::Response::new(INVALID_CATALOG.as_bytes().to_vec());
+    let large = http::Response::new(vec![b' '; 65]);
+
+    assert_eq!("GET", request.method().as_str());
+    assert_eq!("INVALID_RESPONSE", client.decode::<_, Catalog>(&CatalogResource::Powers, invalid).unwrap_err().code);
+    assert_eq!("RESPONSE_TOO_LARGE", client.decode::<_, Catalog>(&CatalogResource::Powers, large).unwrap_err().code);
+}
+
+#[cfg(feature = "http")]
+#[rstest]
+#[case::bad_gateway(502, "<html>Bad Gateway</html>")]
+#[case::no_content(204, "")]
+#[case::not_an_error_object(503, r#"{ "message": "Service Unavailable" }"#)]
+fn test_blocking_decode_http_error(#[case] status: u16, #[case] body: &str) {
+    let client = ScryfallBuilder::new().build_blocking();
+    let response = http::Response::builder().status(status).body(body.as_bytes().to_vec()).unwrap();
+
+    let error = client.decode::<_, Catalog>(&CatalogResource::Powers, response).unwrap_err();
+
+    assert_eq!("HTTP_ERROR", error.code);
+    assert_eq!(status as i16, error.status);
+}
+
+#[rstest]
+#[case::bad_gateway(502, "<html>Bad Gateway</html>")]
+#[case::no_content(204, "")]
+#[case::not_an_error_object(503, r#"{ "message": "Service Unavailable" }"#)]
+#[tokio::test]
+async fn test_async_request_http_error(#[case] status: u16, #[case] body: &str) {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/catalog/powers");
+        then.status(status).body(body);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url);
+
+    let error = client.request(&CatalogResource::Powers).await.unwrap_err();
+
+    assert_eq!("HTTP_ERROR", error.code);
+    assert_eq!(status as i16, error.status);
+    assert_eq!(Some("GET catalog/powers"), error.request());
+}
+
+#[rstest]
+fn test_blocking_max_response_size() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/123");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CARD_LIST);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).max_response_size(16).build_blocking();
+
+    let error = client.request(&CardResource::ById("123".into())).unwrap_err();
+
+    endpoint.assert();
+    assert_eq!("RESPONSE_TOO_LARGE", error.code);
+    assert_eq!(599, error.status);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_max_response_size() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/123");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CARD_LIST);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).max_response_size(16).build();
+
+    let error = client.request(&CardResource::ById("123".into())).await.unwrap_err();
+
+    endpoint.assert();
+    assert_eq!("RESPONSE_TOO_LARGE", error.code);
+    assert_eq!(599, error.status);
+}
+
+fn dump_files(dump: &ResponseDump) -> Vec<(String, String)> {
+    let mut files: Vec<(String, String)> = std::fs::read_dir(dump.dir()).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .map(|path| (path.file_name().unwrap().to_string_lossy().into_owned(), std::fs::read_to_string(path).unwrap()))
+        .collect();
+    files.sort();
+    files
+}
+
+#[rstest]
+fn test_blocking_dump_responses() {
+    let server = MockServer::start();
+    let dump = ResponseDump::new(std::env::temp_dir().join(format!("scryfall-sdk-{}-dump-blocking", std::process::id())));
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/card-names");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CARD_LIST);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).dump_responses(dump.clone()).build_blocking();
+
+    assert!(client.request(&CatalogResource::CardNames).is_err());
+
+    endpoint.assert();
+    let files = dump_files(&dump);
+    assert_eq!(1, files.len());
+    assert!(files[0].0.ends_with("-200-get-catalog-card-names.json"));
+    assert_eq!(CARD_LIST, files[0].1);
+
+    std::fs::remove_dir_all(dump.dir()).unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_dump_responses() {
+    let server = MockServer::start_async().await;
+    let dump = ResponseDump::new(std::env::temp_dir().join(format!("scryfall-sdk-{}-dump-async", std::process::id())));
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/catalog/card-names");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CARD_LIST);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBuilder::new().base_url(&url).dump_responses(dump.clone()).build();
+
+    assert!(client.request(&CatalogResource::CardNames).await.is_err());
+
+    endpoint.assert();
+    let files = dump_files(&dump);
+    assert_eq!(1, files.len());
+    assert!(files[0].0.ends_with("-200-get-catalog-card-names.json"));
+    assert_eq!(CARD_LIST, files[0].1);
+
+    std::fs::remove_dir_all(dump.dir()).unwrap();
+}
+
+#[rstest]
+fn test_blocking_base_url_prefix() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/scryfall/cards/123");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CARD_LIST);
+    });
+
+    let url = server.url("/scryfall/");
+    let client = ScryfallBuilder::new().try_base_url(&url).expect("Expected a valid base URL").build_blocking();
+
+    let _ = client.request(&CardResource::ById("123".into()));
+
+    endpoint.assert();
+    assert_eq!("INVALID_INPUT", ScryfallBuilder::new().try_base_url("ftp://some.url").unwrap_err().code);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_base_url_prefix() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/scryfall/cards/123");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(CARD_LIST);
+    });
+
+    let url = server.url("/scryfall/");
+    let client = ScryfallBuilder::new().try_base_url(&url).expect("Expected a valid base URL").build();
+
+    let _ = client.request(&CardResource::ById("123".into())).await;
+
+    endpoint.assert();
+    assert_eq!("INVALID_INPUT", ScryfallBuilder::new().try_base_url("ftp://some.url").unwrap_err().code);
+}
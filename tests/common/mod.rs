@@ -0,0 +1,11 @@
+//! Shared fixtures for integration tests
+#![allow(dead_code, unused_imports)]
+
+use serde_json::{json, Value};
+
+pub use scryfall_sdk_rust::testing::{card, card_json};
+
+/// Builds the json of a list of cards (in a single page)
+pub fn card_list(cards: &[Value]) -> String {
+    json!({ "object": "list", "total_cards": cards.len(), "has_more": false, "data": cards }).to_string()
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use httpmock::Method::POST;
+use httpmock::MockServer;
+use rstest::{fixture, rstest};
+use serde_json::json;
+
+use scryfall_sdk_rust::{
+    deck::list::{DeckList, DeckListEntry, ResolvedDeck},
+    Scryfall,
+    ScryfallBlocking,
+};
+
+#[fixture]
+#[once]
+fn response() -> String {
+    json!({
+        "object": "list",
+        "not_found": [{ "name": "Missing Card" }],
+        "data": [common::card_json(json!({ "name": "Lightning Bolt", "set": "m10", "collector_number": "146" }))]
+    }).to_string()
+}
+
+#[fixture]
+fn deck_list() -> DeckList {
+    "4 Lightning Bolt (M10) 146\n1 Missing Card".parse().unwrap()
+}
+
+fn assert_resolved(resolved: ResolvedDeck) {
+    assert_eq!(1, resolved.deck.mainboard.len());
+    assert_eq!(4, resolved.deck.mainboard[0].quantity);
+    assert_eq!("Lightning Bolt", resolved.deck.mainboard[0].card.name);
+    assert_eq!(vec![DeckListEntry {
+        quantity: 1,
+        name: "Missing Card".into(),
+        set: None,
+        collector_number: None,
+    }], resolved.not_found);
+}
+
+#[rstest]
+fn test_blocking_resolve(response: &String, deck_list: DeckList) {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(POST)
+            .path("/cards/collection")
+            .body(r#"{"identifiers":[{"set":"m10","collector_number":"146"},{"name":"Missing Card"}]}"#);
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(response);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBlocking::from_url(&url);
+
+    let resolved = deck_list
+        .resolve_blocking(&client)
+        .expect("Expected a resolved deck");
+
+    endpoint.assert();
+    assert_resolved(resolved);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_resolve(response: &String, deck_list: DeckList) {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(POST)
+            .path("/cards/collection")
+            .body(r#"{"identifiers":[{"set":"m10","collector_number":"146"},{"name":"Missing Card"}]}"#);
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(response);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url);
+
+    let resolved = deck_list
+        .resolve(&client).await
+        .expect("Expected a resolved deck");
+
+    endpoint.assert();
+    assert_resolved(resolved);
+}
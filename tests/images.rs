@@ -0,0 +1,142 @@
+mod common;
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use rstest::rstest;
+use serde_json::json;
+
+use scryfall_sdk_rust::{
+    assets::{AssetCache, AssetStatus},
+    images::{prefetch, prefetch_blocking, ImageCache, ImageSize},
+    resources::cards::Card,
+    Scryfall,
+    ScryfallBlocking,
+};
+
+fn card(base_url: &str) -> Card {
+    let image = |name: &str| format!("{base_url}/{name}");
+
+    common::card(json!({
+        "image_uris": {
+            "small": image("small.jpg"),
+            "normal": image("normal.jpg"),
+            "large": image("large.jpg"),
+            "png": image("png.png"),
+            "art_crop": image("art_crop.jpg"),
+            "border_crop": image("border_crop.jpg")
+        }
+    }))
+}
+
+fn dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("scryfall-sdk-{}-prefetch-{name}", std::process::id()))
+}
+
+#[rstest]
+fn test_blocking_prefetch() {
+    let server = MockServer::start();
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/normal.jpg");
+        then.status(200).body("image");
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBlocking::from_url(&url);
+    let cache = ImageCache::new(dir("blocking"));
+    let cards = vec![card(&url)];
+    let progress = AtomicUsize::new(0);
+
+    let first = prefetch_blocking(&client, &cards, ImageSize::Normal, &cache, 4, |done, _| progress.store(done, Ordering::SeqCst));
+    let second = prefetch_blocking(&client, &cards, ImageSize::Normal, &cache, 4, |_, _| {});
+
+    endpoint.assert_hits(1);
+    assert_eq!((1, 0), (first.downloaded, first.cached));
+    assert_eq!((0, 1), (second.downloaded, second.cached));
+    assert_eq!(1, progress.load(Ordering::SeqCst));
+    assert_eq!("image", std::fs::read_to_string(cache.path(&cards[0], 0, ImageSize::Normal)).unwrap());
+
+    std::fs::remove_dir_all(dir("blocking")).unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_prefetch() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/small.jpg");
+        then.status(404);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url);
+    let cache = ImageCache::new(dir("async"));
+    let cards = vec![card(&url)];
+
+    let report = prefetch(&client, &cards, ImageSize::Small, &cache, 4, |_, _| {}).await;
+
+    endpoint.assert();
+    assert_eq!(0, report.downloaded);
+    assert_eq!(1, report.failed.len());
+    assert_eq!(None, cache.get(&cards[0], 0, ImageSize::Small));
+}
+
+#[rstest]
+fn test_blocking_conditional_asset() {
+    let server = MockServer::start();
+
+    let mut download = server.mock(|when, then| {
+        when.method(GET).path("/sets/dom.svg");
+        then.status(200).header("etag", "\"v1\"").body("<svg/>");
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBlocking::from_url(&url);
+    let cache = AssetCache::new(dir("asset-blocking"));
+    let icon = format!("{url}/sets/dom.svg").parse().unwrap();
+
+    let first = cache.fetch_blocking(&client, &icon).expect("Expected an asset");
+    download.assert();
+    download.delete();
+
+    let revalidation = server.mock(|when, then| {
+        when.method(GET).path("/sets/dom.svg").header("if-none-match", "\"v1\"");
+        then.status(304);
+    });
+
+    let second = cache.fetch_blocking(&client, &icon).expect("Expected an asset");
+
+    revalidation.assert();
+    assert_eq!((AssetStatus::Downloaded, AssetStatus::NotModified), (first.status, second.status));
+    assert_eq!("<svg/>", std::fs::read_to_string(second.path).unwrap());
+
+    std::fs::remove_dir_all(dir("asset-blocking")).unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_conditional_asset() {
+    let server = MockServer::start_async().await;
+
+    let endpoint = server.mock(|when, then| {
+        when.method(GET).path("/card-symbols/W.svg");
+        then.status(200).header("last-modified", "Sun, 06 Nov 1994 08:49:37 GMT").body("<svg>W</svg>");
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url);
+    let cache = AssetCache::new(dir("asset-async"));
+    let symbol = format!("{url}/card-symbols/W.svg").parse().unwrap();
+    cache.insert(&symbol, b"<svg>old</svg>", &Default::default()).unwrap();
+
+    let asset = cache.fetch(&client, &symbol).await.expect("Expected an asset");
+
+    endpoint.assert();
+    assert_eq!(AssetStatus::Downloaded, asset.status);
+    assert_eq!(Some("Sun, 06 Nov 1994 08:49:37 GMT".into()), cache.validators(&symbol).and_then(|v| v.last_modified));
+
+    std::fs::remove_dir_all(dir("asset-async")).unwrap();
+}
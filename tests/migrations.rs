@@ -0,0 +1,99 @@
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use rstest::{fixture, rstest};
+use time::macros::date;
+
+use scryfall_sdk_rust::{Scryfall, ScryfallBlocking};
+
+fn migration(performed_at: &str) -> String {
+    format!(r#"{{
+        "object": "migration",
+        "id": "00000000-0000-0000-0000-000000000001",
+        "uri": "http://some.url",
+        "performed_at": "{performed_at}",
+        "migration_strategy": "delete",
+        "old_scryfall_id": "00000000-0000-0000-0000-000000000002",
+        "new_scryfall_id": null,
+        "note": "Duplicate"
+    }}"#)
+}
+
+fn page(has_more: bool, dates: &[&str]) -> String {
+    let data: Vec<String> = dates.iter().map(|d| migration(d)).collect();
+
+    format!(r#"{{ "object": "list", "has_more": {has_more}, "data": [{}] }}"#, data.join(","))
+}
+
+#[fixture]
+#[once]
+fn pages() -> (String, String) {
+    (
+        page(true, &["2024-03-01", "2024-02-01"]),
+        page(true, &["2024-01-15", "2023-12-01"]),
+    )
+}
+
+#[rstest]
+fn test_blocking_migrations_since(pages: &(String, String)) {
+    let server = MockServer::start();
+
+    let first = server.mock(|when, then| {
+        when.method(GET).path("/migrations").query_param("page", "1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(&pages.0);
+    });
+    let second = server.mock(|when, then| {
+        when.method(GET).path("/migrations").query_param("page", "2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(&pages.1);
+    });
+
+    let url = server.base_url();
+    let client = ScryfallBlocking::from_url(&url);
+
+    let migrations = client
+        .migrations_since(Some(date!(2024-01-01)))
+        .expect("Expected valid migrations");
+
+    first.assert();
+    second.assert();
+    assert_eq!(
+        vec![date!(2024-03-01), date!(2024-02-01), date!(2024-01-15)],
+        migrations.iter().map(|m| m.performed_at).collect::<Vec<_>>()
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_migrations_since(pages: &(String, String)) {
+    let server = MockServer::start_async().await;
+
+    let first = server.mock(|when, then| {
+        when.method(GET).path("/migrations").query_param("page", "1");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(&pages.0);
+    });
+    let second = server.mock(|when, then| {
+        when.method(GET).path("/migrations").query_param("page", "2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(&pages.1);
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url);
+
+    let migrations = client
+        .migrations_since(Some(date!(2024-01-01))).await
+        .expect("Expected valid migrations");
+
+    first.assert();
+    second.assert();
+    assert_eq!(
+        vec![date!(2024-03-01), date!(2024-02-01), date!(2024-01-15)],
+        migrations.iter().map(|m| m.performed_at).collect::<Vec<_>>()
+    );
+}
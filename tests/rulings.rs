@@ -54,8 +54,9 @@ fn ruling_list() -> RulingList {
 }
 
 #[rstest]
-#[case::by_card_id(RulingListResource::ByCardId("id"))]
-#[case::by_set_code(RulingListResource::BySetCode("code", 123))]
+#[case::by_card_id(RulingListResource::ByCardId("id".into()))]
+#[case::by_set_code(RulingListResource::BySetCode("code".into(), "123".into()))]
+#[case::by_variant_number(RulingListResource::by_set_code("clb", "120a").unwrap())]
 #[case::by_arena_id(RulingListResource::ByArenaId(123))]
 #[case::by_mtgo_id(RulingListResource::ByMtgoId(123))]
 #[case::by_multiverse_id(RulingListResource::ByMultiverseId(123))]
@@ -87,8 +88,9 @@ fn test_blocking_request(
 }
 
 #[rstest]
-#[case::by_card_id(RulingListResource::ByCardId("id"))]
-#[case::by_set_code(RulingListResource::BySetCode("code", 123))]
+#[case::by_card_id(RulingListResource::ByCardId("id".into()))]
+#[case::by_set_code(RulingListResource::BySetCode("code".into(), "123".into()))]
+#[case::by_variant_number(RulingListResource::by_set_code("clb", "120a").unwrap())]
 #[case::by_arena_id(RulingListResource::ByArenaId(123))]
 #[case::by_mtgo_id(RulingListResource::ByMtgoId(123))]
 #[case::by_multiverse_id(RulingListResource::ByMultiverseId(123))]
@@ -0,0 +1,56 @@
+mod common;
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use rstest::rstest;
+use serde_json::{json, Value};
+
+use scryfall_sdk_rust::{watch::Watcher, Scryfall};
+
+fn card(id: &str, name: &str) -> Value {
+    common::card_json(json!({ "id": id, "name": name, "set": "dmu", "set_name": "Dominaria United" }))
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_async_poll() {
+    let server = MockServer::start_async().await;
+    let first = card("00000000-0000-0000-0000-000000000001", "First Preview");
+    let second = card("00000000-0000-0000-0000-000000000002", "Second Preview");
+
+    let mut endpoint = server.mock(|when, then| {
+        when.method(GET)
+            .path("/cards/search")
+            .query_param("q", "e:dmu")
+            .query_param("order", "spoiled")
+            .query_param("dir", "desc");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(common::card_list(std::slice::from_ref(&first)));
+    });
+
+    let url = server.base_url();
+    let client = Scryfall::from_url(&url);
+    let mut watcher = Watcher::set(&client, "dmu");
+
+    let cards = watcher.poll().await.expect("Expected a first poll");
+    assert_eq!(vec!["First Preview"], cards.iter().map(|c| c.name.as_str()).collect::<Vec<_>>());
+
+    let cards = watcher.poll().await.expect("Expected a second poll");
+    assert!(cards.is_empty());
+    endpoint.assert_hits(2);
+    endpoint.delete();
+
+    endpoint = server.mock(|when, then| {
+        when.method(GET).path("/cards/search");
+
+        then.status(200)
+            .header("content-type", "application/json")
+            .body(common::card_list(&[second, first]));
+    });
+
+    let cards = watcher.poll().await.expect("Expected a third poll");
+    endpoint.assert();
+    assert_eq!(vec!["Second Preview"], cards.iter().map(|c| c.name.as_str()).collect::<Vec<_>>());
+}